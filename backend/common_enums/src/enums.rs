@@ -1026,6 +1026,7 @@ pub enum PaymentMethodType {
     Twint,
     UpiCollect,
     UpiIntent,
+    UpiQr,
     Vipps,
     VietQr,
     Venmo,
@@ -1415,6 +1416,7 @@ pub enum EventClass {
     Payments,
     Refunds,
     Disputes,
+    Settlements,
 }
 
 #[derive(
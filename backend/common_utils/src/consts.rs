@@ -39,6 +39,31 @@ pub const X_CONNECTOR: &str = "x-connector";
 pub const X_MERCHANT_ID: &str = "x-merchant-id";
 /// Header key for reference identification
 pub const X_REFERENCE_ID: &str = "x-reference-id";
+/// Header carrying the client's gRPC deadline as a timeout relative to the request
+pub const GRPC_TIMEOUT: &str = "grpc-timeout";
+/// Header carrying the originating client address through proxies
+pub const X_FORWARDED_FOR: &str = "x-forwarded-for";
+/// Response header carrying the build version of the service
+pub const X_SERVICE_VERSION: &str = "x-service-version";
+/// Request header naming the language error messages are returned in, e.g. `es` or `fr-CA`
+pub const X_LOCALE: &str = "x-locale";
+/// Header overriding the timeout of the outbound connector call in milliseconds, honored
+/// for test mode payments only
+pub const X_CONNECTOR_TIMEOUT_MS: &str = "x-connector-timeout-ms";
+/// Largest connector call timeout `x-connector-timeout-ms` can request
+pub const MAX_CONNECTOR_TIMEOUT_MS: u64 = 120_000;
+/// Header overriding the timeout of the whole request in milliseconds, bounded by
+/// `timeout.max_ms`
+pub const X_TIMEOUT_MS: &str = "x-timeout-ms";
+/// Default bound on the request timeout `x-timeout-ms` can request
+pub const MAX_REQUEST_TIMEOUT_MS: u64 = 300_000;
+/// Time in-flight requests get to complete after a shutdown signal, unless configured
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS: u64 = 30;
+/// Time of connector calls the latency quantile gauges cover, unless configured
+pub const DEFAULT_LATENCY_QUANTILE_WINDOW_SECS: u64 = 300;
+/// Header pointing a single request at another base url of the connector, one of the
+/// connector's configured `allowed_base_url_overrides`
+pub const X_CONNECTOR_BASE_URL: &str = "x-connector-base-url";
 
 // =============================================================================
 // Authentication Headers (Internal)
@@ -130,3 +130,104 @@ pub mod iso8601 {
         }
     }
 }
+
+/// Serialize [`MinorUnit`][MinorUnit] amounts as JSON strings instead of numbers.
+///
+/// Consumers that parse JSON numbers as doubles lose precision on large amounts. The string
+/// representation is opt-in per thread through [`scope`], so that request bodies sent to
+/// connectors keep their numeric amounts while copies written to logs and events do not.
+///
+/// [MinorUnit]: crate::types::MinorUnit
+pub mod minor_unit_as_string {
+    use std::cell::Cell;
+
+    use serde::Serializer;
+
+    thread_local! {
+        static ENABLED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Restores the previous setting when the scope ends, even on unwinding.
+    struct ScopeGuard {
+        previous: bool,
+    }
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            ENABLED.with(|enabled| enabled.set(self.previous));
+        }
+    }
+
+    /// Runs `f` with [`MinorUnit`][crate::types::MinorUnit] amounts serialized as strings on
+    /// the current thread when `enabled` is set.
+    pub fn scope<R>(enabled: bool, f: impl FnOnce() -> R) -> R {
+        let _guard = ScopeGuard {
+            previous: ENABLED.with(|current| current.replace(enabled)),
+        };
+        f()
+    }
+
+    /// Whether amounts are currently serialized as strings on this thread.
+    pub fn is_enabled() -> bool {
+        ENABLED.with(Cell::get)
+    }
+
+    /// Serialize a minor unit amount as a string, or as a number outside of a [`scope`].
+    pub fn serialize<S>(amount: &i64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if is_enabled() {
+            serializer.collect_str(amount)
+        } else {
+            serializer.serialize_i64(*amount)
+        }
+    }
+}
+
+#[cfg(test)]
+mod minor_unit_as_string_tests {
+    #![allow(clippy::unwrap_used)]
+    use serde_json::json;
+
+    use super::minor_unit_as_string;
+    use crate::types::MinorUnit;
+
+    #[derive(serde::Serialize)]
+    struct Amount {
+        value: MinorUnit,
+    }
+
+    #[test]
+    fn serializes_minor_unit_as_string_when_enabled() {
+        let amount = Amount {
+            value: MinorUnit::new(9_007_199_254_740_993),
+        };
+
+        let value = minor_unit_as_string::scope(true, || serde_json::to_value(&amount)).unwrap();
+
+        assert_eq!(value, json!({ "value": "9007199254740993" }));
+    }
+
+    #[test]
+    fn serializes_minor_unit_as_number_by_default() {
+        let amount = Amount {
+            value: MinorUnit::new(1000),
+        };
+
+        let value = serde_json::to_value(&amount).unwrap();
+
+        assert_eq!(value, json!({ "value": 1000 }));
+        assert!(!minor_unit_as_string::is_enabled());
+    }
+
+    #[test]
+    fn restores_previous_setting_after_scope() {
+        minor_unit_as_string::scope(true, || {
+            minor_unit_as_string::scope(false, || assert!(!minor_unit_as_string::is_enabled()));
+            assert!(minor_unit_as_string::is_enabled());
+        });
+
+        assert!(!minor_unit_as_string::is_enabled());
+    }
+}
@@ -6,11 +6,13 @@ use serde_json;
 use tracing_kafka::{builder::KafkaWriterBuilder, KafkaWriter};
 
 use crate::{
-    events::{Event, EventConfig},
+    events::{AuditEvent, DeclineEvent, Event, EventConfig},
     CustomResult, EventPublisherError,
 };
 
 const PARTITION_KEY_METADATA: &str = "partitionKey";
+const AUDIT_PARTITION_KEY_FIELD: &str = "request_id";
+const DECLINE_PARTITION_KEY_FIELD: &str = "request_id";
 
 /// Global static EventPublisher instance
 static EVENT_PUBLISHER: OnceCell<EventPublisher> = OnceCell::new();
@@ -78,12 +80,13 @@ impl EventPublisher {
         })
     }
 
-    /// Publishes a single event to Kafka with metadata as headers.
+    /// Publishes a single event to Kafka with metadata and the given static headers as headers.
     pub async fn publish_event(
         &self,
         event: serde_json::Value,
         topic: &str,
         partition_key_field: &str,
+        static_headers: &[(&str, &str)],
     ) -> CustomResult<(), EventPublisherError> {
         tracing::debug!(
             topic = %topic,
@@ -91,47 +94,25 @@ impl EventPublisher {
             "Starting event publication to Kafka"
         );
 
-        let mut headers: OwnedHeaders = OwnedHeaders::new();
-
-        let key = if let Some(partition_key_value) =
-            event.get(partition_key_field).and_then(|v| v.as_str())
-        {
-            headers = headers.insert(Header {
-                key: PARTITION_KEY_METADATA,
-                value: Some(partition_key_value.as_bytes()),
-            });
-            Some(partition_key_value)
-        } else {
-            tracing::warn!(
-                partition_key_field = %partition_key_field,
-                "Partition key field not found in event, message will be published without key"
-            );
-            None
-        };
+        let message = EventMessage::new(event, partition_key_field, static_headers)?;
+        self.publish_message(message, topic)
+    }
 
-        let event_bytes = serde_json::to_vec(&event).map_err(|e| {
-            tracing::error!(
-                error = ?e,
-                request_id = %event.get("request_id").unwrap_or(&serde_json::Value::Null),
-                connector = %event.get("connector").unwrap_or(&serde_json::Value::Null),
-                flow_type = %event.get("flow_type").unwrap_or(&serde_json::Value::Null),
-                "Failed to serialize audit event to JSON bytes"
-            );
-            error_stack::Report::new(EventPublisherError::EventSerializationFailed)
-                .attach_printable(format!("Serialization error: {e}"))
-                .attach_printable(format!(
-                    "Event context: request_id={}, connector={}, flow_type={}, txn_uuid={}",
-                    event.get("request_id").unwrap_or(&serde_json::Value::Null),
-                    event.get("connector").unwrap_or(&serde_json::Value::Null),
-                    event.get("flow_type").unwrap_or(&serde_json::Value::Null),
-                    event
-                        .get("udf_txn_uuid")
-                        .unwrap_or(&serde_json::Value::Null)
-                ))
-        })?;
+    /// Hands a built message to the Kafka writer
+    fn publish_message(
+        &self,
+        message: EventMessage,
+        topic: &str,
+    ) -> CustomResult<(), EventPublisherError> {
+        let EventMessage {
+            event,
+            key,
+            payload,
+            headers,
+        } = message;
 
         self.writer
-            .publish_event(&self.config.topic, key, &event_bytes, Some(headers))
+            .publish_event(topic, key.as_deref(), &payload, Some(headers))
             .map_err(|e| {
                 tracing::error!(
                     error = ?e,
@@ -139,7 +120,7 @@ impl EventPublisher {
                     request_id = %event.get("request_id").unwrap_or(&serde_json::Value::Null),
                     connector = %event.get("connector").unwrap_or(&serde_json::Value::Null),
                     flow_type = %event.get("flow_type").unwrap_or(&serde_json::Value::Null),
-                    event_size = event_bytes.len(),
+                    event_size = payload.len(),
                     "Failed to publish audit event - critical data may be lost"
                 );
                 error_stack::Report::new(EventPublisherError::EventPublishFailed)
@@ -170,13 +151,98 @@ impl EventPublisher {
         base_event: Event,
         config: &EventConfig,
     ) -> CustomResult<(), EventPublisherError> {
-        let processed_event = self.process_event(&base_event)?;
+        let message = EventMessage::connector_event(&base_event, config)?;
+        self.publish_message(message, &config.topic)
+    }
+}
+
+/// A Kafka message as it is handed to the writer
+#[derive(Debug)]
+pub struct EventMessage {
+    /// The event the message was built from, kept for error context
+    event: serde_json::Value,
+    pub key: Option<String>,
+    pub payload: Vec<u8>,
+    pub headers: OwnedHeaders,
+}
+
+impl EventMessage {
+    /// Builds the message of an event, keyed by its partition key field, with the partition
+    /// key and the given static headers as headers
+    pub fn new(
+        event: serde_json::Value,
+        partition_key_field: &str,
+        static_headers: &[(&str, &str)],
+    ) -> CustomResult<Self, EventPublisherError> {
+        let mut headers: OwnedHeaders = OwnedHeaders::new();
+
+        let key = if let Some(partition_key_value) =
+            event.get(partition_key_field).and_then(|v| v.as_str())
+        {
+            headers = headers.insert(Header {
+                key: PARTITION_KEY_METADATA,
+                value: Some(partition_key_value.as_bytes()),
+            });
+            Some(partition_key_value.to_string())
+        } else {
+            tracing::warn!(
+                partition_key_field = %partition_key_field,
+                "Partition key field not found in event, message will be published without key"
+            );
+            None
+        };
+        for (header_key, header_value) in static_headers {
+            headers = headers.insert(Header {
+                key: header_key,
+                value: Some(header_value.as_bytes()),
+            });
+        }
 
-        self.publish_event(processed_event, &config.topic, &config.partition_key_field)
-            .await
+        let payload = serde_json::to_vec(&event).map_err(|e| {
+            tracing::error!(
+                error = ?e,
+                request_id = %event.get("request_id").unwrap_or(&serde_json::Value::Null),
+                connector = %event.get("connector").unwrap_or(&serde_json::Value::Null),
+                flow_type = %event.get("flow_type").unwrap_or(&serde_json::Value::Null),
+                "Failed to serialize audit event to JSON bytes"
+            );
+            error_stack::Report::new(EventPublisherError::EventSerializationFailed)
+                .attach_printable(format!("Serialization error: {e}"))
+                .attach_printable(format!(
+                    "Event context: request_id={}, connector={}, flow_type={}, txn_uuid={}",
+                    event.get("request_id").unwrap_or(&serde_json::Value::Null),
+                    event.get("connector").unwrap_or(&serde_json::Value::Null),
+                    event.get("flow_type").unwrap_or(&serde_json::Value::Null),
+                    event
+                        .get("udf_txn_uuid")
+                        .unwrap_or(&serde_json::Value::Null)
+                ))
+        })?;
+
+        Ok(Self {
+            event,
+            key,
+            payload,
+            headers,
+        })
+    }
+
+    /// Builds the message published for a connector event
+    pub fn connector_event(
+        base_event: &Event,
+        config: &EventConfig,
+    ) -> CustomResult<Self, EventPublisherError> {
+        let processed_event = Self::process_event(base_event, config)?;
+        let mut headers = config.connector_event_headers(&base_event.connector);
+        headers.extend(config.correlation_headers(base_event));
+
+        Self::new(processed_event, &config.partition_key_field, &headers)
     }
 
-    fn process_event(&self, event: &Event) -> CustomResult<serde_json::Value, EventPublisherError> {
+    fn process_event(
+        event: &Event,
+        config: &EventConfig,
+    ) -> CustomResult<serde_json::Value, EventPublisherError> {
         let mut result = serde_json::to_value(event).map_err(|e| {
             tracing::error!(
                 error = ?e,
@@ -187,11 +253,11 @@ impl EventPublisher {
         })?;
 
         // Process transformations
-        for (target_path, source_field) in &self.config.transformations {
+        for (target_path, source_field) in &config.transformations {
             if let Some(value) = result.get(source_field).cloned() {
                 // Replace _DOT_ and _dot_ with . to support nested keys in environment variables
                 let normalized_path = target_path.replace("_DOT_", ".").replace("_dot_", ".");
-                if let Err(e) = self.set_nested_value(&mut result, &normalized_path, value) {
+                if let Err(e) = Self::set_nested_value(&mut result, &normalized_path, value) {
                     tracing::warn!(
                         target_path = %target_path,
                         normalized_path = %normalized_path,
@@ -204,11 +270,11 @@ impl EventPublisher {
         }
 
         // Process static values - log warnings but continue processing
-        for (target_path, static_value) in &self.config.static_values {
+        for (target_path, static_value) in &config.static_values {
             // Replace _DOT_ and _dot_ with . to support nested keys in environment variables
             let normalized_path = target_path.replace("_DOT_", ".").replace("_dot_", ".");
             let value = serde_json::json!(static_value);
-            if let Err(e) = self.set_nested_value(&mut result, &normalized_path, value) {
+            if let Err(e) = Self::set_nested_value(&mut result, &normalized_path, value) {
                 tracing::warn!(
                     target_path = %target_path,
                     normalized_path = %normalized_path,
@@ -220,11 +286,11 @@ impl EventPublisher {
         }
 
         // Process extraction
-        for (target_path, extraction_path) in &self.config.extractions {
-            if let Some(value) = self.extract_from_request(&result, extraction_path) {
+        for (target_path, extraction_path) in &config.extractions {
+            if let Some(value) = Self::extract_from_request(&result, extraction_path) {
                 // Replace _DOT_ and _dot_ with . to support nested keys in environment variables
                 let normalized_path = target_path.replace("_DOT_", ".").replace("_dot_", ".");
-                if let Err(e) = self.set_nested_value(&mut result, &normalized_path, value) {
+                if let Err(e) = Self::set_nested_value(&mut result, &normalized_path, value) {
                     tracing::warn!(
                         target_path = %target_path,
                         normalized_path = %normalized_path,
@@ -240,7 +306,6 @@ impl EventPublisher {
     }
 
     fn extract_from_request(
-        &self,
         event_value: &serde_json::Value,
         extraction_path: &str,
     ) -> Option<serde_json::Value> {
@@ -262,7 +327,6 @@ impl EventPublisher {
     }
 
     fn set_nested_value(
-        &self,
         target: &mut serde_json::Value,
         path: &str,
         value: serde_json::Value,
@@ -362,3 +426,135 @@ pub async fn emit_event_with_config(
     publisher.emit_event_with_config(event, config).await?;
     Ok(true)
 }
+
+/// Publishes an audit event to the audit topic of its merchant, keyed by request id
+pub async fn emit_audit_event(
+    event: AuditEvent,
+    config: &EventConfig,
+) -> CustomResult<bool, EventPublisherError> {
+    if !config.audit.enabled {
+        return Ok(false);
+    }
+
+    let publisher: &'static EventPublisher = get_event_publisher(config)?;
+    let topic = config.audit.topic_for(&event.merchant_id);
+    let event = serde_json::to_value(&event).map_err(|e| {
+        error_stack::Report::new(EventPublisherError::EventSerializationFailed)
+            .attach_printable(format!("Audit event serialization error: {e}"))
+    })?;
+    publisher
+        .publish_event(event, &topic, AUDIT_PARTITION_KEY_FIELD, &[])
+        .await?;
+    Ok(true)
+}
+
+/// Publishes a decline event to the decline topic, keyed by request id
+pub async fn emit_decline_event(
+    event: DeclineEvent,
+    config: &EventConfig,
+) -> CustomResult<bool, EventPublisherError> {
+    if !config.decline.enabled {
+        return Ok(false);
+    }
+
+    let publisher: &'static EventPublisher = get_event_publisher(config)?;
+    let event = serde_json::to_value(&event).map_err(|e| {
+        error_stack::Report::new(EventPublisherError::EventSerializationFailed)
+            .attach_printable(format!("Decline event serialization error: {e}"))
+    })?;
+    publisher
+        .publish_event(
+            event,
+            &config.decline.topic,
+            DECLINE_PARTITION_KEY_FIELD,
+            &[],
+        )
+        .await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod event_message_tests {
+    #![allow(clippy::expect_used)]
+    use std::collections::HashMap;
+
+    use rdkafka::message::Headers;
+
+    use super::*;
+    use crate::{
+        events::{EventStage, FlowName},
+        lineage,
+    };
+
+    fn connector_event() -> Event {
+        Event {
+            request_id: "request_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            timestamp: 0,
+            flow_type: FlowName::Authorize,
+            connector: "adyen".to_string(),
+            url: None,
+            stage: EventStage::ConnectorCall,
+            latency: None,
+            status_code: Some(200),
+            request_data: None,
+            connector_request_data: None,
+            connector_response_data: None,
+            additional_fields: HashMap::new(),
+            lineage_ids: lineage::LineageIds::empty(""),
+        }
+    }
+
+    fn message_headers(message: &EventMessage) -> Vec<(String, String)> {
+        message
+            .headers
+            .iter()
+            .map(|header| {
+                (
+                    header.key.to_string(),
+                    String::from_utf8_lossy(header.value.unwrap_or_default()).into_owned(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn connector_event_message_carries_correlation_headers() {
+        let config = EventConfig {
+            correlation_headers: true,
+            retention_classes: HashMap::from([("adyen".to_string(), "long".to_string())]),
+            ..Default::default()
+        };
+
+        let message =
+            EventMessage::connector_event(&connector_event(), &config).expect("message builds");
+
+        assert_eq!(message.key.as_deref(), Some("request_123"));
+        assert_eq!(
+            message_headers(&message),
+            vec![
+                ("partitionKey".to_string(), "request_123".to_string()),
+                ("retention".to_string(), "long".to_string()),
+                ("connector".to_string(), "adyen".to_string()),
+                ("flow".to_string(), "Authorize".to_string()),
+                ("merchant_id".to_string(), "merchant_123".to_string()),
+                ("request_id".to_string(), "request_123".to_string()),
+            ]
+        );
+        let payload: serde_json::Value =
+            serde_json::from_slice(&message.payload).expect("payload is JSON");
+        assert_eq!(payload.get("merchant_id"), Some(&"merchant_123".into()));
+        assert_eq!(payload.get("connector"), Some(&"adyen".into()));
+    }
+
+    #[test]
+    fn connector_event_message_without_correlation_headers() {
+        let message = EventMessage::connector_event(&connector_event(), &EventConfig::default())
+            .expect("message builds");
+
+        assert_eq!(
+            message_headers(&message),
+            vec![("partitionKey".to_string(), "request_123".to_string())]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +12,7 @@ use crate::{
     },
     id_type::{self, ApiKeyId, MerchantConnectorAccountId, ProfileAcquirerId},
     lineage,
+    new_types::MaskedCardBin,
     types::TimeRange,
     SecretSerdeValue,
 };
@@ -180,6 +181,7 @@ impl ApiEventMetric for TimeRange {}
 #[derive(Debug, Clone, Serialize)]
 pub struct Event {
     pub request_id: String,
+    pub merchant_id: String,
     pub timestamp: i128,
     pub flow_type: FlowName,
     pub connector: String,
@@ -201,6 +203,7 @@ pub enum FlowName {
     Authorize,
     Refund,
     Capture,
+    CaptureReversal,
     Void,
     Psync,
     Rsync,
@@ -213,6 +216,7 @@ pub enum FlowName {
     RepeatPayment,
     CreateOrder,
     CreateSessionToken,
+    RegisterWebhook,
     Unknown,
 }
 
@@ -222,6 +226,7 @@ impl FlowName {
             Self::Authorize => "Authorize",
             Self::Refund => "Refund",
             Self::Capture => "Capture",
+            Self::CaptureReversal => "CaptureReversal",
             Self::Void => "Void",
             Self::Psync => "Psync",
             Self::Rsync => "Rsync",
@@ -234,6 +239,7 @@ impl FlowName {
             Self::RepeatPayment => "RepeatPayment",
             Self::CreateOrder => "CreateOrder",
             Self::CreateSessionToken => "CreateSessionToken",
+            Self::RegisterWebhook => "RegisterWebhook",
             Self::Unknown => "Unknown",
         }
     }
@@ -252,6 +258,20 @@ impl EventStage {
     }
 }
 
+/// Kafka header carrying the retention class of a connector event
+pub const RETENTION_HEADER: &str = "retention";
+
+/// Kafka headers identifying the call a connector event belongs to
+pub const CONNECTOR_HEADER: &str = "connector";
+pub const FLOW_HEADER: &str = "flow";
+pub const MERCHANT_ID_HEADER: &str = "merchant_id";
+pub const REQUEST_ID_HEADER: &str = "request_id";
+
+/// Placeholder replaced with the merchant id in `AuditConfig::topic_template`
+pub const MERCHANT_ID_PLACEHOLDER: &str = "{merchant_id}";
+/// Longest topic name Kafka accepts
+const MAX_TOPIC_NAME_LENGTH: usize = 249;
+
 /// Configuration for events system
 #[derive(Debug, Clone, Deserialize)]
 pub struct EventConfig {
@@ -265,6 +285,26 @@ pub struct EventConfig {
     pub static_values: HashMap<String, String>, // target_path → static_value
     #[serde(default)]
     pub extractions: HashMap<String, String>, // target_path → extraction_path
+    /// Serialize `MinorUnit` amounts in the published connector request data as strings
+    #[serde(default)]
+    pub minor_unit_as_string: bool,
+    /// Also serialize `MinorUnit` amounts in the logged connector responses as strings
+    #[serde(default)]
+    pub minor_unit_as_string_in_responses: bool,
+    /// Per-RPC audit events, published to their own topic
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Decline events published for every failed authorization
+    #[serde(default)]
+    pub decline: DeclineEventConfig,
+    /// Retention class (e.g. `short` or `long`) sent in the `retention` Kafka header of
+    /// connector events, keyed by connector name
+    #[serde(default)]
+    pub retention_classes: HashMap<String, String>,
+    /// Send the connector, flow, merchant id and request id of connector events as Kafka
+    /// headers, so consumers can filter them without parsing the body
+    #[serde(default)]
+    pub correlation_headers: bool,
 }
 
 impl Default for EventConfig {
@@ -277,6 +317,230 @@ impl Default for EventConfig {
             transformations: HashMap::new(),
             static_values: HashMap::new(),
             extractions: HashMap::new(),
+            minor_unit_as_string: false,
+            minor_unit_as_string_in_responses: false,
+            audit: AuditConfig::default(),
+            decline: DeclineEventConfig::default(),
+            retention_classes: HashMap::new(),
+            correlation_headers: false,
+        }
+    }
+}
+
+impl EventConfig {
+    /// Static Kafka headers attached to every event of the given connector
+    pub fn connector_event_headers(&self, connector: &str) -> Vec<(&str, &str)> {
+        self.retention_classes
+            .get(connector)
+            .map(|retention_class| (RETENTION_HEADER, retention_class.as_str()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Kafka headers identifying the connector call of an event, when enabled
+    pub fn correlation_headers<'a>(&self, event: &'a Event) -> Vec<(&'static str, &'a str)> {
+        if !self.correlation_headers {
+            return Vec::new();
+        }
+        vec![
+            (CONNECTOR_HEADER, event.connector.as_str()),
+            (FLOW_HEADER, event.flow_type.as_str()),
+            (MERCHANT_ID_HEADER, event.merchant_id.as_str()),
+            (REQUEST_ID_HEADER, event.request_id.as_str()),
+        ]
+    }
+}
+
+/// Configuration for the audit events emitted once per gRPC call
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    /// Topic for audit events not routed to a merchant topic
+    pub topic: String,
+    /// Per-merchant topic, e.g. `audit-{merchant_id}`. Events whose merchant id is empty or
+    /// not valid in a topic name go to `topic`
+    pub topic_template: Option<String>,
+    /// Topics of merchants overriding `topic_template`, keyed by merchant id
+    pub merchant_topics: HashMap<String, String>,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            topic: "grpc-audit-events".to_string(),
+            topic_template: None,
+            merchant_topics: HashMap::new(),
         }
     }
 }
+
+impl AuditConfig {
+    /// Topic the audit events of a merchant are published to
+    pub fn topic_for(&self, merchant_id: &str) -> Cow<'_, str> {
+        if let Some(topic) = self.merchant_topics.get(merchant_id) {
+            return Cow::Borrowed(topic);
+        }
+        let is_valid_merchant_id = !merchant_id.is_empty()
+            && merchant_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+        match &self.topic_template {
+            Some(template) if is_valid_merchant_id => {
+                let topic = template.replace(MERCHANT_ID_PLACEHOLDER, merchant_id);
+                if topic.len() <= MAX_TOPIC_NAME_LENGTH {
+                    Cow::Owned(topic)
+                } else {
+                    Cow::Borrowed(&self.topic)
+                }
+            }
+            _ => Cow::Borrowed(&self.topic),
+        }
+    }
+}
+
+/// Configuration for the decline events emitted when an authorization fails
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DeclineEventConfig {
+    pub enabled: bool,
+    pub topic: String,
+}
+
+impl Default for DeclineEventConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            topic: "decline-events".to_string(),
+        }
+    }
+}
+
+/// Redacted summary of a gRPC call, carries no request or response bodies
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEvent {
+    pub request_id: String,
+    pub timestamp: i128,
+    pub method: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub status: String,
+    pub latency_ms: u64,
+}
+
+/// Structured summary of a failed authorization for decline analytics, carries no card
+/// details beyond the masked BIN
+#[derive(Debug, Clone, Serialize)]
+pub struct DeclineEvent {
+    pub request_id: String,
+    pub timestamp: i128,
+    pub merchant_id: String,
+    pub connector: String,
+    /// Connector error code, lowercased with words joined by underscores
+    pub error_code: String,
+    /// `soft_decline`, `hard_decline` or `technical_error`
+    pub category: String,
+    pub bin: Option<MaskedCardBin>,
+    pub card_issuer: Option<String>,
+}
+
+#[cfg(test)]
+mod event_config_tests {
+    use super::*;
+
+    #[test]
+    fn retention_header_follows_connector_config() {
+        let config = EventConfig {
+            retention_classes: HashMap::from([
+                ("adyen".to_string(), "long".to_string()),
+                ("checkout".to_string(), "short".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.connector_event_headers("adyen"),
+            vec![(RETENTION_HEADER, "long")]
+        );
+        assert_eq!(
+            config.connector_event_headers("checkout"),
+            vec![(RETENTION_HEADER, "short")]
+        );
+        assert!(config.connector_event_headers("noon").is_empty());
+    }
+
+    fn connector_event() -> Event {
+        Event {
+            request_id: "request_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            timestamp: 0,
+            flow_type: FlowName::Authorize,
+            connector: "adyen".to_string(),
+            url: None,
+            stage: EventStage::ConnectorCall,
+            latency: None,
+            status_code: Some(200),
+            request_data: None,
+            connector_request_data: None,
+            connector_response_data: None,
+            additional_fields: HashMap::new(),
+            lineage_ids: lineage::LineageIds::empty(""),
+        }
+    }
+
+    #[test]
+    fn correlation_headers_only_sent_when_enabled() {
+        let event = connector_event();
+        let config = EventConfig {
+            correlation_headers: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.correlation_headers(&event),
+            vec![
+                (CONNECTOR_HEADER, "adyen"),
+                (FLOW_HEADER, "Authorize"),
+                (MERCHANT_ID_HEADER, "merchant_123"),
+                (REQUEST_ID_HEADER, "request_123"),
+            ]
+        );
+        assert!(EventConfig::default()
+            .correlation_headers(&event)
+            .is_empty());
+    }
+
+    #[test]
+    fn audit_topic_routed_by_merchant_id() {
+        let config = AuditConfig {
+            topic_template: Some("audit-{merchant_id}".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(config.topic_for("merchant_123"), "audit-merchant_123");
+        assert_eq!(config.topic_for("merchant_456"), "audit-merchant_456");
+        assert_eq!(config.topic_for(""), "grpc-audit-events");
+        assert_eq!(config.topic_for("merchant/123"), "grpc-audit-events");
+        assert_eq!(config.topic_for(&"m".repeat(250)), "grpc-audit-events");
+        assert_eq!(
+            AuditConfig::default().topic_for("merchant_123"),
+            "grpc-audit-events"
+        );
+    }
+
+    #[test]
+    fn audit_merchant_topic_overrides_template() {
+        let config = AuditConfig {
+            topic_template: Some("audit-{merchant_id}".to_string()),
+            merchant_topics: HashMap::from([(
+                "merchant_123".to_string(),
+                "audit-merchant-123".to_string(),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(config.topic_for("merchant_123"), "audit-merchant-123");
+        assert_eq!(config.topic_for("merchant_456"), "audit-merchant_456");
+    }
+}
@@ -15,7 +15,9 @@ pub mod types;
 // Re-export commonly used items
 pub use errors::{CustomResult, EventPublisherError, ParsingError, ValidationError};
 #[cfg(feature = "kafka")]
-pub use event_publisher::{emit_event_with_config, init_event_publisher};
+pub use event_publisher::{
+    emit_audit_event, emit_decline_event, emit_event_with_config, init_event_publisher,
+};
 
 #[cfg(not(feature = "kafka"))]
 pub fn init_event_publisher(_config: &events::EventConfig) -> CustomResult<(), ()> {
@@ -28,6 +30,20 @@ pub async fn emit_event_with_config(
 ) -> CustomResult<bool, ()> {
     Ok(false)
 }
+#[cfg(not(feature = "kafka"))]
+pub async fn emit_audit_event(
+    _event: events::AuditEvent,
+    _config: &events::EventConfig,
+) -> CustomResult<bool, ()> {
+    Ok(false)
+}
+#[cfg(not(feature = "kafka"))]
+pub async fn emit_decline_event(
+    _event: events::DeclineEvent,
+    _config: &events::EventConfig,
+) -> CustomResult<bool, ()> {
+    Ok(false)
+}
 
 pub use global_id::{CellId, GlobalPaymentId};
 pub use id_type::{CustomerId, MerchantId};
@@ -29,6 +29,9 @@ fn apply_mask(val: &str, unmasked_char_count: usize, min_masked_char_count: usiz
         })
 }
 
+/// Number of leading BIN digits that may be shown unmasked
+const CARD_BIN_UNMASKED_DIGITS: usize = 6;
+
 /// Masked bank account
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct MaskedBankAccount(Secret<String>);
@@ -43,3 +46,45 @@ impl From<Secret<String>> for MaskedBankAccount {
         Self::from(secret.expose())
     }
 }
+
+/// Card BIN with everything after the first six digits masked, so longer BINs or
+/// partially masked card numbers returned by connectors can be published
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MaskedCardBin(Secret<String>);
+impl From<String> for MaskedCardBin {
+    fn from(src: String) -> Self {
+        let unmasked: String = src.chars().take(CARD_BIN_UNMASKED_DIGITS).collect();
+        let masked_tail = src.get(unmasked.len()..).unwrap_or_default();
+        let masked_value = format!(
+            "{unmasked}{}",
+            apply_mask(masked_tail, 0, masked_tail.len())
+        );
+        Self(Secret::from(masked_value))
+    }
+}
+impl From<Secret<String>> for MaskedCardBin {
+    fn from(secret: Secret<String>) -> Self {
+        Self::from(secret.expose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperswitch_masking::PeekInterface;
+
+    use super::*;
+
+    #[test]
+    fn card_bin_keeps_first_six_digits() {
+        assert_eq!(MaskedCardBin::from("411111".to_string()).0.peek(), "411111");
+        assert_eq!(
+            MaskedCardBin::from("41111122".to_string()).0.peek(),
+            "411111**"
+        );
+        assert_eq!(
+            MaskedCardBin::from("411111XXXXXX1111".to_string()).0.peek(),
+            "411111**********"
+        );
+        assert_eq!(MaskedCardBin::from("4111".to_string()).0.peek(), "4111");
+    }
+}
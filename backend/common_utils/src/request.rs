@@ -26,7 +26,9 @@ pub enum Method {
     Patch,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, Eq, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum ContentType {
     Json,
     FormUrlEncoded,
@@ -34,6 +36,18 @@ pub enum ContentType {
     Xml,
 }
 
+impl ContentType {
+    /// Value of the `Content-Type` header for this body format
+    pub fn header_value(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::FormUrlEncoded => "application/x-www-form-urlencoded",
+            Self::FormData => "multipart/form-data",
+            Self::Xml => "application/xml",
+        }
+    }
+}
+
 fn default_request_headers() -> [(String, Maskable<String>); 1] {
     use http::header;
 
@@ -81,6 +95,36 @@ impl RequestContent {
             Self::RawBytes(_) => String::new().into(),
         }
     }
+
+    pub fn content_type(&self) -> Option<ContentType> {
+        match self {
+            Self::Json(_) => Some(ContentType::Json),
+            Self::FormUrlEncoded(_) => Some(ContentType::FormUrlEncoded),
+            Self::FormData(_) => Some(ContentType::FormData),
+            Self::Xml(_) => Some(ContentType::Xml),
+            Self::RawBytes(_) => None,
+        }
+    }
+
+    /// Re-encodes a serializable body in another format.
+    /// Multipart and raw byte bodies cannot be converted and are returned as `Err`.
+    pub fn into_content_type(self, content_type: ContentType) -> Result<Self, Self> {
+        match (self, content_type) {
+            (
+                Self::Json(inner) | Self::FormUrlEncoded(inner) | Self::Xml(inner),
+                ContentType::Json,
+            ) => Ok(Self::Json(inner)),
+            (
+                Self::Json(inner) | Self::FormUrlEncoded(inner) | Self::Xml(inner),
+                ContentType::FormUrlEncoded,
+            ) => Ok(Self::FormUrlEncoded(inner)),
+            (
+                Self::Json(inner) | Self::FormUrlEncoded(inner) | Self::Xml(inner),
+                ContentType::Xml,
+            ) => Ok(Self::Xml(inner)),
+            (other, _) => Err(other),
+        }
+    }
 }
 
 impl Request {
@@ -167,7 +167,9 @@ impl AmountConvertor for MinorUnitForConnector {
     PartialOrd,
 )]
 
-pub struct MinorUnit(pub i64);
+pub struct MinorUnit(
+    #[serde(serialize_with = "crate::custom_serde::minor_unit_as_string::serialize")] pub i64,
+);
 
 impl MinorUnit {
     /// gets amount as i64 value will be removed in future
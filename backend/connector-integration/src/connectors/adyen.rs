@@ -14,17 +14,19 @@ use common_utils::{
 };
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, SetupMandate, SubmitEvidence, Void,
     },
     connector_types::{
         AcceptDisputeData, ConnectorSpecifications, ConnectorWebhookSecrets, DisputeDefendData,
-        DisputeFlowData, DisputeResponseData, PaymentCreateOrderData, PaymentCreateOrderResponse,
-        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
-        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData,
-        RefundWebhookDetailsResponse, RefundsData, RefundsResponseData, RequestDetails, ResponseId,
+        DisputeFlowData, DisputeResponseData, PaymentCaptureReversalData, PaymentCreateOrderData,
+        PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
+        PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData,
+        RefundSyncData, RefundWebhookDetailsResponse, RefundsData, RefundsResponseData,
+        RegisterWebhookData, RegisterWebhookResponseData, RequestDetails, ResponseId,
         SessionTokenRequestData, SessionTokenResponseData, SetupMandateRequestData,
-        SubmitEvidenceData, SupportedPaymentMethodsExt, WebhookDetailsResponse,
+        SubmitEvidenceData, SupportedPaymentMethodsExt, ThreeDsVersion, WebhookDetailsResponse,
+        WebhookFlowData,
     },
     errors,
     payment_method_data::{DefaultPCIHolder, PaymentMethodData, PaymentMethodDataTypes},
@@ -48,12 +50,12 @@ use interfaces::{
 };
 use serde::Serialize;
 use transformers::{
-    self as adyen, AdyenCaptureRequest, AdyenCaptureResponse, AdyenDefendDisputeRequest,
-    AdyenDefendDisputeResponse, AdyenDisputeAcceptRequest, AdyenDisputeAcceptResponse,
-    AdyenDisputeSubmitEvidenceRequest, AdyenNotificationRequestItemWH, AdyenPSyncResponse,
-    AdyenPaymentRequest, AdyenPaymentResponse, AdyenRedirectRequest, AdyenRefundRequest,
-    AdyenRefundResponse, AdyenSubmitEvidenceResponse, AdyenVoidRequest, AdyenVoidResponse,
-    SetupMandateRequest, SetupMandateResponse,
+    self as adyen, AdyenCaptureRequest, AdyenCaptureResponse, AdyenCaptureReversalRequest,
+    AdyenCaptureReversalResponse, AdyenDefendDisputeRequest, AdyenDefendDisputeResponse,
+    AdyenDisputeAcceptRequest, AdyenDisputeAcceptResponse, AdyenDisputeSubmitEvidenceRequest,
+    AdyenNotificationRequestItemWH, AdyenPSyncResponse, AdyenPaymentRequest, AdyenPaymentResponse,
+    AdyenRedirectRequest, AdyenRefundRequest, AdyenRefundResponse, AdyenSubmitEvidenceResponse,
+    AdyenVoidRequest, AdyenVoidResponse, SetupMandateRequest, SetupMandateResponse,
 };
 
 use super::macros;
@@ -85,6 +87,14 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
     connector_types::PaymentVoidV2 for Adyen<T>
 {
 }
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
+    connector_types::PaymentCaptureReversalV2 for Adyen<T>
+{
+}
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
+    connector_types::RegisterWebhookV2 for Adyen<T>
+{
+}
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
     connector_types::RefundSyncV2 for Adyen<T>
 {
@@ -147,6 +157,12 @@ macros::create_all_prerequisites!(
             response_body: AdyenVoidResponse,
             router_data: RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
         ),
+        (
+            flow: CaptureReversal,
+            request_body: AdyenCaptureReversalRequest,
+            response_body: AdyenCaptureReversalResponse,
+            router_data: RouterDataV2<CaptureReversal, PaymentFlowData, PaymentCaptureReversalData, PaymentsResponseData>,
+        ),
         (
             flow: Refund,
             request_body: AdyenRefundRequest,
@@ -363,6 +379,13 @@ macros::macro_connector_implementation!(
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
     connector_types::ValidationTrait for Adyen<T>
 {
+    fn supported_three_ds_versions(&self) -> &'static [ThreeDsVersion] {
+        &[ThreeDsVersion::V2, ThreeDsVersion::V1]
+    }
+
+    fn supports_cvc_less_card_payments(&self) -> bool {
+        true
+    }
 }
 
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
@@ -409,6 +432,40 @@ macros::macro_connector_implementation!(
     }
 );
 
+macros::macro_connector_implementation!(
+    connector_default_implementations: [get_content_type, get_error_response_v2],
+    connector: Adyen,
+    curl_request: Json(AdyenCaptureReversalRequest),
+    curl_response: AdyenCaptureReversalResponse,
+    flow_name: CaptureReversal,
+    resource_common_data: PaymentFlowData,
+    flow_request: PaymentCaptureReversalData,
+    flow_response: PaymentsResponseData,
+    http_method: Post,
+    generic_type: T,
+    [PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize],
+    other_functions: {
+        fn get_headers(
+            &self,
+            req: &RouterDataV2<CaptureReversal, PaymentFlowData, PaymentCaptureReversalData, PaymentsResponseData>,
+        ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+            self.build_headers(req)
+        }
+        fn get_url(
+            &self,
+            req: &RouterDataV2<CaptureReversal, PaymentFlowData, PaymentCaptureReversalData, PaymentsResponseData>,
+        ) -> CustomResult<String, errors::ConnectorError> {
+            // Adyen reverses through the payment, not the capture
+            let id = req.request.connector_payment_id.as_ref().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_transaction_id",
+                },
+            )?;
+            Ok(format!("{}{}/payments/{}/reversals", self.connector_base_url_payments(req), ADYEN_API_VERSION, id))
+        }
+    }
+);
+
 macros::macro_connector_implementation!(
     connector_default_implementations: [get_content_type, get_error_response_v2],
     connector: Adyen,
@@ -455,6 +512,16 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
 {
 }
 
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
+    ConnectorIntegrationV2<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Adyen<T>
+{
+}
+
 // SourceVerification implementations for all flows
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
     interfaces::verification::SourceVerification<
@@ -496,6 +563,25 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
 {
 }
 
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
+    interfaces::verification::SourceVerification<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Adyen<T>
+{
+}
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
+    interfaces::verification::SourceVerification<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Adyen<T>
+{
+}
+
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + 'static + Serialize>
     interfaces::verification::SourceVerification<
         Refund,
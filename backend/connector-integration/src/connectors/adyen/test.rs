@@ -62,12 +62,22 @@ mod tests {
                         adyen: ConnectorParams {
                             base_url: "https://checkout-test.adyen.com/".to_string(),
                             dispute_base_url: Some("https://ca-test.adyen.com/ca/services/DisputeService/v30/defendDispute".to_string()),
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
                     external_latency: None,
                     connector_response_headers: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                 },
                 connector_auth_type: ConnectorAuthType::BodyKey {
                     api_key: Secret::new(api_key),
@@ -148,6 +158,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse::default()),
             };
@@ -229,12 +245,22 @@ mod tests {
                         adyen: ConnectorParams {
                             base_url: "https://checkout-test.adyen.com/".to_string(),
                             dispute_base_url: Some("https://ca-test.adyen.com/ca/services/DisputeService/v30/defendDispute".to_string()),
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
                     external_latency: None,
                     connector_response_headers: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                 },
                 connector_auth_type: ConnectorAuthType::BodyKey {
                     api_key: Secret::new(api_key),
@@ -274,6 +300,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse::default()),
             };
@@ -416,4 +448,1066 @@ mod tests {
         //     assert!(result.is_err(), "Expected error for invalid fields");
         // }
     }
+    pub mod capture_reversal {
+        use common_utils::types::MinorUnit;
+        use domain_types::{
+            connector_flow::{CaptureReversal, Refund},
+            connector_types::{
+                PaymentCaptureReversalData, PaymentFlowData, PaymentsResponseData, RefundFlowData,
+                RefundsData, RefundsResponseData, ResponseId,
+            },
+            errors,
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use interfaces::connector_integration_v2::ConnectorIntegrationV2;
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                body_key_auth, handle_response, payment_flow_data, refund_flow_data, request_body,
+                router_data,
+            },
+        };
+
+        fn reversal_router_data() -> RouterDataV2<
+            CaptureReversal,
+            PaymentFlowData,
+            PaymentCaptureReversalData,
+            PaymentsResponseData,
+        > {
+            let mut resource_common_data = payment_flow_data();
+            resource_common_data.status = common_enums::AttemptStatus::Charged;
+            router_data(
+                resource_common_data,
+                body_key_auth(),
+                PaymentCaptureReversalData {
+                    connector_transaction_id: "capture_psp_ref_123".to_string(),
+                    connector_payment_id: Some("payment_psp_ref_456".to_string()),
+                    reversal_reason: None,
+                    integrity_object: None,
+                },
+            )
+        }
+
+        #[test]
+        fn test_reversal_targets_reversal_endpoint_unlike_refund() {
+            let connector = Adyen::<DefaultPCIHolder>::new();
+            let reversal_router_data = reversal_router_data();
+            let refund_router_data: RouterDataV2<
+                Refund,
+                RefundFlowData,
+                RefundsData,
+                RefundsResponseData,
+            > = router_data(
+                refund_flow_data(),
+                body_key_auth(),
+                RefundsData {
+                    refund_id: "refund_ref_123".to_string(),
+                    connector_transaction_id: "capture_psp_ref_123".to_string(),
+                    currency: common_enums::Currency::USD,
+                    payment_amount: 1000,
+                    refund_amount: 1000,
+                    minor_payment_amount: MinorUnit::new(1000),
+                    minor_refund_amount: MinorUnit::new(1000),
+                    ..Default::default()
+                },
+            );
+
+            let reversal_request = connector
+                .build_request_v2(&reversal_router_data)
+                .unwrap()
+                .unwrap();
+            let refund_request = connector
+                .build_request_v2(&refund_router_data)
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                reversal_request.url,
+                "https://checkout-test.adyen.com/v68/payments/payment_psp_ref_456/reversals"
+            );
+            assert_eq!(
+                refund_request.url,
+                "https://checkout-test.adyen.com/v68/payments/capture_psp_ref_123/refunds"
+            );
+
+            let reversal_body = request_body(connector, &reversal_router_data);
+            let refund_body = request_body(connector, &refund_router_data);
+            assert_eq!(reversal_body["reference"], "ref_123");
+            assert!(reversal_body.get("amount").is_none());
+            assert!(refund_body.get("amount").is_some());
+        }
+
+        #[test]
+        fn test_reversal_without_payment_id_is_rejected() {
+            let mut reversal_router_data = reversal_router_data();
+            reversal_router_data.request.connector_payment_id = None;
+
+            let error = Adyen::<DefaultPCIHolder>::new()
+                .build_request_v2(&reversal_router_data)
+                .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_transaction_id"
+                }
+            ));
+        }
+
+        #[test]
+        fn test_reversal_response_identifies_the_capture_and_maps_status() {
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &reversal_router_data(),
+                201,
+                json!({
+                    "paymentPspReference": "payment_psp_ref_456",
+                    "pspReference": "reversal_psp_ref_789",
+                    "status": "received",
+                    "reference": "ref_123",
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.status,
+                common_enums::AttemptStatus::VoidInitiated
+            );
+            assert!(matches!(
+                router_data.response,
+                Ok(PaymentsResponseData::TransactionResponse {
+                    resource_id: ResponseId::ConnectorTransactionId(ref id),
+                    connector_response_reference_id: Some(ref reference),
+                    ..
+                }) if id == "capture_psp_ref_123" && reference == "reversal_psp_ref_789"
+            ));
+        }
+    }
+    pub mod webhook_status {
+        use common_enums::AttemptStatus;
+
+        use crate::connectors::adyen::transformers::{
+            get_adyen_payment_webhook_event, WebhookEventCode,
+        };
+
+        #[test]
+        fn test_only_adyen_success_flag_values_are_success() {
+            for (success, expected) in [
+                ("true", AttemptStatus::Authorized),
+                ("false", AttemptStatus::Failure),
+                ("1", AttemptStatus::Failure),
+                ("yes", AttemptStatus::Failure),
+            ] {
+                assert_eq!(
+                    get_adyen_payment_webhook_event(
+                        WebhookEventCode::Authorisation,
+                        success.to_string()
+                    )
+                    .ok(),
+                    Some(expected),
+                    "{success}"
+                );
+            }
+        }
+    }
+    pub mod refund_reference {
+        use domain_types::{
+            connector_flow::Refund,
+            connector_types::{RefundFlowData, RefundsData, RefundsResponseData},
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{body_key_auth, handle_response, refund_flow_data, router_data},
+        };
+
+        #[test]
+        fn test_refund_response_carries_refund_and_reference_ids() {
+            let refund_router_data: RouterDataV2<
+                Refund,
+                RefundFlowData,
+                RefundsData,
+                RefundsResponseData,
+            > = router_data(
+                refund_flow_data(),
+                body_key_auth(),
+                RefundsData {
+                    refund_id: "refund_ref_123".to_string(),
+                    connector_transaction_id: "psp_payment_123".to_string(),
+                    ..Default::default()
+                },
+            );
+
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &refund_router_data,
+                201,
+                json!({
+                    "merchantAccount": "merchant_123",
+                    "pspReference": "psp_refund_456",
+                    "paymentPspReference": "psp_payment_123",
+                    "reference": "refund_ref_123",
+                    "status": "received"
+                }),
+            );
+
+            let response = router_data.response.unwrap();
+            assert_eq!(response.connector_refund_id, "psp_refund_456");
+            assert_eq!(
+                response.connector_response_reference_id.as_deref(),
+                Some("refund_ref_123")
+            );
+        }
+    }
+    pub mod voucher {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData},
+            payment_address::{Address, AddressDetails, PaymentAddress},
+            payment_method_data::{
+                BoletoVoucherData, DefaultPCIHolder, PaymentMethodData, VoucherData,
+            },
+            router_data_v2::RouterDataV2,
+        };
+        use hyperswitch_masking::Secret;
+        use interfaces::connector_integration_v2::ConnectorIntegrationV2;
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, handle_response, payment_flow_data, request_body,
+                router_data,
+            },
+        };
+
+        type AuthorizeRouterData = RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<DefaultPCIHolder>,
+            PaymentsResponseData,
+        >;
+
+        fn voucher_router_data(
+            voucher_data: VoucherData,
+            currency: common_enums::Currency,
+        ) -> AuthorizeRouterData {
+            let mut resource_common_data = payment_flow_data();
+            resource_common_data.payment_method = common_enums::PaymentMethod::Voucher;
+            resource_common_data.address = PaymentAddress::new(
+                None,
+                Some(Address {
+                    address: Some(AddressDetails {
+                        first_name: Some(Secret::new("Jose".to_string())),
+                        last_name: Some(Secret::new("Silva".to_string())),
+                        country: Some(common_enums::CountryAlpha2::BR),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                None,
+                None,
+            );
+            router_data(
+                resource_common_data,
+                body_key_auth(),
+                PaymentsAuthorizeData {
+                    currency,
+                    ..authorize_data(PaymentMethodData::Voucher(voucher_data))
+                },
+            )
+        }
+
+        fn boleto(social_security_number: Option<&str>) -> VoucherData {
+            VoucherData::Boleto(Box::new(BoletoVoucherData {
+                social_security_number: social_security_number
+                    .map(|number| Secret::new(number.to_string())),
+            }))
+        }
+
+        #[test]
+        fn test_boleto_request_carries_social_security_number_and_shopper_name() {
+            let router_data =
+                voucher_router_data(boleto(Some("56861752509")), common_enums::Currency::BRL);
+
+            let body = request_body(Adyen::<DefaultPCIHolder>::new(), &router_data);
+
+            assert_eq!(body["paymentMethod"]["type"], "boletobancario");
+            assert_eq!(body["socialSecurityNumber"], "56861752509");
+            assert_eq!(body["shopperName"]["firstName"], "Jose");
+            assert_eq!(body["shopperName"]["lastName"], "Silva");
+        }
+
+        #[test]
+        fn test_oxxo_request_has_no_social_security_number() {
+            let router_data = voucher_router_data(VoucherData::Oxxo, common_enums::Currency::MXN);
+
+            let body = request_body(Adyen::<DefaultPCIHolder>::new(), &router_data);
+
+            assert_eq!(body["paymentMethod"]["type"], "oxxo");
+            assert!(body.get("socialSecurityNumber").is_none());
+        }
+
+        #[test]
+        fn test_boleto_without_social_security_number_is_rejected() {
+            let router_data = voucher_router_data(boleto(None), common_enums::Currency::BRL);
+
+            assert!(Adyen::<DefaultPCIHolder>::new()
+                .build_request_v2(&router_data)
+                .is_err());
+        }
+
+        #[test]
+        fn test_voucher_action_returned_as_voucher_metadata() {
+            let router_data =
+                voucher_router_data(boleto(Some("56861752509")), common_enums::Currency::BRL);
+
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &router_data,
+                200,
+                json!({
+                    "resultCode": "PresentToShopper",
+                    "pspReference": "psp_voucher_123",
+                    "action": {
+                        "type": "voucher",
+                        "paymentMethodType": "boletobancario",
+                        "reference": "03399.33335 33823.303087 198801027 2 84370000005000",
+                        "expiresAt": "2030-01-15T00:00:00",
+                        "downloadUrl": "https://test.adyen.com/hpp/generationBoleto.shtml?data=abc"
+                    }
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.status,
+                common_enums::AttemptStatus::AuthenticationPending
+            );
+            match router_data.response.unwrap() {
+                PaymentsResponseData::TransactionResponse {
+                    connector_metadata, ..
+                } => {
+                    assert_eq!(
+                        connector_metadata,
+                        Some(json!({
+                            "voucher_number": "03399.33335 33823.303087 198801027 2 84370000005000",
+                            "voucher_expires_at": 1_894_665_600,
+                            "voucher_download_url": "https://test.adyen.com/hpp/generationBoleto.shtml?data=abc"
+                        }))
+                    );
+                }
+                response => panic!("unexpected response: {response:?}"),
+            }
+        }
+    }
+    pub mod sca_exemption {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{
+                PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData, ScaExemption,
+                ScaExemptionDecision,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                request_body, router_data,
+            },
+        };
+
+        fn exemption_router_data(
+            requested_exemption: Option<ScaExemption>,
+        ) -> RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<DefaultPCIHolder>,
+            PaymentsResponseData,
+        > {
+            router_data(
+                payment_flow_data(),
+                body_key_auth(),
+                PaymentsAuthorizeData {
+                    currency: common_enums::Currency::EUR,
+                    requested_exemption,
+                    ..authorize_data(card())
+                },
+            )
+        }
+
+        #[test]
+        fn test_requested_exemption_sent_as_additional_data() {
+            let router_data = exemption_router_data(Some(ScaExemption::TransactionRiskAnalysis));
+
+            let body = request_body(Adyen::<DefaultPCIHolder>::new(), &router_data);
+
+            assert_eq!(
+                body["additionalData"]["scaExemption"],
+                "transactionRiskAnalysis"
+            );
+        }
+
+        #[test]
+        fn test_no_exemption_sent_when_not_requested() {
+            let router_data = exemption_router_data(None);
+
+            let body = request_body(Adyen::<DefaultPCIHolder>::new(), &router_data);
+
+            assert!(body
+                .get("additionalData")
+                .and_then(|additional_data| additional_data.get("scaExemption"))
+                .is_none());
+        }
+
+        #[test]
+        fn test_exemption_echoed_by_adyen_is_applied() {
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &exemption_router_data(Some(ScaExemption::LowValue)),
+                200,
+                json!({
+                    "pspReference": "psp_exemption_123",
+                    "resultCode": "Authorised",
+                    "merchantReference": "ref_123",
+                    "additionalData": {
+                        "scaExemptionRequested": "lowValue"
+                    }
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.sca_exemption_decision,
+                Some(ScaExemptionDecision::Applied)
+            );
+        }
+
+        #[test]
+        fn test_authentication_required_refusal_rejects_exemption() {
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &exemption_router_data(Some(ScaExemption::LowValue)),
+                200,
+                json!({
+                    "pspReference": "psp_exemption_123",
+                    "resultCode": "Refused",
+                    "refusalReason": "Authentication required",
+                    "refusalReasonCode": "38",
+                    "merchantReference": "ref_123"
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.sca_exemption_decision,
+                Some(ScaExemptionDecision::Rejected)
+            );
+        }
+    }
+    pub mod payment_method_details {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{
+                CardFundingType, PaymentFlowData, PaymentMethodDetails, PaymentsAuthorizeData,
+                PaymentsResponseData,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                router_data,
+            },
+        };
+
+        fn authorized(additional_data: Option<serde_json::Value>) -> Option<PaymentMethodDetails> {
+            let authorize_router_data: RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<DefaultPCIHolder>,
+                PaymentsResponseData,
+            > = router_data(payment_flow_data(), body_key_auth(), authorize_data(card()));
+            let mut body = json!({
+                "pspReference": "psp_details_123",
+                "resultCode": "Authorised",
+                "merchantReference": "ref_123"
+            });
+            if let Some(additional_data) = additional_data {
+                body["additionalData"] = additional_data;
+            }
+
+            handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &authorize_router_data,
+                200,
+                body,
+            )
+            .resource_common_data
+            .payment_method_details
+        }
+
+        #[test]
+        fn test_card_details_read_from_additional_data() {
+            let details = authorized(Some(json!({
+                "cardSummary": "1111",
+                "paymentMethod": "visa",
+                "fundingSource": "CREDIT"
+            })));
+
+            assert_eq!(
+                details,
+                Some(PaymentMethodDetails {
+                    last4: Some("1111".to_string()),
+                    network: Some(common_enums::CardNetwork::Visa),
+                    funding_type: Some(CardFundingType::Credit),
+                    wallet_type: None,
+                })
+            );
+        }
+
+        #[test]
+        fn test_wallet_type_read_from_wallet_card_payment_method() {
+            let details = authorized(Some(json!({
+                "cardSummary": "4444",
+                "paymentMethod": "mc_applepay",
+                "fundingSource": "DEBIT"
+            })));
+
+            assert_eq!(
+                details,
+                Some(PaymentMethodDetails {
+                    last4: Some("4444".to_string()),
+                    network: Some(common_enums::CardNetwork::Mastercard),
+                    funding_type: Some(CardFundingType::Debit),
+                    wallet_type: Some(common_enums::PaymentMethodType::ApplePay),
+                })
+            );
+        }
+
+        #[test]
+        fn test_payment_method_details_absent_when_not_returned() {
+            assert_eq!(authorized(None), None);
+        }
+    }
+    pub mod connector_warnings {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData},
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                router_data,
+            },
+        };
+
+        fn authorized(additional_data: serde_json::Value) -> Vec<String> {
+            let authorize_router_data: RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<DefaultPCIHolder>,
+                PaymentsResponseData,
+            > = router_data(payment_flow_data(), body_key_auth(), authorize_data(card()));
+
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &authorize_router_data,
+                200,
+                json!({
+                    "pspReference": "psp_warnings_123",
+                    "resultCode": "Authorised",
+                    "merchantReference": "ref_123",
+                    "additionalData": additional_data
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.status,
+                common_enums::AttemptStatus::Charged
+            );
+            router_data.resource_common_data.connector_warnings
+        }
+
+        #[test]
+        fn test_address_mismatch_reported_as_warning() {
+            let warnings = authorized(json!({
+                "avsResult": "2 Neither postal code nor address match",
+                "cvcResult": "1 Matches"
+            }));
+
+            assert_eq!(
+                warnings,
+                vec!["AVS: Neither postal code nor address match".to_string()]
+            );
+        }
+
+        #[test]
+        fn test_no_warnings_when_address_and_cvc_match() {
+            let warnings = authorized(json!({
+                "avsResult": "7 Both postal code and address match",
+                "cvcResult": "1 Matches"
+            }));
+
+            assert!(warnings.is_empty());
+        }
+    }
+    pub mod external_three_ds {
+        use std::str::FromStr;
+
+        use common_utils::types::SemanticVersion;
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData},
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+            router_request_types::AuthenticationData,
+        };
+        use hyperswitch_masking::Secret;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, payment_flow_data, request_body, router_data,
+            },
+        };
+
+        const CAVV: &str = "AAABBEg0VhI0VniQEjRWAAAAAAA=";
+
+        fn authentication_data() -> AuthenticationData {
+            AuthenticationData {
+                eci: Some("05".to_string()),
+                cavv: Secret::new(CAVV.to_string()),
+                threeds_server_transaction_id: Some("3ds_server_txn_123".to_string()),
+                message_version: Some(SemanticVersion::from_str("2.2.0").unwrap()),
+                ds_trans_id: Some("ds_txn_123".to_string()),
+                trans_status: None,
+            }
+        }
+
+        /// Adyen authorize request body of a payment authenticated outside Adyen
+        fn mpi_request_body(authentication_data: AuthenticationData) -> serde_json::Value {
+            let authorize_router_data: RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<DefaultPCIHolder>,
+                PaymentsResponseData,
+            > = router_data(
+                payment_flow_data(),
+                body_key_auth(),
+                PaymentsAuthorizeData {
+                    authentication_data: Some(authentication_data),
+                    ..authorize_data(card())
+                },
+            );
+            request_body(Adyen::<DefaultPCIHolder>::new(), &authorize_router_data)
+        }
+
+        #[test]
+        fn test_external_three_ds_data_sent_as_mpi_data() {
+            let body = mpi_request_body(authentication_data());
+
+            assert_eq!(body["mpiData"]["cavv"], CAVV);
+            assert_eq!(body["mpiData"]["eci"], "05");
+            assert_eq!(body["mpiData"]["dsTransID"], "ds_txn_123");
+            assert_eq!(body["mpiData"]["threeDSVersion"], "2.2.0");
+            // ECI 05 is a fully authenticated payment
+            assert_eq!(body["mpiData"]["authenticationResponse"], "Y");
+            assert_eq!(body["mpiData"]["directoryResponse"], "Y");
+            // The payment is already authenticated, Adyen must not start its own 3DS flow
+            assert!(body["additionalData"].get("executeThreeD").is_none());
+        }
+
+        #[test]
+        fn test_authentication_response_follows_the_outcome() {
+            let attempted = mpi_request_body(AuthenticationData {
+                eci: Some("06".to_string()),
+                ..authentication_data()
+            });
+            assert_eq!(attempted["mpiData"]["authenticationResponse"], "A");
+
+            let not_authenticated = mpi_request_body(AuthenticationData {
+                eci: Some("07".to_string()),
+                ..authentication_data()
+            });
+            assert_eq!(not_authenticated["mpiData"]["authenticationResponse"], "N");
+
+            // A status sent by the client wins over the ECI
+            let with_trans_status = mpi_request_body(AuthenticationData {
+                trans_status: Some("A".to_string()),
+                ..authentication_data()
+            });
+            assert_eq!(with_trans_status["mpiData"]["authenticationResponse"], "A");
+            assert_eq!(with_trans_status["mpiData"]["directoryResponse"], "A");
+        }
+    }
+    pub mod mandate_reference {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{
+                MandateReference, PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                router_data,
+            },
+        };
+
+        fn authorized(body: serde_json::Value) -> Option<Box<MandateReference>> {
+            let authorize_router_data: RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<DefaultPCIHolder>,
+                PaymentsResponseData,
+            > = router_data(payment_flow_data(), body_key_auth(), authorize_data(card()));
+
+            match handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &authorize_router_data,
+                200,
+                body,
+            )
+            .response
+            .unwrap()
+            {
+                PaymentsResponseData::TransactionResponse {
+                    mandate_reference, ..
+                } => mandate_reference,
+                response => panic!("unexpected response: {response:?}"),
+            }
+        }
+
+        #[test]
+        fn test_recurring_detail_reference_returned_as_mandate_reference() {
+            let mandate_reference = authorized(json!({
+                "pspReference": "psp_mandate_123",
+                "resultCode": "Authorised",
+                "merchantReference": "ref_123",
+                "additionalData": {
+                    "recurring.recurringDetailReference": "mandate_123",
+                    "recurring.shopperReference": "shopper_123"
+                }
+            }));
+
+            assert_eq!(
+                mandate_reference.unwrap().connector_mandate_id.as_deref(),
+                Some("mandate_123")
+            );
+        }
+
+        #[test]
+        fn test_no_mandate_reference_when_adyen_stored_none() {
+            let mandate_reference = authorized(json!({
+                "pspReference": "psp_mandate_123",
+                "resultCode": "Authorised",
+                "merchantReference": "ref_123"
+            }));
+
+            assert!(mandate_reference.is_none());
+        }
+    }
+    pub mod installments {
+        use common_utils::types::MinorUnit;
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{
+                InstallmentPlan, PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                request_body, router_data,
+            },
+        };
+
+        fn installment_router_data(
+            installment_count: Option<u32>,
+        ) -> RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<DefaultPCIHolder>,
+            PaymentsResponseData,
+        > {
+            router_data(
+                payment_flow_data(),
+                body_key_auth(),
+                PaymentsAuthorizeData {
+                    installment_count,
+                    ..authorize_data(card())
+                },
+            )
+        }
+
+        #[test]
+        fn test_installment_count_sent_to_adyen() {
+            let body = request_body(
+                Adyen::<DefaultPCIHolder>::new(),
+                &installment_router_data(Some(3)),
+            );
+
+            assert_eq!(body["installments"]["value"], 3);
+        }
+
+        #[test]
+        fn test_no_installments_sent_when_not_requested() {
+            let body = request_body(
+                Adyen::<DefaultPCIHolder>::new(),
+                &installment_router_data(None),
+            );
+
+            assert!(body.get("installments").is_none());
+        }
+
+        #[test]
+        fn test_installment_plan_read_from_additional_data() {
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &installment_router_data(Some(3)),
+                200,
+                json!({
+                    "pspReference": "psp_installments_123",
+                    "resultCode": "Authorised",
+                    "merchantReference": "ref_123",
+                    "additionalData": {
+                        "installments.value": "3",
+                        "installmentPaymentData.option[0].subsequentInstallmentAmount": "10200",
+                        "installmentPaymentData.option[0].totalAmountDue": "30600",
+                        "installmentPaymentData.option[0].interestRate": "1.99"
+                    }
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.installment_plan,
+                Some(InstallmentPlan {
+                    installment_count: 3,
+                    installment_amount: Some(MinorUnit::new(10200)),
+                    total_amount: Some(MinorUnit::new(30600)),
+                    interest_rate: Some("1.99".to_string()),
+                })
+            );
+        }
+    }
+    pub mod three_ds_failure {
+        use domain_types::{
+            connector_flow::Authorize,
+            connector_types::{
+                PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData, ThreeDsFailure,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{
+                authorize_data, body_key_auth, card, handle_response, payment_flow_data,
+                router_data,
+            },
+        };
+
+        fn refused(refusal_reason: &str, refusal_reason_code: &str) -> Option<ThreeDsFailure> {
+            let authorize_router_data: RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<DefaultPCIHolder>,
+                PaymentsResponseData,
+            > = router_data(payment_flow_data(), body_key_auth(), authorize_data(card()));
+
+            let router_data = handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &authorize_router_data,
+                200,
+                json!({
+                    "pspReference": "psp_three_ds_123",
+                    "resultCode": "Refused",
+                    "refusalReason": refusal_reason,
+                    "refusalReasonCode": refusal_reason_code,
+                    "merchantReference": "ref_123"
+                }),
+            );
+
+            assert_eq!(
+                router_data.resource_common_data.status,
+                common_enums::AttemptStatus::Failure
+            );
+            router_data.resource_common_data.three_ds_failure
+        }
+
+        #[test]
+        fn test_failed_cardholder_authentication_is_a_hard_failure() {
+            assert_eq!(
+                refused("3D Not Authenticated", "11"),
+                Some(ThreeDsFailure::Hard)
+            );
+        }
+
+        #[test]
+        fn test_technical_three_ds_errors_are_soft_failures() {
+            assert_eq!(
+                refused("RReq not received from DS", "39"),
+                Some(ThreeDsFailure::Soft)
+            );
+            assert_eq!(
+                refused("3DS Authentication Error", "42"),
+                Some(ThreeDsFailure::Soft)
+            );
+        }
+
+        #[test]
+        fn test_other_refusals_are_not_three_ds_failures() {
+            assert_eq!(refused("Not enough balance", "12"), None);
+        }
+    }
+    pub mod three_ds_completion {
+        use domain_types::{
+            connector_flow::PSync,
+            connector_types::{
+                PaymentFlowData, PaymentsResponseData, PaymentsSyncData, ResponseId,
+                ThreeDsCompletion,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+            types::StatusFlagValues,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Adyen,
+            test_utils::{body_key_auth, handle_response, payment_flow_data, router_data},
+        };
+
+        fn synced(
+            result_code: &str,
+            additional_data: serde_json::Value,
+        ) -> Option<ThreeDsCompletion> {
+            synced_with(payment_flow_data(), result_code, additional_data)
+        }
+
+        fn synced_with(
+            flow_data: PaymentFlowData,
+            result_code: &str,
+            additional_data: serde_json::Value,
+        ) -> Option<ThreeDsCompletion> {
+            let sync_router_data: RouterDataV2<
+                PSync,
+                PaymentFlowData,
+                PaymentsSyncData,
+                PaymentsResponseData,
+            > = router_data(
+                flow_data,
+                body_key_auth(),
+                PaymentsSyncData {
+                    connector_transaction_id: ResponseId::ConnectorTransactionId(
+                        "psp_three_ds_123".to_string(),
+                    ),
+                    ..Default::default()
+                },
+            );
+
+            handle_response(
+                Adyen::<DefaultPCIHolder>::new(),
+                &sync_router_data,
+                200,
+                json!({
+                    "pspReference": "psp_three_ds_123",
+                    "resultCode": result_code,
+                    "merchantReference": "ref_123",
+                    "additionalData": additional_data
+                }),
+            )
+            .resource_common_data
+            .three_ds_completion
+        }
+
+        #[test]
+        fn test_challenge_pending_after_three_ds_method_completed() {
+            assert_eq!(
+                synced("ChallengeShopper", json!({})),
+                Some(ThreeDsCompletion::ChallengePending)
+            );
+        }
+
+        #[test]
+        fn test_completed_when_adyen_reports_authentication() {
+            assert_eq!(
+                synced(
+                    "Authorised",
+                    json!({ "threeDOffered": "true", "threeDAuthenticated": "true" })
+                ),
+                Some(ThreeDsCompletion::Completed)
+            );
+        }
+
+        #[test]
+        fn test_not_completed_when_offered_authentication_failed() {
+            assert_eq!(
+                synced(
+                    "Refused",
+                    json!({ "threeDOffered": "true", "threeDAuthenticated": "false" })
+                ),
+                Some(ThreeDsCompletion::NotCompleted)
+            );
+        }
+
+        #[test]
+        fn test_no_completion_without_three_ds_data() {
+            assert_eq!(synced("Authorised", json!({})), None);
+        }
+
+        #[test]
+        fn test_configured_status_flag_values_replace_adyen_ones() {
+            let mut flow_data = payment_flow_data();
+            flow_data.connectors.adyen.status_flag_values = Some(StatusFlagValues {
+                success: vec!["Y".to_string()],
+                failure: vec!["N".to_string()],
+            });
+
+            assert_eq!(
+                synced_with(
+                    flow_data.clone(),
+                    "Refused",
+                    json!({ "threeDOffered": "Y", "threeDAuthenticated": "N" })
+                ),
+                Some(ThreeDsCompletion::NotCompleted)
+            );
+            assert_eq!(
+                synced_with(
+                    flow_data,
+                    "Authorised",
+                    json!({ "threeDOffered": "true", "threeDAuthenticated": "true" })
+                ),
+                None
+            );
+        }
+    }
 }
@@ -9,22 +9,28 @@ use common_utils::{
 };
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, DefendDispute, PSync, Refund, SetupMandate, SubmitEvidence,
-        Void,
+        Accept, Authorize, Capture, CaptureReversal, DefendDispute, PSync, Refund, SetupMandate,
+        SubmitEvidence, Void,
     },
     connector_types::{
-        AcceptDisputeData, DisputeDefendData, DisputeFlowData, DisputeResponseData, EventType,
-        MandateReference, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
-        PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundsData,
-        RefundsResponseData, ResponseId, SetupMandateRequestData, SubmitEvidenceData,
+        AcceptDisputeData, CardFundingType, CardIssuerInfo, DisputeDefendData, DisputeFlowData,
+        DisputeResponseData, EventType, FraudCheckInfo, FraudDecision, InstallmentPlan,
+        MandateReference, PaymentCaptureReversalData, PaymentFlowData, PaymentMethodDetails,
+        PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData,
+        PaymentsSyncData, RefundFlowData, RefundsData, RefundsResponseData, ResponseId,
+        ScaExemption, ScaExemptionDecision, SetupMandateRequestData, SubmitEvidenceData,
+        ThreeDsCompletion, ThreeDsFailure, ThreeDsVersion,
     },
     errors,
     payment_method_data::{
-        Card, PaymentMethodData, PaymentMethodDataTypes, RawCardNumber, WalletData,
+        Card, NetworkTokenData, PaymentMethodData, PaymentMethodDataTypes, RawCardNumber,
+        VoucherData, WalletData,
     },
-    router_data::{ConnectorAuthType, ErrorResponse},
+    router_data::{ConnectorAuthType, ErrorResponse, NetworkTokenNumber},
     router_data_v2::RouterDataV2,
+    router_request_types::AuthenticationData,
     router_response_types::RedirectForm,
+    types::StatusFlagValues,
 };
 use error_stack::{Report, ResultExt};
 use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
@@ -33,7 +39,7 @@ use time::{Duration, OffsetDateTime};
 use url::Url;
 
 use super::AdyenRouterData;
-use crate::types::ResponseRouterData;
+use crate::{types::ResponseRouterData, utils};
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub enum Currency {
@@ -54,6 +60,10 @@ type Error = error_stack::Report<domain_types::errors::ConnectorError>;
 #[serde(rename_all = "lowercase")]
 pub enum CardBrand {
     Visa,
+    #[serde(rename = "mc")]
+    MC,
+    #[serde(rename = "cartebancaire")]
+    CartesBancaires,
 }
 
 #[derive(Debug, Serialize, PartialEq)]
@@ -83,6 +93,17 @@ pub struct AdyenCard<
     network_payment_reference: Option<Secret<String>>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNetworkTokenData {
+    number: NetworkTokenNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Option<Secret<String>>,
+    brand: Option<CardBrand>,
+    network_payment_reference: Option<Secret<String>>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
@@ -99,6 +120,11 @@ pub enum AdyenPaymentMethod<
     #[serde(rename = "googlepay")]
     Gpay(Box<AdyenGPay>),
     ApplePay(Box<AdyenApplePay>),
+    #[serde(rename = "networkToken")]
+    NetworkToken(Box<AdyenNetworkTokenData>),
+    #[serde(rename = "boletobancario")]
+    BoletoBancario,
+    Oxxo,
 }
 
 #[derive(Debug, Serialize)]
@@ -151,8 +177,47 @@ pub enum PaymentMethod<
 struct AdyenMpiData {
     directory_response: String,
     authentication_response: String,
-    token_authentication_verification_value: Secret<String>,
+    token_authentication_verification_value: Option<Secret<String>>,
+    cavv: Option<Secret<String>>,
     eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: Option<String>,
+}
+
+/// 3DS transaction status of an external authentication, taken from the ECI when the client
+/// did not send it: 05/02 mean authenticated, 06/01 attempted, any other ECI not authenticated
+fn get_mpi_authentication_response(authentication_data: &AuthenticationData) -> String {
+    if let Some(trans_status) = &authentication_data.trans_status {
+        return trans_status.clone();
+    }
+    match authentication_data.eci.as_deref() {
+        Some("05" | "02") => "Y",
+        Some("06" | "01") => "A",
+        Some(_) => "N",
+        None => "U",
+    }
+    .to_string()
+}
+
+/// Results of a 3DS authentication completed outside Adyen, sent for a frictionless authorisation
+impl From<&AuthenticationData> for AdyenMpiData {
+    fn from(authentication_data: &AuthenticationData) -> Self {
+        let authentication_response = get_mpi_authentication_response(authentication_data);
+        Self {
+            directory_response: authentication_response.clone(),
+            authentication_response,
+            token_authentication_verification_value: None,
+            cavv: Some(authentication_data.cavv.clone()),
+            eci: authentication_data.eci.clone(),
+            ds_trans_id: authentication_data.ds_trans_id.clone(),
+            three_ds_version: authentication_data
+                .message_version
+                .as_ref()
+                .map(ToString::to_string),
+        }
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -185,9 +250,13 @@ impl<
             PaymentsResponseData,
         >,
     ) -> Self {
-        match item.request.off_session {
-            Some(true) => Self::ContinuedAuthentication,
-            _ => Self::Ecommerce,
+        match item.request.stored_credential_usage {
+            Some(usage) if usage.is_merchant_initiated() => Self::ContinuedAuthentication,
+            Some(_) => Self::Ecommerce,
+            None => match item.request.off_session {
+                Some(true) => Self::ContinuedAuthentication,
+                _ => Self::Ecommerce,
+            },
         }
     }
 }
@@ -206,6 +275,8 @@ pub struct AdditionalData {
     authorisation_type: Option<AuthType>,
     manual_capture: Option<String>,
     execute_three_d: Option<String>,
+    #[serde(rename = "allow3DS2")]
+    allow_three_ds2: Option<String>,
     pub recurring_processing_model: Option<AdyenRecurringModel>,
     /// Enable recurring details in dashboard to receive this ID, https://docs.adyen.com/online-payments/tokenization/create-and-use-tokens#test-and-go-live
     #[serde(rename = "recurring.recurringDetailReference")]
@@ -213,14 +284,78 @@ pub struct AdditionalData {
     #[serde(rename = "recurring.shopperReference")]
     recurring_shopper_reference: Option<String>,
     network_tx_reference: Option<Secret<String>>,
+    #[serde(rename = "networkToken.tokenRequestorId")]
+    network_token_requestor_id: Option<Secret<String>>,
     funds_availability: Option<String>,
     refusal_reason_raw: Option<String>,
     refusal_code_raw: Option<String>,
     merchant_advice_code: Option<String>,
+    card_bin: Option<String>,
+    card_issuing_bank: Option<String>,
+    card_issuing_country: Option<String>,
+    fraud_result_type: Option<AdyenFraudResultType>,
+    sca_exemption: Option<AdyenScaExemption>,
+    sca_exemption_requested: Option<AdyenScaExemption>,
+    card_summary: Option<String>,
+    payment_method: Option<String>,
+    payment_method_variant: Option<String>,
+    funding_source: Option<String>,
+    avs_result: Option<String>,
+    cvc_result: Option<String>,
+    #[serde(rename = "installments.value")]
+    installments_value: Option<String>,
+    #[serde(rename = "installmentPaymentData.option[0].subsequentInstallmentAmount")]
+    installment_amount: Option<String>,
+    #[serde(rename = "installmentPaymentData.option[0].totalAmountDue")]
+    installment_total_amount_due: Option<String>,
+    #[serde(rename = "installmentPaymentData.option[0].interestRate")]
+    installment_interest_rate: Option<String>,
+    three_d_offered: Option<String>,
+    three_d_authenticated: Option<String>,
     #[serde(flatten)]
     riskdata: Option<RiskData>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenScaExemption {
+    LowValue,
+    TransactionRiskAnalysis,
+}
+
+impl From<ScaExemption> for AdyenScaExemption {
+    fn from(value: ScaExemption) -> Self {
+        match value {
+            ScaExemption::LowValue => Self::LowValue,
+            ScaExemption::TransactionRiskAnalysis => Self::TransactionRiskAnalysis,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenFraudResultType {
+    Green,
+    Amber,
+    Red,
+}
+
+impl From<AdyenFraudResultType> for FraudDecision {
+    fn from(value: AdyenFraudResultType) -> Self {
+        match value {
+            AdyenFraudResultType::Green => Self::Accept,
+            AdyenFraudResultType::Amber => Self::Review,
+            AdyenFraudResultType::Red => Self::Reject,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenFraudResult {
+    account_score: i32,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -519,6 +654,13 @@ pub struct AdyenPaymentRequest<
     splits: Option<Vec<AdyenSplitData>>,
     store: Option<String>,
     device_fingerprint: Option<Secret<String>>,
+    installments: Option<AdyenInstallments>,
+}
+
+/// Number of installments a card payment is split into
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenInstallments {
+    value: u32,
 }
 
 #[derive(Debug, Serialize)]
@@ -615,19 +757,64 @@ impl<
 {
     type Error = domain_types::errors::ConnectorError;
     fn try_from((card, card_holder_name): (&Card<T>, Option<String>)) -> Result<Self, Self::Error> {
+        // For co-badged cards the brand selects the network the payment is routed on
+        let brand = match (&card.co_badged_card_data, &card.card_network) {
+            (Some(_), Some(common_enums::CardNetwork::Visa)) => Some(CardBrand::Visa),
+            (Some(_), Some(common_enums::CardNetwork::Mastercard)) => Some(CardBrand::MC),
+            (Some(_), Some(common_enums::CardNetwork::CartesBancaires)) => {
+                Some(CardBrand::CartesBancaires)
+            }
+            (Some(_), _) => None,
+            (None, _) => Some(CardBrand::Visa),
+        };
         let adyen_card = AdyenCard {
             number: card.card_number.clone(),
             expiry_month: card.card_exp_month.clone(),
             expiry_year: card.card_exp_year.clone(),
-            cvc: Some(card.card_cvc.clone()),
+            // An empty CVC was left out of a CVC-less payment
+            cvc: Some(card.card_cvc.clone()).filter(|cvc| !cvc.peek().is_empty()),
             holder_name: card_holder_name.map(Secret::new),
-            brand: Some(CardBrand::Visa),
+            brand,
             network_payment_reference: None,
         };
         Ok(AdyenPaymentMethod::AdyenCard(Box::new(adyen_card)))
     }
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > TryFrom<(&NetworkTokenData, Option<String>)> for AdyenPaymentMethod<T>
+{
+    type Error = domain_types::errors::ConnectorError;
+    fn try_from(
+        (token_data, card_holder_name): (&NetworkTokenData, Option<String>),
+    ) -> Result<Self, Self::Error> {
+        let brand = match token_data.card_network {
+            Some(common_enums::CardNetwork::Visa) => Some(CardBrand::Visa),
+            Some(common_enums::CardNetwork::Mastercard) => Some(CardBrand::MC),
+            _ => None,
+        };
+        let adyen_network_token = AdyenNetworkTokenData {
+            number: token_data.get_network_token(),
+            expiry_month: token_data.get_network_token_expiry_month(),
+            expiry_year: token_data.get_expiry_year_4_digit(),
+            holder_name: card_holder_name
+                .map(Secret::new)
+                .or(token_data.card_holder_name.clone()),
+            brand,
+            network_payment_reference: None,
+        };
+        Ok(AdyenPaymentMethod::NetworkToken(Box::new(
+            adyen_network_token,
+        )))
+    }
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -715,6 +902,38 @@ impl<
     }
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > TryFrom<&VoucherData> for AdyenPaymentMethod<T>
+{
+    type Error = Error;
+    fn try_from(voucher_data: &VoucherData) -> Result<Self, Self::Error> {
+        match voucher_data {
+            VoucherData::Boleto(_) => Ok(AdyenPaymentMethod::BoletoBancario),
+            VoucherData::Oxxo => Ok(AdyenPaymentMethod::Oxxo),
+            VoucherData::Efecty
+            | VoucherData::PagoEfectivo
+            | VoucherData::RedCompra
+            | VoucherData::RedPagos
+            | VoucherData::Alfamart(_)
+            | VoucherData::Indomaret(_)
+            | VoucherData::SevenEleven(_)
+            | VoucherData::Lawson(_)
+            | VoucherData::MiniStop(_)
+            | VoucherData::FamilyMart(_)
+            | VoucherData::Seicomart(_)
+            | VoucherData::PayEasy(_) => Err(errors::ConnectorError::NotImplemented(
+                "payment_method".into(),
+            ))?,
+        }
+    }
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -780,6 +999,18 @@ impl<
             AdyenPaymentMethod::try_from((card_data, card_holder_name))?,
         ));
 
+        let mpi_data = item
+            .router_data
+            .request
+            .authentication_data
+            .as_ref()
+            .map(AdyenMpiData::from);
+        let installments = item
+            .router_data
+            .request
+            .installment_count
+            .map(|value| AdyenInstallments { value });
+
         Ok(AdyenPaymentRequest {
             amount,
             merchant_account: auth_type.merchant_account,
@@ -794,7 +1025,7 @@ impl<
             recurring_processing_model,
             browser_info: None,
             additional_data,
-            mpi_data: None,
+            mpi_data,
             telephone_number: None,
             shopper_name: None,
             shopper_email: None,
@@ -813,6 +1044,128 @@ impl<
             store: None,
             splits: None,
             device_fingerprint: None,
+            installments,
+        })
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<(
+        AdyenRouterData<
+            RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<T>,
+                PaymentsResponseData,
+            >,
+            T,
+        >,
+        &NetworkTokenData,
+    )> for AdyenPaymentRequest<T>
+{
+    type Error = Error;
+    fn try_from(
+        value: (
+            AdyenRouterData<
+                RouterDataV2<
+                    Authorize,
+                    PaymentFlowData,
+                    PaymentsAuthorizeData<T>,
+                    PaymentsResponseData,
+                >,
+                T,
+            >,
+            &NetworkTokenData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (item, token_data) = value;
+        let amount = get_amount_data(&item);
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        let shopper_interaction = AdyenShopperInteraction::from(&item.router_data);
+        let shopper_reference = build_shopper_reference(
+            &item.router_data.request.customer_id.clone(),
+            item.router_data.resource_common_data.merchant_id.clone(),
+        );
+        let (recurring_processing_model, store_payment_method, _) =
+            get_recurring_processing_model(&item.router_data)?;
+
+        let return_url = item.router_data.request.get_router_return_url()?;
+
+        let billing_address = get_address_info(
+            item.router_data
+                .resource_common_data
+                .address
+                .get_payment_billing(),
+        )
+        .and_then(Result::ok);
+
+        let card_holder_name = item.router_data.request.customer_name.clone();
+
+        let additional_data = match token_data.token_requestor_id.clone() {
+            Some(token_requestor_id) => Some(AdditionalData {
+                network_token_requestor_id: Some(token_requestor_id),
+                ..get_additional_data(&item.router_data).unwrap_or_default()
+            }),
+            None => get_additional_data(&item.router_data),
+        };
+
+        let payment_method = PaymentMethod::AdyenPaymentMethod(Box::new(
+            AdyenPaymentMethod::try_from((token_data, card_holder_name))?,
+        ));
+
+        // The token cryptogram is passed as a pre-authenticated TAVV in the MPI data.
+        // Merchant-initiated payments carry no cryptogram and are sent without MPI data
+        let mpi_data = token_data.get_cryptogram().map(|cryptogram| AdyenMpiData {
+            directory_response: "Y".to_string(),
+            authentication_response: "Y".to_string(),
+            token_authentication_verification_value: Some(cryptogram),
+            cavv: None,
+            eci: Some(token_data.eci.clone().unwrap_or("02".to_string())),
+            ds_trans_id: None,
+            three_ds_version: None,
+        });
+
+        Ok(AdyenPaymentRequest {
+            amount,
+            merchant_account: auth_type.merchant_account,
+            payment_method,
+            reference: item
+                .router_data
+                .resource_common_data
+                .connector_request_reference_id
+                .clone(),
+            return_url,
+            shopper_interaction,
+            recurring_processing_model,
+            browser_info: None,
+            additional_data,
+            mpi_data,
+            telephone_number: None,
+            shopper_name: None,
+            shopper_email: None,
+            shopper_locale: None,
+            social_security_number: None,
+            billing_address,
+            delivery_address: None,
+            country_code: None,
+            line_items: None,
+            shopper_reference,
+            store_payment_method,
+            channel: None,
+            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_ip: None,
+            merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            store: None,
+            splits: None,
+            device_fingerprint: None,
+            installments: None,
         })
     }
 }
@@ -898,6 +1251,122 @@ impl<
             store: None,
             splits: None,
             device_fingerprint: None,
+            installments: None,
+        })
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<(
+        AdyenRouterData<
+            RouterDataV2<
+                Authorize,
+                PaymentFlowData,
+                PaymentsAuthorizeData<T>,
+                PaymentsResponseData,
+            >,
+            T,
+        >,
+        &VoucherData,
+    )> for AdyenPaymentRequest<T>
+{
+    type Error = Error;
+    fn try_from(
+        value: (
+            AdyenRouterData<
+                RouterDataV2<
+                    Authorize,
+                    PaymentFlowData,
+                    PaymentsAuthorizeData<T>,
+                    PaymentsResponseData,
+                >,
+                T,
+            >,
+            &VoucherData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (item, voucher_data) = value;
+        let amount = get_amount_data(&item);
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        let payment_method = PaymentMethod::AdyenPaymentMethod(Box::new(
+            AdyenPaymentMethod::try_from(voucher_data)?,
+        ));
+        // Boleto vouchers are issued against the shopper's CPF/CNPJ
+        let social_security_number = match voucher_data {
+            VoucherData::Boleto(boleto_data) => {
+                Some(boleto_data.social_security_number.clone().ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "social_security_number",
+                    },
+                )?)
+            }
+            _ => None,
+        };
+        let shopper_interaction = AdyenShopperInteraction::from(&item.router_data);
+        let (recurring_processing_model, store_payment_method, shopper_reference) =
+            get_recurring_processing_model(&item.router_data)?;
+        let return_url = item.router_data.request.get_router_return_url()?;
+        let additional_data = get_additional_data(&item.router_data);
+        let shopper_name = item
+            .router_data
+            .resource_common_data
+            .get_optional_billing_first_name()
+            .map(|first_name| ShopperName {
+                first_name: Some(first_name),
+                last_name: item
+                    .router_data
+                    .resource_common_data
+                    .get_optional_billing_last_name(),
+            });
+        let billing_address = get_address_info(
+            item.router_data
+                .resource_common_data
+                .address
+                .get_payment_billing(),
+        )
+        .and_then(Result::ok);
+
+        Ok(AdyenPaymentRequest {
+            amount,
+            merchant_account: auth_type.merchant_account,
+            payment_method,
+            reference: item
+                .router_data
+                .resource_common_data
+                .connector_request_reference_id
+                .clone(),
+            return_url,
+            shopper_interaction,
+            recurring_processing_model,
+            browser_info: None,
+            additional_data,
+            mpi_data: None,
+            telephone_number: None,
+            shopper_name,
+            shopper_email: item.router_data.request.email.clone(),
+            shopper_locale: None,
+            social_security_number,
+            billing_address,
+            delivery_address: None,
+            country_code: None,
+            line_items: None,
+            shopper_reference,
+            store_payment_method,
+            channel: None,
+            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_ip: None,
+            merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            store: None,
+            splits: None,
+            device_fingerprint: None,
+            installments: None,
         })
     }
 }
@@ -949,12 +1418,17 @@ impl<
                 PaymentMethodData::Wallet(ref wallet_data) => {
                     AdyenPaymentRequest::try_from((item, wallet_data))
                 }
+                PaymentMethodData::NetworkToken(ref token_data) => {
+                    AdyenPaymentRequest::try_from((item, token_data))
+                }
+                PaymentMethodData::Voucher(ref voucher_data) => {
+                    AdyenPaymentRequest::try_from((item, voucher_data))
+                }
                 PaymentMethodData::PayLater(_)
                 | PaymentMethodData::BankRedirect(_)
                 | PaymentMethodData::BankDebit(_)
                 | PaymentMethodData::BankTransfer(_)
                 | PaymentMethodData::CardRedirect(_)
-                | PaymentMethodData::Voucher(_)
                 | PaymentMethodData::GiftCard(_)
                 | PaymentMethodData::Crypto(_)
                 | PaymentMethodData::MandatePayment
@@ -963,7 +1437,6 @@ impl<
                 | PaymentMethodData::Upi(_)
                 | PaymentMethodData::OpenBanking(_)
                 | PaymentMethodData::CardDetailsForNetworkTransactionId(_)
-                | PaymentMethodData::NetworkToken(_)
                 | PaymentMethodData::MobilePayment(_)
                 | PaymentMethodData::CardToken(_) => Err(
                     domain_types::errors::ConnectorError::NotImplemented("payment method".into()),
@@ -1088,6 +1561,7 @@ pub struct AdyenResponse {
     additional_data: Option<AdditionalData>,
     splits: Option<Vec<AdyenSplitData>>,
     store: Option<String>,
+    fraud_result: Option<AdyenFraudResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1122,6 +1596,13 @@ pub struct AdyenRedirectAction {
     type_of_response: ActionType,
     data: Option<std::collections::HashMap<String, String>>,
     payment_data: Option<String>,
+    reference: Option<String>,
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option_without_timezone"
+    )]
+    expires_at: Option<time::PrimitiveDateTime>,
+    download_url: Option<Url>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1221,6 +1702,14 @@ impl<
         } = value;
         let is_manual_capture = false;
         let pmt = router_data.request.payment_method_type;
+        let card_issuer_info = get_card_issuer_info(&response);
+        let fraud_check = get_fraud_check_info(&response);
+        let payment_method_details = get_payment_method_details(&response);
+        let connector_warnings = get_connector_warnings(&response);
+        let sca_exemption_decision =
+            get_sca_exemption_decision(router_data.request.requested_exemption, &response);
+        let installment_plan = get_installment_plan(&response);
+        let three_ds_failure = get_three_ds_failure(&response);
         let (status, error, payment_response_data) = match response {
             AdyenPaymentResponse::Response(response) => {
                 get_adyen_response(*response, is_manual_capture, http_code, pmt)?
@@ -1234,6 +1723,13 @@ impl<
             response: error.map_or_else(|| Ok(payment_response_data), Err),
             resource_common_data: PaymentFlowData {
                 status,
+                card_issuer_info,
+                fraud_check,
+                sca_exemption_decision,
+                payment_method_details,
+                connector_warnings,
+                installment_plan,
+                three_ds_failure,
                 ..router_data.resource_common_data
             },
             ..router_data
@@ -1253,6 +1749,18 @@ impl<F> TryFrom<ResponseRouterData<AdyenPSyncResponse, Self>>
         } = value;
         let pmt = router_data.request.payment_method_type;
         let is_manual_capture = false;
+        let card_issuer_info = get_card_issuer_info(&response.0);
+        let payment_method_details = get_payment_method_details(&response.0);
+        let connector_warnings = get_connector_warnings(&response.0);
+        let three_ds_completion = get_three_ds_completion(
+            &response.0,
+            router_data
+                .resource_common_data
+                .connectors
+                .adyen
+                .status_flag_values
+                .as_ref(),
+        );
         let (status, error, payment_response_data) = match response {
             AdyenPSyncResponse(AdyenPaymentResponse::Response(response)) => {
                 get_adyen_response(*response, is_manual_capture, http_code, pmt)?
@@ -1266,6 +1774,10 @@ impl<F> TryFrom<ResponseRouterData<AdyenPSyncResponse, Self>>
             response: error.map_or_else(|| Ok(payment_response_data), Err),
             resource_common_data: PaymentFlowData {
                 status,
+                card_issuer_info,
+                payment_method_details,
+                connector_warnings,
+                three_ds_completion,
                 ..router_data.resource_common_data
             },
             ..router_data
@@ -1325,6 +1837,364 @@ impl TryFrom<ResponseRouterData<AdyenVoidResponse, Self>>
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureReversalRequest {
+    merchant_account: Secret<String>,
+    reference: String,
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        AdyenRouterData<
+            RouterDataV2<
+                CaptureReversal,
+                PaymentFlowData,
+                PaymentCaptureReversalData,
+                PaymentsResponseData,
+            >,
+            T,
+        >,
+    > for AdyenCaptureReversalRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: AdyenRouterData<
+            RouterDataV2<
+                CaptureReversal,
+                PaymentFlowData,
+                PaymentCaptureReversalData,
+                PaymentsResponseData,
+            >,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            reference: item
+                .router_data
+                .resource_common_data
+                .connector_request_reference_id
+                .clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureReversalResponse {
+    payment_psp_reference: String,
+    psp_reference: String,
+    status: AdyenCaptureReversalStatus,
+    reference: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdyenCaptureReversalStatus {
+    Received,
+    Processing,
+}
+
+impl From<AdyenCaptureReversalStatus> for AttemptStatus {
+    fn from(item: AdyenCaptureReversalStatus) -> Self {
+        match item {
+            // Adyen has queued the reversal, the outcome is delivered through
+            // the CANCEL_OR_REFUND webhook
+            AdyenCaptureReversalStatus::Received => Self::VoidInitiated,
+            AdyenCaptureReversalStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+impl TryFrom<ResponseRouterData<AdyenCaptureReversalResponse, Self>>
+    for RouterDataV2<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    >
+{
+    type Error = Error;
+    fn try_from(
+        value: ResponseRouterData<AdyenCaptureReversalResponse, Self>,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = value;
+        let status = AttemptStatus::from(response.status);
+
+        Ok(Self {
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(
+                    router_data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(response.psp_reference),
+                incremental_authorization_allowed: None,
+                mandate_reference: None,
+                status_code: http_code,
+            }),
+            resource_common_data: PaymentFlowData {
+                status,
+                ..router_data.resource_common_data
+            },
+            ..router_data
+        })
+    }
+}
+
+fn get_card_issuer_info(response: &AdyenPaymentResponse) -> Option<CardIssuerInfo> {
+    let AdyenPaymentResponse::Response(response) = response else {
+        return None;
+    };
+    let additional_data = response.additional_data.as_ref()?;
+    let card_issuer_info = CardIssuerInfo {
+        card_issuer: additional_data.card_issuing_bank.clone(),
+        card_issuing_country: additional_data.card_issuing_country.clone(),
+        bin: additional_data.card_bin.clone(),
+    };
+    (card_issuer_info != CardIssuerInfo::default()).then_some(card_issuer_info)
+}
+
+/// Refusal reason code Adyen returns when the cardholder failed 3DS authentication
+const ADYEN_THREE_DS_NOT_AUTHENTICATED_REFUSAL_CODE: &str = "11";
+/// Refusal reason code Adyen returns when the directory server did not send the 3DS result
+const ADYEN_THREE_DS_RREQ_NOT_RECEIVED_REFUSAL_CODE: &str = "39";
+/// Refusal reason code Adyen returns for a technical error during 3DS authentication
+const ADYEN_THREE_DS_AUTHENTICATION_ERROR_REFUSAL_CODE: &str = "42";
+
+/// Classifies 3DS refusals: a failed cardholder authentication is hard, technical 3DS
+/// errors are soft
+fn get_three_ds_failure(response: &AdyenPaymentResponse) -> Option<ThreeDsFailure> {
+    let refusal_reason_code = match response {
+        AdyenPaymentResponse::Response(response) => response.refusal_reason_code.as_deref(),
+        AdyenPaymentResponse::RedirectionResponse(response) => {
+            response.refusal_reason_code.as_deref()
+        }
+    }?;
+    match refusal_reason_code {
+        ADYEN_THREE_DS_NOT_AUTHENTICATED_REFUSAL_CODE => Some(ThreeDsFailure::Hard),
+        ADYEN_THREE_DS_RREQ_NOT_RECEIVED_REFUSAL_CODE
+        | ADYEN_THREE_DS_AUTHENTICATION_ERROR_REFUSAL_CODE => Some(ThreeDsFailure::Soft),
+        _ => None,
+    }
+}
+
+/// Progress of the 3DS authentication: a shopper challenge means the 3DS method step is
+/// done, and the `threeDOffered`/`threeDAuthenticated` flags, read with the configured
+/// `status_flag_values`, settle the final outcome
+fn get_three_ds_completion(
+    response: &AdyenPaymentResponse,
+    status_flag_values: Option<&StatusFlagValues>,
+) -> Option<ThreeDsCompletion> {
+    let (result_code, additional_data) = match response {
+        AdyenPaymentResponse::Response(response) => {
+            (&response.result_code, response.additional_data.as_ref())
+        }
+        AdyenPaymentResponse::RedirectionResponse(response) => {
+            (&response.result_code, response.additional_data.as_ref())
+        }
+    };
+    match result_code {
+        AdyenStatus::ChallengeShopper => return Some(ThreeDsCompletion::ChallengePending),
+        AdyenStatus::AuthenticationFinished => return Some(ThreeDsCompletion::Completed),
+        _ => {}
+    }
+    let additional_data = additional_data?;
+    let flag = |value: &Option<String>| {
+        value
+            .as_deref()
+            .and_then(|value| parse_status_flag(value, status_flag_values))
+    };
+    match (
+        flag(&additional_data.three_d_offered),
+        flag(&additional_data.three_d_authenticated),
+    ) {
+        (_, Some(true)) => Some(ThreeDsCompletion::Completed),
+        (Some(true), Some(false)) => Some(ThreeDsCompletion::NotCompleted),
+        _ => None,
+    }
+}
+
+/// Refusal reason code Adyen returns when the issuer requires SCA despite the exemption
+const ADYEN_AUTHENTICATION_REQUIRED_REFUSAL_CODE: &str = "38";
+
+fn get_sca_exemption_decision(
+    requested_exemption: Option<ScaExemption>,
+    response: &AdyenPaymentResponse,
+) -> Option<ScaExemptionDecision> {
+    requested_exemption?;
+    match response {
+        AdyenPaymentResponse::Response(response) => {
+            if response.refusal_reason_code.as_deref()
+                == Some(ADYEN_AUTHENTICATION_REQUIRED_REFUSAL_CODE)
+            {
+                Some(ScaExemptionDecision::Rejected)
+            } else {
+                response
+                    .additional_data
+                    .as_ref()
+                    .and_then(|additional_data| additional_data.sca_exemption_requested.as_ref())
+                    .map(|_| ScaExemptionDecision::Applied)
+            }
+        }
+        // A 3DS redirect means the cardholder is challenged after all
+        AdyenPaymentResponse::RedirectionResponse(response) => {
+            matches!(response.action.type_of_response, ActionType::Redirect)
+                .then_some(ScaExemptionDecision::Rejected)
+        }
+    }
+}
+
+fn get_payment_method_details(response: &AdyenPaymentResponse) -> Option<PaymentMethodDetails> {
+    let AdyenPaymentResponse::Response(response) = response else {
+        return None;
+    };
+    let additional_data = response.additional_data.as_ref()?;
+    // Adyen names wallet payments after the card brand, e.g. `visa_applepay`
+    let payment_method = additional_data.payment_method.as_deref();
+    let wallet_type = [
+        payment_method,
+        additional_data.payment_method_variant.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|name| {
+        if name.contains("applepay") {
+            Some(common_enums::PaymentMethodType::ApplePay)
+        } else if name.contains("googlepay") || name.contains("paywithgoogle") {
+            Some(common_enums::PaymentMethodType::GooglePay)
+        } else if name.contains("samsungpay") {
+            Some(common_enums::PaymentMethodType::SamsungPay)
+        } else {
+            None
+        }
+    });
+    let network = payment_method
+        .and_then(|payment_method| payment_method.split('_').next())
+        .and_then(|brand| match brand {
+            "visa" => Some(common_enums::CardNetwork::Visa),
+            "mc" => Some(common_enums::CardNetwork::Mastercard),
+            "amex" => Some(common_enums::CardNetwork::AmericanExpress),
+            "jcb" => Some(common_enums::CardNetwork::JCB),
+            "diners" => Some(common_enums::CardNetwork::DinersClub),
+            "discover" => Some(common_enums::CardNetwork::Discover),
+            "cup" => Some(common_enums::CardNetwork::UnionPay),
+            "maestro" => Some(common_enums::CardNetwork::Maestro),
+            "cartebancaire" => Some(common_enums::CardNetwork::CartesBancaires),
+            _ => None,
+        });
+    let funding_type = additional_data
+        .funding_source
+        .as_deref()
+        .and_then(|funding_source| match funding_source {
+            "CREDIT" => Some(CardFundingType::Credit),
+            "DEBIT" => Some(CardFundingType::Debit),
+            "PREPAID" | "PREPAID_RELOADABLE" | "PREPAID_NONRELOADABLE" => {
+                Some(CardFundingType::Prepaid)
+            }
+            _ => None,
+        });
+    let payment_method_details = PaymentMethodDetails {
+        // Only a four digit summary is passed on, never anything closer to the card number
+        last4: additional_data.card_summary.clone().filter(|card_summary| {
+            card_summary.len() == 4 && card_summary.chars().all(|c| c.is_ascii_digit())
+        }),
+        network,
+        funding_type,
+        wallet_type,
+    };
+    (payment_method_details != PaymentMethodDetails::default()).then_some(payment_method_details)
+}
+
+/// Installment plan Adyen reports in `installments.value` and the first
+/// `installmentPaymentData` option, with amounts in minor units
+fn get_installment_plan(response: &AdyenPaymentResponse) -> Option<InstallmentPlan> {
+    let AdyenPaymentResponse::Response(response) = response else {
+        return None;
+    };
+    let additional_data = response.additional_data.as_ref()?;
+    let parse_amount = |amount: &Option<String>| {
+        amount
+            .as_deref()
+            .and_then(|amount| amount.parse::<i64>().ok())
+            .map(MinorUnit::new)
+    };
+    Some(InstallmentPlan {
+        installment_count: additional_data
+            .installments_value
+            .as_deref()?
+            .parse()
+            .ok()?,
+        installment_amount: parse_amount(&additional_data.installment_amount),
+        total_amount: parse_amount(&additional_data.installment_total_amount_due),
+        interest_rate: additional_data.installment_interest_rate.clone(),
+    })
+}
+
+/// AVS result codes for which Adyen accepted a payment despite a billing address mismatch
+const ADYEN_AVS_MISMATCH_CODES: [&str; 5] = ["1", "2", "6", "11", "12"];
+/// CVC result code for a security code that did not match
+const ADYEN_CVC_MISMATCH_CODE: &str = "2";
+
+/// Collects AVS and CVC mismatches that Adyen reports as `<code> <description>`
+fn get_connector_warnings(response: &AdyenPaymentResponse) -> Vec<String> {
+    let AdyenPaymentResponse::Response(response) = response else {
+        return Vec::new();
+    };
+    let Some(additional_data) = response.additional_data.as_ref() else {
+        return Vec::new();
+    };
+    let avs_warning = additional_data
+        .avs_result
+        .as_deref()
+        .and_then(|avs_result| {
+            let (code, description) = avs_result.split_once(' ').unwrap_or((avs_result, ""));
+            ADYEN_AVS_MISMATCH_CODES
+                .contains(&code)
+                .then(|| format!("AVS: {}", description.trim()))
+        });
+    let cvc_warning = additional_data
+        .cvc_result
+        .as_deref()
+        .and_then(|cvc_result| {
+            let (code, description) = cvc_result.split_once(' ').unwrap_or((cvc_result, ""));
+            (code == ADYEN_CVC_MISMATCH_CODE).then(|| format!("CVC: {}", description.trim()))
+        });
+    [avs_warning, cvc_warning].into_iter().flatten().collect()
+}
+
+fn get_fraud_check_info(response: &AdyenPaymentResponse) -> Option<FraudCheckInfo> {
+    let AdyenPaymentResponse::Response(response) = response else {
+        return None;
+    };
+    let fraud_check = FraudCheckInfo {
+        fraud_score: response
+            .fraud_result
+            .as_ref()
+            .map(|fraud_result| fraud_result.account_score),
+        fraud_decision: response
+            .additional_data
+            .as_ref()
+            .and_then(|additional_data| additional_data.fraud_result_type.clone())
+            .map(FraudDecision::from),
+    };
+    (fraud_check != FraudCheckInfo::default()).then_some(fraud_check)
+}
+
 pub fn get_adyen_response(
     response: AdyenResponse,
     is_capture_manual: bool,
@@ -1442,7 +2312,10 @@ pub fn get_redirection_response(
         }
     });
 
-    let connector_metadata = get_wait_screen_metadata(&response)?;
+    let connector_metadata = match get_voucher_metadata(&response) {
+        Some(voucher_metadata) => Some(voucher_metadata),
+        None => get_wait_screen_metadata(&response)?,
+    };
 
     let payments_response_data = PaymentsResponseData::TransactionResponse {
         resource_id: match response.psp_reference.as_ref() {
@@ -1463,6 +2336,27 @@ pub fn get_redirection_response(
     Ok((status, error, payments_response_data))
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoucherNextStepData {
+    voucher_number: Option<String>,
+    voucher_expires_at: Option<i64>,
+    voucher_download_url: Option<String>,
+}
+
+pub fn get_voucher_metadata(next_action: &RedirectionResponse) -> Option<serde_json::Value> {
+    let action = &next_action.action;
+    match action.type_of_response {
+        ActionType::Voucher => Some(serde_json::json!(VoucherNextStepData {
+            voucher_number: action.reference.clone(),
+            voucher_expires_at: action
+                .expires_at
+                .map(|expires_at| expires_at.assume_utc().unix_timestamp()),
+            voucher_download_url: action.download_url.as_ref().map(Url::to_string),
+        })),
+        ActionType::Redirect | ActionType::Await | ActionType::QrCode => None,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaitScreenData {
     display_from_timestamp: i128,
@@ -1615,8 +2509,25 @@ pub struct AdyenAmountWH {
     pub currency: common_enums::Currency,
 }
 
+/// Adyen sends its boolean flags, such as the notification `success` and the
+/// `threeDOffered`/`threeDAuthenticated` additional data, as the strings "true" and "false"
+const ADYEN_STATUS_FLAG_VALUES: utils::DefaultStatusFlagValues = utils::DefaultStatusFlagValues {
+    success: &["true"],
+    failure: &["false"],
+};
+
+fn parse_status_flag(flag: &str, configured: Option<&StatusFlagValues>) -> Option<bool> {
+    utils::normalize_status_flag(
+        &serde_json::Value::String(flag.to_string()),
+        configured,
+        &ADYEN_STATUS_FLAG_VALUES,
+    )
+}
+
+// Webhook processing has no access to the connector config, so notifications are always
+// read with Adyen's own flag values
 fn is_success_scenario(is_success: &str) -> bool {
-    is_success == "true"
+    parse_status_flag(is_success, None).unwrap_or(false)
 }
 
 pub(crate) fn get_adyen_payment_webhook_event(
@@ -1777,7 +2688,26 @@ fn get_recurring_processing_model<
                 customer_id.get_string_repr()
             )),
         )),
-        _ => Ok((None, None, None)),
+        _ => match item.request.stored_credential_usage {
+            Some(usage) if usage.is_subsequent() => {
+                // Cardholder paying with a stored card is CardOnFile, merchant-initiated is not
+                let recurring_model = if usage.is_merchant_initiated() {
+                    AdyenRecurringModel::UnscheduledCardOnFile
+                } else {
+                    AdyenRecurringModel::CardOnFile
+                };
+                Ok((
+                    Some(recurring_model),
+                    None,
+                    Some(format!(
+                        "{}_{}",
+                        item.resource_common_data.merchant_id.get_string_repr(),
+                        customer_id.get_string_repr()
+                    )),
+                ))
+            }
+            _ => Ok((None, None, None)),
+        },
     }
 }
 
@@ -1846,11 +2776,21 @@ fn get_additional_data<
     } else {
         None
     };
+    // Adyen falls back to 3DS1 unless 3DS2 is explicitly allowed
+    let allow_three_ds2 = execute_three_d
+        .as_ref()
+        .and(item.request.three_ds_version)
+        .map(|version| (version == ThreeDsVersion::V2).to_string());
+    let sca_exemption = item
+        .request
+        .requested_exemption
+        .map(AdyenScaExemption::from);
 
     if authorisation_type.is_none()
         && manual_capture.is_none()
         && execute_three_d.is_none()
         && riskdata.is_none()
+        && sca_exemption.is_none()
     {
         //without this if-condition when the above 3 values are None, additionalData will be serialized to JSON like this -> additionalData: {}
         //returning None, ensures that additionalData key will not be present in the serialized JSON
@@ -1860,6 +2800,8 @@ fn get_additional_data<
             authorisation_type,
             manual_capture,
             execute_three_d,
+            allow_three_ds2,
+            sca_exemption,
             network_tx_reference: None,
             recurring_detail_reference: None,
             recurring_shopper_reference: None,
@@ -2075,6 +3017,7 @@ impl<F, Req> TryFrom<ResponseRouterData<AdyenRefundResponse, Self>>
             connector_refund_id: response.psp_reference,
             refund_status: status,
             status_code: http_code,
+            connector_response_reference_id: Some(response.reference),
         };
 
         Ok(Self {
@@ -2300,6 +3243,7 @@ impl<
             store: None,
             splits: None,
             device_fingerprint: None,
+            installments: None,
         }))
     }
 }
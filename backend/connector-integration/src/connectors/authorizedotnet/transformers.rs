@@ -6,7 +6,8 @@ use domain_types::{
         MandateReferenceId, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
         PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData,
         RefundSyncData, RefundsData, RefundsResponseData, RepeatPaymentData, ResponseId,
-        SetupMandateRequestData,
+        SetupMandateRequestData, StoredCredentialInitiator, StoredCredentialSequence,
+        StoredCredentialUsage,
     },
     errors::ConnectorError,
     payment_method_data::{
@@ -256,7 +257,29 @@ pub struct UserFields {
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessingOptions {
-    is_subsequent_auth: bool,
+    is_first_subsequent_auth: Option<bool>,
+    is_subsequent_auth: Option<bool>,
+    is_stored_credentials: Option<bool>,
+}
+
+impl From<StoredCredentialUsage> for ProcessingOptions {
+    fn from(usage: StoredCredentialUsage) -> Self {
+        match (usage.sequence, usage.initiator) {
+            // Cardholder-initiated payment that stores the credential for later use
+            (StoredCredentialSequence::First, _) => Self {
+                is_first_subsequent_auth: Some(true),
+                ..Default::default()
+            },
+            (StoredCredentialSequence::Subsequent, StoredCredentialInitiator::Cardholder) => Self {
+                is_stored_credentials: Some(true),
+                ..Default::default()
+            },
+            (StoredCredentialSequence::Subsequent, StoredCredentialInitiator::Merchant) => Self {
+                is_subsequent_auth: Some(true),
+                ..Default::default()
+            },
+        }
+    }
 }
 
 #[skip_serializing_none]
@@ -595,7 +618,11 @@ fn create_regular_transaction_request<
         customer: customer_details,
         bill_to,
         user_fields,
-        processing_options: None,
+        processing_options: item
+            .router_data
+            .request
+            .stored_credential_usage
+            .map(ProcessingOptions::from),
         subsequent_auth_information: None,
         authorization_indicator_type: None,
         ref_trans_id: None,
@@ -629,6 +656,7 @@ pub struct AuthorizedotnetRepeatPaymentTransactionRequest {
     order: Option<Order>,
     customer: Option<CustomerDetails>,
     user_fields: Option<UserFields>,
+    processing_options: ProcessingOptions,
 }
 
 // Implementation for RepeatPayment request conversion
@@ -791,6 +819,9 @@ impl<
             order: Some(order),
             customer: customer_details,
             user_fields,
+            processing_options: ProcessingOptions::from(
+                item.router_data.request.stored_credential_usage,
+            ),
         };
 
         Ok(Self {
@@ -1819,6 +1850,7 @@ impl TryFrom<ResponseRouterData<AuthorizedotnetRefundResponse, Self>>
                 connector_refund_id: transaction_response.transaction_id.clone(),
                 refund_status,
                 status_code: http_code,
+                connector_response_reference_id: None,
             }),
         };
 
@@ -2326,6 +2358,7 @@ impl TryFrom<ResponseRouterData<AuthorizedotnetRSyncResponse, Self>>
                     connector_refund_id: transaction.transaction_id,
                     refund_status,
                     status_code: http_code,
+                    connector_response_reference_id: None,
                 });
 
                 Ok(new_router_data)
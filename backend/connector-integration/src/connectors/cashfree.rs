@@ -9,16 +9,18 @@ use common_enums::AttemptStatus;
 use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt};
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, RepeatPayment, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, RepeatPayment, SetupMandate,
+        SubmitEvidence, Void,
     },
     connector_types::{
         AcceptDisputeData, DisputeDefendData, DisputeFlowData, DisputeResponseData,
-        PaymentCreateOrderData, PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData,
-        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
-        RefundFlowData, RefundSyncData, RefundsData, RefundsResponseData, RepeatPaymentData,
+        PaymentCaptureReversalData, PaymentCreateOrderData, PaymentCreateOrderResponse,
+        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
+        RefundsResponseData, RegisterWebhookData, RegisterWebhookResponseData, RepeatPaymentData,
         SessionTokenRequestData, SessionTokenResponseData, SetupMandateRequestData,
-        SubmitEvidenceData,
+        SubmitEvidenceData, WebhookFlowData,
     },
     errors,
     payment_method_data::PaymentMethodDataTypes,
@@ -114,6 +116,26 @@ impl<
     > connector_types::PaymentVoidV2 for Cashfree<T>
 {
 }
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::PaymentCaptureReversalV2 for Cashfree<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::RegisterWebhookV2 for Cashfree<T>
+{
+}
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -438,6 +460,38 @@ impl<
     for Cashfree<T>
 {
 }
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    ConnectorIntegrationV2<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Cashfree<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    ConnectorIntegrationV2<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Cashfree<T>
+{
+}
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -627,6 +681,18 @@ impl_source_verification_stub!(
     PaymentsResponseData
 );
 impl_source_verification_stub!(Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData);
+impl_source_verification_stub!(
+    CaptureReversal,
+    PaymentFlowData,
+    PaymentCaptureReversalData,
+    PaymentsResponseData
+);
+impl_source_verification_stub!(
+    RegisterWebhook,
+    WebhookFlowData,
+    RegisterWebhookData,
+    RegisterWebhookResponseData
+);
 impl_source_verification_stub!(Refund, RefundFlowData, RefundsData, RefundsResponseData);
 impl_source_verification_stub!(RSync, RefundFlowData, RefundSyncData, RefundsResponseData);
 impl_source_verification_stub!(
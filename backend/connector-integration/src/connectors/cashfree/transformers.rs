@@ -274,6 +274,12 @@ fn get_cashfree_payment_method_data<
                         cardless_emi: None,
                     })
                 }
+                domain_types::payment_method_data::UpiData::UpiQr(_) => {
+                    Err(ConnectorError::NotSupported {
+                        message: "UPI QR".to_string(),
+                        connector: "Cashfree",
+                    })
+                }
             }
         }
         _ => Err(ConnectorError::NotSupported {
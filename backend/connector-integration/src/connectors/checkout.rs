@@ -1,18 +1,21 @@
+mod test;
 pub mod transformers;
 
 use common_utils::{consts, errors::CustomResult, ext_traits::ByteSliceExt};
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, RepeatPayment, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, RepeatPayment, SetupMandate,
+        SubmitEvidence, Void,
     },
     connector_types::{
         AcceptDisputeData, DisputeDefendData, DisputeFlowData, DisputeResponseData,
-        PaymentCreateOrderData, PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData,
-        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
-        RefundFlowData, RefundSyncData, RefundsData, RefundsResponseData, RepeatPaymentData,
+        PaymentCaptureReversalData, PaymentCreateOrderData, PaymentCreateOrderResponse,
+        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
+        RefundsResponseData, RegisterWebhookData, RegisterWebhookResponseData, RepeatPaymentData,
         ResponseId, SessionTokenRequestData, SessionTokenResponseData, SetupMandateRequestData,
-        SubmitEvidenceData,
+        SubmitEvidenceData, WebhookFlowData,
     },
     errors::{self, ConnectorError},
     payment_method_data::PaymentMethodDataTypes,
@@ -30,8 +33,9 @@ use interfaces::{
 use serde::Serialize;
 use transformers::{
     ActionResponse, CheckoutAuthorizeResponse, CheckoutErrorResponse, CheckoutPSyncResponse,
-    CheckoutPaymentsRequest, CheckoutRefundSyncRequest, CheckoutSyncRequest, PaymentCaptureRequest,
-    PaymentCaptureResponse, PaymentVoidRequest, PaymentVoidResponse, RefundRequest, RefundResponse,
+    CheckoutPaymentsRequest, CheckoutRefundSyncRequest, CheckoutSyncRequest,
+    CheckoutWebhookRequest, CheckoutWebhookResponse, PaymentCaptureRequest, PaymentCaptureResponse,
+    PaymentVoidRequest, PaymentVoidResponse, RefundRequest, RefundResponse,
 };
 
 use super::macros;
@@ -94,6 +98,42 @@ impl<
     > connector_types::PaymentVoidV2 for Checkout<T>
 {
 }
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    ConnectorIntegrationV2<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Checkout<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::PaymentCaptureReversalV2 for Checkout<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::RegisterWebhookV2 for Checkout<T>
+{
+}
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -133,6 +173,9 @@ impl<
             + Serialize,
     > connector_types::ValidationTrait for Checkout<T>
 {
+    fn supports_scheduled_capture(&self) -> bool {
+        true
+    }
 }
 impl<
         T: PaymentMethodDataTypes
@@ -244,6 +287,12 @@ macros::create_all_prerequisites!(
             request_body: CheckoutRefundSyncRequest,
             response_body: ActionResponse,
             router_data: RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+        ),
+        (
+            flow: RegisterWebhook,
+            request_body: CheckoutWebhookRequest,
+            response_body: CheckoutWebhookResponse,
+            router_data: RouterDataV2<RegisterWebhook, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>,
         )
     ],
     amount_converters: [],
@@ -274,6 +323,13 @@ macros::create_all_prerequisites!(
         ) -> &'a str {
             &req.resource_common_data.connectors.checkout.base_url
         }
+
+        pub fn connector_base_url_webhooks<'a, F, Req, Res>(
+            &self,
+            req: &'a RouterDataV2<F, WebhookFlowData, Req, Res>,
+        ) -> &'a str {
+            &req.resource_common_data.connectors.checkout.base_url
+        }
     }
 );
 
@@ -539,6 +595,34 @@ macros::macro_connector_implementation!(
     }
 );
 
+macros::macro_connector_implementation!(
+    connector_default_implementations: [get_content_type, get_error_response_v2],
+    connector: Checkout,
+    curl_request: Json(CheckoutWebhookRequest),
+    curl_response: CheckoutWebhookResponse,
+    flow_name: RegisterWebhook,
+    resource_common_data: WebhookFlowData,
+    flow_request: RegisterWebhookData,
+    flow_response: RegisterWebhookResponseData,
+    http_method: Post,
+    generic_type: T,
+    [PaymentMethodDataTypes + std::fmt::Debug + std::marker::Sync + std::marker::Send + 'static + Serialize],
+    other_functions: {
+        fn get_headers(
+            &self,
+            req: &RouterDataV2<RegisterWebhook, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>,
+        ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+            self.build_headers(req)
+        }
+        fn get_url(
+            &self,
+            req: &RouterDataV2<RegisterWebhook, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>,
+        ) -> CustomResult<String, errors::ConnectorError> {
+            Ok(format!("{}webhooks", self.connector_base_url_webhooks(req)))
+        }
+    }
+);
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -712,6 +796,39 @@ impl<
 {
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    interfaces::verification::SourceVerification<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Checkout<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    interfaces::verification::SourceVerification<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Checkout<T>
+{
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    pub mod scheduled_capture {
+        use common_utils::types::MinorUnit;
+        use domain_types::{
+            connector_flow::Capture,
+            connector_types::{
+                PaymentFlowData, PaymentsCaptureData, PaymentsResponseData, ResponseId,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+        };
+        use interfaces::connector_types::ValidationTrait;
+        use time::{format_description::well_known::Iso8601, OffsetDateTime, PrimitiveDateTime};
+
+        use crate::{
+            connectors::Checkout,
+            test_utils::{payment_flow_data, request_body, router_data, signature_key_auth},
+        };
+
+        #[test]
+        fn test_scheduled_capture_forwarded_as_capture_on() {
+            let capture_at = OffsetDateTime::from_unix_timestamp(1_894_708_800).unwrap();
+            let capture_router_data: RouterDataV2<
+                Capture,
+                PaymentFlowData,
+                PaymentsCaptureData,
+                PaymentsResponseData,
+            > = router_data(
+                payment_flow_data(),
+                signature_key_auth(),
+                PaymentsCaptureData {
+                    amount_to_capture: 1000,
+                    minor_amount_to_capture: MinorUnit::new(1000),
+                    currency: common_enums::Currency::USD,
+                    connector_transaction_id: ResponseId::ConnectorTransactionId(
+                        "pay_123".to_string(),
+                    ),
+                    capture_at: Some(PrimitiveDateTime::new(capture_at.date(), capture_at.time())),
+                    ..Default::default()
+                },
+            );
+            let connector = Checkout::<DefaultPCIHolder>::new();
+            assert!(connector.supports_scheduled_capture());
+
+            let body = request_body(connector, &capture_router_data);
+            let capture_on =
+                OffsetDateTime::parse(body["capture_on"].as_str().unwrap(), &Iso8601::DEFAULT)
+                    .unwrap();
+
+            assert_eq!(capture_on, capture_at);
+        }
+    }
+}
@@ -5,11 +5,12 @@ use common_utils::{
     types::MinorUnit,
 };
 use domain_types::{
-    connector_flow::{Authorize, Capture, PSync, RSync, Refund, Void},
+    connector_flow::{Authorize, Capture, PSync, RSync, Refund, RegisterWebhook, Void},
     connector_types::{
         PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
         PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
-        RefundsResponseData, ResponseId,
+        RefundsResponseData, RegisterWebhookData, RegisterWebhookResponseData, ResponseId,
+        WebhookFlowData,
     },
     errors::{self, ConnectorError},
     payment_method_data::{PaymentMethodData, PaymentMethodDataTypes, RawCardNumber},
@@ -19,6 +20,7 @@ use domain_types::{
 use error_stack::report;
 use hyperswitch_masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::types::ResponseRouterData;
 
@@ -162,6 +164,11 @@ pub struct PaymentCaptureRequest {
     pub capture_type: Option<CaptureType>,
     pub processing_channel_id: Secret<String>,
     pub reference: Option<String>,
+    #[serde(
+        with = "common_utils::custom_serde::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub capture_on: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -176,6 +183,20 @@ pub struct RefundRequest {
     pub reference: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CheckoutWebhookRequest {
+    pub url: String,
+    pub active: bool,
+    pub content_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub event_types: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckoutWebhookResponse {
+    pub id: String,
+}
+
 #[derive(Deserialize, Debug, Serialize)]
 pub struct RefundResponse {
     action_id: String,
@@ -586,6 +607,7 @@ impl<
             capture_type: Some(capture_type),
             processing_channel_id,
             reference,
+            capture_on: router_data.request.capture_at,
         })
     }
 }
@@ -620,6 +642,47 @@ impl<
     }
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        super::CheckoutRouterData<
+            RouterDataV2<
+                RegisterWebhook,
+                WebhookFlowData,
+                RegisterWebhookData,
+                RegisterWebhookResponseData,
+            >,
+            T,
+        >,
+    > for CheckoutWebhookRequest
+{
+    type Error = error_stack::Report<ConnectorError>;
+    fn try_from(
+        item: super::CheckoutRouterData<
+            RouterDataV2<
+                RegisterWebhook,
+                WebhookFlowData,
+                RegisterWebhookData,
+                RegisterWebhookResponseData,
+            >,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            url: item.router_data.request.webhook_url.clone(),
+            active: true,
+            content_type: "json".to_string(),
+            event_types: item.router_data.request.event_types.clone(),
+        })
+    }
+}
+
 // Implementation for PaymentVoidRequest with the router data generated by the macro
 impl<
         T: PaymentMethodDataTypes
@@ -878,6 +941,38 @@ impl<F>
     }
 }
 
+// Webhook registration response conversion
+impl<F>
+    TryFrom<
+        ResponseRouterData<
+            CheckoutWebhookResponse,
+            RouterDataV2<F, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>,
+        >,
+    > for RouterDataV2<F, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>
+{
+    type Error = error_stack::Report<ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            CheckoutWebhookResponse,
+            RouterDataV2<F, WebhookFlowData, RegisterWebhookData, RegisterWebhookResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = item;
+
+        let mut router_data = router_data;
+        router_data.response = Ok(RegisterWebhookResponseData {
+            registration_id: response.id,
+            status_code: http_code,
+        });
+
+        Ok(router_data)
+    }
+}
+
 // Refund response conversion
 impl<F>
     TryFrom<
@@ -914,6 +1009,7 @@ impl<F>
             connector_refund_id: checkout_refund_response.response.action_id,
             refund_status,
             status_code: http_code,
+            connector_response_reference_id: None,
         });
 
         Ok(router_data)
@@ -950,6 +1046,7 @@ impl<F>
             connector_refund_id: response.action_id,
             refund_status,
             status_code: http_code,
+            connector_response_reference_id: None,
         });
 
         Ok(router_data)
@@ -1040,6 +1137,7 @@ impl<F>
             connector_refund_id: response.action_id.clone(),
             refund_status,
             status_code: http_code,
+            connector_response_reference_id: None,
         });
 
         Ok(router_data)
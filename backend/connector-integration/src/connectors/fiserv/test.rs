@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    pub mod settlement_details {
+        use common_utils::types::MinorUnit;
+        use domain_types::{
+            connector_flow::Capture,
+            connector_types::{
+                PaymentFlowData, PaymentsCaptureData, PaymentsResponseData, ResponseId,
+                SettlementDetails,
+            },
+            payment_method_data::DefaultPCIHolder,
+            router_data_v2::RouterDataV2,
+            types::AmountUnit,
+        };
+        use serde_json::json;
+
+        use crate::{
+            connectors::Fiserv,
+            test_utils::{handle_response, payment_flow_data, router_data, signature_key_auth},
+        };
+
+        type CaptureRouterData =
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>;
+
+        fn capture_router_data() -> CaptureRouterData {
+            router_data(
+                payment_flow_data(),
+                signature_key_auth(),
+                PaymentsCaptureData {
+                    amount_to_capture: 1000,
+                    minor_amount_to_capture: MinorUnit::new(1000),
+                    currency: common_enums::Currency::USD,
+                    connector_transaction_id: ResponseId::ConnectorTransactionId(
+                        "txn_123".to_string(),
+                    ),
+                    ..Default::default()
+                },
+            )
+        }
+
+        /// Handles a capture response carrying `currency_conversion`
+        fn captured(
+            capture_router_data: &CaptureRouterData,
+            currency_conversion: Option<serde_json::Value>,
+        ) -> Option<SettlementDetails> {
+            let body = json!({
+                "gatewayResponse": {
+                    "gatewayTransactionId": "txn_123",
+                    "transactionState": "CAPTURED",
+                    "transactionProcessingDetails": {
+                        "orderId": "order_123",
+                        "transactionId": "txn_123"
+                    }
+                },
+                "currencyConversion": currency_conversion
+            });
+
+            handle_response(
+                Fiserv::<DefaultPCIHolder>::new(),
+                capture_router_data,
+                201,
+                body,
+            )
+            .resource_common_data
+            .settlement_details
+        }
+
+        #[test]
+        fn test_dcc_settlement_details_returned_on_capture() {
+            let settlement_details = captured(
+                &capture_router_data(),
+                Some(json!({
+                    "conversionType": "DCC",
+                    "foreignAmount": 9.25,
+                    "foreignCurrency": "EUR"
+                })),
+            );
+
+            assert_eq!(
+                settlement_details,
+                Some(SettlementDetails {
+                    amount: MinorUnit::new(925),
+                    currency: common_enums::Currency::EUR,
+                })
+            );
+        }
+
+        #[test]
+        fn test_settlement_details_absent_without_currency_conversion() {
+            assert_eq!(captured(&capture_router_data(), None), None);
+        }
+
+        #[test]
+        fn test_major_unit_amounts_converted_with_currency_exponent() {
+            let conversion = |foreign_amount: serde_json::Value, foreign_currency: &str| {
+                captured(
+                    &capture_router_data(),
+                    Some(json!({
+                        "conversionType": "DCC",
+                        "foreignAmount": foreign_amount,
+                        "foreignCurrency": foreign_currency
+                    })),
+                )
+                .map(|settlement_details| settlement_details.amount)
+            };
+
+            assert_eq!(conversion(json!(12.34), "USD"), Some(MinorUnit::new(1234)));
+            assert_eq!(conversion(json!(1050), "JPY"), Some(MinorUnit::new(1050)));
+        }
+
+        #[test]
+        fn test_configured_minor_unit_amounts_used_as_is() {
+            let mut capture_router_data = capture_router_data();
+            capture_router_data
+                .resource_common_data
+                .connectors
+                .fiserv
+                .response_amount_unit = Some(AmountUnit::Minor);
+
+            let settlement_details = captured(
+                &capture_router_data,
+                Some(json!({
+                    "conversionType": "DCC",
+                    "foreignAmount": 1234,
+                    "foreignCurrency": "USD"
+                })),
+            );
+
+            assert_eq!(
+                settlement_details.map(|settlement_details| settlement_details.amount),
+                Some(MinorUnit::new(1234))
+            );
+        }
+    }
+}
@@ -9,12 +9,13 @@ use domain_types::{
     connector_types::{
         PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
         PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
-        RefundsResponseData, ResponseId,
+        RefundsResponseData, ResponseId, SettlementDetails,
     },
     errors::ConnectorError,
     payment_method_data::{PaymentMethodData, PaymentMethodDataTypes, RawCardNumber},
     router_data::{ConnectorAuthType, ErrorResponse},
     router_data_v2::RouterDataV2,
+    types::{AmountUnit, Connectors},
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_masking::{PeekInterface, Secret};
@@ -231,6 +232,7 @@ impl From<FiservPaymentStatus> for enums::RefundStatus {
 #[serde(rename_all = "camelCase")]
 pub struct FiservPaymentsResponse {
     pub gateway_response: GatewayResponse,
+    pub currency_conversion: Option<CurrencyConversion>,
 }
 
 // Create a new response type for Capture that's a clone of the payments response
@@ -239,6 +241,7 @@ pub struct FiservPaymentsResponse {
 #[serde(rename_all = "camelCase")]
 pub struct FiservCaptureResponse {
     pub gateway_response: GatewayResponse,
+    pub currency_conversion: Option<CurrencyConversion>,
 }
 
 // Create a response type for Void
@@ -278,6 +281,39 @@ pub struct GatewayResponse {
     pub transaction_processing_details: TransactionProcessingDetails,
 }
 
+// Dynamic currency conversion details, present when the cardholder paid in their own currency
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyConversion {
+    pub conversion_type: Option<String>,
+    pub foreign_amount: Option<serde_json::Number>,
+    pub foreign_currency: Option<enums::Currency>,
+}
+
+fn get_settlement_details(
+    currency_conversion: Option<&CurrencyConversion>,
+    connectors: &Connectors,
+) -> Result<Option<SettlementDetails>, error_stack::Report<ConnectorError>> {
+    let Some(CurrencyConversion {
+        foreign_amount: Some(amount),
+        foreign_currency: Some(currency),
+        ..
+    }) = currency_conversion
+    else {
+        return Ok(None);
+    };
+    // Fiserv returns the foreign amount in major units unless configured otherwise
+    let amount = connectors
+        .fiserv
+        .response_amount_unit
+        .unwrap_or(AmountUnit::Major)
+        .to_minor_unit(amount, *currency)?;
+    Ok(Some(SettlementDetails {
+        amount,
+        currency: *currency,
+    }))
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionProcessingDetails {
@@ -305,29 +341,32 @@ pub struct FiservSessionObject {
     pub terminal_id: Secret<String>,
 }
 
+impl FiservSessionObject {
+    /// Reads the session from connector metadata holding either the JSON object itself
+    /// or the object serialized as a string
+    fn from_metadata(value: &serde_json::Value) -> Result<Self, serde_json::Error> {
+        match value {
+            serde_json::Value::String(s) => serde_json::from_str(s),
+            other => serde_json::from_value(other.clone()),
+        }
+    }
+}
+
 // The TryFrom<&Option<pii::SecretSerdeValue>> for FiservSessionObject might not be needed
-// if FiservSessionObject is always parsed from the string within connector_meta_data directly
+// if FiservSessionObject is always parsed from connector_meta_data directly
 // in the TryFrom implementations for FiservPaymentsRequest, FiservCaptureRequest, etc.
 impl TryFrom<&Option<pii::SecretSerdeValue>> for FiservSessionObject {
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
-        let secret_value_str = meta_data
-            .as_ref()
-            .ok_or_else(|| {
-                report!(ConnectorError::MissingRequiredField {
-                    field_name: "connector_meta_data (FiservSessionObject)"
-                })
+        let secret_value = meta_data.as_ref().ok_or_else(|| {
+            report!(ConnectorError::MissingRequiredField {
+                field_name: "connector_meta_data (FiservSessionObject)"
             })
-            .and_then(|secret_value| match secret_value.peek() {
-                serde_json::Value::String(s) => Ok(s.clone()),
-                _ => Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config: "FiservSessionObject in connector_meta_data was not a JSON string",
-                })),
-            })?;
+        })?;
 
-        serde_json::from_str(&secret_value_str).change_context(
+        Self::from_metadata(secret_value.peek()).change_context(
             ConnectorError::InvalidConnectorConfig {
-                config: "Deserializing FiservSessionObject from connector_meta_data string",
+                config: "Deserializing FiservSessionObject from connector_meta_data",
             },
         )
     }
@@ -402,11 +441,16 @@ impl<
 
         // Use FloatMajorUnitForConnector to properly convert minor to major unit
         let converter = FloatMajorUnitForConnector;
-        let amount_major = converter
-            .convert(
+        let minor_amount = router_data
+            .resource_common_data
+            .connectors
+            .fiserv
+            .round_amount(
                 router_data.request.minor_amount,
                 router_data.request.currency,
-            )
+            )?;
+        let amount_major = converter
+            .convert(minor_amount, router_data.request.currency)
             .change_context(ConnectorError::RequestEncodingFailed)?;
 
         let amount = Amount {
@@ -436,18 +480,9 @@ impl<
             })?
             .peek();
 
-        let session_str = match session_meta_value {
-            serde_json::Value::String(s) => s,
-            _ => {
-                return Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config: "connector_meta_data was not a JSON string for FiservSessionObject",
-                }))
-            }
-        };
-
-        let session: FiservSessionObject = serde_json::from_str(session_str).change_context(
+        let session = FiservSessionObject::from_metadata(session_meta_value).change_context(
             ConnectorError::InvalidConnectorConfig {
-                config: "Deserializing FiservSessionObject from connector_meta_data string",
+                config: "Deserializing FiservSessionObject from connector_meta_data",
             },
         )?;
 
@@ -511,42 +546,25 @@ impl<
         // Prioritize connector_metadata from PaymentsCaptureData if available,
         // otherwise fall back to resource_common_data.connector_meta_data.
 
-        // Try to get session string from different sources - converting both paths to String for type consistency
-        let session_str = if let Some(meta) = router_data
+        let session_meta_value = router_data
             .resource_common_data
             .connector_meta_data
             .as_ref()
-        {
-            // Use connector_meta_data from resource_common_data (which is Secret<Value>)
-            match meta.peek() {
-                serde_json::Value::String(s) => s.to_string(), // Convert &str to String
-                _ => return Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config: "connector_meta_data was not a JSON string for FiservSessionObject in Capture",
-                })),
-            }
-        } else if let Some(connector_meta) = router_data.request.connector_metadata.as_ref() {
-            // Use connector_metadata from request (which is Value)
-            match connector_meta {
-                serde_json::Value::String(s) => s.clone(), // String
-                _ => return Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config: "connector_metadata was not a JSON string for FiservSessionObject in Capture",
-                })),
-            }
-        } else {
-            // No metadata available
-            return Err(report!(ConnectorError::MissingRequiredField {
-                field_name:
-                    "connector_metadata or connector_meta_data for FiservSessionObject in Capture"
-            }));
-        };
-
-        let session: FiservSessionObject =
-            serde_json::from_str(&session_str)
-                .change_context(ConnectorError::InvalidConnectorConfig {
-                config:
-                    "Deserializing FiservSessionObject from connector_metadata string in Capture",
+            .map(|meta| meta.peek())
+            .or(router_data.request.connector_metadata.as_ref())
+            .ok_or_else(|| {
+                report!(ConnectorError::MissingRequiredField {
+                    field_name:
+                        "connector_metadata or connector_meta_data for FiservSessionObject in Capture"
+                })
             })?;
 
+        let session = FiservSessionObject::from_metadata(session_meta_value).change_context(
+            ConnectorError::InvalidConnectorConfig {
+                config: "Deserializing FiservSessionObject from connector_metadata in Capture",
+            },
+        )?;
+
         let merchant_details = MerchantDetails {
             merchant_id: auth.merchant_account.clone(),
             terminal_id: Some(session.terminal_id.clone()),
@@ -555,11 +573,16 @@ impl<
         // Use FloatMajorUnitForConnector to properly convert minor to major unit
         let converter = FloatMajorUnitForConnector;
 
-        let amount_major = converter
-            .convert(
+        let minor_amount = router_data
+            .resource_common_data
+            .connectors
+            .fiserv
+            .round_amount(
                 router_data.request.minor_amount_to_capture,
                 router_data.request.currency,
-            )
+            )?;
+        let amount_major = converter
+            .convert(minor_amount, router_data.request.currency)
             .change_context(ConnectorError::RequestEncodingFailed)?;
 
         Ok(Self {
@@ -665,19 +688,9 @@ impl<
             })?
             .peek();
 
-        let session_str = match session_meta_value {
-            serde_json::Value::String(s) => s,
-            _ => {
-                return Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config:
-                        "connector_meta_data was not a JSON string for FiservSessionObject in Void",
-                }))
-            }
-        };
-
-        let session: FiservSessionObject = serde_json::from_str(session_str).change_context(
+        let session = FiservSessionObject::from_metadata(session_meta_value).change_context(
             ConnectorError::InvalidConnectorConfig {
-                config: "Deserializing FiservSessionObject from connector_meta_data string in Void",
+                config: "Deserializing FiservSessionObject from connector_meta_data in Void",
             },
         )?;
 
@@ -726,38 +739,35 @@ impl<
 
         // Try to get session information - use only connector_metadata from request since
         // RefundFlowData doesn't have connector_meta_data field in resource_common_data
-        let session_str = if let Some(connector_meta) =
-            router_data.request.connector_metadata.as_ref()
-        {
-            // Use connector_metadata from request
-            match connector_meta {
-                serde_json::Value::String(s) => s.clone(),
-                _ => return Err(report!(ConnectorError::InvalidConnectorConfig {
-                    config:
-                        "connector_metadata was not a JSON string for FiservSessionObject in Refund",
-                })),
-            }
-        } else {
-            // No metadata available
-            return Err(report!(ConnectorError::MissingRequiredField {
-                field_name:
-                    "connector_metadata or connector_meta_data for FiservSessionObject in Refund"
-            }));
-        };
+        let session_meta_value = router_data
+            .request
+            .connector_metadata
+            .as_ref()
+            .ok_or_else(|| {
+                report!(ConnectorError::MissingRequiredField {
+                    field_name:
+                        "connector_metadata or connector_meta_data for FiservSessionObject in Refund"
+                })
+            })?;
 
-        let session: FiservSessionObject = serde_json::from_str(&session_str).change_context(
+        let session = FiservSessionObject::from_metadata(session_meta_value).change_context(
             ConnectorError::InvalidConnectorConfig {
-                config: "Deserializing FiservSessionObject from metadata string in Refund",
+                config: "Deserializing FiservSessionObject from metadata in Refund",
             },
         )?;
 
         // Convert minor amount to float major unit
         let converter = FloatMajorUnitForConnector;
-        let amount_major = converter
-            .convert(
+        let minor_amount = router_data
+            .resource_common_data
+            .connectors
+            .fiserv
+            .round_amount(
                 router_data.request.minor_refund_amount,
                 router_data.request.currency,
-            )
+            )?;
+        let amount_major = converter
+            .convert(minor_amount, router_data.request.currency)
             .change_context(ConnectorError::RequestEncodingFailed)?;
 
         Ok(Self {
@@ -912,6 +922,10 @@ impl<F> TryFrom<ResponseRouterData<FiservCaptureResponse, Self>>
         // Update the status in router_data
         let mut router_data_out = router_data;
         router_data_out.resource_common_data.status = status;
+        router_data_out.resource_common_data.settlement_details = get_settlement_details(
+            response.currency_conversion.as_ref(),
+            &router_data_out.resource_common_data.connectors,
+        )?;
 
         let response_payload = PaymentsResponseData::TransactionResponse {
             resource_id: ResponseId::ConnectorTransactionId(
@@ -1051,6 +1065,10 @@ impl<F> TryFrom<ResponseRouterData<FiservSyncResponse, Self>>
         // Update the status in router_data
         let mut router_data_out = router_data;
         router_data_out.resource_common_data.status = status;
+        router_data_out.resource_common_data.settlement_details = get_settlement_details(
+            fiserv_payment_response.currency_conversion.as_ref(),
+            &router_data_out.resource_common_data.connectors,
+        )?;
 
         let response_payload = PaymentsResponseData::TransactionResponse {
             resource_id: ResponseId::ConnectorTransactionId(
@@ -1129,6 +1147,7 @@ impl<F> TryFrom<ResponseRouterData<FiservRefundResponse, Self>>
                 }),
             refund_status,
             status_code: http_code,
+            connector_response_reference_id: None,
         };
 
         if refund_status == enums::RefundStatus::Failure {
@@ -1194,6 +1213,7 @@ impl<F> TryFrom<ResponseRouterData<FiservRefundSyncResponse, Self>>
                 }),
             refund_status,
             status_code: http_code,
+            connector_response_reference_id: None,
         };
 
         if refund_status == enums::RefundStatus::Failure {
@@ -896,6 +896,7 @@ impl<F> TryFrom<ResponseRouterData<RefundResponse, Self>>
                 connector_refund_id: item.response.result.transaction.id,
                 refund_status,
                 status_code: item.http_code,
+                connector_response_reference_id: None,
             })
         };
         Ok(Self {
@@ -961,6 +962,7 @@ impl<F> TryFrom<ResponseRouterData<RefundSyncResponse, Self>>
                 connector_refund_id: noon_transaction.id.to_owned(),
                 refund_status,
                 status_code: item.http_code,
+                connector_response_reference_id: None,
             })
         };
         Ok(Self {
@@ -166,6 +166,11 @@ pub fn determine_upi_flow<T: domain_types::payment_method_data::PaymentMethodDat
                     }
                 }
                 UpiData::UpiIntent(_) => Ok(UpiFlowType::Intent),
+                UpiData::UpiQr(_) => Err(errors::ConnectorError::NotSupported {
+                    message: "UPI QR".to_string(),
+                    connector: "Paytm",
+                }
+                .into()),
             }
         }
         _ => {
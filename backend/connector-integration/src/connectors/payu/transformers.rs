@@ -629,6 +629,10 @@ fn determine_upi_app_name<
                     // UPI Collect doesn't typically use app name
                     Ok(upi_collect_data.vpa_id.clone().map(|vpa| vpa.expose()))
                 }
+                UpiData::UpiQr(_) => Err(ConnectorError::NotSupported {
+                    message: "UPI QR".to_string(),
+                    connector: "PayU",
+                }),
             }
         }
         _ => Ok(None),
@@ -682,6 +686,10 @@ fn determine_upi_flow<
                         constants::UPI_S2S_FLOW.to_string(),
                     ))
                 }
+                UpiData::UpiQr(_) => Err(ConnectorError::NotSupported {
+                    message: "UPI QR".to_string(),
+                    connector: "PayU",
+                }),
             }
         }
         _ => Err(ConnectorError::NotSupported {
@@ -246,6 +246,13 @@ impl<
                         .as_ref()
                         .map(|vpa| Secret::new(vpa.peek().to_string())),
                 },
+                UpiData::UpiQr(_) => {
+                    return Err(errors::ConnectorError::NotSupported {
+                        message: "UPI QR".to_string(),
+                        connector: "Phonepe",
+                    }
+                    .into())
+                }
             },
             _ => {
                 return Err(errors::ConnectorError::NotSupported {
@@ -396,6 +403,13 @@ impl<
                         .as_ref()
                         .map(|vpa| Secret::new(vpa.peek().to_string())),
                 },
+                UpiData::UpiQr(_) => {
+                    return Err(errors::ConnectorError::NotSupported {
+                        message: "UPI QR".to_string(),
+                        connector: "Phonepe",
+                    }
+                    .into())
+                }
             },
             _ => {
                 return Err(errors::ConnectorError::NotSupported {
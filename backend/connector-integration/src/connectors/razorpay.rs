@@ -9,23 +9,24 @@ use common_utils::{
     errors::CustomResult,
     ext_traits::ByteSliceExt,
     pii::SecretSerdeValue,
-    request::{Method, RequestContent},
+    request::{ContentType, Method, RequestContent},
     types::{AmountConvertor, MinorUnit},
 };
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, SetupMandate, SubmitEvidence, Void,
     },
     connector_types::{
         AcceptDisputeData, ConnectorSpecifications, ConnectorWebhookSecrets, DisputeDefendData,
-        DisputeFlowData, DisputeResponseData, EventType, PaymentCreateOrderData,
-        PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
-        PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData,
-        RefundSyncData, RefundWebhookDetailsResponse, RefundsData, RefundsResponseData,
-        RequestDetails, ResponseId, SessionTokenRequestData, SessionTokenResponseData,
-        SetupMandateRequestData, SubmitEvidenceData, SupportedPaymentMethodsExt,
-        WebhookDetailsResponse,
+        DisputeFlowData, DisputeResponseData, EventType, PaymentCaptureReversalData,
+        PaymentCreateOrderData, PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData,
+        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
+        RefundFlowData, RefundSyncData, RefundWebhookDetailsResponse, RefundsData,
+        RefundsResponseData, RegisterWebhookData, RegisterWebhookResponseData, RequestDetails,
+        ResponseId, SessionTokenRequestData, SessionTokenResponseData,
+        SettlementWebhookDetailsResponse, SetupMandateRequestData, SubmitEvidenceData,
+        SupportedPaymentMethodsExt, WebhookDetailsResponse, WebhookFlowData,
     },
     errors,
     payment_method_data::{DefaultPCIHolder, PaymentMethodData, PaymentMethodDataTypes},
@@ -142,6 +143,26 @@ impl<
     > connector_types::PaymentVoidV2 for Razorpay<T>
 {
 }
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::PaymentCaptureReversalV2 for Razorpay<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    > connector_types::RegisterWebhookV2 for Razorpay<T>
+{
+}
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -262,6 +283,9 @@ impl<
     fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
         connectors.razorpay.base_url.as_ref()
     }
+    fn supported_request_content_types(&self) -> &'static [ContentType] {
+        &[ContentType::Json, ContentType::FormUrlEncoded]
+    }
 
     fn build_error_response(
         &self,
@@ -819,7 +843,9 @@ impl<
                 .attach_printable(format!("error while decoing webhook body {err}"))
         })?;
 
-        if payload.refund.is_some() {
+        if payload.settlement.is_some() {
+            Ok(EventType::SettlementProcessed)
+        } else if payload.refund.is_some() {
             Ok(EventType::RefundSuccess)
         } else {
             Ok(EventType::PaymentIntentSuccess)
@@ -888,6 +914,36 @@ impl<
             response_headers: None,
         })
     }
+
+    fn process_settlement_webhook(
+        &self,
+        request: RequestDetails,
+        _connector_webhook_secret: Option<ConnectorWebhookSecrets>,
+        _connector_account_details: Option<ConnectorAuthType>,
+    ) -> Result<SettlementWebhookDetailsResponse, error_stack::Report<errors::ConnectorError>> {
+        let request_body_copy = request.body.clone();
+        let payload = transformers::get_webhook_object_from_body(request.body).map_err(|err| {
+            report!(errors::ConnectorError::WebhookBodyDecodingFailed)
+                .attach_printable(format!("error while decoding webhook body {err}"))
+        })?;
+
+        let notif = payload.settlement.ok_or_else(|| {
+            error_stack::Report::new(errors::ConnectorError::WebhookBodyDecodingFailed)
+        })?;
+
+        Ok(SettlementWebhookDetailsResponse {
+            settlement_id: notif.entity.id,
+            amount: MinorUnit::new(notif.entity.amount),
+            currency: common_enums::Currency::INR,
+            settlement_date: time::OffsetDateTime::from_unix_timestamp(notif.entity.created_at)
+                .ok()
+                .map(common_utils::date_time::convert_to_pdt),
+            fees: notif.entity.fees.map(MinorUnit::new),
+            raw_connector_response: Some(String::from_utf8_lossy(&request_body_copy).to_string()),
+            status_code: 200,
+            response_headers: None,
+        })
+    }
 }
 
 impl<
@@ -902,6 +958,39 @@ impl<
 {
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    ConnectorIntegrationV2<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Razorpay<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    ConnectorIntegrationV2<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Razorpay<T>
+{
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -1231,6 +1320,39 @@ impl<
 {
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    interfaces::verification::SourceVerification<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    > for Razorpay<T>
+{
+}
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    interfaces::verification::SourceVerification<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    > for Razorpay<T>
+{
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -1445,8 +1567,11 @@ static RAZORPAY_CONNECTOR_INFO: ConnectorInfo = ConnectorInfo {
     connector_type: PaymentConnectorCategory::PaymentGateway
 };
 
-static RAZORPAY_SUPPORTED_WEBHOOK_FLOWS: &[EventClass] =
-    &[EventClass::Payments, EventClass::Refunds];
+static RAZORPAY_SUPPORTED_WEBHOOK_FLOWS: &[EventClass] = &[
+    EventClass::Payments,
+    EventClass::Refunds,
+    EventClass::Settlements,
+];
 
 impl<
         T: PaymentMethodDataTypes
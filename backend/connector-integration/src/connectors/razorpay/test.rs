@@ -90,10 +90,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -172,6 +182,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse {
                     code: "HE_00".to_string(),
@@ -255,10 +271,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -315,6 +341,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse {
                     code: "HE_01".to_string(),
@@ -372,10 +404,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -432,6 +474,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse {
                     code: "HE_02".to_string(),
@@ -511,10 +559,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -593,6 +651,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse {
                     code: "HE_00".to_string(),
@@ -817,10 +881,20 @@ mod tests {
                 connector_http_status_code: None,
                 external_latency: None,
                 raw_connector_response: None,
+                card_issuer_info: None,
+                fraud_check: None,
+                settlement_details: None,
+                sca_exemption_decision: None,
+                payment_method_details: None,
+                connector_warnings: Vec::new(),
+                installment_plan: None,
+                three_ds_failure: None,
+                three_ds_completion: None,
                 connectors: Connectors {
                     razorpay: ConnectorParams {
                         base_url: "https://api.razorpay.com/".to_string(),
                         dispute_base_url: None,
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
@@ -895,6 +969,12 @@ mod tests {
                 merchant_account_id: None,
                 merchant_config_currency: None,
                 all_keys_required: None,
+                stored_credential_usage: None,
+                preferred_three_ds_version: None,
+                three_ds_version: None,
+                requested_exemption: None,
+                authentication_data: None,
+                installment_count: None,
             },
             response: Err(ErrorResponse {
                 code: "HE_00".to_string(),
@@ -989,10 +1069,20 @@ mod tests {
                 connector_http_status_code: None,
                 external_latency: None,
                 raw_connector_response: None,
+                card_issuer_info: None,
+                fraud_check: None,
+                settlement_details: None,
+                sca_exemption_decision: None,
+                payment_method_details: None,
+                connector_warnings: Vec::new(),
+                installment_plan: None,
+                three_ds_failure: None,
+                three_ds_completion: None,
                 connectors: Connectors {
                     razorpay: ConnectorParams {
                         base_url: "https://api.razorpay.com/".to_string(),
                         dispute_base_url: None,
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
@@ -1067,6 +1157,12 @@ mod tests {
                 merchant_account_id: None,
                 merchant_config_currency: None,
                 all_keys_required: None,
+                stored_credential_usage: None,
+                preferred_three_ds_version: None,
+                three_ds_version: None,
+                requested_exemption: None,
+                authentication_data: None,
+                installment_count: None,
             },
             response: Err(ErrorResponse {
                 code: "HE_00".to_string(),
@@ -1163,10 +1259,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: domain_types::types::Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -1259,10 +1365,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -1360,10 +1476,20 @@ mod tests {
                     connector_http_status_code: None,
                     external_latency: None,
                     raw_connector_response: None,
+                    card_issuer_info: None,
+                    fraud_check: None,
+                    settlement_details: None,
+                    sca_exemption_decision: None,
+                    payment_method_details: None,
+                    connector_warnings: Vec::new(),
+                    installment_plan: None,
+                    three_ds_failure: None,
+                    three_ds_completion: None,
                     connectors: Connectors {
                         razorpay: ConnectorParams {
                             base_url: "https://api.razorpay.com/".to_string(),
                             dispute_base_url: None,
+                            ..Default::default()
                         },
                         ..Default::default()
                     },
@@ -1420,6 +1546,12 @@ mod tests {
                     merchant_account_id: None,
                     merchant_config_currency: None,
                     all_keys_required: None,
+                    stored_credential_usage: None,
+                    preferred_three_ds_version: None,
+                    three_ds_version: None,
+                    requested_exemption: None,
+                    authentication_data: None,
+                    installment_count: None,
                 },
                 response: Err(ErrorResponse {
                     code: "HE_INVALID".to_string(),
@@ -1498,10 +1630,20 @@ mod tests {
                 connector_http_status_code: None,
                 external_latency: None,
                 raw_connector_response: None,
+                card_issuer_info: None,
+                fraud_check: None,
+                settlement_details: None,
+                sca_exemption_decision: None,
+                payment_method_details: None,
+                connector_warnings: Vec::new(),
+                installment_plan: None,
+                three_ds_failure: None,
+                three_ds_completion: None,
                 connectors: Connectors {
                     razorpay: ConnectorParams {
                         base_url: "https://api.razorpay.com/".to_string(),
                         dispute_base_url: None,
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
@@ -1617,10 +1759,20 @@ mod tests {
                 connector_http_status_code: None,
                 external_latency: None,
                 raw_connector_response: None,
+                card_issuer_info: None,
+                fraud_check: None,
+                settlement_details: None,
+                sca_exemption_decision: None,
+                payment_method_details: None,
+                connector_warnings: Vec::new(),
+                installment_plan: None,
+                three_ds_failure: None,
+                three_ds_completion: None,
                 connectors: Connectors {
                     razorpay: ConnectorParams {
                         base_url: "https://api.razorpay.com/".to_string(),
                         dispute_base_url: None,
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
@@ -1725,10 +1877,20 @@ mod tests {
                 connector_http_status_code: None,
                 external_latency: None,
                 raw_connector_response: None,
+                card_issuer_info: None,
+                fraud_check: None,
+                settlement_details: None,
+                sca_exemption_decision: None,
+                payment_method_details: None,
+                connector_warnings: Vec::new(),
+                installment_plan: None,
+                three_ds_failure: None,
+                three_ds_completion: None,
                 connectors: Connectors {
                     razorpay: ConnectorParams {
                         base_url: "https://api.razorpay.com/".to_string(),
                         dispute_base_url: None,
+                        ..Default::default()
                     },
                     ..Default::default()
                 },
@@ -1866,4 +2028,73 @@ mod tests {
 
         assert!(result.is_err(), "Expected error for missing 'error' field");
     }
+
+    mod webhook {
+        use common_enums::Currency;
+        use common_utils::types::MinorUnit;
+        use domain_types::{
+            connector_types::{EventType, HttpMethod, RequestDetails},
+            payment_method_data::DefaultPCIHolder,
+        };
+        use interfaces::connector_types::{BoxedConnector, IncomingWebhook};
+        use serde_json::json;
+
+        use crate::connectors::Razorpay;
+
+        fn webhook_request(body: serde_json::Value) -> RequestDetails {
+            RequestDetails {
+                method: HttpMethod::Post,
+                uri: Some("/webhooks/razorpay".to_string()),
+                headers: std::collections::HashMap::new(),
+                body: serde_json::to_vec(&body).unwrap(),
+                query_params: None,
+                source_ip: None,
+            }
+        }
+
+        #[test]
+        fn test_settlement_processed_webhook() {
+            let request = webhook_request(json!({
+                "entity": "event",
+                "account_id": "acc_BFQ7uQEaa7j2z7",
+                "event": "settlement.processed",
+                "contains": ["settlement"],
+                "payload": {
+                    "settlement": {
+                        "entity": {
+                            "id": "setl_DGlQ1Rj8os78Ec",
+                            "entity": "settlement",
+                            "amount": 9973635,
+                            "status": "processed",
+                            "fees": 471,
+                            "tax": 72,
+                            "utr": "1568176960vxp0rj",
+                            "created_at": 1568176960
+                        }
+                    }
+                },
+                "created_at": 1568176960
+            }));
+            let connector: BoxedConnector<DefaultPCIHolder> = Box::new(Razorpay::new());
+
+            let event_type = connector
+                .get_event_type(request.clone(), None, None)
+                .unwrap();
+            let settlement = connector
+                .process_settlement_webhook(request, None, None)
+                .unwrap();
+
+            assert_eq!(event_type, EventType::SettlementProcessed);
+            assert_eq!(settlement.settlement_id, "setl_DGlQ1Rj8os78Ec");
+            assert_eq!(settlement.amount, MinorUnit::new(9973635));
+            assert_eq!(settlement.currency, Currency::INR);
+            assert_eq!(settlement.fees, Some(MinorUnit::new(471)));
+            assert_eq!(
+                settlement
+                    .settlement_date
+                    .map(|date| date.assume_utc().unix_timestamp()),
+                Some(1568176960)
+            );
+        }
+    }
 }
@@ -744,6 +744,7 @@ impl
             connector_refund_id: response.id,
             refund_status: status,
             status_code: http_code,
+            connector_response_reference_id: None,
         };
 
         Ok(Self {
@@ -779,6 +780,7 @@ impl
             connector_refund_id: response.id,
             refund_status: status,
             status_code: http_code,
+            connector_response_reference_id: None,
         };
 
         Ok(Self {
@@ -1123,6 +1125,7 @@ pub struct RazorpayWebhook {
 pub struct Payload {
     pub payment: Option<PaymentWrapper>,
     pub refund: Option<RefundWrapper>,
+    pub settlement: Option<SettlementWrapper>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -1137,6 +1140,12 @@ pub struct RefundWrapper {
     pub entity: RefundEntity,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SettlementWrapper {
+    pub entity: SettlementEntity,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct PaymentEntity {
@@ -1183,6 +1192,20 @@ pub struct RefundEntity {
     pub status: RazorpayRefundStatus,
 }
 
+/// Payout of collected funds to the merchant's bank account; Razorpay settles in INR
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SettlementEntity {
+    pub id: String,
+    pub amount: i64,
+    pub status: String,
+    /// total fees deducted, including `tax`
+    pub fees: Option<i64>,
+    pub tax: Option<i64>,
+    pub utr: Option<String>,
+    pub created_at: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RazorpayEntity {
@@ -1497,6 +1520,13 @@ impl<
                 ("collect", Some(vpa))
             }
             PaymentMethodData::Upi(UpiData::UpiIntent(_)) => ("intent", None),
+            PaymentMethodData::Upi(UpiData::UpiQr(_)) => {
+                return Err(errors::ConnectorError::NotSupported {
+                    message: "UPI QR".to_string(),
+                    connector: "Razorpay",
+                }
+                .into())
+            }
             _ => ("collect", None), // Default fallback
         };
 
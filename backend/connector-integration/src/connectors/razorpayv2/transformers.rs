@@ -406,9 +406,12 @@ impl<
                     (Some(UpiFlow::Collect), Some(vpa_string))
                 }
                 UpiData::UpiIntent(_) => (Some(UpiFlow::Intent), None),
-                // UpiData::UpiQr(_) => {
-                //     return Err(errors::ConnectorError::NotImplemented("UPI QR flow not supported by RazorpayV2".to_string()).into());
-                // }
+                UpiData::UpiQr(_) => {
+                    return Err(errors::ConnectorError::NotImplemented(
+                        "UPI QR flow not supported by RazorpayV2".to_string(),
+                    )
+                    .into());
+                }
             },
             _ => (None, None),
         };
@@ -532,6 +535,7 @@ impl
             connector_refund_id: response.id,
             refund_status: status,
             status_code: _status_code,
+            connector_response_reference_id: None,
         };
 
         Ok(RouterDataV2 {
@@ -575,6 +579,7 @@ impl
             connector_refund_id: response.id,
             refund_status: status,
             status_code: _status_code,
+            connector_response_reference_id: None,
         };
 
         Ok(RouterDataV2 {
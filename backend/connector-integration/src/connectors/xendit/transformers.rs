@@ -743,6 +743,7 @@ impl<F> TryFrom<ResponseRouterData<RefundResponse, Self>>
                 connector_refund_id: response.id,
                 refund_status: common_enums::RefundStatus::from(response.status),
                 status_code: http_code,
+                connector_response_reference_id: None,
             }),
             ..router_data
         })
@@ -774,6 +775,7 @@ impl<F> TryFrom<ResponseRouterData<RefundResponse, Self>>
                 connector_refund_id: response.id,
                 refund_status: common_enums::RefundStatus::from(response.status),
                 status_code: http_code,
+                connector_response_reference_id: None,
             }),
             ..router_data
         })
@@ -0,0 +1,205 @@
+//! Router data fixtures shared by the connector transformer tests
+
+use std::{marker::PhantomData, str::FromStr};
+
+use common_utils::types::MinorUnit;
+use domain_types::{
+    connector_types::{PaymentFlowData, PaymentsAuthorizeData, RefundFlowData},
+    payment_address::PaymentAddress,
+    payment_method_data::{Card, DefaultPCIHolder, PaymentMethodData, RawCardNumber},
+    router_data::{ConnectorAuthType, ErrorResponse},
+    router_data_v2::RouterDataV2,
+    router_response_types::Response,
+    types::{ConnectorParams, Connectors},
+};
+use hyperswitch_masking::{ExposeInterface, Secret};
+use interfaces::connector_integration_v2::ConnectorIntegrationV2;
+
+pub(crate) fn connectors() -> Connectors {
+    Connectors {
+        adyen: ConnectorParams {
+            base_url: "https://checkout-test.adyen.com/".to_string(),
+            ..Default::default()
+        },
+        checkout: ConnectorParams {
+            base_url: "https://api.sandbox.checkout.com/".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+pub(crate) fn body_key_auth() -> ConnectorAuthType {
+    ConnectorAuthType::BodyKey {
+        api_key: Secret::new("test_api_key".to_string()), // Hardcoded dummy value
+        key1: Secret::new("test_key1".to_string()),       // Hardcoded dummy value
+    }
+}
+
+pub(crate) fn signature_key_auth() -> ConnectorAuthType {
+    ConnectorAuthType::SignatureKey {
+        api_key: Secret::new("test_api_key".to_string()), // Hardcoded dummy value
+        api_secret: Secret::new("test_api_secret".to_string()), // Hardcoded dummy value
+        key1: Secret::new("test_key1".to_string()),       // Hardcoded dummy value
+    }
+}
+
+pub(crate) fn payment_flow_data() -> PaymentFlowData {
+    PaymentFlowData {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        customer_id: None,
+        connector_customer: None,
+        payment_id: "pay_123".to_string(),
+        attempt_id: "attempt_123".to_string(),
+        status: common_enums::AttemptStatus::Pending,
+        payment_method: common_enums::PaymentMethod::Card,
+        description: None,
+        return_url: Some("https://example.com/return".to_string()),
+        address: PaymentAddress::new(None, None, None, None),
+        auth_type: common_enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        preprocessing_id: None,
+        connector_api_version: None,
+        connector_request_reference_id: "ref_123".to_string(),
+        test_mode: None,
+        connector_http_status_code: None,
+        connectors: connectors(),
+        external_latency: None,
+        connector_response_headers: None,
+        raw_connector_response: None,
+        card_issuer_info: None,
+        fraud_check: None,
+        settlement_details: None,
+        sca_exemption_decision: None,
+        payment_method_details: None,
+        connector_warnings: Vec::new(),
+        installment_plan: None,
+        three_ds_failure: None,
+        three_ds_completion: None,
+    }
+}
+
+pub(crate) fn refund_flow_data() -> RefundFlowData {
+    RefundFlowData {
+        status: common_enums::RefundStatus::Pending,
+        refund_id: None,
+        connectors: connectors(),
+        connector_request_reference_id: "refund_ref_123".to_string(),
+        raw_connector_response: None,
+        connector_response_headers: None,
+    }
+}
+
+pub(crate) fn card() -> PaymentMethodData<DefaultPCIHolder> {
+    PaymentMethodData::Card(Card {
+        card_number: RawCardNumber(cards::CardNumber::from_str("4111111111111111").unwrap()),
+        card_exp_month: Secret::new("03".to_string()),
+        card_exp_year: Secret::new("2030".to_string()),
+        card_cvc: Secret::new("737".to_string()),
+        ..Default::default()
+    })
+}
+
+/// Automatically captured payment of 10.00 USD with `payment_method_data`
+pub(crate) fn authorize_data(
+    payment_method_data: PaymentMethodData<DefaultPCIHolder>,
+) -> PaymentsAuthorizeData<DefaultPCIHolder> {
+    PaymentsAuthorizeData {
+        payment_method_data,
+        amount: 1000,
+        order_tax_amount: None,
+        email: None,
+        customer_name: None,
+        currency: common_enums::Currency::USD,
+        confirm: true,
+        statement_descriptor_suffix: None,
+        statement_descriptor: None,
+        capture_method: Some(common_enums::CaptureMethod::Automatic),
+        router_return_url: Some("https://example.com/return".to_string()),
+        webhook_url: None,
+        complete_authorize_url: None,
+        mandate_id: None,
+        setup_future_usage: None,
+        off_session: None,
+        browser_info: None,
+        order_category: None,
+        session_token: None,
+        enrolled_for_3ds: false,
+        related_transaction_id: None,
+        payment_experience: None,
+        payment_method_type: None,
+        customer_id: None,
+        request_incremental_authorization: false,
+        metadata: None,
+        minor_amount: MinorUnit::new(1000),
+        merchant_order_reference_id: None,
+        shipping_cost: None,
+        merchant_account_id: None,
+        integrity_object: None,
+        merchant_config_currency: None,
+        all_keys_required: None,
+        stored_credential_usage: None,
+        preferred_three_ds_version: None,
+        three_ds_version: None,
+        requested_exemption: None,
+        authentication_data: None,
+        installment_count: None,
+    }
+}
+
+pub(crate) fn router_data<F, ResourceCommonData, Req, Resp>(
+    resource_common_data: ResourceCommonData,
+    connector_auth_type: ConnectorAuthType,
+    request: Req,
+) -> RouterDataV2<F, ResourceCommonData, Req, Resp> {
+    RouterDataV2 {
+        flow: PhantomData,
+        resource_common_data,
+        connector_auth_type,
+        request,
+        response: Err(ErrorResponse::default()),
+    }
+}
+
+/// Runs `body` through the connector's response handling as a response with `status_code`
+pub(crate) fn handle_response<C, F, ResourceCommonData, Req, Resp>(
+    connector: &C,
+    router_data: &RouterDataV2<F, ResourceCommonData, Req, Resp>,
+    status_code: u16,
+    body: serde_json::Value,
+) -> RouterDataV2<F, ResourceCommonData, Req, Resp>
+where
+    C: ConnectorIntegrationV2<F, ResourceCommonData, Req, Resp>,
+    F: Clone,
+    ResourceCommonData: Clone,
+    Req: Clone,
+    Resp: Clone,
+{
+    let response = Response {
+        headers: None,
+        response: serde_json::to_vec(&body).unwrap().into(),
+        status_code,
+    };
+    connector
+        .handle_response_v2(router_data, None, response)
+        .unwrap()
+}
+
+/// Builds the connector request for `router_data` and returns its body, unmasked
+pub(crate) fn request_body<C, F, ResourceCommonData, Req, Resp>(
+    connector: &C,
+    router_data: &RouterDataV2<F, ResourceCommonData, Req, Resp>,
+) -> serde_json::Value
+where
+    C: ConnectorIntegrationV2<F, ResourceCommonData, Req, Resp>,
+{
+    let request = connector.build_request_v2(router_data).unwrap().unwrap();
+    let body = request.body.unwrap().get_inner_value().expose();
+    serde_json::from_str(&body).unwrap()
+}
@@ -2,7 +2,7 @@ pub mod xml_utils;
 use common_utils::CustomResult;
 use domain_types::{
     connector_types::PaymentsAuthorizeData, errors, payment_method_data::PaymentMethodDataTypes,
-    router_data::ErrorResponse, router_response_types::Response,
+    router_data::ErrorResponse, router_response_types::Response, types::StatusFlagValues,
 };
 use error_stack::{Report, ResultExt};
 use hyperswitch_masking::{ExposeInterface, Secret};
@@ -111,3 +111,122 @@ pub(crate) fn handle_json_response_deserialization_failure(
         }),
     }
 }
+
+/// Values a connector uses for success and failure in a boolean-like status flag, unless
+/// its config sets `status_flag_values`
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultStatusFlagValues {
+    pub success: &'static [&'static str],
+    pub failure: &'static [&'static str],
+}
+
+/// Interprets a boolean-like status flag from a connector response.
+///
+/// Connectors represent success as `true`, `1`, `"true"`, `"00"` etc. String and number
+/// values are compared, ignoring case, against the `configured` values from the connector
+/// config when set, otherwise against the connector's own `defaults`, so a connector never
+/// accepts a representation it does not use.
+/// Returns `None` when the value cannot be interpreted either way.
+pub fn normalize_status_flag(
+    value: &Value,
+    configured: Option<&StatusFlagValues>,
+    defaults: &DefaultStatusFlagValues,
+) -> Option<bool> {
+    let raw = match value {
+        Value::Bool(flag) => return Some(*flag),
+        Value::Number(number) => number.to_string(),
+        Value::String(string) => string.trim().to_string(),
+        _ => return None,
+    };
+
+    let is_match = |candidate: &str| candidate.eq_ignore_ascii_case(&raw);
+    let (is_success, is_failure) = match configured {
+        Some(values) => (
+            values.success.iter().any(|v| is_match(v)),
+            values.failure.iter().any(|v| is_match(v)),
+        ),
+        None => (
+            defaults.success.iter().any(|v| is_match(v)),
+            defaults.failure.iter().any(|v| is_match(v)),
+        ),
+    };
+    if is_success {
+        Some(true)
+    } else if is_failure {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod status_flag_tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const COMMON_STATUS_FLAG_VALUES: DefaultStatusFlagValues = DefaultStatusFlagValues {
+        success: &["true", "1", "00", "y", "yes", "success", "ok"],
+        failure: &["false", "0", "n", "no", "failure", "failed"],
+    };
+
+    #[test]
+    fn test_success_representations() {
+        for value in [
+            json!(true),
+            json!(1),
+            json!("true"),
+            json!("00"),
+            json!("SUCCESS"),
+        ] {
+            assert_eq!(
+                normalize_status_flag(&value, None, &COMMON_STATUS_FLAG_VALUES),
+                Some(true),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_failure_representations() {
+        for value in [
+            json!(false),
+            json!(0),
+            json!("false"),
+            json!("0"),
+            json!("N"),
+        ] {
+            assert_eq!(
+                normalize_status_flag(&value, None, &COMMON_STATUS_FLAG_VALUES),
+                Some(false),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_representation_is_none() {
+        assert_eq!(
+            normalize_status_flag(&json!("05"), None, &COMMON_STATUS_FLAG_VALUES),
+            None
+        );
+        assert_eq!(
+            normalize_status_flag(&json!(null), None, &COMMON_STATUS_FLAG_VALUES),
+            None
+        );
+    }
+
+    #[test]
+    fn test_configured_values_replace_connector_ones() {
+        let configured = StatusFlagValues {
+            success: vec!["A".to_string()],
+            failure: vec!["1".to_string()],
+        };
+        let normalize =
+            |value| normalize_status_flag(&value, Some(&configured), &COMMON_STATUS_FLAG_VALUES);
+        assert_eq!(normalize(json!("a")), Some(true));
+        assert_eq!(normalize(json!(1)), Some(false));
+        assert_eq!(normalize(json!("00")), None);
+        assert_eq!(normalize(json!(true)), Some(true));
+    }
+}
@@ -19,6 +19,9 @@ pub struct Refund;
 #[derive(Debug, Clone)]
 pub struct Capture;
 
+#[derive(Debug, Clone)]
+pub struct CaptureReversal;
+
 #[derive(Debug, Clone)]
 pub struct SetupMandate;
 
@@ -37,6 +40,9 @@ pub struct DefendDispute;
 #[derive(Debug, Clone)]
 pub struct CreateSessionToken;
 
+#[derive(Debug, Clone)]
+pub struct RegisterWebhook;
+
 #[derive(strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum FlowName {
@@ -48,6 +54,7 @@ pub enum FlowName {
     SetupMandate,
     RepeatPayment,
     Capture,
+    CaptureReversal,
     AcceptDispute,
     SubmitEvidence,
     DefendDispute,
@@ -55,4 +62,5 @@ pub enum FlowName {
     IncomingWebhook,
     Dsync,
     CreateSessionToken,
+    RegisterWebhook,
 }
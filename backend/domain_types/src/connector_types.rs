@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use base64::Engine;
 use common_enums::{
     AttemptStatus, AuthenticationType, Currency, DisputeStatus, EventClass, PaymentMethod,
     PaymentMethodType,
@@ -23,10 +24,11 @@ use crate::{
     payment_method_data::{self, Card, PaymentMethodData, PaymentMethodDataTypes},
     router_data::PaymentMethodToken,
     router_request_types::{
-        AcceptDisputeIntegrityObject, AuthoriseIntegrityObject, BrowserInformation,
-        CaptureIntegrityObject, CreateOrderIntegrityObject, DefendDisputeIntegrityObject,
-        PaymentSynIntegrityObject, PaymentVoidIntegrityObject, RefundIntegrityObject,
-        RefundSyncIntegrityObject, RepeatPaymentIntegrityObject, SetupMandateIntegrityObject,
+        AcceptDisputeIntegrityObject, AuthenticationData, AuthoriseIntegrityObject,
+        BrowserInformation, CaptureIntegrityObject, CaptureReversalIntegrityObject,
+        CreateOrderIntegrityObject, DefendDisputeIntegrityObject, PaymentSynIntegrityObject,
+        PaymentVoidIntegrityObject, RefundIntegrityObject, RefundSyncIntegrityObject,
+        RegisterWebhookIntegrityObject, RepeatPaymentIntegrityObject, SetupMandateIntegrityObject,
         SubmitEvidenceIntegrityObject, SyncRequestType,
     },
     router_response_types::RedirectForm,
@@ -173,6 +175,10 @@ pub trait ConnectorResponseHeaders {
     }
 }
 
+pub trait ConnectorHttpStatusCode {
+    fn set_connector_http_status_code(&mut self, status_code: u16);
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Eq, PartialEq)]
 pub struct NetworkTokenWithNTIRef {
     pub network_transaction_id: String,
@@ -209,6 +215,69 @@ impl MandateIds {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum StoredCredentialSequence {
+    First,
+    Subsequent,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum StoredCredentialInitiator {
+    Cardholder,
+    Merchant,
+}
+
+/// Consent indicator for payments made with stored credentials, mapped by each connector to
+/// its own stored-credential / recurring indicators
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct StoredCredentialUsage {
+    pub sequence: StoredCredentialSequence,
+    pub initiator: StoredCredentialInitiator,
+}
+
+impl StoredCredentialUsage {
+    /// Merchant-initiated charge of a credential stored by an earlier payment, outside any
+    /// fixed schedule
+    pub fn unscheduled_merchant_initiated() -> Self {
+        Self {
+            sequence: StoredCredentialSequence::Subsequent,
+            initiator: StoredCredentialInitiator::Merchant,
+        }
+    }
+
+    pub fn is_merchant_initiated(&self) -> bool {
+        self.initiator == StoredCredentialInitiator::Merchant
+    }
+
+    pub fn is_subsequent(&self) -> bool {
+        self.sequence == StoredCredentialSequence::Subsequent
+    }
+}
+
+/// 3DS protocol version used to authenticate the cardholder
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum ThreeDsVersion {
+    V1,
+    V2,
+}
+
+impl ThreeDsVersion {
+    /// Picks the version to request from the connector.
+    ///
+    /// The preferred version is used when the connector supports it, otherwise 3DS2 is requested
+    /// where supported with 3DS1 as the fallback. Returns `None` when the connector does not
+    /// advertise any version.
+    pub fn negotiate(preferred: Option<Self>, supported: &[Self]) -> Option<Self> {
+        preferred
+            .filter(|version| supported.contains(version))
+            .or_else(|| {
+                [Self::V2, Self::V1]
+                    .into_iter()
+                    .find(|version| supported.contains(version))
+            })
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PaymentsSyncData {
     pub connector_transaction_id: ResponseId,
@@ -279,6 +348,124 @@ pub struct PaymentFlowData {
     pub external_latency: Option<u128>,
     pub connectors: Connectors,
     pub raw_connector_response: Option<String>,
+    /// issuer and BIN details of the card, when returned by the connector
+    pub card_issuer_info: Option<CardIssuerInfo>,
+    /// score and decision of the connector's fraud check, when returned by the connector
+    pub fraud_check: Option<FraudCheckInfo>,
+    /// amount and currency captured by the connector when they differ from the payment's (DCC)
+    pub settlement_details: Option<SettlementDetails>,
+    /// connector's decision on the requested SCA exemption, when returned by the connector
+    pub sca_exemption_decision: Option<ScaExemptionDecision>,
+    /// masked card and wallet details of the payment method, when returned by the connector
+    pub payment_method_details: Option<PaymentMethodDetails>,
+    /// non-fatal warnings the connector returned with a successful response
+    pub connector_warnings: Vec<String>,
+    /// installment plan the connector set up for the payment, when returned by the connector
+    pub installment_plan: Option<InstallmentPlan>,
+    /// soft or hard classification of a failed 3DS authentication, when the connector reports one
+    pub three_ds_failure: Option<ThreeDsFailure>,
+    /// progress of the 3DS authentication, when the connector reports it
+    pub three_ds_completion: Option<ThreeDsCompletion>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CardIssuerInfo {
+    pub card_issuer: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bin: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FraudCheckInfo {
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<FraudDecision>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaymentMethodDetails {
+    /// last four digits of the card number, never the full number
+    pub last4: Option<String>,
+    pub network: Option<common_enums::CardNetwork>,
+    pub funding_type: Option<CardFundingType>,
+    pub wallet_type: Option<common_enums::PaymentMethodType>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CardFundingType {
+    Credit,
+    Debit,
+    Prepaid,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettlementDetails {
+    pub amount: MinorUnit,
+    pub currency: Currency,
+}
+
+/// PSD2 Strong Customer Authentication exemption requested from the connector
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ScaExemption {
+    TransactionRiskAnalysis,
+    LowValue,
+}
+
+/// Whether the connector applied the requested SCA exemption
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ScaExemptionDecision {
+    Applied,
+    Rejected,
+}
+
+/// Whether a failed 3DS authentication leaves room for a retry without 3DS
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ThreeDsFailure {
+    /// technical failure (e.g. the directory server did not answer), a non-3DS retry may succeed
+    Soft,
+    /// the cardholder failed the authentication, a non-3DS retry should not be attempted
+    Hard,
+}
+
+impl ThreeDsFailure {
+    pub fn allows_fallback(self) -> bool {
+        matches!(self, Self::Soft)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Soft => "soft",
+            Self::Hard => "hard",
+        }
+    }
+}
+
+/// How far the 3DS authentication of a payment has progressed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ThreeDsCompletion {
+    /// the 3DS method (device data collection) completed, the cardholder challenge is outstanding
+    ChallengePending,
+    /// authentication completed, frictionless or after a challenge
+    Completed,
+    /// authentication was offered but the cardholder did not complete it
+    NotCompleted,
+}
+
+/// Installment plan the connector set up for a payment
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallmentPlan {
+    pub installment_count: u32,
+    pub installment_amount: Option<MinorUnit>,
+    /// total amount due including interest
+    pub total_amount: Option<MinorUnit>,
+    pub interest_rate: Option<String>,
+}
+
+/// Outcome of the connector's fraud check
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FraudDecision {
+    Accept,
+    Review,
+    Reject,
 }
 
 impl PaymentFlowData {
@@ -702,6 +889,12 @@ impl ConnectorResponseHeaders for PaymentFlowData {
     }
 }
 
+impl ConnectorHttpStatusCode for PaymentFlowData {
+    fn set_connector_http_status_code(&mut self, status_code: u16) {
+        self.connector_http_status_code = Some(status_code);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentVoidData {
     pub connector_transaction_id: String,
@@ -735,6 +928,17 @@ impl PaymentVoidData {
     }
 }
 
+/// Reversal of a capture that has not been settled yet, as opposed to a refund of settled funds
+#[derive(Debug, Default, Clone)]
+pub struct PaymentCaptureReversalData {
+    /// connector id of the capture being reversed
+    pub connector_transaction_id: String,
+    /// connector id of the payment the capture belongs to
+    pub connector_payment_id: Option<String>,
+    pub reversal_reason: Option<String>,
+    pub integrity_object: Option<CaptureReversalIntegrityObject>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct PaymentsAuthorizeData<T: PaymentMethodDataTypes> {
     pub payment_method_data: payment_method_data::PaymentMethodData<T>,
@@ -783,6 +987,15 @@ pub struct PaymentsAuthorizeData<T: PaymentMethodDataTypes> {
     pub integrity_object: Option<AuthoriseIntegrityObject>,
     pub merchant_config_currency: Option<common_enums::Currency>,
     pub all_keys_required: Option<bool>,
+    pub stored_credential_usage: Option<StoredCredentialUsage>,
+    pub preferred_three_ds_version: Option<ThreeDsVersion>,
+    /// Version negotiated against the connector capabilities, set only for 3DS payments
+    pub three_ds_version: Option<ThreeDsVersion>,
+    pub requested_exemption: Option<ScaExemption>,
+    /// Results of a 3DS authentication completed outside the connector
+    pub authentication_data: Option<AuthenticationData>,
+    /// Number of installments the customer chose to split the payment into
+    pub installment_count: Option<u32>,
 }
 
 impl<T: PaymentMethodDataTypes> PaymentsAuthorizeData<T> {
@@ -968,6 +1181,11 @@ impl<T: PaymentMethodDataTypes> PaymentsAuthorizeData<T> {
         self.session_token = session_token;
         self
     }
+
+    pub fn set_three_ds_version(mut self, three_ds_version: Option<ThreeDsVersion>) -> Self {
+        self.three_ds_version = three_ds_version;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -989,6 +1207,43 @@ impl ResponseId {
             .attach_printable("Expected connector transaction ID not found"),
         }
     }
+
+    /// Packs several connector ids into `EncodedData` as URL-safe base64 encoded JSON,
+    /// so they can be read back with [`Self::decode_ids`] on sync.
+    pub fn encode_ids<T: Serialize>(
+        ids: &T,
+    ) -> errors::CustomResult<Self, errors::ValidationError> {
+        let json =
+            serde_json::to_vec(ids).change_context(errors::ValidationError::InvalidValue {
+                message: "connector ids could not be serialized".to_string(),
+            })?;
+        Ok(Self::EncodedData(
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json),
+        ))
+    }
+
+    /// Reads back connector ids packed with [`Self::encode_ids`].
+    pub fn decode_ids<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> errors::CustomResult<T, errors::ValidationError> {
+        let Self::EncodedData(data) = self else {
+            return Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "encoded_data",
+            })
+            .attach_printable("Expected encoded data not found");
+        };
+        let json = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(data)
+            .change_context(errors::ValidationError::IncorrectValueProvided {
+                field_name: "encoded_data",
+            })
+            .attach_printable("Encoded data is not base64")?;
+        serde_json::from_slice(&json)
+            .change_context(errors::ValidationError::IncorrectValueProvided {
+                field_name: "encoded_data",
+            })
+            .attach_printable("Encoded data does not hold the expected ids")
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1065,6 +1320,8 @@ pub struct RefundsResponseData {
     pub connector_refund_id: String,
     pub refund_status: common_enums::RefundStatus,
     pub status_code: u16,
+    /// Reference the connector reports for the refund, distinct from `connector_refund_id`
+    pub connector_response_reference_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -1097,6 +1354,11 @@ impl ConnectorResponseHeaders for RefundFlowData {
     }
 }
 
+impl ConnectorHttpStatusCode for RefundFlowData {
+    // Not tracked for refund flows
+    fn set_connector_http_status_code(&mut self, _status_code: u16) {}
+}
+
 #[derive(Debug, Clone)]
 pub struct WebhookDetailsResponse {
     pub resource_id: Option<ResponseId>,
@@ -1138,6 +1400,20 @@ pub struct DisputeWebhookDetailsResponse {
     pub connector_reason_code: Option<String>,
 }
 
+/// Settlement of funds to the merchant, normalized from a connector webhook
+#[derive(Debug, Clone)]
+pub struct SettlementWebhookDetailsResponse {
+    pub settlement_id: String,
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    pub settlement_date: Option<time::PrimitiveDateTime>,
+    /// fees deducted by the connector before settling
+    pub fees: Option<MinorUnit>,
+    pub raw_connector_response: Option<String>,
+    pub status_code: u16,
+    pub response_headers: Option<http::HeaderMap>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HttpMethod {
     Options,
@@ -1158,6 +1434,8 @@ pub struct RequestDetails {
     pub headers: HashMap<String, String>,
     pub body: Vec<u8>,
     pub query_params: Option<String>,
+    /// Address the caller received the request from
+    pub source_ip: Option<std::net::IpAddr>,
 }
 
 #[derive(Debug, Clone)]
@@ -1223,6 +1501,9 @@ pub enum EventType {
     RecoveryPaymentSuccess,
     RecoveryPaymentPending,
     RecoveryInvoiceCancel,
+
+    // Settlement events
+    SettlementProcessed,
     IncomingWebhookEventUnspecified,
 
     // Legacy broad categories (for backward compatibility)
@@ -1307,6 +1588,11 @@ impl EventType {
         )
     }
 
+    /// Returns true if this event type is settlement-related
+    pub fn is_settlement_event(&self) -> bool {
+        matches!(self, Self::SettlementProcessed)
+    }
+
     /// Returns true if this event type is miscellaneous
     pub fn is_misc_event(&self) -> bool {
         matches!(
@@ -1425,6 +1711,9 @@ impl ForeignTryFrom<grpc_api_types::payments::WebhookEventType> for EventType {
             grpc_api_types::payments::WebhookEventType::RecoveryInvoiceCancel => {
                 Ok(Self::RecoveryInvoiceCancel)
             }
+            grpc_api_types::payments::WebhookEventType::SettlementProcessed => {
+                Ok(Self::SettlementProcessed)
+            }
             grpc_api_types::payments::WebhookEventType::IncomingWebhookEventUnspecified => {
                 Ok(Self::IncomingWebhookEventUnspecified)
             }
@@ -1481,6 +1770,7 @@ impl ForeignTryFrom<EventType> for grpc_api_types::payments::WebhookEventType {
             EventType::RecoveryPaymentSuccess => Ok(Self::RecoveryPaymentSuccess),
             EventType::RecoveryPaymentPending => Ok(Self::RecoveryPaymentPending),
             EventType::RecoveryInvoiceCancel => Ok(Self::RecoveryInvoiceCancel),
+            EventType::SettlementProcessed => Ok(Self::SettlementProcessed),
             EventType::IncomingWebhookEventUnspecified => Ok(Self::IncomingWebhookEventUnspecified),
 
             // Legacy broad categories (for backward compatibility)
@@ -1514,6 +1804,19 @@ impl ForeignTryFrom<grpc_api_types::payments::RequestDetails> for RequestDetails
         value: grpc_api_types::payments::RequestDetails,
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         let method = HttpMethod::foreign_try_from(value.method())?;
+        let source_ip = value
+            .source_ip
+            .map(|source_ip| {
+                source_ip.trim().parse().map_err(|_| {
+                    error_stack::Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+                        sub_code: "INVALID_SOURCE_IP".to_string(),
+                        error_identifier: 400,
+                        error_message: format!("Invalid request source IP address: {source_ip}"),
+                        error_object: None,
+                    }))
+                })
+            })
+            .transpose()?;
 
         Ok(Self {
             method,
@@ -1521,6 +1824,7 @@ impl ForeignTryFrom<grpc_api_types::payments::RequestDetails> for RequestDetails
             headers: value.headers,
             body: value.body,
             query_params: value.query_params,
+            source_ip,
         })
     }
 }
@@ -1600,6 +1904,8 @@ pub struct PaymentsCaptureData {
     pub connector_metadata: Option<serde_json::Value>,
     pub integrity_object: Option<CaptureIntegrityObject>,
     pub browser_info: Option<BrowserInformation>,
+    /// time at which a scheduled capture should be executed
+    pub capture_at: Option<time::PrimitiveDateTime>,
 }
 
 impl PaymentsCaptureData {
@@ -1697,6 +2003,7 @@ pub struct RepeatPaymentData {
     pub browser_info: Option<BrowserInformation>,
     pub email: Option<common_utils::pii::Email>,
     pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub stored_credential_usage: StoredCredentialUsage,
 }
 
 impl RepeatPaymentData {
@@ -1764,6 +2071,11 @@ impl ConnectorResponseHeaders for DisputeFlowData {
     }
 }
 
+impl ConnectorHttpStatusCode for DisputeFlowData {
+    // Not tracked for dispute flows
+    fn set_connector_http_status_code(&mut self, _status_code: u16) {}
+}
+
 #[derive(Debug, Clone)]
 pub struct DisputeResponseData {
     pub connector_dispute_id: String,
@@ -1772,6 +2084,55 @@ pub struct DisputeResponseData {
     pub status_code: u16,
 }
 
+/// Common data of merchant-level webhook management flows, which are not tied to a payment
+#[derive(Debug, Clone)]
+pub struct WebhookFlowData {
+    pub connectors: Connectors,
+    pub connector_request_reference_id: String,
+    pub raw_connector_response: Option<String>,
+    pub connector_response_headers: Option<http::HeaderMap>,
+}
+
+impl RawConnectorResponse for WebhookFlowData {
+    fn set_raw_connector_response(&mut self, response: Option<String>) {
+        self.raw_connector_response = response;
+    }
+
+    fn get_raw_connector_response(&self) -> Option<String> {
+        self.raw_connector_response.clone()
+    }
+}
+
+impl ConnectorResponseHeaders for WebhookFlowData {
+    fn set_connector_response_headers(&mut self, headers: Option<http::HeaderMap>) {
+        self.connector_response_headers = headers;
+    }
+
+    fn get_connector_response_headers(&self) -> Option<&http::HeaderMap> {
+        self.connector_response_headers.as_ref()
+    }
+}
+
+impl ConnectorHttpStatusCode for WebhookFlowData {
+    // Not tracked for webhook management flows
+    fn set_connector_http_status_code(&mut self, _status_code: u16) {}
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisterWebhookData {
+    pub webhook_url: String,
+    /// connector event names to subscribe to, all events when empty
+    pub event_types: Vec<String>,
+    pub integrity_object: Option<RegisterWebhookIntegrityObject>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisterWebhookResponseData {
+    /// connector id of the registered webhook
+    pub registration_id: String,
+    pub status_code: u16,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SubmitEvidenceData {
     pub dispute_id: Option<String>,
@@ -2119,3 +2480,45 @@ impl SupportedPaymentMethodsExt for SupportedPaymentMethods {
         }
     }
 }
+
+#[cfg(test)]
+mod encoded_ids_tests {
+    #![allow(clippy::unwrap_used)]
+    #![allow(clippy::panic)]
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CompositeId {
+        order_id: String,
+        payment_id: String,
+        attempt: u32,
+    }
+
+    #[test]
+    fn composite_id_round_trips_through_encoded_data() {
+        let ids = CompositeId {
+            order_id: "order_123".to_string(),
+            payment_id: "pay/456+789".to_string(),
+            attempt: 2,
+        };
+
+        let response_id = ResponseId::encode_ids(&ids).unwrap();
+        let ResponseId::EncodedData(data) = &response_id else {
+            panic!("expected encoded data, got {response_id:?}");
+        };
+        let synced_id = ResponseId::EncodedData(data.clone());
+
+        assert!(!data.contains(['/', '+', '=']));
+        assert_eq!(synced_id.decode_ids::<CompositeId>().unwrap(), ids);
+    }
+
+    #[test]
+    fn non_encoded_response_ids_are_rejected() {
+        assert!(ResponseId::ConnectorTransactionId("txn_123".to_string())
+            .decode_ids::<CompositeId>()
+            .is_err());
+        assert!(ResponseId::EncodedData("not base64!".to_string())
+            .decode_ids::<CompositeId>()
+            .is_err());
+    }
+}
@@ -57,6 +57,7 @@ pub enum ApplicationErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    PreconditionFailed(ApiError),
 }
 
 #[derive(Debug, serde::Serialize, Clone)]
@@ -907,6 +908,8 @@ pub enum ConnectorError {
     InSufficientBalanceInPaymentMethod,
     #[error("Server responded with Request Timeout")]
     RequestTimeoutReceived,
+    #[error("Connector rejected the configured credentials with HTTP status {status_code}")]
+    ConnectorAuthenticationFailed { status_code: u16 },
     #[error("The given currency method is not configured with the given connector")]
     CurrencyNotSupported {
         message: String,
@@ -104,13 +104,15 @@ pub struct PaymentsCancelData {
     pub capture_method: Option<CaptureMethod>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AuthenticationData {
     pub eci: Option<String>,
     pub cavv: Secret<String>,
     pub threeds_server_transaction_id: Option<String>,
     pub message_version: Option<SemanticVersion>,
     pub ds_trans_id: Option<String>,
+    /// 3DS transaction status (transStatus), upper-cased
+    pub trans_status: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +178,16 @@ pub struct PaymentVoidIntegrityObject {
     pub connector_transaction_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CaptureReversalIntegrityObject {
+    pub connector_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegisterWebhookIntegrityObject {
+    pub webhook_url: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RefundIntegrityObject {
     pub refund_amount: MinorUnit,
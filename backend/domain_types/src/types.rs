@@ -1,17 +1,25 @@
 use core::result::Result;
-use std::{borrow::Cow, collections::HashMap, fmt::Debug, str::FromStr};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug, net::IpAddr, str::FromStr};
 
 use common_enums::{CaptureMethod, CardNetwork, PaymentMethod, PaymentMethodType};
-use common_utils::{consts::NO_ERROR_CODE, id_type::CustomerId, pii::Email, Method};
+use common_utils::{
+    consts::NO_ERROR_CODE,
+    id_type::CustomerId,
+    pii::Email,
+    types::{AmountConvertor, FloatMajorUnit, FloatMajorUnitForConnector, MinorUnit},
+    Method,
+};
 use error_stack::{report, ResultExt};
 use grpc_api_types::payments::{
     AcceptDisputeResponse, DisputeDefendRequest, DisputeDefendResponse, DisputeResponse,
     DisputeServiceSubmitEvidenceResponse, PaymentServiceAuthorizeRequest,
     PaymentServiceAuthorizeResponse, PaymentServiceCaptureResponse, PaymentServiceGetResponse,
-    PaymentServiceRegisterRequest, PaymentServiceRegisterResponse, PaymentServiceVoidRequest,
-    PaymentServiceVoidResponse, RefundResponse,
+    PaymentServiceRegisterRequest, PaymentServiceRegisterResponse,
+    PaymentServiceRegisterWebhookRequest, PaymentServiceRegisterWebhookResponse,
+    PaymentServiceReverseCaptureRequest, PaymentServiceReverseCaptureResponse,
+    PaymentServiceVoidRequest, PaymentServiceVoidResponse, RefundResponse, SettlementResponse,
 };
-use hyperswitch_masking::{ExposeInterface, Secret};
+use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
 use serde::Serialize;
 use serde_json::json;
 use tonic;
@@ -32,24 +40,55 @@ fn extract_connector_request_reference_id(
         .unwrap_or_default()
 }
 
+/// Reads `key` from the request metadata as JSON so connectors receive the parsed object;
+/// values that are not valid JSON are passed through as a plain string.
+pub fn parse_connector_meta(
+    metadata: &HashMap<String, String>,
+    key: &str,
+) -> Result<Option<Secret<serde_json::Value>>, error_stack::Report<ApplicationErrorResponse>> {
+    Ok(metadata.get(key).map(|raw| {
+        Secret::new(
+            serde_json::from_str(raw).unwrap_or_else(|_| serde_json::Value::String(raw.clone())),
+        )
+    }))
+}
+
+/// Hands the `EncodedData` transaction id of a sync request to connectors as
+/// `{"encoded_data": ...}` in the flow's connector metadata.
+fn sync_connector_meta_data(
+    transaction_id: &Option<grpc_api_types::payments::Identifier>,
+) -> Option<Secret<serde_json::Value>> {
+    match transaction_id.as_ref()?.id_type.as_ref()? {
+        grpc_api_types::payments::identifier::IdType::EncodedData(data) => {
+            Some(Secret::new(json!({ "encoded_data": data })))
+        }
+        _ => None,
+    }
+}
+
 // For decoding connector_meta_data and Engine trait - base64 crate no longer needed here
 use crate::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, RepeatPayment, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, RepeatPayment, SetupMandate,
+        SubmitEvidence, Void,
     },
     connector_types::{
-        AcceptDisputeData, ConnectorMandateReferenceId, ConnectorResponseHeaders,
-        DisputeDefendData, DisputeFlowData, DisputeResponseData, DisputeWebhookDetailsResponse,
-        MandateReferenceId, MultipleCaptureRequestData, PaymentCreateOrderData,
-        PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
-        PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RawConnectorResponse,
-        RefundFlowData, RefundSyncData, RefundWebhookDetailsResponse, RefundsData,
-        RefundsResponseData, RepeatPaymentData, ResponseId, SessionTokenRequestData,
-        SessionTokenResponseData, SetupMandateRequestData, SubmitEvidenceData,
-        WebhookDetailsResponse,
+        AcceptDisputeData, CardFundingType, ConnectorEnum, ConnectorMandateReferenceId,
+        ConnectorResponseHeaders, DisputeDefendData, DisputeFlowData, DisputeResponseData,
+        DisputeWebhookDetailsResponse, FraudDecision, InstallmentPlan, MandateReferenceId,
+        MultipleCaptureRequestData, PaymentCaptureReversalData, PaymentCreateOrderData,
+        PaymentCreateOrderResponse, PaymentFlowData, PaymentMethodDetails, PaymentVoidData,
+        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
+        RawConnectorResponse, RefundFlowData, RefundSyncData, RefundWebhookDetailsResponse,
+        RefundsData, RefundsResponseData, RegisterWebhookData, RegisterWebhookResponseData,
+        RepeatPaymentData, ResponseId, ScaExemption, ScaExemptionDecision, SessionTokenRequestData,
+        SessionTokenResponseData, SettlementDetails, SettlementWebhookDetailsResponse,
+        SetupMandateRequestData, StoredCredentialInitiator, StoredCredentialSequence,
+        StoredCredentialUsage, SubmitEvidenceData, ThreeDsCompletion, ThreeDsFailure,
+        ThreeDsVersion, WebhookDetailsResponse, WebhookFlowData,
     },
-    errors::{ApiError, ApplicationErrorResponse},
+    errors::{ApiError, ApplicationErrorResponse, ConnectorError},
     mandates::{self, MandateData},
     payment_address,
     payment_address::{Address, AddressDetails, PaymentAddress, PhoneDetails},
@@ -62,10 +101,13 @@ use crate::{
     router_request_types,
     router_request_types::BrowserInformation,
     router_response_types,
-    utils::{extract_merchant_id_from_metadata, ForeignFrom, ForeignTryFrom},
+    utils::{
+        apply_base_url_override, extract_merchant_id_from_metadata, ForeignFrom, ForeignTryFrom,
+    },
 };
 
 #[derive(Clone, serde::Deserialize, Debug, Default)]
+#[serde(default)]
 pub struct Connectors {
     // Added pub
     pub adyen: ConnectorParams,
@@ -86,13 +128,877 @@ pub struct Connectors {
     pub novalnet: ConnectorParams,
     pub nexinets: ConnectorParams,
     pub noon: ConnectorParams,
+    /// connector of requests without the x-connector header, set per request from the server's
+    /// `default_connector`
+    #[serde(skip)]
+    pub default_connector: Option<ConnectorEnum>,
 }
 
 #[derive(Clone, serde::Deserialize, Debug, Default)]
 pub struct ConnectorParams {
     /// base url
+    #[serde(default)]
     pub base_url: String,
     pub dispute_base_url: Option<String>,
+    /// request body format to use instead of the connector's canonical one
+    #[serde(default)]
+    pub request_content_type: Option<common_utils::request::ContentType>,
+    /// default polling hints returned with pending responses
+    #[serde(default)]
+    pub polling: Option<PollingConfig>,
+    /// inbound gRPC metadata keys forwarded to the connector, mapped to the outbound header name
+    #[serde(default)]
+    pub forwarded_headers: HashMap<String, String>,
+    /// connector decline codes after which an authorize is retried once
+    #[serde(default)]
+    pub soft_decline_codes: Vec<String>,
+    /// header carrying the idempotency key of a soft decline retry, e.g. `Idempotency-Key`;
+    /// soft declines are not retried when unset
+    #[serde(default)]
+    pub retry_idempotency_header: Option<String>,
+    /// request fields that must be present before an authorize is sent to the connector
+    #[serde(default)]
+    pub required_fields: Vec<RequiredField>,
+    /// addresses the connector sends webhooks from; any source is accepted when empty
+    #[serde(default)]
+    pub webhook_allowed_source_ips: Vec<IpNetwork>,
+    /// body format of the connector's webhooks, checked against the content-type and parsed
+    /// before the webhook is transformed; bodies are passed through unchecked when unset
+    #[serde(default)]
+    pub webhook_body_format: Option<WebhookBodyFormat>,
+    /// unit of the amounts in connector responses, overriding the unit the connector declares
+    #[serde(default)]
+    pub response_amount_unit: Option<AmountUnit>,
+    /// decimal places the connector accepts for currencies where it supports fewer than the currency's own
+    #[serde(default)]
+    pub amount_decimal_places: HashMap<common_enums::Currency, u8>,
+    /// rounding applied to amounts with more decimal places than the connector accepts
+    #[serde(default)]
+    pub amount_rounding: AmountRounding,
+    /// capture method used when an authorize request leaves it unspecified
+    #[serde(default)]
+    pub default_capture_method: Option<common_enums::CaptureMethod>,
+    /// largest number of installments the connector accepts; installment requests are rejected when unset
+    #[serde(default)]
+    pub max_installment_count: Option<u32>,
+    /// returns connector responses that cannot be parsed as an error carrying the start of the
+    /// response text instead of an internal error
+    #[serde(default)]
+    pub unparsable_response_fallback: UnparsableResponseFallbackConfig,
+    /// base urls a request may select with the `x-connector-base-url` header instead of
+    /// `base_url`, e.g. a sandbox for staging requests; the header is rejected when empty, and
+    /// http urls are only accepted for requests in test mode
+    #[serde(default)]
+    pub allowed_base_url_overrides: Vec<String>,
+    /// client certificate and trust settings for connectors that require mutual TLS
+    #[serde(default)]
+    pub tls: Option<ConnectorTlsConfig>,
+    /// values of boolean-like flags in connector responses, replacing the values the connector
+    /// declares itself
+    #[serde(default)]
+    pub status_flag_values: Option<StatusFlagValues>,
+}
+
+impl ConnectorParams {
+    /// Rounds an amount sent to the connector to the decimal places it accepts for `currency`.
+    /// The result stays in the currency's minor unit; amounts in other currencies are unchanged.
+    pub fn round_amount(
+        &self,
+        amount: MinorUnit,
+        currency: common_enums::Currency,
+    ) -> Result<MinorUnit, error_stack::Report<ConnectorError>> {
+        let Some(&decimal_places) = self.amount_decimal_places.get(&currency) else {
+            return Ok(amount);
+        };
+        let currency_decimal_places = currency
+            .number_of_digits_after_decimal_point()
+            .change_context(ConnectorError::AmountConversionFailed)?;
+        let step = currency_decimal_places
+            .checked_sub(decimal_places)
+            .and_then(|dropped_places| 10_i64.checked_pow(dropped_places.into()))
+            .unwrap_or(1);
+        self.amount_rounding
+            .round(amount.get_amount_as_i64(), step)
+            .map(MinorUnit::new)
+            .ok_or(report!(ConnectorError::AmountConversionFailed))
+            .attach_printable_lazy(|| {
+                format!("`{amount}` cannot be rounded to {decimal_places} decimal places")
+            })
+    }
+}
+
+/// Rounding rule for amounts with more precision than a connector accepts
+#[derive(Clone, Copy, serde::Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AmountRounding {
+    /// nearest multiple, halves rounded up
+    #[default]
+    HalfUp,
+    /// nearest multiple, halves rounded to the even multiple
+    HalfEven,
+    /// drops the extra precision
+    Truncate,
+}
+
+impl AmountRounding {
+    /// Rounds a non-negative amount to a multiple of `step`.
+    fn round(self, amount: i64, step: i64) -> Option<i64> {
+        let quotient = amount.checked_div_euclid(step)?;
+        let doubled_remainder = amount.checked_rem_euclid(step)?.checked_mul(2)?;
+        let round_up = match self {
+            Self::HalfUp => doubled_remainder >= step,
+            Self::HalfEven => {
+                doubled_remainder > step || (doubled_remainder == step && quotient % 2 != 0)
+            }
+            Self::Truncate => false,
+        };
+        quotient.checked_add(i64::from(round_up))?.checked_mul(step)
+    }
+}
+
+/// Unit of an amount returned by a connector
+#[derive(Clone, Copy, serde::Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AmountUnit {
+    /// smallest currency unit, e.g. cents for USD
+    #[default]
+    Minor,
+    /// whole currency unit, scaled to minor units by the currency exponent
+    Major,
+}
+
+impl AmountUnit {
+    /// Converts an amount parsed from a connector response into minor units.
+    pub fn to_minor_unit(
+        self,
+        amount: &serde_json::Number,
+        currency: common_enums::Currency,
+    ) -> Result<MinorUnit, error_stack::Report<ConnectorError>> {
+        match self {
+            Self::Minor => amount
+                .as_i64()
+                .map(MinorUnit::new)
+                .ok_or(report!(ConnectorError::AmountConversionFailed))
+                .attach_printable_lazy(|| format!("`{amount}` is not a whole minor amount")),
+            Self::Major => {
+                let amount = amount
+                    .as_f64()
+                    .ok_or(report!(ConnectorError::AmountConversionFailed))?;
+                FloatMajorUnitForConnector
+                    .convert_back(FloatMajorUnit(amount), currency)
+                    .change_context(ConnectorError::AmountConversionFailed)
+            }
+        }
+    }
+}
+
+/// Body format a connector sends its webhooks in
+#[derive(Clone, Copy, serde::Deserialize, Debug, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookBodyFormat {
+    Json,
+    FormUrlEncoded,
+    Xml,
+}
+
+impl WebhookBodyFormat {
+    /// Format named by a media type, e.g. `application/json` or `application/vnd.api+json`;
+    /// media types that name no structured format, like `text/plain`, give `None`
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        let media_type = media_type.trim().to_ascii_lowercase();
+        match media_type.as_str() {
+            "application/json" | "text/json" => Some(Self::Json),
+            "application/x-www-form-urlencoded" => Some(Self::FormUrlEncoded),
+            "application/xml" | "text/xml" => Some(Self::Xml),
+            _ if media_type.ends_with("+json") => Some(Self::Json),
+            _ if media_type.ends_with("+xml") => Some(Self::Xml),
+            _ => None,
+        }
+    }
+}
+
+/// IP address or CIDR block, e.g. `203.0.113.0/24`
+#[derive(Clone, Copy, serde::Deserialize, Debug, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub struct IpNetwork {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.address, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                self.same_prefix(u32::from(network).into(), u32::from(ip).into(), 32)
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                self.same_prefix(u128::from(network), u128::from(ip), 128)
+            }
+            _ => false,
+        }
+    }
+
+    fn same_prefix(&self, network: u128, ip: u128, address_bits: u8) -> bool {
+        let host_bits = u32::from(address_bits - self.prefix_len);
+        network.checked_shr(host_bits).unwrap_or(0) == ip.checked_shr(host_bits).unwrap_or(0)
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = match value.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (value, None),
+        };
+        let address = IpAddr::from_str(address.trim())
+            .map_err(|_| format!("invalid IP address in `{value}`"))?;
+        let address_bits = if address.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix_len| *prefix_len <= address_bits)
+                .ok_or_else(|| format!("invalid prefix length in `{value}`"))?,
+            None => address_bits,
+        };
+        Ok(Self {
+            address,
+            prefix_len,
+        })
+    }
+}
+
+impl TryFrom<String> for IpNetwork {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Authorize request field a connector can be configured to require
+#[derive(Clone, Copy, serde::Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RequiredField {
+    Email,
+    Phone,
+    BillingAddress,
+}
+
+impl RequiredField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::BillingAddress => "billing_address",
+        }
+    }
+
+    fn is_present(&self, request: &PaymentServiceAuthorizeRequest) -> bool {
+        let billing = request
+            .address
+            .as_ref()
+            .and_then(|address| address.billing_address.as_ref());
+        match self {
+            Self::Email => {
+                request.email.is_some() || billing.is_some_and(|billing| billing.email.is_some())
+            }
+            Self::Phone => billing.is_some_and(|billing| billing.phone_number.is_some()),
+            Self::BillingAddress => billing.is_some_and(|billing| {
+                billing.line1.is_some()
+                    && billing.city.is_some()
+                    && billing.zip_code.is_some()
+                    && billing.country_alpha2_code.is_some()
+            }),
+        }
+    }
+}
+
+/// Checks an authorize request against the connector's required fields.
+///
+/// All missing fields are reported together so the caller can fix the request in one go.
+pub fn validate_required_fields(
+    request: &PaymentServiceAuthorizeRequest,
+    required_fields: &[RequiredField],
+) -> Result<(), error_stack::Report<ApplicationErrorResponse>> {
+    let missing_fields: Vec<&str> = required_fields
+        .iter()
+        .filter(|field| !field.is_present(request))
+        .map(RequiredField::as_str)
+        .collect();
+    if missing_fields.is_empty() {
+        return Ok(());
+    }
+    Err(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "MISSING_REQUIRED_FIELDS".to_owned(),
+        error_identifier: 400,
+        error_message: format!("Missing required fields: {}", missing_fields.join(", ")),
+        error_object: None,
+    }))?
+}
+
+/// Rejects an installment count the connector does not accept. Requests without installments
+/// always pass.
+pub fn validate_installment_count(
+    request: &PaymentServiceAuthorizeRequest,
+    max_installment_count: Option<u32>,
+) -> Result<(), error_stack::Report<ApplicationErrorResponse>> {
+    let Some(installment_count) = request.installment_count else {
+        return Ok(());
+    };
+    let error_message = match max_installment_count {
+        _ if installment_count == 0 => "Installment count must be at least 1".to_string(),
+        None => "Installments are not supported by the connector".to_string(),
+        Some(max_installment_count) if installment_count > max_installment_count => {
+            format!("Installment count must not exceed {max_installment_count}")
+        }
+        Some(_) => return Ok(()),
+    };
+    Err(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "INVALID_INSTALLMENT_COUNT".to_owned(),
+        error_identifier: 400,
+        error_message,
+        error_object: None,
+    }))?
+}
+
+/// Rejects an authorize request that does not name exactly one payment method: either no
+/// payment method at all, a category such as `card` or `wallet` without a method inside it, or
+/// a reward without its reward type.
+///
+/// Protobuf decoding keeps only the last branch of a `oneof`, so conflicting branches sent on
+/// the wire never reach this check.
+pub fn validate_single_payment_method(
+    request: &PaymentServiceAuthorizeRequest,
+) -> Result<(), error_stack::Report<ApplicationErrorResponse>> {
+    use grpc_api_types::payments::payment_method::PaymentMethod;
+
+    let is_specified = match request
+        .payment_method
+        .as_ref()
+        .and_then(|payment_method| payment_method.payment_method.as_ref())
+    {
+        None => false,
+        Some(PaymentMethod::Card(card)) => card.card_type.is_some(),
+        Some(PaymentMethod::Wallet(wallet)) => wallet.wallet_type.is_some(),
+        Some(PaymentMethod::BankRedirect(bank_redirect)) => {
+            bank_redirect.bank_redirect_type.is_some()
+        }
+        Some(PaymentMethod::Reward(reward)) => {
+            reward.reward_type() != grpc_api_types::payments::RewardType::Unspecified
+        }
+        Some(
+            PaymentMethod::UpiCollect(_)
+            | PaymentMethod::UpiIntent(_)
+            | PaymentMethod::UpiQr(_)
+            | PaymentMethod::Token(_)
+            | PaymentMethod::NetworkToken(_)
+            | PaymentMethod::Boleto(_)
+            | PaymentMethod::Oxxo(_),
+        ) => true,
+    };
+    if is_specified {
+        return Ok(());
+    }
+    Err(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "AMBIGUOUS_PAYMENT_METHOD".to_owned(),
+        error_identifier: 400,
+        error_message: "Exactly one payment method must be specified".to_owned(),
+        error_object: None,
+    }))?
+}
+
+/// Requests carrying a capture method that falls back to the connector's default
+pub trait CaptureMethodRequest {
+    fn requested_capture_method(&self) -> grpc_api_types::payments::CaptureMethod;
+    fn set_requested_capture_method(
+        &mut self,
+        capture_method: grpc_api_types::payments::CaptureMethod,
+    );
+}
+
+impl CaptureMethodRequest for PaymentServiceAuthorizeRequest {
+    fn requested_capture_method(&self) -> grpc_api_types::payments::CaptureMethod {
+        self.capture_method()
+    }
+    fn set_requested_capture_method(
+        &mut self,
+        capture_method: grpc_api_types::payments::CaptureMethod,
+    ) {
+        self.set_capture_method(capture_method)
+    }
+}
+
+impl CaptureMethodRequest for grpc_api_types::payments::PaymentServiceRepeatEverythingRequest {
+    fn requested_capture_method(&self) -> grpc_api_types::payments::CaptureMethod {
+        self.capture_method()
+    }
+    fn set_requested_capture_method(
+        &mut self,
+        capture_method: grpc_api_types::payments::CaptureMethod,
+    ) {
+        self.set_capture_method(capture_method)
+    }
+}
+
+impl CaptureMethodRequest for grpc_api_types::payments::PaymentServiceRefundRequest {
+    fn requested_capture_method(&self) -> grpc_api_types::payments::CaptureMethod {
+        self.capture_method()
+    }
+    fn set_requested_capture_method(
+        &mut self,
+        capture_method: grpc_api_types::payments::CaptureMethod,
+    ) {
+        self.set_capture_method(capture_method)
+    }
+}
+
+/// Sets the connector's default capture method on a request that leaves it unspecified.
+/// Without a connector default an authorize request is left as is and rejected, while repeat
+/// and refund requests go to the connector without a capture method.
+pub fn apply_default_capture_method<R: CaptureMethodRequest>(
+    request: &mut R,
+    default_capture_method: Option<common_enums::CaptureMethod>,
+) {
+    if let Some(default_capture_method) = default_capture_method {
+        if request.requested_capture_method()
+            == grpc_api_types::payments::CaptureMethod::Unspecified
+        {
+            request.set_requested_capture_method(
+                grpc_api_types::payments::CaptureMethod::foreign_from(default_capture_method),
+            );
+        }
+    }
+}
+
+/// Capture method of a request whose capture method is optional, `None` when it is left
+/// unspecified
+fn optional_capture_method(
+    capture_method: grpc_api_types::payments::CaptureMethod,
+) -> Result<Option<common_enums::CaptureMethod>, error_stack::Report<ApplicationErrorResponse>> {
+    match capture_method {
+        grpc_api_types::payments::CaptureMethod::Unspecified => Ok(None),
+        capture_method => common_enums::CaptureMethod::foreign_try_from(capture_method).map(Some),
+    }
+}
+
+#[derive(Clone, serde::Deserialize, Debug)]
+pub struct PollingConfig {
+    /// seconds a client should wait between two status polls
+    pub suggested_interval_secs: u64,
+    /// seconds after which a client should stop polling
+    pub expiry_secs: u64,
+}
+
+#[derive(Clone, serde::Deserialize, Debug)]
+#[serde(default)]
+pub struct UnparsableResponseFallbackConfig {
+    /// returns the internal error for unparsable responses instead when disabled
+    pub enabled: bool,
+    /// characters of the response text kept in the error message
+    pub max_chars: usize,
+}
+
+impl Default for UnparsableResponseFallbackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_chars: 256,
+        }
+    }
+}
+
+/// Values a connector uses for success and failure in a boolean-like status flag
+#[derive(Clone, serde::Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct StatusFlagValues {
+    #[serde(default)]
+    pub success: Vec<String>,
+    #[serde(default)]
+    pub failure: Vec<String>,
+}
+
+#[derive(Clone, serde::Deserialize, Debug, PartialEq, Eq, Hash)]
+pub struct ConnectorTlsConfig {
+    /// PEM file of the client certificate presented to the connector, set with `client_key_path`
+    pub client_certificate_path: Option<std::path::PathBuf>,
+    /// PEM file of the private key of the client certificate
+    pub client_key_path: Option<std::path::PathBuf>,
+    /// PEM bundle of CA certificates trusted for the connector, in addition to the system roots
+    pub root_ca_path: Option<std::path::PathBuf>,
+    /// skips verification of the connector's certificate; only meant for sandboxes
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+}
+
+pub const SUGGESTED_POLL_INTERVAL_KEY: &str = "suggested_poll_interval";
+pub const POLL_EXPIRY_KEY: &str = "poll_expiry";
+/// Response metadata key set when a sync contradicts the caller's terminal status
+pub const STATUS_CONFLICT_KEY: &str = "status_conflict";
+
+pub const SCHEDULED_CAPTURE_AT_KEY: &str = "scheduled_capture_at";
+
+/// Builds the capture response for a scheduled capture the connector cannot delay.
+///
+/// No connector call is made; the payment stays authorized and the requested capture time
+/// (unix timestamp) is returned in the metadata for the caller to execute the capture then.
+pub fn generate_scheduled_capture_response(
+    transaction_id: Option<grpc_api_types::payments::Identifier>,
+    capture_data: &PaymentsCaptureData,
+    connector: ConnectorEnum,
+) -> PaymentServiceCaptureResponse {
+    let connector_metadata = capture_data
+        .capture_at
+        .map(|capture_at| {
+            (
+                SCHEDULED_CAPTURE_AT_KEY.to_string(),
+                capture_at.assume_utc().unix_timestamp().to_string(),
+            )
+        })
+        .into_iter()
+        .collect();
+    PaymentServiceCaptureResponse {
+        transaction_id,
+        status: grpc_api_types::payments::PaymentStatus::Authorized.into(),
+        error_code: None,
+        error_message: None,
+        status_code: 0,
+        connector_http_status_code: None,
+        response_headers: HashMap::new(),
+        response_ref_id: None,
+        connector_metadata,
+        settlement_currency: None,
+        settlement_amount: None,
+        connector: Some(connector.to_string()),
+        environment: response_environment(None),
+        payment_id: None,
+        raw_error_message: None,
+        retryable: None,
+    }
+}
+
+/// Environment reported on responses: sandbox when the request ran in test mode, production
+/// when it did not. Unset when the request did not say, e.g. in flows without a test mode flag.
+fn response_environment(test_mode: Option<bool>) -> Option<i32> {
+    let environment = match test_mode? {
+        true => grpc_api_types::payments::Environment::Sandbox,
+        false => grpc_api_types::payments::Environment::Production,
+    };
+    Some(environment.into())
+}
+
+/// Splits the connector's DCC settlement details into the gRPC currency and minor amount.
+fn grpc_settlement_details(
+    settlement_details: Option<SettlementDetails>,
+) -> (Option<i32>, Option<i64>) {
+    settlement_details
+        .map(|settlement_details| {
+            (
+                grpc_api_types::payments::Currency::from_str_name(
+                    &settlement_details.currency.to_string(),
+                )
+                .map(|currency| currency as i32),
+                Some(settlement_details.amount.get_amount_as_i64()),
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Amount and currency the connector reported for the payment. The sync request carries
+/// neither, so both stay unknown when the connector doesn't report them.
+fn connector_reported_sync_amount(
+    request: &PaymentsSyncData,
+) -> Option<(MinorUnit, common_enums::Currency)> {
+    request
+        .integrity_object
+        .as_ref()
+        .map(|reported| (reported.amount, reported.currency))
+}
+
+/// Converts a minor unit amount to whole major units. Amounts with a fractional major unit
+/// part cannot be carried in an integer and are left unset.
+fn whole_major_unit_amount(amount: MinorUnit, currency: common_enums::Currency) -> Option<i64> {
+    let digits = currency.number_of_digits_after_decimal_point().ok()?;
+    let factor = 10_i64.checked_pow(u32::from(digits))?;
+    let amount = amount.get_amount_as_i64();
+    (amount.checked_rem(factor)? == 0)
+        .then(|| amount.checked_div(factor))
+        .flatten()
+}
+
+impl ForeignFrom<InstallmentPlan> for grpc_api_types::payments::InstallmentPlan {
+    fn foreign_from(value: InstallmentPlan) -> Self {
+        Self {
+            installment_count: value.installment_count,
+            installment_amount: value
+                .installment_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            total_amount: value.total_amount.map(|amount| amount.get_amount_as_i64()),
+            interest_rate: value.interest_rate,
+        }
+    }
+}
+
+/// Converts the connector's masked payment method details into the gRPC message, dropping
+/// networks and wallets the API does not model.
+fn grpc_payment_method_details(
+    payment_method_details: Option<PaymentMethodDetails>,
+) -> Option<grpc_api_types::payments::PaymentMethodDetails> {
+    use grpc_api_types::payments::{CardNetwork, PaymentMethodType};
+
+    let payment_method_details = payment_method_details?;
+    let network = payment_method_details
+        .network
+        .and_then(|network| match network {
+            common_enums::CardNetwork::Visa => Some(CardNetwork::Visa),
+            common_enums::CardNetwork::Mastercard => Some(CardNetwork::Mastercard),
+            common_enums::CardNetwork::AmericanExpress => Some(CardNetwork::Amex),
+            common_enums::CardNetwork::JCB => Some(CardNetwork::Jcb),
+            common_enums::CardNetwork::DinersClub => Some(CardNetwork::Diners),
+            common_enums::CardNetwork::Discover => Some(CardNetwork::Discover),
+            common_enums::CardNetwork::CartesBancaires => Some(CardNetwork::CartesBancaires),
+            common_enums::CardNetwork::UnionPay => Some(CardNetwork::Unionpay),
+            common_enums::CardNetwork::RuPay => Some(CardNetwork::Rupay),
+            common_enums::CardNetwork::Maestro => Some(CardNetwork::Maestro),
+            _ => None,
+        });
+    let wallet_type =
+        payment_method_details
+            .wallet_type
+            .and_then(|wallet_type| match wallet_type {
+                common_enums::PaymentMethodType::ApplePay => Some(PaymentMethodType::ApplePay),
+                common_enums::PaymentMethodType::GooglePay => Some(PaymentMethodType::GooglePay),
+                common_enums::PaymentMethodType::SamsungPay => Some(PaymentMethodType::SamsungPay),
+                _ => None,
+            });
+    Some(grpc_api_types::payments::PaymentMethodDetails {
+        last4: payment_method_details.last4,
+        network: network.map(|network| network as i32),
+        funding_type: payment_method_details.funding_type.map(|funding_type| {
+            grpc_api_types::payments::CardFundingType::foreign_from(funding_type) as i32
+        }),
+        wallet_type: wallet_type.map(|wallet_type| wallet_type as i32),
+    })
+}
+
+/// Returns true when an authorize attempt was declined with one of the connector's configured
+/// soft-decline codes and can be sent once more.
+///
+/// Only definite declines qualify; a response whose outcome is unknown or still in progress is
+/// never retried, so a retry cannot double-charge.
+pub fn is_soft_decline(
+    response: &Result<PaymentsResponseData, crate::router_data::ErrorResponse>,
+    soft_decline_codes: &[String],
+) -> bool {
+    let Err(error) = response else {
+        return false;
+    };
+    let is_declined = matches!(
+        error.attempt_status,
+        Some(common_enums::AttemptStatus::Failure)
+            | Some(common_enums::AttemptStatus::AuthorizationFailed)
+    );
+    is_declined && soft_decline_codes.contains(&error.code)
+}
+
+/// Whether a failed connector call may succeed when the client sends it again
+pub trait IsRetryable {
+    fn is_retryable(&self, soft_decline_codes: &[String]) -> bool;
+}
+
+/// Timeouts, 5xx responses and soft declines are retryable; credential failures and hard
+/// declines are not.
+impl IsRetryable for crate::router_data::ErrorResponse {
+    fn is_retryable(&self, soft_decline_codes: &[String]) -> bool {
+        let is_declined = matches!(
+            self.attempt_status,
+            Some(common_enums::AttemptStatus::Failure)
+                | Some(common_enums::AttemptStatus::AuthorizationFailed)
+        );
+        match self.status_code {
+            401 | 403 => false,
+            408 | 504 => true,
+            _ if soft_decline_codes.contains(&self.code) => true,
+            _ if is_declined => false,
+            status_code => (500..=599).contains(&status_code),
+        }
+    }
+}
+
+/// Only a connector call that timed out is retryable; the request may not have reached the
+/// connector.
+impl IsRetryable for error_stack::Report<ConnectorError> {
+    fn is_retryable(&self, _soft_decline_codes: &[String]) -> bool {
+        self.current_context().is_connector_timeout()
+            || matches!(
+                self.downcast_ref::<crate::errors::ApiClientError>(),
+                Some(crate::errors::ApiClientError::RequestTimeoutReceived)
+            )
+    }
+}
+
+/// `retryable` for a response built from the connector's error, judged against the soft
+/// decline codes configured for `connector`.
+fn error_response_retryable(
+    error: &crate::router_data::ErrorResponse,
+    connectors: &Connectors,
+    connector: &ConnectorEnum,
+) -> Option<bool> {
+    let soft_decline_codes = &connectors
+        .get_connector_params(connector)
+        .soft_decline_codes;
+    Some(error.is_retryable(soft_decline_codes))
+}
+
+/// Adds `suggested_poll_interval` (seconds) and `poll_expiry` (unix timestamp) to the response
+/// metadata of a pending payment.
+///
+/// Hints already present in the metadata come from the connector and take precedence over the
+/// connector's configured defaults.
+pub fn add_poll_hints(
+    metadata: &mut HashMap<String, String>,
+    status: grpc_api_types::payments::PaymentStatus,
+    polling: Option<&PollingConfig>,
+) {
+    let is_pending = matches!(
+        status,
+        grpc_api_types::payments::PaymentStatus::Pending
+            | grpc_api_types::payments::PaymentStatus::AuthenticationPending
+    );
+    let Some(polling) = polling.filter(|_| is_pending) else {
+        return;
+    };
+
+    metadata
+        .entry(SUGGESTED_POLL_INTERVAL_KEY.to_string())
+        .or_insert_with(|| polling.suggested_interval_secs.to_string());
+    metadata
+        .entry(POLL_EXPIRY_KEY.to_string())
+        .or_insert_with(|| {
+            let expiry_secs = i64::try_from(polling.expiry_secs).unwrap_or(i64::MAX);
+            common_utils::date_time::now_unix_timestamp()
+                .saturating_add(expiry_secs)
+                .to_string()
+        });
+}
+
+/// Flags a sync whose status contradicts the terminal status the caller already knew.
+///
+/// A terminal payment may still move from charged to auto refunded; any other change is
+/// logged with both statuses and marked with `status_conflict` in the response metadata.
+pub fn flag_status_conflict(
+    metadata: &mut HashMap<String, String>,
+    connector: &ConnectorEnum,
+    prior_status: Option<grpc_api_types::payments::PaymentStatus>,
+    status: grpc_api_types::payments::PaymentStatus,
+) -> bool {
+    use grpc_api_types::payments::PaymentStatus;
+
+    let Some(prior_status) = prior_status else {
+        return false;
+    };
+    let is_terminal = matches!(
+        prior_status,
+        PaymentStatus::Charged
+            | PaymentStatus::AutoRefunded
+            | PaymentStatus::Voided
+            | PaymentStatus::AuthenticationFailed
+            | PaymentStatus::AuthorizationFailed
+            | PaymentStatus::RouterDeclined
+            | PaymentStatus::Failure
+            | PaymentStatus::IntegrityFailure
+    );
+    let is_allowed_transition = prior_status == status
+        || (prior_status == PaymentStatus::Charged && status == PaymentStatus::AutoRefunded);
+    if !is_terminal || is_allowed_transition {
+        return false;
+    }
+
+    tracing::warn!(
+        %connector,
+        prior_status = prior_status.as_str_name(),
+        connector_status = status.as_str_name(),
+        "connector reported a status that conflicts with the prior terminal status"
+    );
+    metadata.insert(STATUS_CONFLICT_KEY.to_string(), "true".to_string());
+    true
+}
+
+impl Connectors {
+    pub fn get_connector_params(&self, connector: &ConnectorEnum) -> &ConnectorParams {
+        match connector {
+            ConnectorEnum::Adyen => &self.adyen,
+            ConnectorEnum::Razorpay => &self.razorpay,
+            ConnectorEnum::RazorpayV2 => &self.razorpayv2,
+            ConnectorEnum::Fiserv => &self.fiserv,
+            ConnectorEnum::Elavon => &self.elavon,
+            ConnectorEnum::Xendit => &self.xendit,
+            ConnectorEnum::Checkout => &self.checkout,
+            ConnectorEnum::Authorizedotnet => &self.authorizedotnet,
+            ConnectorEnum::Mifinity => &self.mifinity,
+            ConnectorEnum::Phonepe => &self.phonepe,
+            ConnectorEnum::Cashfree => &self.cashfree,
+            ConnectorEnum::Paytm => &self.paytm,
+            ConnectorEnum::Fiuu => &self.fiuu,
+            ConnectorEnum::Payu => &self.payu,
+            ConnectorEnum::Cashtocode => &self.cashtocode,
+            ConnectorEnum::Novalnet => &self.novalnet,
+            ConnectorEnum::Nexinets => &self.nexinets,
+            ConnectorEnum::Noon => &self.noon,
+        }
+    }
+
+    /// Params of every connector, with the connector they belong to
+    pub fn iter(&self) -> impl Iterator<Item = (ConnectorEnum, &ConnectorParams)> {
+        [
+            ConnectorEnum::Adyen,
+            ConnectorEnum::Razorpay,
+            ConnectorEnum::RazorpayV2,
+            ConnectorEnum::Fiserv,
+            ConnectorEnum::Elavon,
+            ConnectorEnum::Xendit,
+            ConnectorEnum::Checkout,
+            ConnectorEnum::Authorizedotnet,
+            ConnectorEnum::Mifinity,
+            ConnectorEnum::Phonepe,
+            ConnectorEnum::Cashfree,
+            ConnectorEnum::Paytm,
+            ConnectorEnum::Fiuu,
+            ConnectorEnum::Payu,
+            ConnectorEnum::Cashtocode,
+            ConnectorEnum::Novalnet,
+            ConnectorEnum::Nexinets,
+            ConnectorEnum::Noon,
+        ]
+        .into_iter()
+        .map(|connector| (connector, self.get_connector_params(&connector)))
+    }
+
+    pub fn get_connector_params_mut(&mut self, connector: &ConnectorEnum) -> &mut ConnectorParams {
+        match connector {
+            ConnectorEnum::Adyen => &mut self.adyen,
+            ConnectorEnum::Razorpay => &mut self.razorpay,
+            ConnectorEnum::RazorpayV2 => &mut self.razorpayv2,
+            ConnectorEnum::Fiserv => &mut self.fiserv,
+            ConnectorEnum::Elavon => &mut self.elavon,
+            ConnectorEnum::Xendit => &mut self.xendit,
+            ConnectorEnum::Checkout => &mut self.checkout,
+            ConnectorEnum::Authorizedotnet => &mut self.authorizedotnet,
+            ConnectorEnum::Mifinity => &mut self.mifinity,
+            ConnectorEnum::Phonepe => &mut self.phonepe,
+            ConnectorEnum::Cashfree => &mut self.cashfree,
+            ConnectorEnum::Paytm => &mut self.paytm,
+            ConnectorEnum::Fiuu => &mut self.fiuu,
+            ConnectorEnum::Payu => &mut self.payu,
+            ConnectorEnum::Cashtocode => &mut self.cashtocode,
+            ConnectorEnum::Novalnet => &mut self.novalnet,
+            ConnectorEnum::Nexinets => &mut self.nexinets,
+            ConnectorEnum::Noon => &mut self.noon,
+        }
+    }
 }
 
 #[derive(Debug, serde::Deserialize, Clone)]
@@ -103,6 +1009,32 @@ pub struct Proxy {
     pub bypass_proxy_urls: Vec<String>,
 }
 
+/// Sampling of connector traffic logs (request and response bodies).
+///
+/// Successful calls are logged at `success_sample_rate` (0.0 - 1.0); failed calls are always
+/// logged in full.
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorTrafficLogConfig {
+    pub success_sample_rate: f64,
+}
+
+impl Default for ConnectorTrafficLogConfig {
+    fn default() -> Self {
+        Self {
+            success_sample_rate: 1.0,
+        }
+    }
+}
+
+impl ConnectorTrafficLogConfig {
+    /// Decides whether a call should be fully logged, given a uniformly drawn `sample` in
+    /// `[0.0, 1.0)` and whether the call failed.
+    pub fn should_log(&self, is_failure: bool, sample: f64) -> bool {
+        is_failure || sample < self.success_sample_rate
+    }
+}
+
 impl ForeignTryFrom<grpc_api_types::payments::CaptureMethod> for common_enums::CaptureMethod {
     type Error = ApplicationErrorResponse;
 
@@ -114,7 +1046,30 @@ impl ForeignTryFrom<grpc_api_types::payments::CaptureMethod> for common_enums::C
             grpc_api_types::payments::CaptureMethod::Manual => Ok(Self::Manual),
             grpc_api_types::payments::CaptureMethod::ManualMultiple => Ok(Self::ManualMultiple),
             grpc_api_types::payments::CaptureMethod::Scheduled => Ok(Self::Scheduled),
-            _ => Ok(Self::Automatic),
+            grpc_api_types::payments::CaptureMethod::SequentialAutomatic => {
+                Ok(Self::SequentialAutomatic)
+            }
+            grpc_api_types::payments::CaptureMethod::Unspecified => {
+                Err(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "UNSPECIFIED_CAPTURE_METHOD".to_owned(),
+                    error_identifier: 400,
+                    error_message: "Capture method must be specified".to_owned(),
+                    error_object: None,
+                })
+                .into())
+            }
+        }
+    }
+}
+
+impl ForeignFrom<common_enums::CaptureMethod> for grpc_api_types::payments::CaptureMethod {
+    fn foreign_from(value: common_enums::CaptureMethod) -> Self {
+        match value {
+            common_enums::CaptureMethod::Automatic => Self::Automatic,
+            common_enums::CaptureMethod::Manual => Self::Manual,
+            common_enums::CaptureMethod::ManualMultiple => Self::ManualMultiple,
+            common_enums::CaptureMethod::Scheduled => Self::Scheduled,
+            common_enums::CaptureMethod::SequentialAutomatic => Self::SequentialAutomatic,
         }
     }
 }
@@ -166,6 +1121,29 @@ impl<
 
     fn foreign_try_from(
         value: grpc_api_types::payments::PaymentMethod,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Self::foreign_try_from((value, CvcRequirement::Required))
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + Default
+            + Debug
+            + Send
+            + Eq
+            + PartialEq
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + Clone
+            + CardConversionHelper<T>,
+    > ForeignTryFrom<(grpc_api_types::payments::PaymentMethod, CvcRequirement)>
+    for PaymentMethodData<T>
+{
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        (value, cvc_requirement): (grpc_api_types::payments::PaymentMethod, CvcRequirement),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         tracing::info!("PaymentMethod data received: {:?}", value);
         match value.payment_method {
@@ -173,11 +1151,11 @@ impl<
                 grpc_api_types::payments::payment_method::PaymentMethod::Card(card_type) => {
                     match card_type.card_type {
                         Some(grpc_api_types::payments::card_payment_method_type::CardType::Credit(card)) => {
-                            let card = payment_method_data::Card::<T>::foreign_try_from(card)?;
+                            let card = payment_method_data::Card::<T>::foreign_try_from((card, cvc_requirement))?;
                             Ok(PaymentMethodData::Card(card))
                         },
                         Some(grpc_api_types::payments::card_payment_method_type::CardType::Debit(card)) => {
-                                                    let card = payment_method_data::Card::<T>::foreign_try_from(card)?;
+                                                    let card = payment_method_data::Card::<T>::foreign_try_from((card, cvc_requirement))?;
                             Ok(PaymentMethodData::Card(card))},
                         Some(grpc_api_types::payments::card_payment_method_type::CardType::CardRedirect(_card_redirect)) => {
                             Err(report!(ApplicationErrorResponse::BadRequest(ApiError {
@@ -188,11 +1166,11 @@ impl<
                             })))
                         },
                         Some(grpc_api_types::payments::card_payment_method_type::CardType::CreditProxy(card)) => {
-                            let x = payment_method_data::Card::<T>::foreign_try_from(card)?;
+                            let x = payment_method_data::Card::<T>::foreign_try_from((card, cvc_requirement))?;
                             Ok(PaymentMethodData::Card(x))
                         },
                         Some(grpc_api_types::payments::card_payment_method_type::CardType::DebitProxy(card)) => {
-                            let x = payment_method_data::Card::<T>::foreign_try_from(card)?;
+                            let x = payment_method_data::Card::<T>::foreign_try_from((card, cvc_requirement))?;
                             Ok(PaymentMethodData::Card(x))
                         },
                         None => Err(report!(ApplicationErrorResponse::BadRequest(ApiError {
@@ -224,16 +1202,33 @@ impl<
                     ))
                 }
                 grpc_api_types::payments::payment_method::PaymentMethod::UpiQr(_upi_qr) => {
-                    // UpiQr is not yet implemented, fallback to UpiIntent
-                    Ok(PaymentMethodData::Upi(
-                        crate::payment_method_data::UpiData::UpiIntent(
-                            crate::payment_method_data::UpiIntentData {},
-                        ),
-                    ))
+                    Ok(PaymentMethodData::Upi(payment_method_data::UpiData::UpiQr(
+                        payment_method_data::UpiQrData {},
+                    )))
                 }
                 grpc_api_types::payments::payment_method::PaymentMethod::Reward(_) => {
                     Ok(PaymentMethodData::Reward)
                 },
+                grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(network_token) => {
+                    Ok(PaymentMethodData::NetworkToken(
+                        payment_method_data::NetworkTokenData::foreign_try_from(network_token)?,
+                    ))
+                },
+                grpc_api_types::payments::payment_method::PaymentMethod::Boleto(boleto) => {
+                    Ok(PaymentMethodData::Voucher(payment_method_data::VoucherData::Boleto(
+                        Box::new(payment_method_data::BoletoVoucherData {
+                            social_security_number: boleto.social_security_number,
+                        }),
+                    )))
+                },
+                grpc_api_types::payments::payment_method::PaymentMethod::Oxxo(_) => {
+                    Ok(PaymentMethodData::Voucher(payment_method_data::VoucherData::Oxxo))
+                },
+                grpc_api_types::payments::payment_method::PaymentMethod::BankRedirect(bank_redirect) => {
+                    Ok(PaymentMethodData::BankRedirect(
+                        payment_method_data::BankRedirectData::foreign_try_from(bank_redirect)?,
+                    ))
+                },
                 grpc_api_types::payments::payment_method::PaymentMethod::Wallet(wallet_type) => {
                     match wallet_type.wallet_type {
                         Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::Mifinity(mifinity_data)) => {
@@ -385,6 +1380,46 @@ impl<
                             };
                             Ok(PaymentMethodData::Wallet(payment_method_data::WalletData::GooglePay(wallet_data)))
                         }
+                        Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::SamsungPay(samsung_wallet)) => {
+                            let payment_credential = samsung_wallet.payment_credential.ok_or_else(|| {
+                                ApplicationErrorResponse::BadRequest(ApiError {
+                                    sub_code: "MISSING_SAMSUNG_PAY_PAYMENT_CREDENTIAL".to_owned(),
+                                    error_identifier: 400,
+                                    error_message: "Samsung Pay payment credential is required".to_owned(),
+                                    error_object: None,
+                                })
+                            })?;
+                            let card_brand = payment_credential.card_brand();
+                            let token_data = payment_credential.token_data.ok_or_else(|| {
+                                ApplicationErrorResponse::BadRequest(ApiError {
+                                    sub_code: "MISSING_SAMSUNG_PAY_TOKEN_DATA".to_owned(),
+                                    error_identifier: 400,
+                                    error_message: "Samsung Pay token data is required".to_owned(),
+                                    error_object: None,
+                                })
+                            })?;
+
+                            let wallet_data = payment_method_data::SamsungPayWalletData {
+                                payment_credential: payment_method_data::SamsungPayWalletCredentials {
+                                    method: payment_credential.method,
+                                    recurring_payment: payment_credential.recurring_payment,
+                                    card_brand: common_enums::SamsungPayCardBrand::foreign_from(card_brand),
+                                    dpan_last_four_digits: payment_credential.dpan_last_four_digits,
+                                    card_last_four_digits: payment_credential.card_last_four_digits,
+                                    token_data: payment_method_data::SamsungPayTokenData {
+                                        three_ds_type: token_data.r#type,
+                                        version: token_data.version,
+                                        data: Secret::new(token_data.data),
+                                    },
+                                },
+                            };
+                            Ok(PaymentMethodData::Wallet(payment_method_data::WalletData::SamsungPay(Box::new(wallet_data))))
+                        }
+                        Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::Paze(paze_wallet)) => {
+                            Ok(PaymentMethodData::Wallet(payment_method_data::WalletData::Paze(payment_method_data::PazeWalletData {
+                                complete_response: Secret::new(paze_wallet.complete_response),
+                            })))
+                        }
                         Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::AmazonPayRedirect(_)) => {
                             Ok(PaymentMethodData::Wallet(payment_method_data::WalletData::AmazonPayRedirect(Box::new(payment_method_data::AmazonPayRedirectData {}))))
                         }
@@ -458,8 +1493,8 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethodType> for Option<Paym
                 Ok(Some(PaymentMethodType::UpiIntent))
             }
             grpc_api_types::payments::PaymentMethodType::UpiQr => {
-                Ok(Some(PaymentMethodType::UpiIntent))
-            } // UpiQr not yet implemented, fallback to UpiIntent
+                Ok(Some(PaymentMethodType::UpiQr))
+            }
             grpc_api_types::payments::PaymentMethodType::ClassicReward => {
                 Ok(Some(PaymentMethodType::ClassicReward))
             }
@@ -472,6 +1507,10 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethodType> for Option<Paym
             grpc_api_types::payments::PaymentMethodType::GooglePay => {
                 Ok(Some(PaymentMethodType::GooglePay))
             }
+            grpc_api_types::payments::PaymentMethodType::SamsungPay => {
+                Ok(Some(PaymentMethodType::SamsungPay))
+            }
+            grpc_api_types::payments::PaymentMethodType::Paze => Ok(Some(PaymentMethodType::Paze)),
             grpc_api_types::payments::PaymentMethodType::AmazonPay => {
                 Ok(Some(PaymentMethodType::AmazonPay))
             }
@@ -490,6 +1529,15 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethodType> for Option<Paym
             grpc_api_types::payments::PaymentMethodType::Cashapp => {
                 Ok(Some(PaymentMethodType::Cashapp))
             }
+            grpc_api_types::payments::PaymentMethodType::MbWay => {
+                Ok(Some(PaymentMethodType::MbWay))
+            }
+            grpc_api_types::payments::PaymentMethodType::Klarna => {
+                Ok(Some(PaymentMethodType::Klarna))
+            }
+            grpc_api_types::payments::PaymentMethodType::Affirm => {
+                Ok(Some(PaymentMethodType::Affirm))
+            }
             _ => Err(ApplicationErrorResponse::BadRequest(ApiError {
                 sub_code: "INVALID_PAYMENT_METHOD_TYPE".to_owned(),
                 error_identifier: 400,
@@ -544,7 +1592,7 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for Option<PaymentM
                 },
                 grpc_api_types::payments::payment_method::PaymentMethod::UpiCollect(_) => Ok(Some(PaymentMethodType::UpiCollect)),
                 grpc_api_types::payments::payment_method::PaymentMethod::UpiIntent(_) => Ok(Some(PaymentMethodType::UpiIntent)),
-                grpc_api_types::payments::payment_method::PaymentMethod::UpiQr(_) => Ok(Some(PaymentMethodType::UpiIntent)), // UpiQr not yet implemented, fallback to UpiIntent
+                grpc_api_types::payments::payment_method::PaymentMethod::UpiQr(_) => Ok(Some(PaymentMethodType::UpiQr)),
                 grpc_api_types::payments::payment_method::PaymentMethod::Reward(reward) => {
                     match reward.reward_type() {
                         grpc_api_types::payments::RewardType::Classicreward => Ok(Some(PaymentMethodType::ClassicReward)),
@@ -557,6 +1605,27 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for Option<PaymentM
                         })))
                     }
                 },
+                grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(_) => {
+                    Ok(None)
+                },
+                grpc_api_types::payments::payment_method::PaymentMethod::Boleto(_) => Ok(Some(PaymentMethodType::Boleto)),
+                grpc_api_types::payments::payment_method::PaymentMethod::Oxxo(_) => Ok(Some(PaymentMethodType::Oxxo)),
+                grpc_api_types::payments::payment_method::PaymentMethod::BankRedirect(bank_redirect) => {
+                    match bank_redirect.bank_redirect_type {
+                        Some(grpc_api_types::payments::bank_redirect_payment_method_type::BankRedirectType::Eps(_)) => {
+                            Ok(Some(PaymentMethodType::Eps))
+                        }
+                        Some(grpc_api_types::payments::bank_redirect_payment_method_type::BankRedirectType::Interac(_)) => {
+                            Ok(Some(PaymentMethodType::Interac))
+                        }
+                        None => Err(report!(ApplicationErrorResponse::BadRequest(ApiError {
+                            sub_code: "INVALID_PAYMENT_METHOD".to_owned(),
+                            error_identifier: 400,
+                            error_message: "Bank redirect type is required".to_owned(),
+                            error_object: None,
+                        }))),
+                    }
+                },
                 grpc_api_types::payments::payment_method::PaymentMethod::Wallet(wallet_type) => {
                     match wallet_type.wallet_type {
                         Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::Mifinity(_mifinity_data)) => {
@@ -569,6 +1638,12 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for Option<PaymentM
                         Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::GooglePay(_)) => {
                             Ok(Some(PaymentMethodType::GooglePay))
                         }
+                        Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::SamsungPay(_)) => {
+                            Ok(Some(PaymentMethodType::SamsungPay))
+                        }
+                        Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::Paze(_)) => {
+                            Ok(Some(PaymentMethodType::Paze))
+                        }
                         Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::AmazonPayRedirect(_)) => {
                             Ok(Some(PaymentMethodType::AmazonPay))
                         }
@@ -609,10 +1684,85 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for Option<PaymentM
     }
 }
 
+impl ForeignTryFrom<grpc_api_types::payments::CoBadgedCardData>
+    for payment_method_data::CoBadgedCardData
+{
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: grpc_api_types::payments::CoBadgedCardData,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        let co_badged_card_networks = value
+            .co_badged_card_networks()
+            .map(common_enums::CardNetwork::foreign_try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        if co_badged_card_networks.len() < 2 {
+            return Err(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_CO_BADGED_CARD_DATA".to_owned(),
+                error_identifier: 400,
+                error_message: "Co-badged card must carry at least two card networks".to_owned(),
+                error_object: None,
+            })
+            .into());
+        }
+        let issuer_country_code =
+            common_enums::CountryAlpha2::from_str(&value.issuer_country_alpha2).map_err(|_| {
+                ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "INVALID_CO_BADGED_CARD_DATA".to_owned(),
+                    error_identifier: 400,
+                    error_message: format!(
+                        "Invalid issuer country code: {}",
+                        value.issuer_country_alpha2
+                    ),
+                    error_object: None,
+                })
+            })?;
+        Ok(Self {
+            co_badged_card_networks,
+            issuer_country_code,
+            is_regulated: value.is_regulated,
+            regulated_name: value.regulated_name.map(|name| {
+                common_enums::RegulatedName::from_str(&name)
+                    .unwrap_or(common_enums::RegulatedName::Unknown(name))
+            }),
+        })
+    }
+}
+
+/// Whether a card payment must carry the card's CVC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CvcRequirement {
+    Required,
+    /// Merchant-initiated payments, which charge a stored credential, and connectors that
+    /// accept card payments without a CVC, see `supports_cvc_less_card_payments`. A missing CVC
+    /// is converted to an empty one, which connectors leave out of their requests.
+    Optional,
+}
+
+impl CvcRequirement {
+    fn card_cvc(
+        self,
+        card_cvc: Option<Secret<String>>,
+    ) -> Result<Secret<String>, error_stack::Report<ApplicationErrorResponse>> {
+        match (card_cvc, self) {
+            (Some(card_cvc), _) => Ok(card_cvc),
+            (None, Self::Optional) => Ok(Secret::new(String::new())),
+            (None, Self::Required) => Err(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "MISSING_CVC".to_owned(),
+                error_identifier: 400,
+                error_message: "Missing CVC".to_owned(),
+                error_object: None,
+            })
+            .into()),
+        }
+    }
+}
+
 // Helper trait for generic card conversion
 pub trait CardConversionHelper<T: PaymentMethodDataTypes> {
     fn convert_card_details(
         card: grpc_api_types::payments::CardDetails,
+        cvc_requirement: CvcRequirement,
     ) -> Result<payment_method_data::Card<T>, error_stack::Report<ApplicationErrorResponse>>;
 }
 
@@ -620,13 +1770,32 @@ pub trait CardConversionHelper<T: PaymentMethodDataTypes> {
 impl CardConversionHelper<DefaultPCIHolder> for DefaultPCIHolder {
     fn convert_card_details(
         card: grpc_api_types::payments::CardDetails,
+        cvc_requirement: CvcRequirement,
     ) -> Result<
         payment_method_data::Card<DefaultPCIHolder>,
         error_stack::Report<ApplicationErrorResponse>,
     > {
-        let card_network = Some(common_enums::CardNetwork::foreign_try_from(
-            card.card_network(),
-        )?);
+        let card_network = common_enums::CardNetwork::foreign_try_from(card.card_network())?;
+        let co_badged_card_data = card
+            .co_badged_card_data
+            .map(payment_method_data::CoBadgedCardData::foreign_try_from)
+            .transpose()?;
+        if let Some(co_badged_card_data) = &co_badged_card_data {
+            if !co_badged_card_data
+                .co_badged_card_networks
+                .contains(&card_network)
+            {
+                return Err(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "INVALID_CO_BADGED_CARD_NETWORK".to_owned(),
+                    error_identifier: 400,
+                    error_message: format!(
+                        "Card network {card_network} is not one of the co-badged card networks"
+                    ),
+                    error_object: None,
+                })
+                .into());
+            }
+        }
         Ok(payment_method_data::Card {
             card_number: RawCardNumber::<DefaultPCIHolder>(card.card_number.ok_or(
                 ApplicationErrorResponse::BadRequest(ApiError {
@@ -652,22 +1821,15 @@ impl CardConversionHelper<DefaultPCIHolder> for DefaultPCIHolder {
                     error_message: "Missing Card Expiry Year".to_owned(),
                     error_object: None,
                 }))?,
-            card_cvc: card
-                .card_cvc
-                .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
-                    sub_code: "MISSING_CVC".to_owned(),
-                    error_identifier: 400,
-                    error_message: "Missing CVC".to_owned(),
-                    error_object: None,
-                }))?,
+            card_cvc: cvc_requirement.card_cvc(card.card_cvc)?,
             card_issuer: card.card_issuer,
-            card_network,
+            card_network: Some(card_network),
             card_type: card.card_type,
             card_issuing_country: card.card_issuing_country_alpha2,
             bank_code: card.bank_code,
             nick_name: card.nick_name.map(|name| name.into()),
             card_holder_name: card.card_holder_name,
-            co_badged_card_data: None,
+            co_badged_card_data,
         })
     }
 }
@@ -676,6 +1838,7 @@ impl CardConversionHelper<DefaultPCIHolder> for DefaultPCIHolder {
 impl CardConversionHelper<VaultTokenHolder> for VaultTokenHolder {
     fn convert_card_details(
         card: grpc_api_types::payments::CardDetails,
+        cvc_requirement: CvcRequirement,
     ) -> Result<
         payment_method_data::Card<VaultTokenHolder>,
         error_stack::Report<ApplicationErrorResponse>,
@@ -707,14 +1870,7 @@ impl CardConversionHelper<VaultTokenHolder> for VaultTokenHolder {
                     error_message: "Missing Card Expiry Year".to_owned(),
                     error_object: None,
                 }))?,
-            card_cvc: card
-                .card_cvc
-                .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
-                    sub_code: "MISSING_CVC".to_owned(),
-                    error_identifier: 400,
-                    error_message: "Missing CVC".to_owned(),
-                    error_object: None,
-                }))?,
+            card_cvc: cvc_requirement.card_cvc(card.card_cvc)?,
             card_issuer: card.card_issuer,
             card_network: None,
             card_type: card.card_type,
@@ -722,7 +1878,10 @@ impl CardConversionHelper<VaultTokenHolder> for VaultTokenHolder {
             bank_code: card.bank_code,
             nick_name: card.nick_name.map(|name| name.into()),
             card_holder_name: card.card_holder_name,
-            co_badged_card_data: None,
+            co_badged_card_data: card
+                .co_badged_card_data
+                .map(payment_method_data::CoBadgedCardData::foreign_try_from)
+                .transpose()?,
         })
     }
 }
@@ -745,7 +1904,176 @@ where
     fn foreign_try_from(
         card: grpc_api_types::payments::CardDetails,
     ) -> Result<Self, error_stack::Report<Self::Error>> {
-        T::convert_card_details(card)
+        T::convert_card_details(card, CvcRequirement::Required)
+    }
+}
+
+impl<T> ForeignTryFrom<(grpc_api_types::payments::CardDetails, CvcRequirement)>
+    for payment_method_data::Card<T>
+where
+    T: PaymentMethodDataTypes
+        + Default
+        + Debug
+        + Send
+        + Eq
+        + PartialEq
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + Clone
+        + CardConversionHelper<T>,
+{
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (card, cvc_requirement): (grpc_api_types::payments::CardDetails, CvcRequirement),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        T::convert_card_details(card, cvc_requirement)
+    }
+}
+
+impl ForeignTryFrom<grpc_api_types::payments::BankRedirectPaymentMethodType>
+    for payment_method_data::BankRedirectData
+{
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        value: grpc_api_types::payments::BankRedirectPaymentMethodType,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        match value.bank_redirect_type {
+            Some(grpc_api_types::payments::bank_redirect_payment_method_type::BankRedirectType::Eps(
+                eps,
+            )) => {
+                let bank_name = eps.bank_name.ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "MISSING_BANK_NAME".to_owned(),
+                    error_identifier: 400,
+                    error_message: "Missing bank name for EPS".to_owned(),
+                    error_object: None,
+                }))?;
+                let bank_name = common_enums::BankNames::from_str(&bank_name).map_err(|_| {
+                    ApplicationErrorResponse::BadRequest(ApiError {
+                        sub_code: "INVALID_BANK_NAME".to_owned(),
+                        error_identifier: 400,
+                        error_message: format!("Invalid bank name for EPS: {bank_name}"),
+                        error_object: None,
+                    })
+                })?;
+                Ok(Self::Eps {
+                    bank_name: Some(bank_name),
+                    country: Some(bank_redirect_country(eps.country, "EPS")?),
+                })
+            }
+            Some(
+                grpc_api_types::payments::bank_redirect_payment_method_type::BankRedirectType::Interac(
+                    interac,
+                ),
+            ) => {
+                let email = interac
+                    .email
+                    .map(|email| {
+                        Email::try_from(email.expose()).map_err(|_| {
+                            ApplicationErrorResponse::BadRequest(ApiError {
+                                sub_code: "INVALID_EMAIL_FORMAT".to_owned(),
+                                error_identifier: 400,
+                                error_message: "Invalid email".to_owned(),
+                                error_object: None,
+                            })
+                        })
+                    })
+                    .transpose()?;
+                Ok(Self::Interac {
+                    country: Some(bank_redirect_country(interac.country, "Interac")?),
+                    email,
+                })
+            }
+            None => Err(report!(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_PAYMENT_METHOD".to_owned(),
+                error_identifier: 400,
+                error_message: "Bank redirect type is required".to_owned(),
+                error_object: None,
+            }))),
+        }
+    }
+}
+
+/// Parses the country of the customer's bank, which bank redirects require
+fn bank_redirect_country(
+    country: Option<String>,
+    payment_method: &str,
+) -> Result<common_enums::CountryAlpha2, error_stack::Report<ApplicationErrorResponse>> {
+    let country = country.ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "MISSING_COUNTRY".to_owned(),
+        error_identifier: 400,
+        error_message: format!("Missing bank country for {payment_method}"),
+        error_object: None,
+    }))?;
+    common_enums::CountryAlpha2::from_str(&country).map_err(|_| {
+        report!(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "INVALID_COUNTRY".to_owned(),
+            error_identifier: 400,
+            error_message: format!("Invalid bank country for {payment_method}: {country}"),
+            error_object: None,
+        }))
+    })
+}
+
+impl ForeignTryFrom<grpc_api_types::payments::NetworkTokenDetails>
+    for payment_method_data::NetworkTokenData
+{
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        token: grpc_api_types::payments::NetworkTokenDetails,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        let card_network = token
+            .card_network
+            .map(|_| common_enums::CardNetwork::foreign_try_from(token.card_network()))
+            .transpose()?;
+        let network_token = token
+            .network_token
+            .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "MISSING_NETWORK_TOKEN".to_owned(),
+                error_identifier: 400,
+                error_message: "Missing network token".to_owned(),
+                error_object: None,
+            }))?;
+        let network_token = cards::NetworkToken::from_str(network_token.peek()).map_err(|_| {
+            ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_NETWORK_TOKEN".to_owned(),
+                error_identifier: 400,
+                error_message: "Invalid network token".to_owned(),
+                error_object: None,
+            })
+        })?;
+        let cryptogram = token
+            .cryptogram
+            .filter(|cryptogram| !cryptogram.peek().trim().is_empty());
+
+        Ok(Self {
+            network_token,
+            network_token_exp_month: token.network_token_exp_month.ok_or(
+                ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "MISSING_EXP_MONTH".to_owned(),
+                    error_identifier: 400,
+                    error_message: "Missing Network Token Expiry Month".to_owned(),
+                    error_object: None,
+                }),
+            )?,
+            network_token_exp_year: token.network_token_exp_year.ok_or(
+                ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "MISSING_EXP_YEAR".to_owned(),
+                    error_identifier: 400,
+                    error_message: "Missing Network Token Expiry Year".to_owned(),
+                    error_object: None,
+                }),
+            )?,
+            cryptogram,
+            card_issuer: token.card_issuer,
+            card_network,
+            card_type: None,
+            card_issuing_country: None,
+            bank_code: None,
+            card_holder_name: token.card_holder_name,
+            nick_name: None,
+            eci: token.eci,
+            token_requestor_id: token.token_requestor_id,
+        })
     }
 }
 
@@ -927,6 +2255,31 @@ impl<
 
     fn foreign_try_from(
         value: PaymentServiceAuthorizeRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Self::foreign_try_from((value, CvcRequirement::Required))
+    }
+}
+
+/// Converts an authorize request for a connector with the given CVC requirement.
+/// Merchant-initiated payments never require a CVC.
+impl<
+        T: PaymentMethodDataTypes
+            + Default
+            + Debug
+            + Send
+            + Eq
+            + PartialEq
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + Clone
+            + CardConversionHelper<T>,
+    > ForeignTryFrom<(PaymentServiceAuthorizeRequest, CvcRequirement)>
+    for PaymentsAuthorizeData<T>
+{
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        (value, connector_cvc_requirement): (PaymentServiceAuthorizeRequest, CvcRequirement),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         let email: Option<Email> = match value.email {
             Some(ref email_str) => {
@@ -943,11 +2296,58 @@ impl<
             None => None,
         };
 
+        let stored_credential_usage = value
+            .stored_credential_usage
+            .map(StoredCredentialUsage::foreign_try_from)
+            .transpose()?;
+        // Off-session payments charge a stored credential, which carries no mandate here; the
+        // consent indicator is the only reference the connector gets
+        if value.off_session == Some(true) && stored_credential_usage.is_none() {
+            return Err(missing_stored_credential_usage_error(
+                "Stored credential usage is required for merchant-initiated payments",
+            ));
+        }
+        let is_merchant_initiated =
+            stored_credential_usage.is_some_and(|usage| usage.is_merchant_initiated());
+        let cvc_requirement = if is_merchant_initiated {
+            CvcRequirement::Optional
+        } else {
+            connector_cvc_requirement
+        };
+        // A customer-initiated network token payment is only usable with the cryptogram
+        // generated for the transaction; merchant-initiated ones carry none
+        if !is_merchant_initiated && is_network_token_without_cryptogram(&value) {
+            return Err(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "MISSING_CRYPTOGRAM".to_owned(),
+                error_identifier: 400,
+                error_message: "Missing network token cryptogram".to_owned(),
+                error_object: None,
+            }))?;
+        }
+
+        let authentication_data = value
+            .authentication_data
+            .clone()
+            .map(router_request_types::AuthenticationData::foreign_try_from)
+            .transpose()?;
+        if value.auth_type() == grpc_api_types::payments::AuthenticationType::ExternalThreeDs
+            && authentication_data.is_none()
+        {
+            return Err(invalid_authentication_data_error(
+                "Authentication data is required for externally authenticated payments",
+            ));
+        }
+
+        let setup_future_usage = match value.setup_future_usage() {
+            grpc_api_types::payments::FutureUsage::Unspecified => None,
+            future_usage => Some(common_enums::FutureUsage::foreign_try_from(future_usage)?),
+        };
+
         Ok(Self {
             capture_method: Some(common_enums::CaptureMethod::foreign_try_from(
                 value.capture_method(),
             )?),
-            payment_method_data: PaymentMethodData::<T>::foreign_try_from(
+            payment_method_data: PaymentMethodData::<T>::foreign_try_from((
                 value.payment_method.clone().ok_or_else(|| {
                     ApplicationErrorResponse::BadRequest(ApiError {
                         sub_code: "INVALID_PAYMENT_METHOD_DATA".to_owned(),
@@ -956,7 +2356,8 @@ impl<
                         error_object: None,
                     })
                 })?,
-            )
+                cvc_requirement,
+            ))
             .change_context(ApplicationErrorResponse::BadRequest(ApiError {
                 sub_code: "INVALID_PAYMENT_METHOD_DATA".to_owned(),
                 error_identifier: 400,
@@ -989,12 +2390,12 @@ impl<
 
             router_return_url: value.return_url,
             complete_authorize_url: None,
-            setup_future_usage: None,
+            setup_future_usage,
             mandate_id: None,
             off_session: None,
             order_category: value.order_category,
             session_token: None,
-            enrolled_for_3ds: false,
+            enrolled_for_3ds: value.enrolled_for_3ds,
             related_transaction_id: None,
             payment_experience: None,
             customer_id: value
@@ -1027,10 +2428,150 @@ impl<
             integrity_object: None,
             merchant_config_currency: None,
             all_keys_required: None, // Field not available in new proto structure
+            stored_credential_usage,
+            preferred_three_ds_version: Option::<ThreeDsVersion>::foreign_from(
+                value.preferred_three_ds_version(),
+            ),
+            three_ds_version: None,
+            requested_exemption: Option::<ScaExemption>::foreign_from(value.requested_exemption()),
+            authentication_data,
+            installment_count: value.installment_count,
+        })
+    }
+}
+
+impl ForeignTryFrom<grpc_api_types::payments::AuthenticationData>
+    for router_request_types::AuthenticationData
+{
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: grpc_api_types::payments::AuthenticationData,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        if value.cavv.is_empty() {
+            return Err(invalid_authentication_data_error(
+                "CAVV is required in authentication data",
+            ));
+        }
+
+        let message_version = value
+            .message_version
+            .as_deref()
+            .map(common_utils::types::SemanticVersion::from_str)
+            .transpose()
+            .map_err(|_| invalid_authentication_data_error("Invalid 3DS message version"))?;
+
+        Ok(Self {
+            eci: value.eci,
+            cavv: Secret::new(value.cavv),
+            threeds_server_transaction_id: value
+                .threeds_server_transaction_id
+                .and_then(|id| id.id_type)
+                .and_then(|id_type| match id_type {
+                    grpc_api_types::payments::identifier::IdType::Id(id) => Some(id),
+                    _ => None,
+                }),
+            message_version,
+            ds_trans_id: value.ds_transaction_id,
+            trans_status: value
+                .trans_status
+                .map(|trans_status| trans_status.trim().to_ascii_uppercase()),
+        })
+    }
+}
+
+fn invalid_authentication_data_error(
+    error_message: &str,
+) -> error_stack::Report<ApplicationErrorResponse> {
+    error_stack::Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "INVALID_AUTHENTICATION_DATA".to_owned(),
+        error_identifier: 400,
+        error_message: error_message.to_owned(),
+        error_object: None,
+    }))
+}
+
+impl ForeignTryFrom<grpc_api_types::payments::StoredCredentialUsage> for StoredCredentialUsage {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: grpc_api_types::payments::StoredCredentialUsage,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        let invalid_usage = |error_message: &str| {
+            error_stack::Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_STORED_CREDENTIAL_USAGE".to_owned(),
+                error_identifier: 400,
+                error_message: error_message.to_owned(),
+                error_object: None,
+            }))
+        };
+
+        let sequence = match value.sequence() {
+            grpc_api_types::payments::StoredCredentialSequence::First => {
+                StoredCredentialSequence::First
+            }
+            grpc_api_types::payments::StoredCredentialSequence::Subsequent => {
+                StoredCredentialSequence::Subsequent
+            }
+            grpc_api_types::payments::StoredCredentialSequence::Unspecified => {
+                return Err(invalid_usage("Stored credential sequence is required"))
+            }
+        };
+        let initiator = match value.initiator() {
+            grpc_api_types::payments::StoredCredentialInitiator::Cardholder => {
+                StoredCredentialInitiator::Cardholder
+            }
+            grpc_api_types::payments::StoredCredentialInitiator::Merchant => {
+                StoredCredentialInitiator::Merchant
+            }
+            grpc_api_types::payments::StoredCredentialInitiator::Unspecified => {
+                return Err(invalid_usage("Stored credential initiator is required"))
+            }
+        };
+
+        // A merchant can only initiate a payment with a credential the cardholder stored earlier
+        if initiator == StoredCredentialInitiator::Merchant
+            && sequence == StoredCredentialSequence::First
+        {
+            return Err(invalid_usage(
+                "Merchant-initiated payments must use a previously stored credential",
+            ));
+        }
+
+        Ok(Self {
+            sequence,
+            initiator,
         })
     }
 }
 
+fn is_network_token_without_cryptogram(request: &PaymentServiceAuthorizeRequest) -> bool {
+    match request
+        .payment_method
+        .as_ref()
+        .and_then(|payment_method| payment_method.payment_method.as_ref())
+    {
+        Some(grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(token)) => {
+            !token
+                .cryptogram
+                .as_ref()
+                .is_some_and(|cryptogram| !cryptogram.peek().trim().is_empty())
+        }
+        _ => false,
+    }
+}
+
+fn missing_stored_credential_usage_error(
+    error_message: &str,
+) -> error_stack::Report<ApplicationErrorResponse> {
+    error_stack::Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "MISSING_STORED_CREDENTIAL_USAGE".to_owned(),
+        error_identifier: 400,
+        error_message: error_message.to_owned(),
+        error_object: None,
+    }))
+}
+
 impl ForeignTryFrom<grpc_api_types::payments::PaymentAddress> for payment_address::PaymentAddress {
     type Error = ApplicationErrorResponse;
     fn foreign_try_from(
@@ -1345,7 +2886,14 @@ impl ForeignTryFrom<grpc_api_types::payments::CountryAlpha2> for common_enums::C
             grpc_api_types::payments::CountryAlpha2::Ye => Ok(Self::YE),
             grpc_api_types::payments::CountryAlpha2::Zm => Ok(Self::ZM),
             grpc_api_types::payments::CountryAlpha2::Zw => Ok(Self::ZW),
-            grpc_api_types::payments::CountryAlpha2::Unspecified => Ok(Self::US), // Default to US if unspecified
+            grpc_api_types::payments::CountryAlpha2::Unspecified => {
+                Err(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "UNSPECIFIED_COUNTRY".to_owned(),
+                    error_identifier: 400,
+                    error_message: "Country must be specified".to_owned(),
+                    error_object: None,
+                }))?
+            }
         }
     }
 }
@@ -1355,11 +2903,18 @@ impl ForeignTryFrom<grpc_api_types::payments::Address> for AddressDetails {
     fn foreign_try_from(
         value: grpc_api_types::payments::Address,
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        // An address without a country keeps it unset rather than assuming one
+        let country = value
+            .country_alpha2_code
+            .map(|code| {
+                common_enums::CountryAlpha2::foreign_try_from(
+                    grpc_api_types::payments::CountryAlpha2::try_from(code).unwrap_or_default(),
+                )
+            })
+            .transpose()?;
         Ok(Self {
             city: value.city.clone().map(|city| city.expose()),
-            country: Some(common_enums::CountryAlpha2::foreign_try_from(
-                value.country_alpha2_code(),
-            )?),
+            country,
             line1: value.line1,
             line2: value.line2,
             line3: value.line3,
@@ -1383,12 +2938,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, metadata): (
+        (value, mut connectors, metadata): (
             PaymentServiceAuthorizeRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, value.test_mode)?;
         let address = match &value.address {
             // Borrow value.address
             Some(address_value) => {
@@ -1439,11 +2995,7 @@ impl
             connector_customer: value.connector_customer_id,
             description: value.metadata.get("description").cloned(),
             return_url: value.return_url.clone(),
-            connector_meta_data: {
-                value.metadata.get("connector_meta_data").map(|json_string| {
-                    Ok::<Secret<serde_json::Value>, error_stack::Report<ApplicationErrorResponse>>(Secret::new(serde_json::Value::String(json_string.clone())))
-                }).transpose()? // Converts Option<Result<T, E>> to Result<Option<T>, E> and propagates E if it's an Err
-            },
+            connector_meta_data: parse_connector_meta(&value.metadata, "connector_meta_data")?,
             amount_captured: None,
             minor_amount_captured: None,
             access_token: None,
@@ -1457,6 +3009,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -1472,12 +3033,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::PaymentServiceRepeatEverythingRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, value.test_mode)?;
         // For repeat payment operations, address information is typically not available or required
         let address: PaymentAddress = crate::payment_address::PaymentAddress::new(
             None,        // shipping
@@ -1517,6 +3079,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -1532,12 +3103,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::PaymentServiceGetRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         // For sync operations, address information is typically not available or required
         let address: PaymentAddress = crate::payment_address::PaymentAddress::new(
             None,        // shipping
@@ -1563,7 +3135,7 @@ impl
             connector_customer: None,
             description: None,
             return_url: None,
-            connector_meta_data: None,
+            connector_meta_data: sync_connector_meta_data(&value.transaction_id),
             amount_captured: None,
             minor_amount_captured: None,
             access_token: None,
@@ -1577,6 +3149,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -1592,12 +3173,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, metadata): (
+        (value, mut connectors, metadata): (
             PaymentServiceVoidRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         // For void operations, address information is typically not available or required
         // Since this is a PaymentServiceVoidRequest, we use default address values
         let address: PaymentAddress = payment_address::PaymentAddress::new(
@@ -1638,6 +3220,85 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
+            connector_response_headers: None,
+        })
+    }
+}
+
+impl
+    ForeignTryFrom<(
+        PaymentServiceReverseCaptureRequest,
+        Connectors,
+        &tonic::metadata::MetadataMap,
+    )> for PaymentFlowData
+{
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        (value, mut connectors, metadata): (
+            PaymentServiceReverseCaptureRequest,
+            Connectors,
+            &tonic::metadata::MetadataMap,
+        ),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
+        // Capture reversals only reference an existing capture, no address information is sent
+        let address: PaymentAddress = payment_address::PaymentAddress::new(
+            None,        // shipping
+            None,        // billing
+            None,        // payment_method_billing
+            Some(false), // should_unify_address = false for capture reversals
+        );
+
+        let merchant_id_from_header = extract_merchant_id_from_metadata(metadata)?;
+
+        Ok(Self {
+            merchant_id: merchant_id_from_header,
+            payment_id: "IRRELEVANT_PAYMENT_ID".to_string(),
+            attempt_id: "IRRELEVANT_ATTEMPT_ID".to_string(),
+            status: common_enums::AttemptStatus::Charged,
+            payment_method: common_enums::PaymentMethod::Card, //TODO
+            address,
+            auth_type: common_enums::AuthenticationType::default(),
+            connector_request_reference_id: extract_connector_request_reference_id(
+                &value.request_ref_id,
+            ),
+            customer_id: None,
+            connector_customer: None,
+            description: None,
+            return_url: None,
+            connector_meta_data: None,
+            amount_captured: None,
+            minor_amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_token: None,
+            preprocessing_id: None,
+            connector_api_version: None,
+            test_mode: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            connectors,
+            raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -1669,7 +3330,13 @@ pub fn generate_create_order_response(
         PaymentCreateOrderData,
         PaymentCreateOrderResponse,
     >,
+    connector: ConnectorEnum,
 ) -> Result<PaymentServiceAuthorizeResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
     let transaction_response = router_data_v2.response;
     let status = router_data_v2.resource_common_data.status;
     let grpc_status = grpc_api_types::payments::PaymentStatus::foreign_from(status);
@@ -1693,11 +3360,30 @@ pub fn generate_create_order_response(
                 error_message: None,
                 error_code: None,
                 status_code: 200,
+                connector_http_status_code,
                 raw_connector_response,
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
                 connector_metadata: std::collections::HashMap::new(),
+                next_action: None,
+                three_ds_version: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                bin: None,
+                fraud_score: None,
+                fraud_decision: None,
+                exemption_decision: None,
+                payment_method_details: None,
+                warnings: Vec::new(),
+                mandate_reference: None,
+                raw_error_message: None,
+                installment_plan: None,
+                retryable: None,
+                three_ds_fallback_allowed: None,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
             }
         }
         Err(err) => {
@@ -1723,11 +3409,30 @@ pub fn generate_create_order_response(
                 error_message: Some(err.message),
                 error_code: Some(err.code),
                 status_code: err.status_code as u32,
+                connector_http_status_code,
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
                 connector_metadata: std::collections::HashMap::new(),
                 raw_connector_response,
+                next_action: None,
+                three_ds_version: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                bin: None,
+                fraud_score: None,
+                fraud_decision: None,
+                exemption_decision: None,
+                payment_method_details: None,
+                warnings: Vec::new(),
+                mandate_reference: None,
+                raw_error_message: None,
+                installment_plan: None,
+                retryable: None,
+                three_ds_fallback_allowed: None,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
             }
         }
     };
@@ -1741,7 +3446,13 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
         PaymentsAuthorizeData<T>,
         PaymentsResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<PaymentServiceAuthorizeResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
     let transaction_response = router_data_v2.response;
     let status = router_data_v2.resource_common_data.status;
     info!("Payment authorize response status: {:?}", status);
@@ -1751,6 +3462,36 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
         .get_connector_response_headers_as_map();
     let grpc_status = grpc_api_types::payments::PaymentStatus::foreign_from(status);
     let raw_connector_response = router_data_v2.resource_common_data.raw_connector_response;
+    let card_issuer_info = router_data_v2
+        .resource_common_data
+        .card_issuer_info
+        .unwrap_or_default();
+    let fraud_check = router_data_v2
+        .resource_common_data
+        .fraud_check
+        .unwrap_or_default();
+    let fraud_decision = fraud_check
+        .fraud_decision
+        .map(|decision| grpc_api_types::payments::FraudDecision::foreign_from(decision) as i32);
+    let exemption_decision = router_data_v2
+        .resource_common_data
+        .sca_exemption_decision
+        .map(|decision| {
+            grpc_api_types::payments::ScaExemptionDecision::foreign_from(decision) as i32
+        });
+    let payment_method_details =
+        grpc_payment_method_details(router_data_v2.resource_common_data.payment_method_details);
+    let warnings = router_data_v2.resource_common_data.connector_warnings;
+    let installment_plan = router_data_v2
+        .resource_common_data
+        .installment_plan
+        .map(grpc_api_types::payments::InstallmentPlan::foreign_from);
+    let three_ds_failure = router_data_v2.resource_common_data.three_ds_failure;
+    let three_ds_fallback_allowed = three_ds_failure.map(ThreeDsFailure::allows_fallback);
+    let three_ds_version = router_data_v2
+        .request
+        .three_ds_version
+        .map(|version| grpc_api_types::payments::ThreeDsVersion::foreign_from(version) as i32);
     let response = match transaction_response {
         Ok(response) => match response {
             PaymentsResponseData::TransactionResponse {
@@ -1760,9 +3501,18 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
                 network_txn_id,
                 connector_response_reference_id,
                 incremental_authorization_allowed,
-                mandate_reference: _,
+                mandate_reference,
                 status_code,
             } => {
+                let next_action = get_authorize_next_action(
+                    status,
+                    redirection_data.as_deref(),
+                    connector_metadata.as_ref(),
+                );
+                let mandate_reference_grpc =
+                    mandate_reference.map(|m| grpc_api_types::payments::MandateReference {
+                        mandate_id: m.connector_mandate_id,
+                    });
                 PaymentServiceAuthorizeResponse {
                     transaction_id: Some(grpc_api_types::payments::Identifier::foreign_try_from(resource_id)?),
                     redirection_data: redirection_data.map(
@@ -1799,9 +3549,9 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
                                 },
                                 crate::router_response_types::RedirectForm::Mifinity { initialization_token } => {
                                     Ok(grpc_api_types::payments::RedirectForm {
-                                        form_type: Some(grpc_api_types::payments::redirect_form::FormType::Uri(
-                                            grpc_api_types::payments::UriData {
-                                                uri: initialization_token,
+                                        form_type: Some(grpc_api_types::payments::redirect_form::FormType::Mifinity(
+                                            grpc_api_types::payments::MifinityInitialization {
+                                                initialization_token,
                                             }
                                         ))
                                     })
@@ -1816,11 +3566,10 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
                             }
                         }
                     ).transpose()?,
-                    connector_metadata: connector_metadata
-                        .and_then(|value| value.as_object().cloned())
-                        .map(|map| {map.into_iter().filter_map(|(k, v)| v.as_str()
-                            .map(|s| (k, s.to_string())))
-                            .collect::<HashMap<_, _>>()}).unwrap_or_default(),
+                    connector_metadata: flatten_connector_metadata(connector_metadata)
+                        .into_iter()
+                        .chain(three_ds_failure_metadata(three_ds_failure))
+                        .collect(),
                     network_txn_id,
                     response_ref_id: connector_response_reference_id.map(|id| grpc_api_types::payments::Identifier {
                         id_type: Some(grpc_api_types::payments::identifier::IdType::Id(id)),
@@ -1831,7 +3580,26 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
                     error_code: None,
                     raw_connector_response,
                     status_code: status_code as u32,
+                    connector_http_status_code,
                     response_headers,
+                    next_action,
+                    three_ds_version,
+                    card_issuer: card_issuer_info.card_issuer,
+                    card_issuing_country: card_issuer_info.card_issuing_country,
+                    bin: card_issuer_info.bin,
+                    fraud_score: fraud_check.fraud_score,
+                    fraud_decision,
+                    exemption_decision,
+                    payment_method_details,
+                    warnings,
+                    mandate_reference: mandate_reference_grpc,
+                    raw_error_message: None,
+                    installment_plan,
+                    retryable: None,
+                    three_ds_fallback_allowed,
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
                 }
             }
             _ => Err(ApplicationErrorResponse::BadRequest(ApiError {
@@ -1862,15 +3630,178 @@ pub fn generate_payment_authorize_response<T: PaymentMethodDataTypes>(
                 error_message: Some(err.message),
                 error_code: Some(err.code),
                 status_code: err.status_code as u32,
+                connector_http_status_code,
                 response_headers,
                 raw_connector_response,
-                connector_metadata: std::collections::HashMap::new(),
+                connector_metadata: three_ds_failure_metadata(three_ds_failure),
+                next_action: None,
+                three_ds_version,
+                card_issuer: card_issuer_info.card_issuer,
+                card_issuing_country: card_issuer_info.card_issuing_country,
+                bin: card_issuer_info.bin,
+                fraud_score: fraud_check.fraud_score,
+                fraud_decision,
+                exemption_decision,
+                payment_method_details,
+                warnings: Vec::new(),
+                mandate_reference: None,
+                raw_error_message: None,
+                installment_plan: None,
+                retryable: None,
+                three_ds_fallback_allowed,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
             }
         }
     };
     Ok(response)
 }
 
+/// Response metadata key carrying the `soft` or `hard` classification of a failed 3DS
+/// authentication
+pub const THREE_DS_FAILURE_METADATA_KEY: &str = "three_ds_failure";
+
+fn three_ds_failure_metadata(three_ds_failure: Option<ThreeDsFailure>) -> HashMap<String, String> {
+    three_ds_failure
+        .map(|failure| {
+            (
+                THREE_DS_FAILURE_METADATA_KEY.to_string(),
+                failure.as_str().to_string(),
+            )
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Top-level string values of the connector metadata; nested and non-string values are
+/// dropped.
+pub fn flatten_connector_metadata(
+    connector_metadata: Option<serde_json::Value>,
+) -> HashMap<String, String> {
+    connector_metadata
+        .and_then(|value| value.as_object().cloned())
+        .map(|map| {
+            map.into_iter()
+                .filter_map(|(key, value)| value.as_str().map(|value| (key, value.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Derives the typed next action of an authorize response from the connector's
+/// redirection data, QR code metadata and the attempt status.
+fn get_authorize_next_action(
+    status: common_enums::AttemptStatus,
+    redirection_data: Option<&router_response_types::RedirectForm>,
+    connector_metadata: Option<&serde_json::Value>,
+) -> Option<grpc_api_types::payments::NextAction> {
+    use grpc_api_types::payments::next_action::Action;
+
+    let action = match redirection_data {
+        Some(router_response_types::RedirectForm::Form {
+            endpoint,
+            method,
+            form_fields,
+        }) => Some(Action::RedirectToUrl(
+            grpc_api_types::payments::RedirectToUrl {
+                url: endpoint.clone(),
+                method: grpc_api_types::payments::HttpMethod::foreign_from(*method) as i32,
+                form_fields: form_fields.clone(),
+            },
+        )),
+        Some(router_response_types::RedirectForm::Uri { uri }) => Some(Action::RedirectToUrl(
+            grpc_api_types::payments::RedirectToUrl {
+                url: uri.clone(),
+                method: grpc_api_types::payments::HttpMethod::Get as i32,
+                form_fields: HashMap::new(),
+            },
+        )),
+        Some(router_response_types::RedirectForm::Mifinity {
+            initialization_token,
+        }) => Some(Action::InitializeMifinity(
+            grpc_api_types::payments::MifinityInitialization {
+                initialization_token: initialization_token.clone(),
+            },
+        )),
+        // Other redirection forms are only available through the raw redirection_data
+        Some(_) => None,
+        None => get_display_voucher_action(connector_metadata)
+            .or_else(|| get_display_qr_action(connector_metadata))
+            .or(match status {
+                common_enums::AttemptStatus::AuthenticationPending
+                | common_enums::AttemptStatus::Pending => Some(Action::WaitForPolling(
+                    grpc_api_types::payments::WaitForPolling {},
+                )),
+                _ => None,
+            }),
+    };
+
+    action.map(|action| grpc_api_types::payments::NextAction {
+        action: Some(action),
+    })
+}
+
+fn get_display_qr_action(
+    connector_metadata: Option<&serde_json::Value>,
+) -> Option<grpc_api_types::payments::next_action::Action> {
+    let metadata = connector_metadata?.as_object()?;
+    let image_data_url = metadata
+        .get("image_data_url")
+        .or_else(|| metadata.get("color_image_data_url"))
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string);
+    let qr_code_url = metadata
+        .get("qr_code_url")
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string);
+
+    if image_data_url.is_none() && qr_code_url.is_none() {
+        return None;
+    }
+
+    Some(grpc_api_types::payments::next_action::Action::DisplayQr(
+        grpc_api_types::payments::DisplayQr {
+            image_data_url,
+            qr_code_url,
+            display_to_timestamp: metadata
+                .get("display_to_timestamp")
+                .and_then(serde_json::Value::as_i64),
+        },
+    ))
+}
+
+fn get_display_voucher_action(
+    connector_metadata: Option<&serde_json::Value>,
+) -> Option<grpc_api_types::payments::next_action::Action> {
+    let metadata = connector_metadata?.as_object()?;
+    let get_string = |key: &str| {
+        metadata
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    };
+    let voucher_number = get_string("voucher_number");
+    let download_url = get_string("voucher_download_url");
+
+    if voucher_number.is_none() && download_url.is_none() {
+        return None;
+    }
+
+    Some(
+        grpc_api_types::payments::next_action::Action::DisplayVoucher(
+            grpc_api_types::payments::DisplayVoucher {
+                voucher_number,
+                expires_at: metadata
+                    .get("voucher_expires_at")
+                    .and_then(serde_json::Value::as_i64),
+                barcode: get_string("voucher_barcode"),
+                download_url,
+            },
+        ),
+    )
+}
+
 // ForeignTryFrom for PaymentMethod gRPC enum to internal enum
 impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for common_enums::PaymentMethod {
     type Error = ApplicationErrorResponse;
@@ -1906,6 +3837,21 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentMethod> for common_enums::P
                 payment_method:
                     Some(grpc_api_types::payments::payment_method::PaymentMethod::Wallet(_)),
             } => Ok(Self::Wallet),
+            grpc_api_types::payments::PaymentMethod {
+                payment_method:
+                    Some(grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(_)),
+            } => Ok(Self::Card),
+            grpc_api_types::payments::PaymentMethod {
+                payment_method:
+                    Some(
+                        grpc_api_types::payments::payment_method::PaymentMethod::Boleto(_)
+                        | grpc_api_types::payments::payment_method::PaymentMethod::Oxxo(_),
+                    ),
+            } => Ok(Self::Voucher),
+            grpc_api_types::payments::PaymentMethod {
+                payment_method:
+                    Some(grpc_api_types::payments::payment_method::PaymentMethod::BankRedirect(_)),
+            } => Ok(Self::BankRedirect),
             _ => Ok(Self::Card), // Default fallback
         }
     }
@@ -1923,6 +3869,8 @@ impl ForeignTryFrom<grpc_api_types::payments::AuthenticationType>
             grpc_api_types::payments::AuthenticationType::Unspecified => Ok(Self::NoThreeDs), // Default to NoThreeDs for unspecified
             grpc_api_types::payments::AuthenticationType::ThreeDs => Ok(Self::ThreeDs),
             grpc_api_types::payments::AuthenticationType::NoThreeDs => Ok(Self::NoThreeDs),
+            // Already authenticated, the connector must not challenge the customer again
+            grpc_api_types::payments::AuthenticationType::ExternalThreeDs => Ok(Self::NoThreeDs),
         }
     }
 }
@@ -2006,7 +3954,7 @@ impl
             connector_customer: None,
             description: None,
             return_url: None,
-            connector_meta_data: None,
+            connector_meta_data: sync_connector_meta_data(&value.transaction_id),
             amount_captured: None,
             minor_amount_captured: None,
             access_token: None,
@@ -2020,6 +3968,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -2056,7 +4013,7 @@ impl ForeignFrom<common_enums::AttemptStatus> for grpc_api_types::payments::Paym
             common_enums::AttemptStatus::PartialChargedAndChargeable => {
                 Self::PartialChargedAndChargeable
             }
-            common_enums::AttemptStatus::IntegrityFailure => Self::Failure,
+            common_enums::AttemptStatus::IntegrityFailure => Self::IntegrityFailure,
             common_enums::AttemptStatus::Unknown => Self::AttemptStatusUnspecified,
         }
     }
@@ -2109,26 +4066,139 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentStatus> for common_enums::A
             grpc_api_types::payments::PaymentStatus::PartialChargedAndChargeable => {
                 Ok(Self::PartialChargedAndChargeable)
             }
+            grpc_api_types::payments::PaymentStatus::IntegrityFailure => Ok(Self::IntegrityFailure),
             grpc_api_types::payments::PaymentStatus::AttemptStatusUnspecified => Ok(Self::Unknown),
         }
     }
 }
 
-impl ForeignFrom<common_enums::RefundStatus> for grpc_api_types::payments::RefundStatus {
-    fn foreign_from(status: common_enums::RefundStatus) -> Self {
-        match status {
-            common_enums::RefundStatus::Failure => Self::RefundFailure,
-            common_enums::RefundStatus::ManualReview => Self::RefundManualReview,
-            common_enums::RefundStatus::Pending => Self::RefundPending,
-            common_enums::RefundStatus::Success => Self::RefundSuccess,
-            common_enums::RefundStatus::TransactionFailure => Self::RefundTransactionFailure,
+impl ForeignFrom<common_enums::RefundStatus> for grpc_api_types::payments::RefundStatus {
+    fn foreign_from(status: common_enums::RefundStatus) -> Self {
+        match status {
+            common_enums::RefundStatus::Failure => Self::RefundFailure,
+            common_enums::RefundStatus::ManualReview => Self::RefundManualReview,
+            common_enums::RefundStatus::Pending => Self::RefundPending,
+            common_enums::RefundStatus::Success => Self::RefundSuccess,
+            common_enums::RefundStatus::TransactionFailure => Self::RefundTransactionFailure,
+        }
+    }
+}
+
+pub fn generate_payment_void_response(
+    router_data_v2: RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    connector: ConnectorEnum,
+) -> Result<PaymentServiceVoidResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
+    let transaction_response = router_data_v2.response;
+
+    match transaction_response {
+        Ok(response) => match response {
+            PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data: _,
+                connector_metadata,
+                network_txn_id: _,
+                connector_response_reference_id,
+                incremental_authorization_allowed: _,
+                mandate_reference: _,
+                status_code,
+            } => {
+                let status = router_data_v2.resource_common_data.status;
+                let grpc_status = grpc_api_types::payments::PaymentStatus::foreign_from(status);
+
+                let grpc_resource_id =
+                    grpc_api_types::payments::Identifier::foreign_try_from(resource_id)?;
+
+                Ok(PaymentServiceVoidResponse {
+                    transaction_id: Some(grpc_resource_id),
+                    status: grpc_status.into(),
+                    response_ref_id: connector_response_reference_id.map(|id| {
+                        grpc_api_types::payments::Identifier {
+                            id_type: Some(grpc_api_types::payments::identifier::IdType::Id(id)),
+                        }
+                    }),
+                    connector_metadata: flatten_connector_metadata(connector_metadata),
+                    error_code: None,
+                    error_message: None,
+                    status_code: status_code as u32,
+                    connector_http_status_code,
+                    response_headers: router_data_v2
+                        .resource_common_data
+                        .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    raw_error_message: None,
+                    retryable: None,
+                })
+            }
+            _ => Err(report!(ApplicationErrorResponse::InternalServerError(
+                ApiError {
+                    sub_code: "INVALID_RESPONSE_TYPE".to_owned(),
+                    error_identifier: 500,
+                    error_message: "Invalid response type received from connector".to_owned(),
+                    error_object: None,
+                }
+            ))),
+        },
+        Err(e) => {
+            let status = e
+                .attempt_status
+                .map(grpc_api_types::payments::PaymentStatus::foreign_from)
+                .unwrap_or_default();
+            let retryable = error_response_retryable(
+                &e,
+                &router_data_v2.resource_common_data.connectors,
+                &connector,
+            );
+            Ok(PaymentServiceVoidResponse {
+                transaction_id: Some(grpc_api_types::payments::Identifier {
+                    id_type: Some(
+                        grpc_api_types::payments::identifier::IdType::NoResponseIdMarker(()),
+                    ),
+                }),
+                response_ref_id: e.connector_transaction_id.map(|id| {
+                    grpc_api_types::payments::Identifier {
+                        id_type: Some(grpc_api_types::payments::identifier::IdType::Id(id)),
+                    }
+                }),
+                status: status as i32,
+                connector_metadata: HashMap::new(),
+                error_message: Some(e.message),
+                error_code: Some(e.code),
+                status_code: e.status_code as u32,
+                connector_http_status_code,
+                response_headers: router_data_v2
+                    .resource_common_data
+                    .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable,
+            })
         }
     }
 }
 
-pub fn generate_payment_void_response(
-    router_data_v2: RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
-) -> Result<PaymentServiceVoidResponse, error_stack::Report<ApplicationErrorResponse>> {
+pub fn generate_payment_reverse_capture_response(
+    router_data_v2: RouterDataV2<
+        CaptureReversal,
+        PaymentFlowData,
+        PaymentCaptureReversalData,
+        PaymentsResponseData,
+    >,
+    connector: ConnectorEnum,
+) -> Result<PaymentServiceReverseCaptureResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
     let transaction_response = router_data_v2.response;
 
     match transaction_response {
@@ -2149,7 +4219,7 @@ pub fn generate_payment_void_response(
                 let grpc_resource_id =
                     grpc_api_types::payments::Identifier::foreign_try_from(resource_id)?;
 
-                Ok(PaymentServiceVoidResponse {
+                Ok(PaymentServiceReverseCaptureResponse {
                     transaction_id: Some(grpc_resource_id),
                     status: grpc_status.into(),
                     response_ref_id: connector_response_reference_id.map(|id| {
@@ -2160,9 +4230,13 @@ pub fn generate_payment_void_response(
                     error_code: None,
                     error_message: None,
                     status_code: status_code as u32,
+                    connector_http_status_code,
                     response_headers: router_data_v2
                         .resource_common_data
                         .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    raw_error_message: None,
                 })
             }
             _ => Err(report!(ApplicationErrorResponse::InternalServerError(
@@ -2179,7 +4253,7 @@ pub fn generate_payment_void_response(
                 .attempt_status
                 .map(grpc_api_types::payments::PaymentStatus::foreign_from)
                 .unwrap_or_default();
-            Ok(PaymentServiceVoidResponse {
+            Ok(PaymentServiceReverseCaptureResponse {
                 transaction_id: Some(grpc_api_types::payments::Identifier {
                     id_type: Some(
                         grpc_api_types::payments::identifier::IdType::NoResponseIdMarker(()),
@@ -2194,14 +4268,105 @@ pub fn generate_payment_void_response(
                 error_message: Some(e.message),
                 error_code: Some(e.code),
                 status_code: e.status_code as u32,
+                connector_http_status_code,
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                raw_error_message: None,
             })
         }
     }
 }
 
+impl ForeignFrom<grpc_api_types::payments::ThreeDsVersion> for Option<ThreeDsVersion> {
+    fn foreign_from(value: grpc_api_types::payments::ThreeDsVersion) -> Self {
+        match value {
+            grpc_api_types::payments::ThreeDsVersion::Unspecified => None,
+            grpc_api_types::payments::ThreeDsVersion::V1 => Some(ThreeDsVersion::V1),
+            grpc_api_types::payments::ThreeDsVersion::V2 => Some(ThreeDsVersion::V2),
+        }
+    }
+}
+
+impl ForeignFrom<ThreeDsVersion> for grpc_api_types::payments::ThreeDsVersion {
+    fn foreign_from(value: ThreeDsVersion) -> Self {
+        match value {
+            ThreeDsVersion::V1 => Self::V1,
+            ThreeDsVersion::V2 => Self::V2,
+        }
+    }
+}
+
+impl ForeignFrom<ThreeDsCompletion> for grpc_api_types::payments::ThreeDsCompletion {
+    fn foreign_from(value: ThreeDsCompletion) -> Self {
+        match value {
+            ThreeDsCompletion::ChallengePending => Self::ChallengePending,
+            ThreeDsCompletion::Completed => Self::Completed,
+            ThreeDsCompletion::NotCompleted => Self::NotCompleted,
+        }
+    }
+}
+
+impl ForeignFrom<FraudDecision> for grpc_api_types::payments::FraudDecision {
+    fn foreign_from(value: FraudDecision) -> Self {
+        match value {
+            FraudDecision::Accept => Self::Accept,
+            FraudDecision::Review => Self::Review,
+            FraudDecision::Reject => Self::Reject,
+        }
+    }
+}
+
+impl ForeignFrom<grpc_api_types::payments::ScaExemption> for Option<ScaExemption> {
+    fn foreign_from(value: grpc_api_types::payments::ScaExemption) -> Self {
+        match value {
+            grpc_api_types::payments::ScaExemption::Unspecified => None,
+            grpc_api_types::payments::ScaExemption::TransactionRiskAnalysis => {
+                Some(ScaExemption::TransactionRiskAnalysis)
+            }
+            grpc_api_types::payments::ScaExemption::LowValue => Some(ScaExemption::LowValue),
+        }
+    }
+}
+
+impl ForeignFrom<grpc_api_types::payments::samsung_wallet::payment_credential::CardBrand>
+    for common_enums::SamsungPayCardBrand
+{
+    fn foreign_from(
+        value: grpc_api_types::payments::samsung_wallet::payment_credential::CardBrand,
+    ) -> Self {
+        use grpc_api_types::payments::samsung_wallet::payment_credential::CardBrand;
+        match value {
+            CardBrand::Visa => Self::Visa,
+            CardBrand::Mastercard => Self::MasterCard,
+            CardBrand::Amex => Self::Amex,
+            CardBrand::Discover => Self::Discover,
+            CardBrand::Jcb | CardBrand::Unspecified => Self::Unknown,
+        }
+    }
+}
+
+impl ForeignFrom<ScaExemptionDecision> for grpc_api_types::payments::ScaExemptionDecision {
+    fn foreign_from(value: ScaExemptionDecision) -> Self {
+        match value {
+            ScaExemptionDecision::Applied => Self::Applied,
+            ScaExemptionDecision::Rejected => Self::Rejected,
+        }
+    }
+}
+
+impl ForeignFrom<CardFundingType> for grpc_api_types::payments::CardFundingType {
+    fn foreign_from(funding_type: CardFundingType) -> Self {
+        match funding_type {
+            CardFundingType::Credit => Self::Credit,
+            CardFundingType::Debit => Self::Debit,
+            CardFundingType::Prepaid => Self::Prepaid,
+        }
+    }
+}
+
 impl ForeignFrom<common_enums::DisputeStage> for grpc_api_types::payments::DisputeStage {
     fn foreign_from(status: common_enums::DisputeStage) -> Self {
         match status {
@@ -2214,7 +4379,61 @@ impl ForeignFrom<common_enums::DisputeStage> for grpc_api_types::payments::Dispu
 
 pub fn generate_payment_sync_response(
     router_data_v2: RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    connector: ConnectorEnum,
 ) -> Result<PaymentServiceGetResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
+    let (settlement_currency, settlement_amount) = grpc_settlement_details(
+        router_data_v2
+            .resource_common_data
+            .settlement_details
+            .clone(),
+    );
+    let card_issuer_info = router_data_v2
+        .resource_common_data
+        .card_issuer_info
+        .clone()
+        .unwrap_or_default();
+    let payment_method_details = grpc_payment_method_details(
+        router_data_v2
+            .resource_common_data
+            .payment_method_details
+            .clone(),
+    );
+    let warnings = router_data_v2
+        .resource_common_data
+        .connector_warnings
+        .clone();
+    let three_ds_completion =
+        router_data_v2
+            .resource_common_data
+            .three_ds_completion
+            .map(|completion| {
+                grpc_api_types::payments::ThreeDsCompletion::foreign_from(completion).into()
+            });
+    let reported_amount = connector_reported_sync_amount(&router_data_v2.request);
+    let reported_currency = reported_amount.map(|(_, currency)| currency);
+    let captured = router_data_v2
+        .resource_common_data
+        .minor_amount_captured
+        .or(router_data_v2
+            .resource_common_data
+            .amount_captured
+            .map(MinorUnit::new));
+    let amount =
+        reported_amount.and_then(|(amount, currency)| whole_major_unit_amount(amount, currency));
+    let minor_amount = reported_amount.map(|(amount, _)| amount.get_amount_as_i64());
+    let captured_amount = captured
+        .zip(reported_currency)
+        .and_then(|(captured, currency)| whole_major_unit_amount(captured, currency));
+    let minor_captured_amount = captured.map(MinorUnit::get_amount_as_i64);
+    let currency = reported_currency.and_then(|currency| {
+        grpc_api_types::payments::Currency::from_str_name(&currency.to_string())
+            .map(|currency| currency as i32)
+    });
     let transaction_response = router_data_v2.response;
     let raw_connector_response = router_data_v2
         .resource_common_data
@@ -2225,7 +4444,7 @@ pub fn generate_payment_sync_response(
             PaymentsResponseData::TransactionResponse {
                 resource_id,
                 redirection_data: _,
-                connector_metadata: _,
+                connector_metadata,
                 network_txn_id: _,
                 connector_response_reference_id: _,
                 incremental_authorization_allowed: _,
@@ -2251,11 +4470,11 @@ pub fn generate_payment_sync_response(
                     error_message: None,
                     network_txn_id: None,
                     response_ref_id: None,
-                    amount: None,
-                    minor_amount: None,
-                    currency: None,
-                    captured_amount: None,
-                    minor_captured_amount: None,
+                    amount,
+                    minor_amount,
+                    currency,
+                    captured_amount,
+                    minor_captured_amount,
                     payment_method_type: None,
                     capture_method: None,
                     auth_type: None,
@@ -2267,12 +4486,26 @@ pub fn generate_payment_sync_response(
                     email: None,
                     connector_customer_id: None,
                     merchant_order_reference_id: None,
-                    metadata: std::collections::HashMap::new(),
+                    metadata: flatten_connector_metadata(connector_metadata),
                     status_code: status_code as u32,
+                    connector_http_status_code,
+                    card_issuer: card_issuer_info.card_issuer,
+                    card_issuing_country: card_issuer_info.card_issuing_country,
+                    bin: card_issuer_info.bin,
+                    settlement_currency,
+                    settlement_amount,
+                    payment_method_details: payment_method_details,
+                    warnings,
                     raw_connector_response,
                     response_headers: router_data_v2
                         .resource_common_data
                         .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    three_ds_completion,
+                    raw_error_message: None,
+                    retryable: None,
                 })
             }
             _ => Err(report!(ApplicationErrorResponse::InternalServerError(
@@ -2289,6 +4522,11 @@ pub fn generate_payment_sync_response(
                 .attempt_status
                 .map(grpc_api_types::payments::PaymentStatus::foreign_from)
                 .unwrap_or_default();
+            let retryable = error_response_retryable(
+                &e,
+                &router_data_v2.resource_common_data.connectors,
+                &connector,
+            );
             Ok(PaymentServiceGetResponse {
                 transaction_id: Some(grpc_api_types::payments::Identifier {
                     id_type: Some(
@@ -2320,9 +4558,23 @@ pub fn generate_payment_sync_response(
                 metadata: std::collections::HashMap::new(),
                 raw_connector_response,
                 status_code: e.status_code as u32,
+                connector_http_status_code,
+                card_issuer: card_issuer_info.card_issuer,
+                card_issuing_country: card_issuer_info.card_issuing_country,
+                bin: card_issuer_info.bin,
+                settlement_currency,
+                settlement_amount,
+                payment_method_details: payment_method_details,
+                warnings: Vec::new(),
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                three_ds_completion,
+                raw_error_message: None,
+                retryable,
             })
         }
     }
@@ -2400,12 +4652,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, _metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::RefundServiceGetRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         Ok(RefundFlowData {
             connector_request_reference_id: extract_connector_request_reference_id(
                 &value.request_ref_id,
@@ -2457,12 +4710,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, _metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::PaymentServiceRefundRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         Ok(RefundFlowData {
             connector_request_reference_id: extract_connector_request_reference_id(
                 &value.request_ref_id,
@@ -2506,7 +4760,9 @@ impl ForeignFrom<common_utils::Method> for grpc_api_types::payments::HttpMethod
 
 pub fn generate_accept_dispute_response(
     router_data_v2: RouterDataV2<Accept, DisputeFlowData, AcceptDisputeData, DisputeResponseData>,
+    connector: ConnectorEnum,
 ) -> Result<AcceptDisputeResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(None);
     let dispute_response = router_data_v2.response;
     let response_headers = router_data_v2
         .resource_common_data
@@ -2526,6 +4782,10 @@ pub fn generate_accept_dispute_response(
                 response_ref_id: None,
                 status_code: response.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
             })
         }
         Err(e) => {
@@ -2540,6 +4800,10 @@ pub fn generate_accept_dispute_response(
                 response_ref_id: None,
                 status_code: e.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
             })
         }
     }
@@ -2577,12 +4841,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, _metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::AcceptDisputeRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         Ok(DisputeFlowData {
             connector_request_reference_id: extract_connector_request_reference_id(
                 &value.request_ref_id,
@@ -2605,7 +4870,9 @@ pub fn generate_submit_evidence_response(
         SubmitEvidenceData,
         DisputeResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<DisputeServiceSubmitEvidenceResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(None);
     let dispute_response = router_data_v2.response;
     let response_headers = router_data_v2
         .resource_common_data
@@ -2626,6 +4893,10 @@ pub fn generate_submit_evidence_response(
                 response_ref_id: None,
                 status_code: response.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
             })
         }
         Err(e) => {
@@ -2644,6 +4915,10 @@ pub fn generate_submit_evidence_response(
                 response_ref_id: None,
                 status_code: e.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
             })
         }
     }
@@ -2687,12 +4962,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, _metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::DisputeServiceSubmitEvidenceRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         Ok(DisputeFlowData {
             connector_request_reference_id: extract_connector_request_reference_id(
                 &value.request_ref_id,
@@ -2710,7 +4986,9 @@ impl
 
 pub fn generate_refund_sync_response(
     router_data_v2: RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+    connector: ConnectorEnum,
 ) -> Result<RefundResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(None);
     let refunds_response = router_data_v2.response;
     let raw_connector_response = router_data_v2
         .resource_common_data
@@ -2751,6 +5029,11 @@ pub fn generate_refund_sync_response(
                 raw_connector_response,
                 status_code: response.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable: None,
             })
         }
         Err(e) => {
@@ -2758,6 +5041,11 @@ pub fn generate_refund_sync_response(
                 .attempt_status
                 .map(grpc_api_types::payments::PaymentStatus::foreign_from)
                 .unwrap_or_default();
+            let retryable = error_response_retryable(
+                &e,
+                &router_data_v2.resource_common_data.connectors,
+                &connector,
+            );
             let response_headers = router_data_v2
                 .resource_common_data
                 .get_connector_response_headers_as_map();
@@ -2799,6 +5087,11 @@ pub fn generate_refund_sync_response(
                 refund_metadata: std::collections::HashMap::new(),
                 status_code: e.status_code as u32,
                 response_headers,
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable,
             })
         }
     }
@@ -2861,8 +5154,22 @@ impl ForeignTryFrom<WebhookDetailsResponse> for PaymentServiceGetResponse {
             merchant_order_reference_id: None,
             metadata: std::collections::HashMap::new(),
             status_code: value.status_code as u32,
+            connector_http_status_code: None,
+            card_issuer: None,
+            card_issuing_country: None,
+            bin: None,
+            settlement_currency: None,
+            settlement_amount: None,
+            payment_method_details: None,
+            warnings: Vec::new(),
             raw_connector_response: None,
             response_headers,
+            connector: None,
+            environment: None,
+            payment_id: None,
+            three_ds_completion: None,
+            raw_error_message: None,
+            retryable: None,
         })
     }
 }
@@ -2893,6 +5200,110 @@ impl ForeignTryFrom<PaymentServiceVoidRequest> for PaymentVoidData {
     }
 }
 
+impl ForeignTryFrom<PaymentServiceReverseCaptureRequest> for PaymentCaptureReversalData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceReverseCaptureRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        let connector_transaction_id = value
+            .transaction_id
+            .and_then(|id| id.id_type)
+            .and_then(|id_type| match id_type {
+                grpc_api_types::payments::identifier::IdType::Id(id) => Some(id),
+                _ => None,
+            })
+            .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "MISSING_CAPTURE_ID".to_owned(),
+                error_identifier: 400,
+                error_message: "Capture id is required to reverse a capture".to_owned(),
+                error_object: None,
+            }))?;
+
+        let connector_payment_id = value
+            .payment_transaction_id
+            .and_then(|id| id.id_type)
+            .and_then(|id_type| match id_type {
+                grpc_api_types::payments::identifier::IdType::Id(id) => Some(id),
+                _ => None,
+            });
+
+        Ok(Self {
+            connector_transaction_id,
+            connector_payment_id,
+            reversal_reason: value.reason,
+            integrity_object: None,
+        })
+    }
+}
+
+impl ForeignTryFrom<PaymentServiceRegisterWebhookRequest> for RegisterWebhookData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceRegisterWebhookRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        url::Url::parse(&value.webhook_url)
+            .ok()
+            .filter(|url| url.scheme() == "https" || url.scheme() == "http")
+            .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_WEBHOOK_URL".to_owned(),
+                error_identifier: 400,
+                error_message: "Webhook URL must be an absolute http(s) URL".to_owned(),
+                error_object: None,
+            }))?;
+
+        Ok(Self {
+            webhook_url: value.webhook_url,
+            event_types: value.event_types,
+            integrity_object: None,
+        })
+    }
+}
+
+impl ForeignTryFrom<(PaymentServiceRegisterWebhookRequest, Connectors)> for WebhookFlowData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        (value, connectors): (PaymentServiceRegisterWebhookRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(Self {
+            connectors,
+            connector_request_reference_id: extract_connector_request_reference_id(
+                &value.request_ref_id,
+            ),
+            raw_connector_response: None,
+            connector_response_headers: None,
+        })
+    }
+}
+
+pub fn generate_register_webhook_response(
+    router_data_v2: RouterDataV2<
+        RegisterWebhook,
+        WebhookFlowData,
+        RegisterWebhookData,
+        RegisterWebhookResponseData,
+    >,
+) -> PaymentServiceRegisterWebhookResponse {
+    match router_data_v2.response {
+        Ok(response) => PaymentServiceRegisterWebhookResponse {
+            registration_id: Some(response.registration_id),
+            error_code: None,
+            error_message: None,
+            status_code: u32::from(response.status_code),
+            raw_error_message: None,
+        },
+        Err(e) => PaymentServiceRegisterWebhookResponse {
+            registration_id: None,
+            error_code: Some(e.code),
+            error_message: Some(e.message),
+            status_code: u32::from(e.status_code),
+            raw_error_message: None,
+        },
+    }
+}
+
 impl ForeignTryFrom<RefundWebhookDetailsResponse> for RefundResponse {
     type Error = ApplicationErrorResponse;
 
@@ -2943,6 +5354,11 @@ impl ForeignTryFrom<RefundWebhookDetailsResponse> for RefundResponse {
             refund_metadata: std::collections::HashMap::new(),
             status_code: value.status_code as u32,
             response_headers,
+            connector: None,
+            environment: None,
+            payment_id: None,
+            raw_error_message: None,
+            retryable: None,
         })
     }
 }
@@ -2993,6 +5409,55 @@ impl ForeignTryFrom<DisputeWebhookDetailsResponse> for DisputeResponse {
             }),
             status_code: value.status_code as u32,
             response_headers,
+            payment_id: None,
+            raw_error_message: None,
+        })
+    }
+}
+
+impl ForeignTryFrom<SettlementWebhookDetailsResponse> for SettlementResponse {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: SettlementWebhookDetailsResponse,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        let currency =
+            grpc_api_types::payments::Currency::from_str_name(&value.currency.to_string())
+                .ok_or_else(|| {
+                    ApplicationErrorResponse::InternalServerError(ApiError {
+                        sub_code: "INVALID_SETTLEMENT_CURRENCY".to_owned(),
+                        error_identifier: 500,
+                        error_message: format!(
+                            "Unsupported settlement currency {}",
+                            value.currency
+                        ),
+                        error_object: None,
+                    })
+                })?;
+        let response_headers = value
+            .response_headers
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        value
+                            .to_str()
+                            .ok()
+                            .map(|v| (name.to_string(), v.to_string()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            settlement_id: value.settlement_id,
+            amount: value.amount.get_amount_as_i64(),
+            currency: currency.into(),
+            settlement_date: value
+                .settlement_date
+                .map(|date| date.assume_utc().unix_timestamp()),
+            fees: value.fees.map(|fees| fees.get_amount_as_i64()),
+            status_code: u32::from(value.status_code),
+            response_headers,
         })
     }
 }
@@ -3005,7 +5470,23 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceRefundRequest> for R
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         let minor_refund_amount = common_utils::types::MinorUnit::new(value.minor_refund_amount);
 
+        // Without a known captured amount the connector is left to reject an over-refund
+        if let Some(minor_captured_amount) = value.minor_captured_amount {
+            if value.minor_refund_amount > minor_captured_amount {
+                Err(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "REFUND_EXCEEDS_CAPTURED".to_owned(),
+                    error_identifier: 400,
+                    error_message: format!(
+                        "Refund amount {} exceeds the captured amount {minor_captured_amount}",
+                        value.minor_refund_amount
+                    ),
+                    error_object: None,
+                }))?;
+            }
+        }
+
         let minor_payment_amount = common_utils::types::MinorUnit::new(value.minor_payment_amount);
+        let capture_method = optional_capture_method(value.capture_method())?;
 
         // Extract transaction_id as connector_transaction_id
         let connector_transaction_id = value
@@ -3027,34 +5508,17 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceRefundRequest> for R
             reason: value.reason.clone(),
             webhook_url: value.webhook_url,
             refund_amount: value.refund_amount,
-            connector_metadata: {
-                value
-                    .metadata
-                    .get("connector_metadata")
-                    .map(|json_string| {
-                        Ok::<serde_json::Value, error_stack::Report<ApplicationErrorResponse>>(
-                            serde_json::Value::String(json_string.clone()),
-                        )
-                    })
-                    .transpose()? // Should be Option<serde_json::Value>, not Secret
-            },
-            refund_connector_metadata: {
-                value.refund_metadata.get("refund_metadata").map(|json_string| {
-                    Ok::<Secret<serde_json::Value>, error_stack::Report<ApplicationErrorResponse>>(Secret::new(serde_json::Value::String(json_string.clone())))
-                }).transpose()?
-            },
+            connector_metadata: parse_connector_meta(&value.metadata, "connector_metadata")?
+                .map(ExposeInterface::expose),
+            refund_connector_metadata: parse_connector_meta(
+                &value.refund_metadata,
+                "refund_metadata",
+            )?,
             minor_payment_amount,
             minor_refund_amount,
             refund_status: common_enums::RefundStatus::Pending,
             merchant_account_id: value.merchant_account_id,
-            capture_method: value
-                .capture_method
-                .map(|cm| {
-                    common_enums::CaptureMethod::foreign_try_from(
-                        grpc_api_types::payments::CaptureMethod::try_from(cm).unwrap_or_default(),
-                    )
-                })
-                .transpose()?,
+            capture_method,
             browser_info: value
                 .browser_info
                 .map(BrowserInformation::foreign_try_from)
@@ -3211,7 +5675,9 @@ impl ForeignTryFrom<grpc_api_types::payments::DisputeServiceSubmitEvidenceReques
 
 pub fn generate_refund_response(
     router_data_v2: RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+    connector: ConnectorEnum,
 ) -> Result<RefundResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(None);
     let refund_response = router_data_v2.response;
     let raw_connector_response = router_data_v2
         .resource_common_data
@@ -3226,7 +5692,11 @@ pub fn generate_refund_response(
                 transaction_id: Some(grpc_api_types::payments::Identifier::default()),
                 refund_id: response.connector_refund_id,
                 status: grpc_status as i32,
-                response_ref_id: None,
+                response_ref_id: response.connector_response_reference_id.map(|id| {
+                    grpc_api_types::payments::Identifier {
+                        id_type: Some(grpc_api_types::payments::identifier::IdType::Id(id)),
+                    }
+                }),
                 error_code: None,
                 error_message: None,
                 refund_amount: None,
@@ -3248,6 +5718,11 @@ pub fn generate_refund_response(
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable: None,
             })
         }
         Err(e) => {
@@ -3255,6 +5730,11 @@ pub fn generate_refund_response(
                 .attempt_status
                 .map(grpc_api_types::payments::PaymentStatus::foreign_from)
                 .unwrap_or_default();
+            let retryable = error_response_retryable(
+                &e,
+                &router_data_v2.resource_common_data.connectors,
+                &connector,
+            );
 
             Ok(RefundResponse {
                 transaction_id: Some(
@@ -3288,6 +5768,11 @@ pub fn generate_refund_response(
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable,
             })
         }
     }
@@ -3323,6 +5808,21 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceCaptureRequest>
                 });
 
         let minor_amount = common_utils::types::MinorUnit::new(value.amount_to_capture);
+        let capture_at = value
+            .capture_at
+            .map(|capture_at| {
+                time::OffsetDateTime::from_unix_timestamp(capture_at)
+                    .ok()
+                    .map(common_utils::date_time::convert_to_pdt)
+                    .filter(|capture_at| *capture_at > common_utils::date_time::now())
+                    .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+                        sub_code: "INVALID_CAPTURE_AT".to_owned(),
+                        error_identifier: 400,
+                        error_message: "Scheduled capture time must be in the future".to_owned(),
+                        error_object: None,
+                    }))
+            })
+            .transpose()?;
 
         Ok(Self {
             amount_to_capture: value.amount_to_capture,
@@ -3330,22 +5830,14 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceCaptureRequest>
             currency: common_enums::Currency::foreign_try_from(value.currency())?,
             connector_transaction_id,
             multiple_capture_data,
-            connector_metadata: {
-                value
-                    .metadata
-                    .get("connector_metadata")
-                    .map(|json_string| {
-                        Ok::<serde_json::Value, error_stack::Report<ApplicationErrorResponse>>(
-                            serde_json::Value::String(json_string.clone()),
-                        )
-                    })
-                    .transpose()? // Converts Option<Result<T, E>> to Result<Option<T>, E> and propagates E if it's an Err
-            },
+            connector_metadata: parse_connector_meta(&value.metadata, "connector_metadata")?
+                .map(ExposeInterface::expose),
             browser_info: value
                 .browser_info
                 .map(BrowserInformation::foreign_try_from)
                 .transpose()?,
             integrity_object: None,
+            capture_at,
         })
     }
 }
@@ -3380,7 +5872,7 @@ impl
             connector_customer: None,
             description: None,
             return_url: None,
-            connector_meta_data: None,
+            connector_meta_data: parse_connector_meta(&value.metadata, "connector_meta_data")?,
             amount_captured: None,
             minor_amount_captured: None,
             access_token: None,
@@ -3394,6 +5886,15 @@ impl
             external_latency: None,
             connectors,
             connector_response_headers: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
         })
     }
 }
@@ -3408,12 +5909,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, metadata): (
+        (value, mut connectors, metadata): (
             grpc_api_types::payments::PaymentServiceCaptureRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         let merchant_id_from_header = extract_merchant_id_from_metadata(metadata)?;
 
         Ok(Self {
@@ -3431,7 +5933,7 @@ impl
             connector_customer: None,
             description: None,
             return_url: None,
-            connector_meta_data: None,
+            connector_meta_data: parse_connector_meta(&value.metadata, "connector_meta_data")?,
             amount_captured: None,
             minor_amount_captured: None,
             access_token: None,
@@ -3445,6 +5947,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -3457,7 +5968,19 @@ pub fn generate_payment_capture_response(
         PaymentsCaptureData,
         PaymentsResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<PaymentServiceCaptureResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
+    let (settlement_currency, settlement_amount) = grpc_settlement_details(
+        router_data_v2
+            .resource_common_data
+            .settlement_details
+            .clone(),
+    );
     let transaction_response = router_data_v2.response;
 
     match transaction_response {
@@ -3488,9 +6011,18 @@ pub fn generate_payment_capture_response(
                     error_message: None,
                     status: grpc_status.into(),
                     status_code: status_code as u32,
+                    connector_http_status_code,
+                    connector_metadata: HashMap::new(),
+                    settlement_currency,
+                    settlement_amount,
                     response_headers: router_data_v2
                         .resource_common_data
                         .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    raw_error_message: None,
+                    retryable: None,
                 })
             }
             _ => Err(report!(ApplicationErrorResponse::InternalServerError(
@@ -3507,6 +6039,11 @@ pub fn generate_payment_capture_response(
                 .attempt_status
                 .map(grpc_api_types::payments::PaymentStatus::foreign_from)
                 .unwrap_or_default();
+            let retryable = error_response_retryable(
+                &e,
+                &router_data_v2.resource_common_data.connectors,
+                &connector,
+            );
             Ok(PaymentServiceCaptureResponse {
                 transaction_id: Some(grpc_api_types::payments::Identifier {
                     id_type: Some(
@@ -3522,9 +6059,18 @@ pub fn generate_payment_capture_response(
                 error_message: Some(e.message),
                 error_code: Some(e.code),
                 status_code: e.status_code as u32,
+                connector_http_status_code,
+                connector_metadata: HashMap::new(),
+                settlement_currency,
+                settlement_amount,
                 response_headers: router_data_v2
                     .resource_common_data
                     .get_connector_response_headers_as_map(),
+                connector: Some(connector.to_string()),
+                environment,
+                payment_id: None,
+                raw_error_message: None,
+                retryable,
             })
         }
     }
@@ -3541,7 +6087,7 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, environment, metadata): (
+        (value, mut connectors, environment, metadata): (
             PaymentServiceRegisterRequest,
             Connectors,
             String,
@@ -3564,6 +6110,7 @@ impl
             common_utils::consts::CONST_PRODUCTION => Some(false),
             _ => Some(true),
         };
+        apply_base_url_override(&mut connectors, metadata, test_mode)?;
 
         let merchant_id_from_header = extract_merchant_id_from_metadata(metadata)?;
 
@@ -3596,6 +6143,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -3765,7 +6321,13 @@ pub fn generate_setup_mandate_response<T: PaymentMethodDataTypes>(
         SetupMandateRequestData<T>,
         PaymentsResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<PaymentServiceRegisterResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
     let transaction_response = router_data_v2.response;
     let status = router_data_v2.resource_common_data.status;
     let grpc_status = grpc_api_types::payments::PaymentStatus::foreign_from(status);
@@ -3812,6 +6374,15 @@ pub fn generate_setup_mandate_response<T: PaymentMethodDataTypes>(
                                         ))
                                     })
                                 },
+                                router_response_types::RedirectForm::Mifinity { initialization_token } => {
+                                    Ok(grpc_api_types::payments::RedirectForm {
+                                        form_type: Some(grpc_api_types::payments::redirect_form::FormType::Mifinity(
+                                            grpc_api_types::payments::MifinityInitialization {
+                                                initialization_token,
+                                            }
+                                        ))
+                                    })
+                                },
                                 _ => Err(
                                     ApplicationErrorResponse::BadRequest(ApiError {
                                         sub_code: "INVALID_RESPONSE".to_owned(),
@@ -3834,9 +6405,14 @@ pub fn generate_setup_mandate_response<T: PaymentMethodDataTypes>(
                     error_message: None,
                     error_code: None,
                     status_code: status_code as u32,
+                    connector_http_status_code,
                     response_headers: router_data_v2
                         .resource_common_data
-                        .get_connector_response_headers_as_map()
+                        .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    raw_error_message: None,
                 }
             }
             _ => Err(ApplicationErrorResponse::BadRequest(ApiError {
@@ -3863,9 +6439,14 @@ pub fn generate_setup_mandate_response<T: PaymentMethodDataTypes>(
             error_message: Some(err.message),
             error_code: Some(err.code),
             status_code: err.status_code as u32,
+            connector_http_status_code,
             response_headers: router_data_v2
                 .resource_common_data
                 .get_connector_response_headers_as_map(),
+            connector: Some(connector.to_string()),
+            environment,
+            payment_id: None,
+            raw_error_message: None,
         },
     };
     Ok(response)
@@ -3901,12 +6482,13 @@ impl
     type Error = ApplicationErrorResponse;
 
     fn foreign_try_from(
-        (value, connectors, _metadata): (
+        (value, mut connectors, metadata): (
             DisputeDefendRequest,
             Connectors,
             &tonic::metadata::MetadataMap,
         ),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        apply_base_url_override(&mut connectors, metadata, None)?;
         Ok(DisputeFlowData {
             connector_request_reference_id: extract_connector_request_reference_id(
                 &value.request_ref_id,
@@ -3943,7 +6525,9 @@ pub fn generate_defend_dispute_response(
         DisputeDefendData,
         DisputeResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<DisputeDefendResponse, error_stack::Report<ApplicationErrorResponse>> {
+    let environment = response_environment(None);
     let defend_dispute_response = router_data_v2.response;
 
     match defend_dispute_response {
@@ -3958,6 +6542,10 @@ pub fn generate_defend_dispute_response(
             response_headers: router_data_v2
                 .resource_common_data
                 .get_connector_response_headers_as_map(),
+            connector: Some(connector.to_string()),
+            environment,
+            payment_id: None,
+            raw_error_message: None,
         }),
         Err(e) => Ok(DisputeDefendResponse {
             dispute_id: e
@@ -3972,6 +6560,10 @@ pub fn generate_defend_dispute_response(
             response_headers: router_data_v2
                 .resource_common_data
                 .get_connector_response_headers_as_map(),
+            connector: Some(connector.to_string()),
+            environment,
+            payment_id: None,
+            raw_error_message: None,
         }),
     }
 }
@@ -4226,6 +6818,19 @@ impl ForeignTryFrom<grpc_api_types::payments::BrowserInformation> for BrowserInf
     fn foreign_try_from(
         value: grpc_api_types::payments::BrowserInformation,
     ) -> Result<Self, error_stack::Report<Self::Error>> {
+        // Connectors need a valid IP for 3DS, so a malformed one is rejected instead of dropped
+        let ip_address = value
+            .ip_address
+            .map(|ip| {
+                ip.parse::<IpAddr>()
+                    .change_context(ApplicationErrorResponse::BadRequest(ApiError {
+                        sub_code: "INVALID_IP_ADDRESS".to_owned(),
+                        error_identifier: 400,
+                        error_message: "Invalid IP address in browser info".to_owned(),
+                        error_object: None,
+                    }))
+            })
+            .transpose()?;
         Ok(Self {
             color_depth: value.color_depth.map(|cd| cd as u8),
             java_enabled: value.java_enabled,
@@ -4234,7 +6839,7 @@ impl ForeignTryFrom<grpc_api_types::payments::BrowserInformation> for BrowserInf
             screen_height: value.screen_height,
             screen_width: value.screen_width,
             time_zone: value.time_zone_offset_minutes,
-            ip_address: value.ip_address.and_then(|ip| ip.parse().ok()),
+            ip_address,
             accept_header: value.accept_header,
             user_agent: value.user_agent,
             os_type: value.os_type,
@@ -4281,6 +6886,14 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceRepeatEverythingRequ
         let metadata = value.metadata;
         let webhook_url = value.webhook_url;
 
+        // Repeat payments are merchant-initiated charges of a stored credential unless the
+        // request says otherwise
+        let stored_credential_usage = value
+            .stored_credential_usage
+            .map(StoredCredentialUsage::foreign_try_from)
+            .transpose()?
+            .unwrap_or_else(StoredCredentialUsage::unscheduled_merchant_initiated);
+
         // Extract mandate reference
         let mandate_reference = value.mandate_reference.clone().ok_or_else(|| {
             ApplicationErrorResponse::BadRequest(ApiError {
@@ -4336,15 +6949,14 @@ impl ForeignTryFrom<grpc_api_types::payments::PaymentServiceRepeatEverythingRequ
             },
             webhook_url,
             integrity_object: None,
-            capture_method: Some(common_enums::CaptureMethod::foreign_try_from(
-                capture_method,
-            )?),
+            capture_method: optional_capture_method(capture_method)?,
             email,
             browser_info: value
                 .browser_info
                 .map(BrowserInformation::foreign_try_from)
                 .transpose()?,
             payment_method_type,
+            stored_credential_usage,
         })
     }
 }
@@ -4394,6 +7006,15 @@ impl
             external_latency: None,
             connectors,
             raw_connector_response: None,
+            card_issuer_info: None,
+            fraud_check: None,
+            settlement_details: None,
+            sca_exemption_decision: None,
+            payment_method_details: None,
+            connector_warnings: Vec::new(),
+            installment_plan: None,
+            three_ds_failure: None,
+            three_ds_completion: None,
             connector_response_headers: None,
         })
     }
@@ -4406,10 +7027,16 @@ pub fn generate_repeat_payment_response(
         RepeatPaymentData,
         PaymentsResponseData,
     >,
+    connector: ConnectorEnum,
 ) -> Result<
     grpc_api_types::payments::PaymentServiceRepeatEverythingResponse,
     error_stack::Report<ApplicationErrorResponse>,
 > {
+    let environment = response_environment(router_data_v2.resource_common_data.test_mode);
+    let connector_http_status_code = router_data_v2
+        .resource_common_data
+        .connector_http_status_code
+        .map(u32::from);
     let transaction_response = router_data_v2.response;
     let status = router_data_v2.resource_common_data.status;
     let grpc_status = grpc_api_types::payments::PaymentStatus::foreign_from(status);
@@ -4439,10 +7066,15 @@ pub fn generate_repeat_payment_response(
                         }
                     }),
                     status_code: status_code as u32,
+                    connector_http_status_code,
                     raw_connector_response,
                     response_headers: router_data_v2
                         .resource_common_data
                         .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    raw_error_message: None,
                 },
             ),
             _ => Err(ApplicationErrorResponse::BadRequest(ApiError {
@@ -4475,11 +7107,19 @@ pub fn generate_repeat_payment_response(
                     }),
                     raw_connector_response: None,
                     status_code: err.status_code as u32,
+                    connector_http_status_code,
                     response_headers: router_data_v2
                         .resource_common_data
                         .get_connector_response_headers_as_map(),
+                    connector: Some(connector.to_string()),
+                    environment,
+                    payment_id: None,
+                    raw_error_message: None,
                 },
             )
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,157 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::{
+    connector_types::FraudCheckInfo,
+    router_data::{ConnectorAuthType, ErrorResponse},
+};
+
+mod amount_rounding;
+mod amount_unit;
+mod bank_redirect;
+mod base_url_override;
+mod browser_info;
+mod card_issuer_info;
+mod co_badged_card;
+mod connector_http_status;
+mod connector_metadata;
+mod connector_warnings;
+mod country;
+mod cvc_requirement;
+mod default_capture_method;
+mod external_three_ds;
+mod fraud_check;
+mod installments;
+mod ip_network;
+mod mandate_reference;
+mod mifinity_next_action;
+mod network_token;
+mod next_action;
+mod parse_connector_meta;
+mod payment_method_details;
+mod payment_method_type;
+mod payment_status;
+mod poll_hint;
+mod refund_amount;
+mod refund_reference;
+mod required_fields;
+mod response_origin;
+mod samsung_pay_paze;
+mod sca_exemption;
+mod scheduled_capture;
+mod settlement_details;
+mod setup_future_usage;
+mod single_payment_method;
+mod soft_decline;
+mod status_conflict;
+mod stored_credential;
+mod sync_amount;
+mod sync_encoded_data;
+mod three_ds_completion;
+mod three_ds_failure;
+mod upi_qr;
+mod voucher;
+
+fn payment_flow_data(
+    status: common_enums::AttemptStatus,
+    connector_http_status_code: Option<u16>,
+) -> PaymentFlowData {
+    PaymentFlowData {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        customer_id: None,
+        connector_customer: None,
+        payment_id: "pay_123".to_string(),
+        attempt_id: "attempt_123".to_string(),
+        status,
+        payment_method: common_enums::PaymentMethod::Card,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::new(None, None, None, None),
+        auth_type: common_enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        preprocessing_id: None,
+        connector_api_version: None,
+        connector_request_reference_id: "ref_123".to_string(),
+        test_mode: None,
+        connector_http_status_code,
+        connectors: Connectors::default(),
+        external_latency: None,
+        connector_response_headers: None,
+        raw_connector_response: None,
+        card_issuer_info: None,
+        fraud_check: None,
+        settlement_details: None,
+        sca_exemption_decision: None,
+        payment_method_details: None,
+        connector_warnings: Vec::new(),
+        installment_plan: None,
+        three_ds_failure: None,
+        three_ds_completion: None,
+    }
+}
+
+fn sync_router_data(
+    resource_common_data: PaymentFlowData,
+    response: Result<PaymentsResponseData, ErrorResponse>,
+) -> RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData> {
+    RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data,
+        connector_auth_type: ConnectorAuthType::NoKey,
+        request: PaymentsSyncData::default(),
+        response,
+    }
+}
+
+fn authorize_router_data(
+    fraud_check: Option<FraudCheckInfo>,
+) -> RouterDataV2<
+    Authorize,
+    PaymentFlowData,
+    PaymentsAuthorizeData<DefaultPCIHolder>,
+    PaymentsResponseData,
+> {
+    let request = PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(
+                    grpc_api_types::payments::NetworkTokenDetails {
+                        network_token: Some(Secret::new("4111111111111111".to_string())),
+                        network_token_exp_month: Some(Secret::new("12".to_string())),
+                        network_token_exp_year: Some(Secret::new("30".to_string())),
+                        ..Default::default()
+                    },
+                ),
+            ),
+        }),
+        ..Default::default()
+    };
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200));
+    resource_common_data.fraud_check = fraud_check;
+    RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data,
+        connector_auth_type: ConnectorAuthType::NoKey,
+        request: PaymentsAuthorizeData::foreign_try_from(request).unwrap(),
+        response: Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 200,
+        }),
+    }
+}
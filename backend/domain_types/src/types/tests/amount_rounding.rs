@@ -0,0 +1,49 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+/// Connector that accepts two decimal places for the three decimal Kuwaiti dinar
+fn params(amount_rounding: AmountRounding) -> ConnectorParams {
+    ConnectorParams {
+        amount_decimal_places: HashMap::from([(common_enums::Currency::KWD, 2)]),
+        amount_rounding,
+        ..Default::default()
+    }
+}
+
+fn round(amount_rounding: AmountRounding, amount: i64) -> i64 {
+    params(amount_rounding)
+        .round_amount(MinorUnit::new(amount), common_enums::Currency::KWD)
+        .unwrap()
+        .get_amount_as_i64()
+}
+
+#[test]
+fn half_up_rounds_halves_away_from_zero() {
+    assert_eq!(round(AmountRounding::HalfUp, 1234), 1230);
+    assert_eq!(round(AmountRounding::HalfUp, 1235), 1240);
+    assert_eq!(round(AmountRounding::HalfUp, 1245), 1250);
+    assert_eq!(round(AmountRounding::HalfUp, 1240), 1240);
+}
+
+#[test]
+fn half_even_rounds_halves_to_even() {
+    assert_eq!(round(AmountRounding::HalfEven, 1235), 1240);
+    assert_eq!(round(AmountRounding::HalfEven, 1245), 1240);
+    assert_eq!(round(AmountRounding::HalfEven, 1246), 1250);
+    assert_eq!(round(AmountRounding::HalfEven, 1244), 1240);
+}
+
+#[test]
+fn truncate_drops_extra_precision() {
+    assert_eq!(round(AmountRounding::Truncate, 1239), 1230);
+    assert_eq!(round(AmountRounding::Truncate, 1230), 1230);
+}
+
+#[test]
+fn currencies_without_configured_decimal_places_are_unchanged() {
+    let amount = params(AmountRounding::Truncate)
+        .round_amount(MinorUnit::new(1239), common_enums::Currency::USD)
+        .unwrap();
+
+    assert_eq!(amount, MinorUnit::new(1239));
+}
@@ -0,0 +1,35 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn major_unit_amount_scaled_by_currency_exponent() {
+    let amount = serde_json::Number::from_f64(10.5).unwrap();
+
+    assert_eq!(
+        AmountUnit::Major
+            .to_minor_unit(&amount, common_enums::Currency::USD)
+            .unwrap(),
+        MinorUnit::new(1050)
+    );
+    assert_eq!(
+        AmountUnit::Major
+            .to_minor_unit(&serde_json::Number::from(1050), common_enums::Currency::JPY)
+            .unwrap(),
+        MinorUnit::new(1050)
+    );
+}
+
+#[test]
+fn fractional_minor_unit_amount_is_rejected() {
+    let amount = serde_json::Number::from_f64(10.5).unwrap();
+
+    assert!(AmountUnit::Minor
+        .to_minor_unit(&amount, common_enums::Currency::USD)
+        .is_err());
+    assert_eq!(
+        AmountUnit::Minor
+            .to_minor_unit(&serde_json::Number::from(1050), common_enums::Currency::USD)
+            .unwrap(),
+        MinorUnit::new(1050)
+    );
+}
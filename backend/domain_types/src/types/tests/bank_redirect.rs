@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+use grpc_api_types::payments::bank_redirect_payment_method_type::BankRedirectType;
+
+fn bank_redirect(bank_redirect_type: BankRedirectType) -> grpc_api_types::payments::PaymentMethod {
+    grpc_api_types::payments::PaymentMethod {
+        payment_method: Some(
+            grpc_api_types::payments::payment_method::PaymentMethod::BankRedirect(
+                grpc_api_types::payments::BankRedirectPaymentMethodType {
+                    bank_redirect_type: Some(bank_redirect_type),
+                },
+            ),
+        ),
+    }
+}
+
+fn bad_request_sub_code(error: &error_stack::Report<ApplicationErrorResponse>) -> String {
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => api_error.sub_code.clone(),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn converts_eps_with_bank_and_country() {
+    let payment_method = bank_redirect(BankRedirectType::Eps(grpc_api_types::payments::Eps {
+        bank_name: Some("bank_austria".to_string()),
+        country: Some("AT".to_string()),
+    }));
+
+    let data =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method.clone()).unwrap();
+
+    match data {
+        PaymentMethodData::BankRedirect(payment_method_data::BankRedirectData::Eps {
+            bank_name,
+            country,
+        }) => {
+            assert_eq!(bank_name, Some(common_enums::BankNames::BankAustria));
+            assert_eq!(country, Some(common_enums::CountryAlpha2::AT));
+        }
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(payment_method.clone()).unwrap(),
+        Some(PaymentMethodType::Eps)
+    );
+    assert_eq!(
+        common_enums::PaymentMethod::foreign_try_from(payment_method).unwrap(),
+        common_enums::PaymentMethod::BankRedirect
+    );
+}
+
+#[test]
+fn rejects_eps_without_bank_name() {
+    let payment_method = bank_redirect(BankRedirectType::Eps(grpc_api_types::payments::Eps {
+        bank_name: None,
+        country: Some("AT".to_string()),
+    }));
+
+    let error =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method).unwrap_err();
+
+    assert_eq!(bad_request_sub_code(&error), "MISSING_BANK_NAME");
+}
+
+#[test]
+fn converts_interac_with_country_and_email() {
+    let payment_method = bank_redirect(BankRedirectType::Interac(
+        grpc_api_types::payments::Interac {
+            country: Some("CA".to_string()),
+            email: Some(Secret::new("customer@example.com".to_string())),
+        },
+    ));
+
+    let data =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method.clone()).unwrap();
+
+    match data {
+        PaymentMethodData::BankRedirect(payment_method_data::BankRedirectData::Interac {
+            country,
+            email,
+        }) => {
+            assert_eq!(country, Some(common_enums::CountryAlpha2::CA));
+            assert_eq!(
+                email.map(|email| email.expose().expose()),
+                Some("customer@example.com".to_string())
+            );
+        }
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(payment_method).unwrap(),
+        Some(PaymentMethodType::Interac)
+    );
+}
+
+#[test]
+fn rejects_interac_with_invalid_country() {
+    let payment_method = bank_redirect(BankRedirectType::Interac(
+        grpc_api_types::payments::Interac {
+            country: Some("Canada".to_string()),
+            email: None,
+        },
+    ));
+
+    let error =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method).unwrap_err();
+
+    assert_eq!(bad_request_sub_code(&error), "INVALID_COUNTRY");
+}
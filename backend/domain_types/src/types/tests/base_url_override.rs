@@ -0,0 +1,178 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn metadata(base_url: Option<&str>) -> tonic::metadata::MetadataMap {
+    let mut metadata = tonic::metadata::MetadataMap::new();
+    metadata.insert(
+        common_utils::consts::X_MERCHANT_ID,
+        "merchant_123".parse().unwrap(),
+    );
+    metadata.insert(common_utils::consts::X_CONNECTOR, "adyen".parse().unwrap());
+    if let Some(base_url) = base_url {
+        metadata.insert(
+            common_utils::consts::X_CONNECTOR_BASE_URL,
+            base_url.parse().unwrap(),
+        );
+    }
+    metadata
+}
+
+fn connectors(allowed_base_url_overrides: &[&str]) -> Connectors {
+    let mut connectors = Connectors::default();
+    connectors.adyen.base_url = "https://checkout-test.adyen.com/".to_string();
+    connectors.adyen.allowed_base_url_overrides = allowed_base_url_overrides
+        .iter()
+        .map(|base_url| base_url.to_string())
+        .collect();
+    connectors
+}
+
+fn sync_flow_data(
+    connectors: Connectors,
+    metadata: &tonic::metadata::MetadataMap,
+) -> Result<PaymentFlowData, error_stack::Report<ApplicationErrorResponse>> {
+    PaymentFlowData::foreign_try_from((
+        grpc_api_types::payments::PaymentServiceGetRequest::default(),
+        connectors,
+        metadata,
+    ))
+}
+
+fn sub_code(error: &error_stack::Report<ApplicationErrorResponse>) -> &str {
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error)
+        | ApplicationErrorResponse::ForbiddenCommonResource(api_error) => &api_error.sub_code,
+        _ => "",
+    }
+}
+
+const SANDBOX_URL: &str = "https://sandbox.example.com/";
+const HTTP_SANDBOX_URL: &str = "http://sandbox.example.com/";
+
+#[test]
+fn override_replaces_the_selected_connector_base_url() {
+    let flow_data =
+        sync_flow_data(connectors(&[SANDBOX_URL]), &metadata(Some(SANDBOX_URL))).unwrap();
+
+    assert_eq!(flow_data.connectors.adyen.base_url, SANDBOX_URL);
+}
+
+#[test]
+fn base_url_is_kept_without_override() {
+    let flow_data = sync_flow_data(connectors(&[SANDBOX_URL]), &metadata(None)).unwrap();
+
+    assert_eq!(
+        flow_data.connectors.adyen.base_url,
+        "https://checkout-test.adyen.com/"
+    );
+}
+
+#[test]
+fn invalid_override_is_rejected() {
+    let error =
+        sync_flow_data(connectors(&[SANDBOX_URL]), &metadata(Some("not a url"))).unwrap_err();
+
+    assert_eq!(sub_code(&error), "INVALID_CONNECTOR_BASE_URL");
+}
+
+#[test]
+fn override_outside_the_allow_list_is_rejected() {
+    for base_url in [
+        "https://attacker.example.com/",
+        "http://sandbox.example.com/",
+    ] {
+        let error =
+            sync_flow_data(connectors(&[SANDBOX_URL]), &metadata(Some(base_url))).unwrap_err();
+        assert_eq!(
+            sub_code(&error),
+            "BASE_URL_OVERRIDE_NOT_ALLOWED",
+            "{base_url}"
+        );
+    }
+}
+
+#[test]
+fn override_requires_a_configured_allow_list() {
+    let error = sync_flow_data(connectors(&[]), &metadata(Some(SANDBOX_URL))).unwrap_err();
+
+    assert_eq!(sub_code(&error), "BASE_URL_OVERRIDE_NOT_ALLOWED");
+}
+
+#[test]
+fn override_applies_to_refunds_and_disputes() {
+    let metadata = metadata(Some(SANDBOX_URL));
+    let refund_flow_data = RefundFlowData::foreign_try_from((
+        grpc_api_types::payments::PaymentServiceRefundRequest::default(),
+        connectors(&[SANDBOX_URL]),
+        &metadata,
+    ))
+    .unwrap();
+    let dispute_flow_data = DisputeFlowData::foreign_try_from((
+        grpc_api_types::payments::AcceptDisputeRequest::default(),
+        connectors(&[SANDBOX_URL]),
+        &metadata,
+    ))
+    .unwrap();
+
+    assert_eq!(refund_flow_data.connectors.adyen.base_url, SANDBOX_URL);
+    assert_eq!(dispute_flow_data.connectors.adyen.base_url, SANDBOX_URL);
+}
+
+#[test]
+fn override_applies_to_the_default_connector_without_x_connector() {
+    let mut metadata = metadata(Some(SANDBOX_URL));
+    metadata.remove(common_utils::consts::X_CONNECTOR);
+    let mut connectors = connectors(&[SANDBOX_URL]);
+    connectors.default_connector = Some(ConnectorEnum::Adyen);
+
+    let flow_data = sync_flow_data(connectors, &metadata).unwrap();
+
+    assert_eq!(flow_data.connectors.adyen.base_url, SANDBOX_URL);
+}
+
+#[test]
+fn override_without_any_connector_is_rejected() {
+    let mut metadata = metadata(Some(SANDBOX_URL));
+    metadata.remove(common_utils::consts::X_CONNECTOR);
+
+    let error = sync_flow_data(connectors(&[SANDBOX_URL]), &metadata).unwrap_err();
+
+    assert_eq!(sub_code(&error), "INVALID_CONNECTOR_BASE_URL");
+}
+
+#[test]
+fn allowed_http_override_is_rejected_outside_test_mode() {
+    for test_mode in [None, Some(false)] {
+        let mut connectors = connectors(&[HTTP_SANDBOX_URL]);
+        let error = apply_base_url_override(
+            &mut connectors,
+            &metadata(Some(HTTP_SANDBOX_URL)),
+            test_mode,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            sub_code(&error),
+            "INVALID_CONNECTOR_BASE_URL",
+            "{test_mode:?}"
+        );
+        assert_eq!(
+            connectors.adyen.base_url,
+            "https://checkout-test.adyen.com/"
+        );
+    }
+}
+
+#[test]
+fn allowed_http_override_is_accepted_in_test_mode() {
+    let mut connectors = connectors(&[HTTP_SANDBOX_URL]);
+
+    apply_base_url_override(
+        &mut connectors,
+        &metadata(Some(HTTP_SANDBOX_URL)),
+        Some(true),
+    )
+    .unwrap();
+
+    assert_eq!(connectors.adyen.base_url, HTTP_SANDBOX_URL);
+}
@@ -0,0 +1,44 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn browser_info(ip_address: Option<&str>) -> grpc_api_types::payments::BrowserInformation {
+    grpc_api_types::payments::BrowserInformation {
+        ip_address: ip_address.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn parses_ipv4_address() {
+    let info = BrowserInformation::foreign_try_from(browser_info(Some("203.0.113.7"))).unwrap();
+
+    assert_eq!(info.ip_address, Some("203.0.113.7".parse().unwrap()));
+}
+
+#[test]
+fn parses_ipv6_address() {
+    let info = BrowserInformation::foreign_try_from(browser_info(Some("2001:db8::1"))).unwrap();
+
+    assert_eq!(info.ip_address, Some("2001:db8::1".parse().unwrap()));
+}
+
+#[test]
+fn absent_ip_address_stays_none() {
+    let info = BrowserInformation::foreign_try_from(browser_info(None)).unwrap();
+
+    assert_eq!(info.ip_address, None);
+}
+
+#[test]
+fn rejects_malformed_ip_address() {
+    let error =
+        BrowserInformation::foreign_try_from(browser_info(Some("203.0.113.999"))).unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "INVALID_IP_ADDRESS")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
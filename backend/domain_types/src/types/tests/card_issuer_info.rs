@@ -0,0 +1,45 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn sync_response_surfaces_card_issuer_info() {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200));
+    resource_common_data.card_issuer_info = Some(crate::connector_types::CardIssuerInfo {
+        card_issuer: Some("Example Bank".to_string()),
+        card_issuing_country: Some("NL".to_string()),
+        bin: Some("411111".to_string()),
+    });
+    let router_data = sync_router_data(
+        resource_common_data,
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 200,
+        }),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.card_issuer.as_deref(), Some("Example Bank"));
+    assert_eq!(response.card_issuing_country.as_deref(), Some("NL"));
+    assert_eq!(response.bin.as_deref(), Some("411111"));
+}
+
+#[test]
+fn sync_response_omits_card_issuer_info_when_not_returned() {
+    let router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Pending, None),
+        Err(ErrorResponse::default()),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.card_issuer, None);
+    assert_eq!(response.bin, None);
+}
@@ -0,0 +1,66 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn co_badged_card_details(
+    card_network: grpc_api_types::payments::CardNetwork,
+) -> grpc_api_types::payments::CardDetails {
+    grpc_api_types::payments::CardDetails {
+        card_number: Some(cards::CardNumber::from_str("4000000000001000").unwrap()),
+        card_exp_month: Some(Secret::new("03".to_string())),
+        card_exp_year: Some(Secret::new("2030".to_string())),
+        card_cvc: Some(Secret::new("737".to_string())),
+        card_network: Some(card_network as i32),
+        co_badged_card_data: Some(grpc_api_types::payments::CoBadgedCardData {
+            co_badged_card_networks: vec![
+                grpc_api_types::payments::CardNetwork::CartesBancaires as i32,
+                grpc_api_types::payments::CardNetwork::Visa as i32,
+            ],
+            issuer_country_alpha2: "FR".to_string(),
+            is_regulated: true,
+            regulated_name: None,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn converts_co_badged_card_with_two_networks() {
+    let card = payment_method_data::Card::<DefaultPCIHolder>::foreign_try_from(
+        co_badged_card_details(grpc_api_types::payments::CardNetwork::CartesBancaires),
+    )
+    .unwrap();
+
+    assert_eq!(
+        card.card_network,
+        Some(common_enums::CardNetwork::CartesBancaires)
+    );
+    let co_badged_card_data = card.co_badged_card_data.unwrap();
+    assert_eq!(
+        co_badged_card_data.co_badged_card_networks,
+        vec![
+            common_enums::CardNetwork::CartesBancaires,
+            common_enums::CardNetwork::Visa
+        ]
+    );
+    assert_eq!(
+        co_badged_card_data.issuer_country_code,
+        common_enums::CountryAlpha2::FR
+    );
+    assert!(co_badged_card_data.is_regulated);
+}
+
+#[test]
+fn rejects_network_outside_co_badged_networks() {
+    let error = payment_method_data::Card::<DefaultPCIHolder>::foreign_try_from(
+        co_badged_card_details(grpc_api_types::payments::CardNetwork::Mastercard),
+    )
+    .unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "INVALID_CO_BADGED_CARD_NETWORK")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::router_data::ErrorResponse;
+
+#[test]
+fn sync_response_keeps_domain_status_and_connector_http_status() {
+    let router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(202)),
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 202,
+        }),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(
+        response.status(),
+        grpc_api_types::payments::PaymentStatus::Charged
+    );
+    assert_eq!(response.connector_http_status_code, Some(202));
+}
+
+#[test]
+fn sync_error_response_keeps_domain_status_and_connector_http_status() {
+    let router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Pending, Some(402)),
+        Err(ErrorResponse {
+            status_code: 402,
+            attempt_status: Some(common_enums::AttemptStatus::Failure),
+            ..Default::default()
+        }),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(
+        response.status(),
+        grpc_api_types::payments::PaymentStatus::Failure
+    );
+    assert_eq!(response.connector_http_status_code, Some(402));
+}
+
+#[test]
+fn connector_http_status_absent_without_connector_call() {
+    let router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Pending, None),
+        Err(ErrorResponse::default()),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.connector_http_status_code, None);
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::router_data::ConnectorAuthType;
+
+fn connector_metadata() -> serde_json::Value {
+    json!({
+        "order_id": "order_123",
+        "nested": {"session_id": "session_123"},
+        "attempts": 2,
+        "three_ds": true,
+        "tags": ["a", "b"],
+        "missing": null,
+    })
+}
+
+fn transaction_response(connector_metadata: serde_json::Value) -> PaymentsResponseData {
+    PaymentsResponseData::TransactionResponse {
+        resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+        redirection_data: None,
+        connector_metadata: Some(connector_metadata),
+        network_txn_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        mandate_reference: None,
+        status_code: 200,
+    }
+}
+
+fn expected_metadata() -> HashMap<String, String> {
+    HashMap::from([("order_id".to_string(), "order_123".to_string())])
+}
+
+#[test]
+fn flattening_keeps_only_top_level_string_values() {
+    assert_eq!(
+        flatten_connector_metadata(Some(connector_metadata())),
+        expected_metadata()
+    );
+    assert!(flatten_connector_metadata(Some(json!(["order_123"]))).is_empty());
+    assert!(flatten_connector_metadata(None).is_empty());
+}
+
+#[test]
+fn authorize_response_flattens_connector_metadata() {
+    let mut router_data = authorize_router_data(None);
+    router_data.response = Ok(transaction_response(connector_metadata()));
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.connector_metadata, expected_metadata());
+}
+
+#[test]
+fn sync_response_flattens_connector_metadata() {
+    let router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200)),
+        Ok(transaction_response(connector_metadata())),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.metadata, expected_metadata());
+}
+
+#[test]
+fn void_response_flattens_connector_metadata() {
+    let router_data = RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data: payment_flow_data(common_enums::AttemptStatus::Voided, Some(200)),
+        connector_auth_type: ConnectorAuthType::NoKey,
+        request: PaymentVoidData {
+            connector_transaction_id: "txn_123".to_string(),
+            cancellation_reason: None,
+            integrity_object: None,
+            raw_connector_response: None,
+            browser_info: None,
+        },
+        response: Ok(transaction_response(connector_metadata())),
+    };
+
+    let response = generate_payment_void_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.connector_metadata, expected_metadata());
+}
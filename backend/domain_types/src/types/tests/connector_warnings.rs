@@ -0,0 +1,45 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn avs_warning() -> Vec<String> {
+    vec!["AVS: Neither postal code nor address match".to_string()]
+}
+
+#[test]
+fn authorize_response_surfaces_connector_warnings() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.connector_warnings = avs_warning();
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(
+        response.status(),
+        grpc_api_types::payments::PaymentStatus::Charged
+    );
+    assert_eq!(response.error_code, None);
+    assert_eq!(response.warnings, avs_warning());
+}
+
+#[test]
+fn sync_response_surfaces_connector_warnings() {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200));
+    resource_common_data.connector_warnings = avs_warning();
+    let router_data = sync_router_data(
+        resource_common_data,
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 200,
+        }),
+    );
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.warnings, avs_warning());
+}
@@ -0,0 +1,54 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn address(
+    country: Option<grpc_api_types::payments::CountryAlpha2>,
+) -> grpc_api_types::payments::Address {
+    grpc_api_types::payments::Address {
+        line1: Some(Secret::new("Keizersgracht 1".to_string())),
+        country_alpha2_code: country.map(i32::from),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn converts_specified_country() {
+    assert_eq!(
+        common_enums::CountryAlpha2::foreign_try_from(grpc_api_types::payments::CountryAlpha2::Nl)
+            .unwrap(),
+        common_enums::CountryAlpha2::NL
+    );
+
+    let address = AddressDetails::foreign_try_from(address(Some(
+        grpc_api_types::payments::CountryAlpha2::Nl,
+    )))
+    .unwrap();
+    assert_eq!(address.country, Some(common_enums::CountryAlpha2::NL));
+}
+
+#[test]
+fn rejects_unspecified_country() {
+    let error = AddressDetails::foreign_try_from(address(Some(
+        grpc_api_types::payments::CountryAlpha2::Unspecified,
+    )))
+    .unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "UNSPECIFIED_COUNTRY")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn address_without_country_keeps_it_unset() {
+    let address = AddressDetails::foreign_try_from(address(None)).unwrap();
+
+    assert_eq!(address.country, None);
+    assert_eq!(
+        address.line1.map(|line| line.expose()).as_deref(),
+        Some("Keizersgracht 1")
+    );
+}
@@ -0,0 +1,111 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn cvc_less_authorize_request(
+    initiator: grpc_api_types::payments::StoredCredentialInitiator,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::Card(
+                    grpc_api_types::payments::CardPaymentMethodType {
+                        card_type: Some(
+                            grpc_api_types::payments::card_payment_method_type::CardType::Credit(
+                                grpc_api_types::payments::CardDetails {
+                                    card_number: Some(
+                                        cards::CardNumber::from_str("4111111111111111").unwrap(),
+                                    ),
+                                    card_exp_month: Some(Secret::new("03".to_string())),
+                                    card_exp_year: Some(Secret::new("2030".to_string())),
+                                    card_cvc: None,
+                                    card_network: Some(
+                                        grpc_api_types::payments::CardNetwork::Visa as i32,
+                                    ),
+                                    ..Default::default()
+                                },
+                            ),
+                        ),
+                    },
+                ),
+            ),
+        }),
+        stored_credential_usage: Some(grpc_api_types::payments::StoredCredentialUsage {
+            sequence: grpc_api_types::payments::StoredCredentialSequence::Subsequent.into(),
+            initiator: initiator.into(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn merchant_initiated_authorize_accepts_card_without_cvc() {
+    let request =
+        cvc_less_authorize_request(grpc_api_types::payments::StoredCredentialInitiator::Merchant);
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from((
+        request,
+        CvcRequirement::Optional,
+    ))
+    .unwrap();
+
+    match data.payment_method_data {
+        PaymentMethodData::Card(card) => assert!(card.card_cvc.peek().is_empty()),
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+}
+
+#[test]
+fn merchant_initiated_authorize_accepts_card_without_cvc_when_connector_needs_it() {
+    let request =
+        cvc_less_authorize_request(grpc_api_types::payments::StoredCredentialInitiator::Merchant);
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from((
+        request,
+        CvcRequirement::Required,
+    ))
+    .unwrap();
+
+    match data.payment_method_data {
+        PaymentMethodData::Card(card) => assert!(card.card_cvc.peek().is_empty()),
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+}
+
+#[test]
+fn customer_initiated_authorize_requires_cvc() {
+    let request =
+        cvc_less_authorize_request(grpc_api_types::payments::StoredCredentialInitiator::Cardholder);
+
+    let error = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap_err();
+
+    let missing_cvc = error.frames().any(|frame| {
+        matches!(
+            frame.downcast_ref::<ApplicationErrorResponse>(),
+            Some(ApplicationErrorResponse::BadRequest(api_error))
+                if api_error.sub_code == "MISSING_CVC"
+        )
+    });
+    assert!(missing_cvc);
+}
+
+#[test]
+fn connector_without_cvc_accepts_customer_initiated_authorize() {
+    let request =
+        cvc_less_authorize_request(grpc_api_types::payments::StoredCredentialInitiator::Cardholder);
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from((
+        request,
+        CvcRequirement::Optional,
+    ))
+    .unwrap();
+
+    assert!(matches!(
+        data.payment_method_data,
+        PaymentMethodData::Card(_)
+    ));
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn capture_method(
+    requested: Option<grpc_api_types::payments::CaptureMethod>,
+    connector_default: Option<common_enums::CaptureMethod>,
+) -> common_enums::CaptureMethod {
+    let mut request = PaymentServiceAuthorizeRequest {
+        capture_method: requested.map(i32::from),
+        ..Default::default()
+    };
+    apply_default_capture_method(&mut request, connector_default);
+    common_enums::CaptureMethod::foreign_try_from(request.capture_method()).unwrap()
+}
+
+#[test]
+fn connector_default_used_when_unspecified() {
+    assert_eq!(
+        capture_method(None, Some(common_enums::CaptureMethod::Manual)),
+        common_enums::CaptureMethod::Manual
+    );
+    assert_eq!(
+        capture_method(
+            Some(grpc_api_types::payments::CaptureMethod::Unspecified),
+            Some(common_enums::CaptureMethod::Manual)
+        ),
+        common_enums::CaptureMethod::Manual
+    );
+}
+
+#[test]
+fn requested_capture_method_wins_over_connector_default() {
+    assert_eq!(
+        capture_method(
+            Some(grpc_api_types::payments::CaptureMethod::Automatic),
+            Some(common_enums::CaptureMethod::Manual)
+        ),
+        common_enums::CaptureMethod::Automatic
+    );
+}
+
+#[test]
+fn connector_default_applied_to_repeat_requests() {
+    let mut request = grpc_api_types::payments::PaymentServiceRepeatEverythingRequest::default();
+    apply_default_capture_method(&mut request, Some(common_enums::CaptureMethod::Manual));
+
+    assert_eq!(
+        request.capture_method(),
+        grpc_api_types::payments::CaptureMethod::Manual
+    );
+}
+
+#[test]
+fn refund_without_capture_method_is_accepted() {
+    let request = grpc_api_types::payments::PaymentServiceRefundRequest {
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Unspecified.into()),
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        ..Default::default()
+    };
+
+    let refunds_data = RefundsData::foreign_try_from(request).unwrap();
+
+    assert_eq!(refunds_data.capture_method, None);
+}
+
+#[test]
+fn rejected_without_connector_default() {
+    let mut request = PaymentServiceAuthorizeRequest::default();
+    apply_default_capture_method(&mut request, None);
+
+    let error =
+        common_enums::CaptureMethod::foreign_try_from(request.capture_method()).unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "UNSPECIFIED_CAPTURE_METHOD")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn converts_each_specified_capture_method() {
+    for (requested, expected) in [
+        (
+            grpc_api_types::payments::CaptureMethod::Automatic,
+            common_enums::CaptureMethod::Automatic,
+        ),
+        (
+            grpc_api_types::payments::CaptureMethod::Manual,
+            common_enums::CaptureMethod::Manual,
+        ),
+        (
+            grpc_api_types::payments::CaptureMethod::ManualMultiple,
+            common_enums::CaptureMethod::ManualMultiple,
+        ),
+        (
+            grpc_api_types::payments::CaptureMethod::Scheduled,
+            common_enums::CaptureMethod::Scheduled,
+        ),
+        (
+            grpc_api_types::payments::CaptureMethod::SequentialAutomatic,
+            common_enums::CaptureMethod::SequentialAutomatic,
+        ),
+    ] {
+        assert_eq!(
+            common_enums::CaptureMethod::foreign_try_from(requested).unwrap(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn authentication_data() -> grpc_api_types::payments::AuthenticationData {
+    grpc_api_types::payments::AuthenticationData {
+        eci: Some("05".to_string()),
+        cavv: "AAABBEg0VhI0VniQEjRWAAAAAAA=".to_string(),
+        threeds_server_transaction_id: Some(grpc_api_types::payments::Identifier {
+            id_type: Some(grpc_api_types::payments::identifier::IdType::Id(
+                "3ds_server_txn_123".to_string(),
+            )),
+        }),
+        message_version: Some("2.2.0".to_string()),
+        ds_transaction_id: Some("ds_txn_123".to_string()),
+        trans_status: None,
+    }
+}
+
+fn externally_authenticated_request(
+    authentication_data: Option<grpc_api_types::payments::AuthenticationData>,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Eur.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::Card(
+                    grpc_api_types::payments::CardPaymentMethodType {
+                        card_type: Some(
+                            grpc_api_types::payments::card_payment_method_type::CardType::Credit(
+                                grpc_api_types::payments::CardDetails {
+                                    card_number: Some(
+                                        cards::CardNumber::from_str("4111111111111111").unwrap(),
+                                    ),
+                                    card_exp_month: Some(Secret::new("03".to_string())),
+                                    card_exp_year: Some(Secret::new("2030".to_string())),
+                                    card_cvc: Some(Secret::new("737".to_string())),
+                                    ..Default::default()
+                                },
+                            ),
+                        ),
+                    },
+                ),
+            ),
+        }),
+        auth_type: grpc_api_types::payments::AuthenticationType::ExternalThreeDs.into(),
+        authentication_data,
+        ..Default::default()
+    }
+}
+
+fn invalid_authentication_data(request: PaymentServiceAuthorizeRequest) -> String {
+    let error = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap_err();
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => api_error.sub_code.clone(),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn converts_external_authentication_data() {
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(
+        externally_authenticated_request(Some(grpc_api_types::payments::AuthenticationData {
+            trans_status: Some(" y ".to_string()),
+            ..authentication_data()
+        })),
+    )
+    .unwrap();
+    let authentication_data = data.authentication_data.unwrap();
+
+    assert_eq!(authentication_data.eci.as_deref(), Some("05"));
+    assert_eq!(
+        authentication_data.cavv.expose(),
+        "AAABBEg0VhI0VniQEjRWAAAAAAA="
+    );
+    assert_eq!(
+        authentication_data.threeds_server_transaction_id.as_deref(),
+        Some("3ds_server_txn_123")
+    );
+    assert_eq!(
+        authentication_data
+            .message_version
+            .map(|version| version.to_string()),
+        Some("2.2.0".to_string())
+    );
+    assert_eq!(
+        authentication_data.ds_trans_id.as_deref(),
+        Some("ds_txn_123")
+    );
+    assert_eq!(authentication_data.trans_status.as_deref(), Some("Y"));
+}
+
+#[test]
+fn external_three_ds_requires_authentication_data() {
+    assert_eq!(
+        invalid_authentication_data(externally_authenticated_request(None)),
+        "INVALID_AUTHENTICATION_DATA"
+    );
+}
+
+#[test]
+fn authentication_data_requires_cavv_and_valid_message_version() {
+    assert_eq!(
+        invalid_authentication_data(externally_authenticated_request(Some(
+            grpc_api_types::payments::AuthenticationData {
+                cavv: String::new(),
+                ..authentication_data()
+            }
+        ))),
+        "INVALID_AUTHENTICATION_DATA"
+    );
+    assert_eq!(
+        invalid_authentication_data(externally_authenticated_request(Some(
+            grpc_api_types::payments::AuthenticationData {
+                message_version: Some("two".to_string()),
+                ..authentication_data()
+            }
+        ))),
+        "INVALID_AUTHENTICATION_DATA"
+    );
+}
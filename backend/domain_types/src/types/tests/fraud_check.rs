@@ -0,0 +1,29 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::connector_types::{FraudCheckInfo, FraudDecision};
+
+#[test]
+fn authorize_response_surfaces_fraud_score_and_decision() {
+    let router_data = authorize_router_data(Some(FraudCheckInfo {
+        fraud_score: Some(85),
+        fraud_decision: Some(FraudDecision::Review),
+    }));
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.fraud_score, Some(85));
+    assert_eq!(
+        response.fraud_decision(),
+        grpc_api_types::payments::FraudDecision::Review
+    );
+}
+
+#[test]
+fn authorize_response_omits_fraud_check_when_not_returned() {
+    let response =
+        generate_payment_authorize_response(authorize_router_data(None), ConnectorEnum::Adyen)
+            .unwrap();
+
+    assert_eq!(response.fraud_score, None);
+    assert_eq!(response.fraud_decision, None);
+}
@@ -0,0 +1,58 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn installment_request(installment_count: Option<u32>) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        installment_count,
+        ..Default::default()
+    }
+}
+
+fn assert_invalid_installment_count(installment_count: u32, max_installment_count: Option<u32>) {
+    let error = validate_installment_count(
+        &installment_request(Some(installment_count)),
+        max_installment_count,
+    )
+    .unwrap_err();
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "INVALID_INSTALLMENT_COUNT")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn installment_count_validated_against_connector_limit() {
+    assert!(validate_installment_count(&installment_request(None), None).is_ok());
+    assert!(validate_installment_count(&installment_request(Some(12)), Some(12)).is_ok());
+
+    assert_invalid_installment_count(13, Some(12));
+    assert_invalid_installment_count(0, Some(12));
+    // Connectors without a configured limit do not support installments
+    assert_invalid_installment_count(3, None);
+}
+
+#[test]
+fn authorize_response_surfaces_installment_plan() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.installment_plan = Some(InstallmentPlan {
+        installment_count: 3,
+        installment_amount: Some(MinorUnit::new(10200)),
+        total_amount: Some(MinorUnit::new(30600)),
+        interest_rate: Some("1.99".to_string()),
+    });
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(
+        response.installment_plan,
+        Some(grpc_api_types::payments::InstallmentPlan {
+            installment_count: 3,
+            installment_amount: Some(10200),
+            total_amount: Some(30600),
+            interest_rate: Some("1.99".to_string()),
+        })
+    );
+}
@@ -0,0 +1,26 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn matches_addresses_inside_the_cidr_block() {
+    let network = IpNetwork::from_str("203.0.113.0/24").unwrap();
+
+    assert!(network.contains("203.0.113.7".parse().unwrap()));
+    assert!(network.contains("::ffff:203.0.113.7".parse().unwrap()));
+    assert!(!network.contains("203.0.114.7".parse().unwrap()));
+    assert!(!network.contains("2001:db8::1".parse().unwrap()));
+}
+
+#[test]
+fn single_address_matches_only_itself() {
+    let network = IpNetwork::from_str("2001:db8::1").unwrap();
+
+    assert!(network.contains("2001:db8::1".parse().unwrap()));
+    assert!(!network.contains("2001:db8::2".parse().unwrap()));
+}
+
+#[test]
+fn rejects_invalid_prefix_length() {
+    assert!(IpNetwork::from_str("203.0.113.0/33").is_err());
+    assert!(IpNetwork::from_str("not-an-ip/24").is_err());
+}
@@ -0,0 +1,42 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::connector_types::MandateReference;
+
+fn with_mandate_reference(
+    mandate_reference: Option<MandateReference>,
+) -> PaymentServiceAuthorizeResponse {
+    let mut router_data = authorize_router_data(None);
+    router_data.response = Ok(PaymentsResponseData::TransactionResponse {
+        resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+        redirection_data: None,
+        connector_metadata: None,
+        network_txn_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        mandate_reference: mandate_reference.map(Box::new),
+        status_code: 200,
+    });
+    generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap()
+}
+
+#[test]
+fn authorize_response_surfaces_mandate_reference() {
+    let response = with_mandate_reference(Some(MandateReference {
+        connector_mandate_id: Some("mandate_123".to_string()),
+        payment_method_id: None,
+    }));
+
+    assert_eq!(
+        response.status(),
+        grpc_api_types::payments::PaymentStatus::Charged
+    );
+    assert_eq!(
+        response.mandate_reference.unwrap().mandate_id.as_deref(),
+        Some("mandate_123")
+    );
+}
+
+#[test]
+fn authorize_response_omits_mandate_reference_when_none_was_stored() {
+    assert_eq!(with_mandate_reference(None).mandate_reference, None);
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+use grpc_api_types::payments::{next_action::Action, redirect_form::FormType};
+
+#[test]
+fn authorize_response_maps_mifinity_token_to_its_own_variant() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.status = common_enums::AttemptStatus::AuthenticationPending;
+    router_data.response = Ok(PaymentsResponseData::TransactionResponse {
+        resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+        redirection_data: Some(Box::new(router_response_types::RedirectForm::Mifinity {
+            initialization_token: "mifinity_token_123".to_string(),
+        })),
+        connector_metadata: None,
+        network_txn_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        mandate_reference: None,
+        status_code: 200,
+    });
+
+    let response =
+        generate_payment_authorize_response(router_data, ConnectorEnum::Mifinity).unwrap();
+
+    match response.redirection_data.unwrap().form_type.unwrap() {
+        FormType::Mifinity(mifinity) => {
+            assert_eq!(mifinity.initialization_token, "mifinity_token_123")
+        }
+        form_type => panic!("unexpected redirect form: {form_type:?}"),
+    }
+    match response.next_action.unwrap().action.unwrap() {
+        Action::InitializeMifinity(mifinity) => {
+            assert_eq!(mifinity.initialization_token, "mifinity_token_123")
+        }
+        action => panic!("unexpected next action: {action:?}"),
+    }
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn network_token_details() -> grpc_api_types::payments::NetworkTokenDetails {
+    grpc_api_types::payments::NetworkTokenDetails {
+        network_token: Some(Secret::new("4111111111111111".to_string())),
+        network_token_exp_month: Some(Secret::new("12".to_string())),
+        network_token_exp_year: Some(Secret::new("30".to_string())),
+        cryptogram: Some(Secret::new("AgAAAAAAAIR8CQrXcIhbQAAAAAA=".to_string())),
+        eci: Some("05".to_string()),
+        token_requestor_id: Some(Secret::new("40010030273".to_string())),
+        card_network: Some(grpc_api_types::payments::CardNetwork::Visa as i32),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn converts_network_token_payment_method() {
+    let payment_method = grpc_api_types::payments::PaymentMethod {
+        payment_method: Some(
+            grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(
+                network_token_details(),
+            ),
+        ),
+    };
+
+    let data = PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method).unwrap();
+
+    match data {
+        PaymentMethodData::NetworkToken(token) => {
+            assert_eq!(token.get_network_token().get_card_no(), "4111111111111111");
+            assert_eq!(token.get_expiry_year_4_digit().expose(), "2030");
+            assert_eq!(
+                token.get_cryptogram().map(ExposeInterface::expose),
+                Some("AgAAAAAAAIR8CQrXcIhbQAAAAAA=".to_string())
+            );
+            assert_eq!(token.card_network, Some(common_enums::CardNetwork::Visa));
+            assert_eq!(
+                token.token_requestor_id.map(ExposeInterface::expose),
+                Some("40010030273".to_string())
+            );
+        }
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+}
+
+fn cryptogram_less_authorize_request(
+    initiator: grpc_api_types::payments::StoredCredentialInitiator,
+    cryptogram: Option<Secret<String>>,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(
+                    grpc_api_types::payments::NetworkTokenDetails {
+                        cryptogram,
+                        ..network_token_details()
+                    },
+                ),
+            ),
+        }),
+        stored_credential_usage: Some(grpc_api_types::payments::StoredCredentialUsage {
+            sequence: grpc_api_types::payments::StoredCredentialSequence::Subsequent.into(),
+            initiator: initiator.into(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rejects_customer_initiated_network_token_without_cryptogram() {
+    for cryptogram in [None, Some(Secret::new(" ".to_string()))] {
+        let request = cryptogram_less_authorize_request(
+            grpc_api_types::payments::StoredCredentialInitiator::Cardholder,
+            cryptogram,
+        );
+
+        let error =
+            PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap_err();
+
+        let missing_cryptogram = error.frames().any(|frame| {
+            matches!(
+                frame.downcast_ref::<ApplicationErrorResponse>(),
+                Some(ApplicationErrorResponse::BadRequest(api_error))
+                    if api_error.sub_code == "MISSING_CRYPTOGRAM"
+            )
+        });
+        assert!(missing_cryptogram);
+    }
+}
+
+#[test]
+fn accepts_merchant_initiated_network_token_without_cryptogram() {
+    let request = cryptogram_less_authorize_request(
+        grpc_api_types::payments::StoredCredentialInitiator::Merchant,
+        None,
+    );
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap();
+
+    match data.payment_method_data {
+        PaymentMethodData::NetworkToken(token) => {
+            assert_eq!(token.cryptogram, None);
+            assert_eq!(
+                token.token_requestor_id.map(ExposeInterface::expose),
+                Some("40010030273".to_string())
+            );
+        }
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+}
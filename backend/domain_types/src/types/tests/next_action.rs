@@ -0,0 +1,96 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+use grpc_api_types::payments::next_action::Action;
+
+#[test]
+fn maps_redirect_form_to_redirect_to_url() {
+    let redirection_data = router_response_types::RedirectForm::Form {
+        endpoint: "https://acs.example.com/challenge".to_string(),
+        method: Method::Post,
+        form_fields: HashMap::from([("creq".to_string(), "abc".to_string())]),
+    };
+
+    let next_action = get_authorize_next_action(
+        common_enums::AttemptStatus::AuthenticationPending,
+        Some(&redirection_data),
+        None,
+    )
+    .unwrap();
+
+    match next_action.action.unwrap() {
+        Action::RedirectToUrl(redirect) => {
+            assert_eq!(redirect.url, "https://acs.example.com/challenge");
+            assert_eq!(
+                redirect.method,
+                grpc_api_types::payments::HttpMethod::Post as i32
+            );
+            assert_eq!(redirect.form_fields.get("creq").unwrap(), "abc");
+        }
+        action => panic!("unexpected next action: {action:?}"),
+    }
+}
+
+#[test]
+fn maps_qr_metadata_to_display_qr() {
+    let metadata = json!({
+        "image_data_url": "data:image/png;base64,AAAA",
+        "display_to_timestamp": 1_700_000_000_i64,
+    });
+
+    let next_action = get_authorize_next_action(
+        common_enums::AttemptStatus::AuthenticationPending,
+        None,
+        Some(&metadata),
+    )
+    .unwrap();
+
+    match next_action.action.unwrap() {
+        Action::DisplayQr(qr) => {
+            assert_eq!(
+                qr.image_data_url.as_deref(),
+                Some("data:image/png;base64,AAAA")
+            );
+            assert_eq!(qr.qr_code_url, None);
+            assert_eq!(qr.display_to_timestamp, Some(1_700_000_000));
+        }
+        action => panic!("unexpected next action: {action:?}"),
+    }
+}
+
+#[test]
+fn maps_voucher_metadata_to_display_voucher() {
+    let metadata = json!({
+        "voucher_number": "59168675976701",
+        "voucher_expires_at": 1_700_000_000_i64,
+        "voucher_barcode": "59168675976701",
+    });
+
+    let next_action = get_authorize_next_action(
+        common_enums::AttemptStatus::AuthenticationPending,
+        None,
+        Some(&metadata),
+    )
+    .unwrap();
+
+    match next_action.action.unwrap() {
+        Action::DisplayVoucher(voucher) => {
+            assert_eq!(voucher.voucher_number.as_deref(), Some("59168675976701"));
+            assert_eq!(voucher.expires_at, Some(1_700_000_000));
+            assert_eq!(voucher.barcode.as_deref(), Some("59168675976701"));
+            assert_eq!(voucher.download_url, None);
+        }
+        action => panic!("unexpected next action: {action:?}"),
+    }
+}
+
+#[test]
+fn pending_without_redirect_waits_for_polling() {
+    let next_action =
+        get_authorize_next_action(common_enums::AttemptStatus::Pending, None, None).unwrap();
+    assert!(matches!(
+        next_action.action,
+        Some(Action::WaitForPolling(_))
+    ));
+    assert!(get_authorize_next_action(common_enums::AttemptStatus::Charged, None, None).is_none());
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn metadata(value: &str) -> HashMap<String, String> {
+    HashMap::from([("connector_meta_data".to_string(), value.to_string())])
+}
+
+#[test]
+fn parses_json_object() {
+    let parsed = parse_connector_meta(
+        &metadata(r#"{"terminal_id":"10000001"}"#),
+        "connector_meta_data",
+    )
+    .unwrap();
+
+    assert_eq!(
+        parsed.map(ExposeInterface::expose),
+        Some(json!({ "terminal_id": "10000001" }))
+    );
+}
+
+#[test]
+fn keeps_plain_string() {
+    let parsed =
+        parse_connector_meta(&metadata("terminal-10000001"), "connector_meta_data").unwrap();
+
+    assert_eq!(
+        parsed.map(ExposeInterface::expose),
+        Some(serde_json::Value::String("terminal-10000001".to_string()))
+    );
+}
+
+#[test]
+fn missing_key_is_none() {
+    let parsed = parse_connector_meta(&HashMap::new(), "connector_meta_data").unwrap();
+
+    assert!(parsed.is_none());
+}
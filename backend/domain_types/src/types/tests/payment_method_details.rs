@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn authorize_response_surfaces_payment_method_details() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.payment_method_details = Some(PaymentMethodDetails {
+        last4: Some("4444".to_string()),
+        network: Some(CardNetwork::Mastercard),
+        funding_type: Some(CardFundingType::Debit),
+        wallet_type: Some(PaymentMethodType::ApplePay),
+    });
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+    let details = response.payment_method_details.unwrap();
+
+    assert_eq!(details.last4.as_deref(), Some("4444"));
+    assert_eq!(
+        details.network(),
+        grpc_api_types::payments::CardNetwork::Mastercard
+    );
+    assert_eq!(
+        details.funding_type(),
+        grpc_api_types::payments::CardFundingType::Debit
+    );
+    assert_eq!(
+        details.wallet_type(),
+        grpc_api_types::payments::PaymentMethodType::ApplePay
+    );
+}
+
+#[test]
+fn non_wallet_payment_method_type_is_not_reported_as_wallet() {
+    let details = grpc_payment_method_details(Some(PaymentMethodDetails {
+        last4: Some("1111".to_string()),
+        network: Some(CardNetwork::Visa),
+        funding_type: Some(CardFundingType::Credit),
+        wallet_type: Some(PaymentMethodType::Credit),
+    }))
+    .unwrap();
+
+    assert_eq!(
+        details.network(),
+        grpc_api_types::payments::CardNetwork::Visa
+    );
+    assert_eq!(details.wallet_type, None);
+}
+
+#[test]
+fn authorize_response_omits_payment_method_details_when_not_returned() {
+    let response =
+        generate_payment_authorize_response(authorize_router_data(None), ConnectorEnum::Adyen)
+            .unwrap();
+
+    assert_eq!(response.payment_method_details, None);
+}
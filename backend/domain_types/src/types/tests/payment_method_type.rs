@@ -0,0 +1,36 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn converts_wallet_and_pay_later_types() {
+    let cases = [
+        (
+            grpc_api_types::payments::PaymentMethodType::SamsungPay,
+            PaymentMethodType::SamsungPay,
+        ),
+        (
+            grpc_api_types::payments::PaymentMethodType::Paze,
+            PaymentMethodType::Paze,
+        ),
+        (
+            grpc_api_types::payments::PaymentMethodType::MbWay,
+            PaymentMethodType::MbWay,
+        ),
+        (
+            grpc_api_types::payments::PaymentMethodType::Klarna,
+            PaymentMethodType::Klarna,
+        ),
+        (
+            grpc_api_types::payments::PaymentMethodType::Affirm,
+            PaymentMethodType::Affirm,
+        ),
+    ];
+
+    for (grpc_type, expected) in cases {
+        assert_eq!(
+            <Option<PaymentMethodType>>::foreign_try_from(grpc_type).unwrap(),
+            Some(expected),
+            "{grpc_type:?}"
+        );
+    }
+}
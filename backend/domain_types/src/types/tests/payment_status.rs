@@ -0,0 +1,17 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn integrity_failure_round_trips() {
+    let status = grpc_api_types::payments::PaymentStatus::foreign_from(
+        common_enums::AttemptStatus::IntegrityFailure,
+    );
+    assert_eq!(
+        status,
+        grpc_api_types::payments::PaymentStatus::IntegrityFailure
+    );
+    assert_eq!(
+        common_enums::AttemptStatus::foreign_try_from(status).unwrap(),
+        common_enums::AttemptStatus::IntegrityFailure
+    );
+}
@@ -0,0 +1,52 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn polling_config() -> PollingConfig {
+    PollingConfig {
+        suggested_interval_secs: 5,
+        expiry_secs: 900,
+    }
+}
+
+#[test]
+fn pending_response_gets_suggested_poll_interval() {
+    let mut metadata = HashMap::new();
+    let before = common_utils::date_time::now_unix_timestamp();
+
+    add_poll_hints(
+        &mut metadata,
+        grpc_api_types::payments::PaymentStatus::Pending,
+        Some(&polling_config()),
+    );
+
+    assert_eq!(metadata.get(SUGGESTED_POLL_INTERVAL_KEY).unwrap(), "5");
+    let expiry: i64 = metadata.get(POLL_EXPIRY_KEY).unwrap().parse().unwrap();
+    assert!(expiry >= before + 900);
+}
+
+#[test]
+fn connector_hints_take_precedence() {
+    let mut metadata = HashMap::from([(SUGGESTED_POLL_INTERVAL_KEY.to_string(), "30".to_string())]);
+
+    add_poll_hints(
+        &mut metadata,
+        grpc_api_types::payments::PaymentStatus::AuthenticationPending,
+        Some(&polling_config()),
+    );
+
+    assert_eq!(metadata.get(SUGGESTED_POLL_INTERVAL_KEY).unwrap(), "30");
+    assert!(metadata.contains_key(POLL_EXPIRY_KEY));
+}
+
+#[test]
+fn final_response_has_no_poll_hints() {
+    let mut metadata = HashMap::new();
+
+    add_poll_hints(
+        &mut metadata,
+        grpc_api_types::payments::PaymentStatus::Charged,
+        Some(&polling_config()),
+    );
+
+    assert!(metadata.is_empty());
+}
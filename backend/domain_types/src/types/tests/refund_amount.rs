@@ -0,0 +1,41 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn refund_request(
+    minor_refund_amount: i64,
+    minor_captured_amount: Option<i64>,
+) -> grpc_api_types::payments::PaymentServiceRefundRequest {
+    grpc_api_types::payments::PaymentServiceRefundRequest {
+        refund_id: "refund_123".to_string(),
+        currency: grpc_api_types::payments::Currency::Usd as i32,
+        minor_payment_amount: 1000,
+        minor_refund_amount,
+        minor_captured_amount,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn refund_exceeding_captured_amount_is_rejected() {
+    let error = RefundsData::foreign_try_from(refund_request(800, Some(500))).unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "REFUND_EXCEEDS_CAPTURED");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn refund_within_captured_amount_is_accepted() {
+    let refund = RefundsData::foreign_try_from(refund_request(500, Some(500))).unwrap();
+
+    assert_eq!(refund.minor_refund_amount, MinorUnit::new(500));
+}
+
+#[test]
+fn refund_passes_through_when_captured_amount_is_unknown() {
+    assert!(RefundsData::foreign_try_from(refund_request(800, None)).is_ok());
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn refund_router_data(
+    connector_response_reference_id: Option<String>,
+) -> RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData> {
+    RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data: RefundFlowData {
+            status: common_enums::RefundStatus::Pending,
+            refund_id: None,
+            connectors: Connectors::default(),
+            connector_request_reference_id: "refund_ref_123".to_string(),
+            raw_connector_response: None,
+            connector_response_headers: None,
+        },
+        connector_auth_type: ConnectorAuthType::NoKey,
+        request: RefundsData::default(),
+        response: Ok(RefundsResponseData {
+            connector_refund_id: "psp_refund_456".to_string(),
+            refund_status: common_enums::RefundStatus::Pending,
+            status_code: 201,
+            connector_response_reference_id,
+        }),
+    }
+}
+
+#[test]
+fn refund_response_carries_connector_reference_id() {
+    let response = generate_refund_response(
+        refund_router_data(Some("refund_ref_123".to_string())),
+        ConnectorEnum::Adyen,
+    )
+    .unwrap();
+
+    assert_eq!(response.refund_id, "psp_refund_456");
+    assert_eq!(
+        response.response_ref_id,
+        Some(grpc_api_types::payments::Identifier {
+            id_type: Some(grpc_api_types::payments::identifier::IdType::Id(
+                "refund_ref_123".to_string()
+            )),
+        })
+    );
+}
+
+#[test]
+fn refund_response_without_connector_reference_has_no_reference_id() {
+    let response =
+        generate_refund_response(refund_router_data(None), ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.response_ref_id, None);
+}
@@ -0,0 +1,75 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn authorize_request(
+    billing_address: Option<grpc_api_types::payments::Address>,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        address: Some(grpc_api_types::payments::PaymentAddress {
+            shipping_address: None,
+            billing_address,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rejects_missing_billing_address() {
+    let error =
+        validate_required_fields(&authorize_request(None), &[RequiredField::BillingAddress])
+            .unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "MISSING_REQUIRED_FIELDS");
+            assert_eq!(
+                api_error.error_message,
+                "Missing required fields: billing_address"
+            );
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn lists_all_missing_fields_at_once() {
+    let error = validate_required_fields(
+        &authorize_request(None),
+        &[
+            RequiredField::Email,
+            RequiredField::Phone,
+            RequiredField::BillingAddress,
+        ],
+    )
+    .unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => assert_eq!(
+            api_error.error_message,
+            "Missing required fields: email, phone, billing_address"
+        ),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn accepts_complete_billing_address() {
+    let billing_address = grpc_api_types::payments::Address {
+        line1: Some(Secret::new("1 Main Street".to_string())),
+        city: Some(Secret::new("Amsterdam".to_string())),
+        zip_code: Some(Secret::new("1011AB".to_string())),
+        country_alpha2_code: Some(grpc_api_types::payments::CountryAlpha2::Nl.into()),
+        ..Default::default()
+    };
+
+    assert!(validate_required_fields(
+        &authorize_request(Some(billing_address)),
+        &[RequiredField::BillingAddress],
+    )
+    .is_ok());
+}
@@ -0,0 +1,40 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn authorize_response_reports_connector_and_environment() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.test_mode = Some(true);
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.connector.as_deref(), Some("adyen"));
+    assert_eq!(
+        response.environment(),
+        grpc_api_types::payments::Environment::Sandbox
+    );
+}
+
+#[test]
+fn authorize_response_outside_test_mode_reports_production() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.test_mode = Some(false);
+
+    let response =
+        generate_payment_authorize_response(router_data, ConnectorEnum::Checkout).unwrap();
+
+    assert_eq!(response.connector.as_deref(), Some("checkout"));
+    assert_eq!(
+        response.environment(),
+        grpc_api_types::payments::Environment::Production
+    );
+}
+
+#[test]
+fn authorize_response_without_test_mode_leaves_environment_unset() {
+    let response =
+        generate_payment_authorize_response(authorize_router_data(None), ConnectorEnum::Checkout)
+            .unwrap();
+
+    assert_eq!(response.environment, None);
+}
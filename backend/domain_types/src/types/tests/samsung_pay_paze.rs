@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn wallet_payment_method(
+    wallet_type: grpc_api_types::payments::wallet_payment_method_type::WalletType,
+) -> grpc_api_types::payments::PaymentMethod {
+    grpc_api_types::payments::PaymentMethod {
+        payment_method: Some(
+            grpc_api_types::payments::payment_method::PaymentMethod::Wallet(
+                grpc_api_types::payments::WalletPaymentMethodType {
+                    wallet_type: Some(wallet_type),
+                },
+            ),
+        ),
+    }
+}
+
+fn samsung_pay_payment_method() -> grpc_api_types::payments::PaymentMethod {
+    use grpc_api_types::payments::samsung_wallet::{payment_credential, PaymentCredential};
+
+    wallet_payment_method(
+        grpc_api_types::payments::wallet_payment_method_type::WalletType::SamsungPay(
+            grpc_api_types::payments::SamsungWallet {
+                payment_credential: Some(PaymentCredential {
+                    method: Some("3DS".to_string()),
+                    recurring_payment: Some(false),
+                    card_brand: payment_credential::CardBrand::Visa.into(),
+                    dpan_last_four_digits: Some("5678".to_string()),
+                    card_last_four_digits: "1234".to_string(),
+                    token_data: Some(payment_credential::TokenData {
+                        r#type: Some("S".to_string()),
+                        version: "100".to_string(),
+                        data: "samsung_pay_token".to_string(),
+                    }),
+                }),
+            },
+        ),
+    )
+}
+
+fn paze_payment_method() -> grpc_api_types::payments::PaymentMethod {
+    wallet_payment_method(
+        grpc_api_types::payments::wallet_payment_method_type::WalletType::Paze(
+            grpc_api_types::payments::PazeWallet {
+                complete_response: "paze_complete_response".to_string(),
+            },
+        ),
+    )
+}
+
+#[test]
+fn converts_samsung_pay_wallet_data() {
+    let data =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(samsung_pay_payment_method())
+            .unwrap();
+
+    let PaymentMethodData::Wallet(payment_method_data::WalletData::SamsungPay(wallet)) = data
+    else {
+        panic!("expected Samsung Pay wallet data");
+    };
+    let credential = wallet.payment_credential;
+    assert_eq!(
+        credential.card_brand,
+        common_enums::SamsungPayCardBrand::Visa
+    );
+    assert_eq!(credential.card_last_four_digits, "1234");
+    assert_eq!(credential.token_data.version, "100");
+    assert_eq!(credential.token_data.data.peek(), "samsung_pay_token");
+}
+
+#[test]
+fn rejects_samsung_pay_without_token_data() {
+    let mut payment_method = samsung_pay_payment_method();
+    if let Some(grpc_api_types::payments::payment_method::PaymentMethod::Wallet(wallet)) =
+        payment_method.payment_method.as_mut()
+    {
+        if let Some(grpc_api_types::payments::wallet_payment_method_type::WalletType::SamsungPay(
+            samsung_wallet,
+        )) = wallet.wallet_type.as_mut()
+        {
+            if let Some(credential) = samsung_wallet.payment_credential.as_mut() {
+                credential.token_data = None;
+            }
+        }
+    }
+
+    assert!(PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method).is_err());
+}
+
+#[test]
+fn converts_paze_wallet_data() {
+    let data =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(paze_payment_method()).unwrap();
+
+    let PaymentMethodData::Wallet(payment_method_data::WalletData::Paze(wallet)) = data else {
+        panic!("expected Paze wallet data");
+    };
+    assert_eq!(wallet.complete_response.peek(), "paze_complete_response");
+}
+
+#[test]
+fn converts_samsung_pay_and_paze_payment_method_types() {
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(samsung_pay_payment_method()).unwrap(),
+        Some(PaymentMethodType::SamsungPay)
+    );
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(paze_payment_method()).unwrap(),
+        Some(PaymentMethodType::Paze)
+    );
+    assert_eq!(
+        common_enums::PaymentMethod::foreign_try_from(paze_payment_method()).unwrap(),
+        common_enums::PaymentMethod::Wallet
+    );
+}
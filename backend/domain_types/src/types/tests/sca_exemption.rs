@@ -0,0 +1,42 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+#[test]
+fn converts_requested_exemption() {
+    assert_eq!(
+        Option::<ScaExemption>::foreign_from(
+            grpc_api_types::payments::ScaExemption::TransactionRiskAnalysis
+        ),
+        Some(ScaExemption::TransactionRiskAnalysis)
+    );
+    assert_eq!(
+        Option::<ScaExemption>::foreign_from(grpc_api_types::payments::ScaExemption::LowValue),
+        Some(ScaExemption::LowValue)
+    );
+    assert_eq!(
+        Option::<ScaExemption>::foreign_from(grpc_api_types::payments::ScaExemption::Unspecified),
+        None
+    );
+}
+
+#[test]
+fn authorize_response_surfaces_exemption_decision() {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.sca_exemption_decision = Some(ScaExemptionDecision::Rejected);
+
+    let response = generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(
+        response.exemption_decision(),
+        grpc_api_types::payments::ScaExemptionDecision::Rejected
+    );
+}
+
+#[test]
+fn authorize_response_omits_exemption_decision_when_none_was_requested() {
+    let response =
+        generate_payment_authorize_response(authorize_router_data(None), ConnectorEnum::Adyen)
+            .unwrap();
+
+    assert_eq!(response.exemption_decision, None);
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+const ONE_DAY_SECS: i64 = 24 * 60 * 60;
+
+fn capture_request(capture_at: i64) -> grpc_api_types::payments::PaymentServiceCaptureRequest {
+    grpc_api_types::payments::PaymentServiceCaptureRequest {
+        transaction_id: Some(grpc_api_types::payments::Identifier {
+            id_type: Some(grpc_api_types::payments::identifier::IdType::Id(
+                "pay_123".to_string(),
+            )),
+        }),
+        amount_to_capture: 1000,
+        currency: grpc_api_types::payments::Currency::Usd as i32,
+        capture_at: Some(capture_at),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn scheduled_capture_time_is_kept_on_the_capture() {
+    let capture_at = common_utils::date_time::now_unix_timestamp() + ONE_DAY_SECS;
+
+    let capture = PaymentsCaptureData::foreign_try_from(capture_request(capture_at)).unwrap();
+
+    assert_eq!(
+        capture
+            .capture_at
+            .map(|capture_at| capture_at.assume_utc().unix_timestamp()),
+        Some(capture_at)
+    );
+}
+
+#[test]
+fn scheduled_capture_in_the_past_is_rejected() {
+    let capture_at = common_utils::date_time::now_unix_timestamp() - ONE_DAY_SECS;
+
+    let error = PaymentsCaptureData::foreign_try_from(capture_request(capture_at)).unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "INVALID_CAPTURE_AT")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
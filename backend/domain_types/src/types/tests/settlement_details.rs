@@ -0,0 +1,52 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn capture_router_data(
+    settlement_details: Option<SettlementDetails>,
+) -> RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData> {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(201));
+    resource_common_data.settlement_details = settlement_details;
+    RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data,
+        connector_auth_type: ConnectorAuthType::NoKey,
+        request: PaymentsCaptureData::default(),
+        response: Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 201,
+        }),
+    }
+}
+
+#[test]
+fn capture_response_carries_settlement_currency_and_amount() {
+    let router_data = capture_router_data(Some(SettlementDetails {
+        amount: MinorUnit::new(925),
+        currency: common_enums::Currency::EUR,
+    }));
+
+    let response = generate_payment_capture_response(router_data, ConnectorEnum::Fiserv).unwrap();
+
+    assert_eq!(
+        response.settlement_currency,
+        Some(grpc_api_types::payments::Currency::Eur as i32)
+    );
+    assert_eq!(response.settlement_amount, Some(925));
+}
+
+#[test]
+fn capture_response_without_settlement_details_has_none() {
+    let response =
+        generate_payment_capture_response(capture_router_data(None), ConnectorEnum::Fiserv)
+            .unwrap();
+
+    assert_eq!(response.settlement_currency, None);
+    assert_eq!(response.settlement_amount, None);
+}
@@ -0,0 +1,59 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn authorize_request(
+    setup_future_usage: Option<grpc_api_types::payments::FutureUsage>,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::Card(
+                    grpc_api_types::payments::CardPaymentMethodType {
+                        card_type: Some(
+                            grpc_api_types::payments::card_payment_method_type::CardType::Credit(
+                                grpc_api_types::payments::CardDetails {
+                                    card_number: Some(
+                                        cards::CardNumber::from_str("4111111111111111").unwrap(),
+                                    ),
+                                    card_exp_month: Some(Secret::new("03".to_string())),
+                                    card_exp_year: Some(Secret::new("2030".to_string())),
+                                    card_cvc: Some(Secret::new("737".to_string())),
+                                    ..Default::default()
+                                },
+                            ),
+                        ),
+                    },
+                ),
+            ),
+        }),
+        setup_future_usage: setup_future_usage.map(Into::into),
+        enrolled_for_3ds: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn carries_off_session_usage() {
+    let request = authorize_request(Some(grpc_api_types::payments::FutureUsage::OffSession));
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap();
+
+    assert_eq!(
+        data.setup_future_usage,
+        Some(common_enums::FutureUsage::OffSession)
+    );
+    assert!(data.enrolled_for_3ds);
+}
+
+#[test]
+fn unspecified_usage_stays_none() {
+    let request = authorize_request(None);
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap();
+
+    assert_eq!(data.setup_future_usage, None);
+}
@@ -0,0 +1,74 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use grpc_api_types::payments::payment_method::PaymentMethod;
+
+use super::*;
+
+fn authorize_request(payment_method: Option<PaymentMethod>) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        payment_method: Some(grpc_api_types::payments::PaymentMethod { payment_method }),
+        ..Default::default()
+    }
+}
+
+fn assert_ambiguous(request: &PaymentServiceAuthorizeRequest) {
+    match validate_single_payment_method(request)
+        .unwrap_err()
+        .current_context()
+    {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "AMBIGUOUS_PAYMENT_METHOD")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn accepts_a_single_card() {
+    let request = authorize_request(Some(PaymentMethod::Card(
+        grpc_api_types::payments::CardPaymentMethodType {
+            card_type: Some(
+                grpc_api_types::payments::card_payment_method_type::CardType::Credit(
+                    Default::default(),
+                ),
+            ),
+        },
+    )));
+
+    assert!(validate_single_payment_method(&request).is_ok());
+}
+
+#[test]
+fn rejects_wallet_category_without_a_wallet() {
+    let request = authorize_request(Some(PaymentMethod::Wallet(
+        grpc_api_types::payments::WalletPaymentMethodType { wallet_type: None },
+    )));
+
+    assert_ambiguous(&request);
+}
+
+#[test]
+fn rejects_missing_payment_method() {
+    assert_ambiguous(&authorize_request(None));
+    assert_ambiguous(&PaymentServiceAuthorizeRequest::default());
+}
+
+#[test]
+fn accepts_payment_methods_without_a_category() {
+    let request = authorize_request(Some(PaymentMethod::UpiQr(
+        grpc_api_types::payments::UpiQr {},
+    )));
+
+    assert!(validate_single_payment_method(&request).is_ok());
+}
+
+#[test]
+fn rejects_reward_without_a_reward_type() {
+    let request = authorize_request(Some(PaymentMethod::Reward(
+        grpc_api_types::payments::RewardPaymentMethodType {
+            reward_type: grpc_api_types::payments::RewardType::Unspecified as i32,
+        },
+    )));
+
+    assert_ambiguous(&request);
+}
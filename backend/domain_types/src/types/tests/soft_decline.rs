@@ -0,0 +1,145 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+use crate::router_data::ErrorResponse;
+
+fn declined_with(code: &str) -> Result<PaymentsResponseData, ErrorResponse> {
+    Err(ErrorResponse {
+        code: code.to_string(),
+        attempt_status: Some(common_enums::AttemptStatus::Failure),
+        ..Default::default()
+    })
+}
+
+fn soft_decline_codes() -> Vec<String> {
+    vec!["20".to_string()]
+}
+
+#[test]
+fn soft_decline_is_retried() {
+    assert!(is_soft_decline(&declined_with("20"), &soft_decline_codes()));
+}
+
+#[test]
+fn hard_decline_is_not_retried() {
+    assert!(!is_soft_decline(&declined_with("6"), &soft_decline_codes()));
+}
+
+#[test]
+fn soft_decline_code_with_unknown_outcome_is_not_retried() {
+    let response = Err(ErrorResponse {
+        code: "20".to_string(),
+        attempt_status: Some(common_enums::AttemptStatus::Pending),
+        ..Default::default()
+    });
+
+    assert!(!is_soft_decline(&response, &soft_decline_codes()));
+}
+
+#[test]
+fn connector_timeout_is_retryable() {
+    let timeout = error_stack::report!(crate::errors::ApiClientError::RequestTimeoutReceived)
+        .change_context(ConnectorError::ProcessingStepFailed(None));
+    let gateway_timeout = ErrorResponse {
+        status_code: 504,
+        ..Default::default()
+    };
+
+    assert!(timeout.is_retryable(&soft_decline_codes()));
+    assert!(gateway_timeout.is_retryable(&soft_decline_codes()));
+}
+
+#[test]
+fn hard_decline_is_not_retryable() {
+    let Err(hard_decline) = declined_with("6") else {
+        panic!("expected a decline");
+    };
+    let Err(soft_decline) = declined_with("20") else {
+        panic!("expected a decline");
+    };
+
+    assert!(!hard_decline.is_retryable(&soft_decline_codes()));
+    assert!(soft_decline.is_retryable(&soft_decline_codes()));
+}
+
+fn synced_with(error: ErrorResponse) -> PaymentServiceGetResponse {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Failure, Some(200));
+    resource_common_data.connectors.adyen.soft_decline_codes = soft_decline_codes();
+    generate_payment_sync_response(
+        sync_router_data(resource_common_data, Err(error)),
+        ConnectorEnum::Adyen,
+    )
+    .unwrap()
+}
+
+#[test]
+fn sync_error_response_reports_retryable() {
+    let Err(soft_decline) = declined_with("20") else {
+        panic!("expected a decline");
+    };
+    let Err(hard_decline) = declined_with("6") else {
+        panic!("expected a decline");
+    };
+    let gateway_timeout = ErrorResponse {
+        status_code: 504,
+        ..Default::default()
+    };
+
+    assert_eq!(synced_with(soft_decline).retryable, Some(true));
+    assert_eq!(synced_with(hard_decline).retryable, Some(false));
+    assert_eq!(synced_with(gateway_timeout).retryable, Some(true));
+}
+
+#[test]
+fn successful_sync_response_leaves_retryable_unset() {
+    let response = generate_payment_sync_response(
+        sync_router_data(
+            payment_flow_data(common_enums::AttemptStatus::Charged, Some(200)),
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+                redirection_data: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                mandate_reference: None,
+                status_code: 200,
+            }),
+        ),
+        ConnectorEnum::Adyen,
+    )
+    .unwrap();
+
+    assert_eq!(response.retryable, None);
+}
+
+#[test]
+fn refund_error_response_reports_retryable() {
+    let refunded_with = |status_code| {
+        generate_refund_response(
+            RouterDataV2 {
+                flow: std::marker::PhantomData,
+                resource_common_data: RefundFlowData {
+                    status: common_enums::RefundStatus::Pending,
+                    refund_id: None,
+                    connectors: Connectors::default(),
+                    connector_request_reference_id: "refund_ref_123".to_string(),
+                    raw_connector_response: None,
+                    connector_response_headers: None,
+                },
+                connector_auth_type: ConnectorAuthType::NoKey,
+                request: RefundsData::default(),
+                response: Err(ErrorResponse {
+                    status_code,
+                    ..Default::default()
+                }),
+            },
+            ConnectorEnum::Adyen,
+        )
+        .unwrap()
+    };
+
+    assert_eq!(refunded_with(503).retryable, Some(true));
+    assert_eq!(refunded_with(401).retryable, Some(false));
+}
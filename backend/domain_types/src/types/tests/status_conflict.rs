@@ -0,0 +1,50 @@
+use super::*;
+use grpc_api_types::payments::PaymentStatus;
+
+#[test]
+fn regression_from_terminal_status_is_flagged() {
+    let mut metadata = HashMap::new();
+
+    let conflict = flag_status_conflict(
+        &mut metadata,
+        &ConnectorEnum::Adyen,
+        Some(PaymentStatus::Charged),
+        PaymentStatus::Failure,
+    );
+
+    assert!(conflict);
+    assert_eq!(
+        metadata.get(STATUS_CONFLICT_KEY).map(String::as_str),
+        Some("true")
+    );
+}
+
+#[test]
+fn progress_from_non_terminal_status_is_not_flagged() {
+    let mut metadata = HashMap::new();
+
+    let conflict = flag_status_conflict(
+        &mut metadata,
+        &ConnectorEnum::Adyen,
+        Some(PaymentStatus::Pending),
+        PaymentStatus::Charged,
+    );
+
+    assert!(!conflict);
+    assert!(metadata.is_empty());
+}
+
+#[test]
+fn auto_refund_after_charge_is_not_flagged() {
+    let mut metadata = HashMap::new();
+
+    let conflict = flag_status_conflict(
+        &mut metadata,
+        &ConnectorEnum::Adyen,
+        Some(PaymentStatus::Charged),
+        PaymentStatus::AutoRefunded,
+    );
+
+    assert!(!conflict);
+    assert!(metadata.is_empty());
+}
@@ -0,0 +1,137 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn usage(
+    sequence: grpc_api_types::payments::StoredCredentialSequence,
+    initiator: grpc_api_types::payments::StoredCredentialInitiator,
+) -> grpc_api_types::payments::StoredCredentialUsage {
+    grpc_api_types::payments::StoredCredentialUsage {
+        sequence: sequence.into(),
+        initiator: initiator.into(),
+    }
+}
+
+fn authorize_request(
+    stored_credential_usage: Option<grpc_api_types::payments::StoredCredentialUsage>,
+) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        payment_method: Some(grpc_api_types::payments::PaymentMethod {
+            payment_method: Some(
+                grpc_api_types::payments::payment_method::PaymentMethod::NetworkToken(
+                    grpc_api_types::payments::NetworkTokenDetails {
+                        network_token: Some(Secret::new("4111111111111111".to_string())),
+                        network_token_exp_month: Some(Secret::new("12".to_string())),
+                        network_token_exp_year: Some(Secret::new("30".to_string())),
+                        cryptogram: Some(Secret::new("AgAAAAAAAIR8CQrXcIhbQAAAAAA=".to_string())),
+                        ..Default::default()
+                    },
+                ),
+            ),
+        }),
+        stored_credential_usage,
+        ..Default::default()
+    }
+}
+
+fn repeat_request(
+    stored_credential_usage: Option<grpc_api_types::payments::StoredCredentialUsage>,
+) -> grpc_api_types::payments::PaymentServiceRepeatEverythingRequest {
+    grpc_api_types::payments::PaymentServiceRepeatEverythingRequest {
+        mandate_reference: Some(grpc_api_types::payments::MandateReference {
+            mandate_id: Some("mandate_123".to_string()),
+        }),
+        amount: 1000,
+        minor_amount: 1000,
+        currency: grpc_api_types::payments::Currency::Usd.into(),
+        capture_method: Some(grpc_api_types::payments::CaptureMethod::Automatic.into()),
+        stored_credential_usage,
+        ..Default::default()
+    }
+}
+
+fn assert_bad_request(error: error_stack::Report<ApplicationErrorResponse>, sub_code: &str) {
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, sub_code)
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn propagates_first_usage_on_authorize() {
+    let request = authorize_request(Some(usage(
+        grpc_api_types::payments::StoredCredentialSequence::First,
+        grpc_api_types::payments::StoredCredentialInitiator::Cardholder,
+    )));
+
+    let data = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap();
+
+    assert_eq!(
+        data.stored_credential_usage,
+        Some(StoredCredentialUsage {
+            sequence: StoredCredentialSequence::First,
+            initiator: StoredCredentialInitiator::Cardholder,
+        })
+    );
+}
+
+#[test]
+fn propagates_subsequent_usage_on_repeat() {
+    let request = repeat_request(Some(usage(
+        grpc_api_types::payments::StoredCredentialSequence::Subsequent,
+        grpc_api_types::payments::StoredCredentialInitiator::Merchant,
+    )));
+
+    let data = RepeatPaymentData::foreign_try_from(request).unwrap();
+
+    assert_eq!(
+        data.stored_credential_usage,
+        StoredCredentialUsage {
+            sequence: StoredCredentialSequence::Subsequent,
+            initiator: StoredCredentialInitiator::Merchant,
+        }
+    );
+}
+
+#[test]
+fn defaults_repeat_without_usage_to_merchant_initiated() {
+    let data = RepeatPaymentData::foreign_try_from(repeat_request(None)).unwrap();
+
+    assert_eq!(
+        data.stored_credential_usage,
+        StoredCredentialUsage {
+            sequence: StoredCredentialSequence::Subsequent,
+            initiator: StoredCredentialInitiator::Merchant,
+        }
+    );
+}
+
+#[test]
+fn rejects_off_session_authorize_without_usage() {
+    let request = PaymentServiceAuthorizeRequest {
+        off_session: Some(true),
+        ..authorize_request(None)
+    };
+
+    let error = PaymentsAuthorizeData::<DefaultPCIHolder>::foreign_try_from(request).unwrap_err();
+
+    assert_bad_request(error, "MISSING_STORED_CREDENTIAL_USAGE");
+}
+
+#[test]
+fn rejects_merchant_initiated_first_usage() {
+    let request = repeat_request(Some(usage(
+        grpc_api_types::payments::StoredCredentialSequence::First,
+        grpc_api_types::payments::StoredCredentialInitiator::Merchant,
+    )));
+
+    let error = RepeatPaymentData::foreign_try_from(request).unwrap_err();
+
+    assert_bad_request(error, "INVALID_STORED_CREDENTIAL_USAGE");
+}
@@ -0,0 +1,93 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+use crate::router_request_types::PaymentSynIntegrityObject;
+
+fn transaction_response() -> PaymentsResponseData {
+    PaymentsResponseData::TransactionResponse {
+        resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+        redirection_data: None,
+        connector_metadata: None,
+        network_txn_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        mandate_reference: None,
+        status_code: 200,
+    }
+}
+
+fn reported_amount(amount: i64, currency: common_enums::Currency) -> PaymentSynIntegrityObject {
+    PaymentSynIntegrityObject {
+        amount: MinorUnit::new(amount),
+        currency,
+    }
+}
+
+#[test]
+fn sync_response_carries_amount_currency_and_captured_amount() {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::PartialCharged, Some(200));
+    resource_common_data.minor_amount_captured = Some(MinorUnit::new(600));
+    let mut router_data = sync_router_data(resource_common_data, Ok(transaction_response()));
+    router_data.request.integrity_object = Some(reported_amount(1000, common_enums::Currency::EUR));
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.amount, Some(10));
+    assert_eq!(response.minor_amount, Some(1000));
+    assert_eq!(
+        response.currency,
+        Some(grpc_api_types::payments::Currency::Eur as i32)
+    );
+    assert_eq!(response.captured_amount, Some(6));
+    assert_eq!(response.minor_captured_amount, Some(600));
+}
+
+#[test]
+fn sync_response_reports_zero_captured_amount() {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Authorized, Some(200));
+    resource_common_data.minor_amount_captured = Some(MinorUnit::zero());
+    let mut router_data = sync_router_data(resource_common_data, Ok(transaction_response()));
+    router_data.request.integrity_object = Some(reported_amount(0, common_enums::Currency::JPY));
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.amount, Some(0));
+    assert_eq!(response.minor_amount, Some(0));
+    assert_eq!(response.captured_amount, Some(0));
+    assert_eq!(response.minor_captured_amount, Some(0));
+}
+
+#[test]
+fn sync_response_leaves_fractional_major_amounts_unset() {
+    let mut resource_common_data =
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200));
+    resource_common_data.minor_amount_captured = Some(MinorUnit::new(1050));
+    let mut router_data = sync_router_data(resource_common_data, Ok(transaction_response()));
+    router_data.request.integrity_object = Some(reported_amount(1050, common_enums::Currency::USD));
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.amount, None);
+    assert_eq!(response.minor_amount, Some(1050));
+    assert_eq!(response.captured_amount, None);
+    assert_eq!(response.minor_captured_amount, Some(1050));
+}
+
+#[test]
+fn sync_response_leaves_unknown_amounts_unset() {
+    let mut router_data = sync_router_data(
+        payment_flow_data(common_enums::AttemptStatus::Charged, Some(200)),
+        Ok(transaction_response()),
+    );
+    // The sync request carries no amount of its own
+    router_data.request.amount = MinorUnit::new(1000);
+
+    let response = generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap();
+
+    assert_eq!(response.amount, None);
+    assert_eq!(response.minor_amount, None);
+    assert_eq!(response.currency, None);
+    assert_eq!(response.captured_amount, None);
+    assert_eq!(response.minor_captured_amount, None);
+}
@@ -0,0 +1,51 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn get_request(
+    id_type: grpc_api_types::payments::identifier::IdType,
+) -> grpc_api_types::payments::PaymentServiceGetRequest {
+    grpc_api_types::payments::PaymentServiceGetRequest {
+        transaction_id: Some(grpc_api_types::payments::Identifier {
+            id_type: Some(id_type),
+        }),
+        ..Default::default()
+    }
+}
+
+fn metadata() -> tonic::metadata::MetadataMap {
+    let mut metadata = tonic::metadata::MetadataMap::new();
+    metadata.insert(
+        common_utils::consts::X_MERCHANT_ID,
+        "merchant_123".parse().unwrap(),
+    );
+    metadata
+}
+
+#[test]
+fn sync_flow_data_retains_encoded_data() {
+    let request = get_request(grpc_api_types::payments::identifier::IdType::EncodedData(
+        "encoded_payload".to_string(),
+    ));
+
+    let sync_data = PaymentsSyncData::foreign_try_from(request.clone()).unwrap();
+    let flow_data =
+        PaymentFlowData::foreign_try_from((request, Connectors::default(), &metadata())).unwrap();
+
+    assert_eq!(sync_data.encoded_data.as_deref(), Some("encoded_payload"));
+    assert_eq!(
+        flow_data.connector_meta_data.unwrap().expose(),
+        json!({ "encoded_data": "encoded_payload" })
+    );
+}
+
+#[test]
+fn sync_flow_data_without_encoded_data_has_no_connector_meta() {
+    let request = get_request(grpc_api_types::payments::identifier::IdType::Id(
+        "txn_123".to_string(),
+    ));
+
+    let flow_data =
+        PaymentFlowData::foreign_try_from((request, Connectors::default(), &metadata())).unwrap();
+
+    assert!(flow_data.connector_meta_data.is_none());
+}
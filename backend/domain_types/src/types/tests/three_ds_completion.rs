@@ -0,0 +1,49 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn synced(three_ds_completion: Option<ThreeDsCompletion>) -> PaymentServiceGetResponse {
+    let mut resource_common_data = payment_flow_data(
+        common_enums::AttemptStatus::AuthenticationPending,
+        Some(200),
+    );
+    resource_common_data.three_ds_completion = three_ds_completion;
+    let router_data = sync_router_data(
+        resource_common_data,
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            redirection_data: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            mandate_reference: None,
+            status_code: 200,
+        }),
+    );
+    generate_payment_sync_response(router_data, ConnectorEnum::Adyen).unwrap()
+}
+
+#[test]
+fn sync_response_surfaces_three_ds_completion() {
+    for (completion, expected) in [
+        (
+            ThreeDsCompletion::ChallengePending,
+            grpc_api_types::payments::ThreeDsCompletion::ChallengePending,
+        ),
+        (
+            ThreeDsCompletion::Completed,
+            grpc_api_types::payments::ThreeDsCompletion::Completed,
+        ),
+        (
+            ThreeDsCompletion::NotCompleted,
+            grpc_api_types::payments::ThreeDsCompletion::NotCompleted,
+        ),
+    ] {
+        assert_eq!(synced(Some(completion)).three_ds_completion(), expected);
+    }
+}
+
+#[test]
+fn sync_response_omits_three_ds_completion_without_three_ds_data() {
+    assert_eq!(synced(None).three_ds_completion, None);
+}
@@ -0,0 +1,51 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn refused(three_ds_failure: Option<ThreeDsFailure>) -> PaymentServiceAuthorizeResponse {
+    let mut router_data = authorize_router_data(None);
+    router_data.resource_common_data.status = common_enums::AttemptStatus::Failure;
+    router_data.resource_common_data.three_ds_failure = three_ds_failure;
+    router_data.response = Err(ErrorResponse {
+        attempt_status: Some(common_enums::AttemptStatus::Failure),
+        ..Default::default()
+    });
+    generate_payment_authorize_response(router_data, ConnectorEnum::Adyen).unwrap()
+}
+
+#[test]
+fn hard_three_ds_failure_disallows_fallback() {
+    let response = refused(Some(ThreeDsFailure::Hard));
+
+    assert_eq!(response.three_ds_fallback_allowed, Some(false));
+    assert_eq!(
+        response
+            .connector_metadata
+            .get(THREE_DS_FAILURE_METADATA_KEY)
+            .map(String::as_str),
+        Some("hard")
+    );
+}
+
+#[test]
+fn soft_three_ds_failure_allows_fallback() {
+    let response = refused(Some(ThreeDsFailure::Soft));
+
+    assert_eq!(response.three_ds_fallback_allowed, Some(true));
+    assert_eq!(
+        response
+            .connector_metadata
+            .get(THREE_DS_FAILURE_METADATA_KEY)
+            .map(String::as_str),
+        Some("soft")
+    );
+}
+
+#[test]
+fn other_failures_carry_no_three_ds_classification() {
+    let response = refused(None);
+
+    assert_eq!(response.three_ds_fallback_allowed, None);
+    assert!(!response
+        .connector_metadata
+        .contains_key(THREE_DS_FAILURE_METADATA_KEY));
+}
@@ -0,0 +1,42 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+fn upi_qr_payment_method() -> grpc_api_types::payments::PaymentMethod {
+    grpc_api_types::payments::PaymentMethod {
+        payment_method: Some(
+            grpc_api_types::payments::payment_method::PaymentMethod::UpiQr(
+                grpc_api_types::payments::UpiQr {},
+            ),
+        ),
+    }
+}
+
+#[test]
+fn converts_upi_qr_payment_method_data() {
+    let data =
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(upi_qr_payment_method()).unwrap();
+
+    assert!(matches!(
+        data,
+        PaymentMethodData::Upi(payment_method_data::UpiData::UpiQr(_))
+    ));
+}
+
+#[test]
+fn converts_upi_qr_payment_method_type() {
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(upi_qr_payment_method()).unwrap(),
+        Some(PaymentMethodType::UpiQr)
+    );
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(
+            grpc_api_types::payments::PaymentMethodType::UpiQr
+        )
+        .unwrap(),
+        Some(PaymentMethodType::UpiQr)
+    );
+    assert_eq!(
+        common_enums::PaymentMethod::foreign_try_from(upi_qr_payment_method()).unwrap(),
+        common_enums::PaymentMethod::Upi
+    );
+}
@@ -0,0 +1,62 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+use super::*;
+
+fn voucher_payment_method(
+    payment_method: grpc_api_types::payments::payment_method::PaymentMethod,
+) -> grpc_api_types::payments::PaymentMethod {
+    grpc_api_types::payments::PaymentMethod {
+        payment_method: Some(payment_method),
+    }
+}
+
+#[test]
+fn converts_boleto_to_voucher_payment_method_data() {
+    let payment_method = voucher_payment_method(
+        grpc_api_types::payments::payment_method::PaymentMethod::Boleto(
+            grpc_api_types::payments::Boleto {
+                social_security_number: Some(Secret::new("56861752509".to_string())),
+            },
+        ),
+    );
+
+    match PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method.clone()).unwrap() {
+        PaymentMethodData::Voucher(payment_method_data::VoucherData::Boleto(boleto)) => {
+            assert_eq!(
+                boleto.social_security_number.map(|number| number.expose()),
+                Some("56861752509".to_string())
+            )
+        }
+        other => panic!("unexpected payment method data: {other:?}"),
+    }
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(payment_method.clone()).unwrap(),
+        Some(PaymentMethodType::Boleto)
+    );
+    assert_eq!(
+        common_enums::PaymentMethod::foreign_try_from(payment_method).unwrap(),
+        common_enums::PaymentMethod::Voucher
+    );
+}
+
+#[test]
+fn converts_oxxo_to_voucher_payment_method_data() {
+    let payment_method = voucher_payment_method(
+        grpc_api_types::payments::payment_method::PaymentMethod::Oxxo(
+            grpc_api_types::payments::Oxxo {},
+        ),
+    );
+
+    assert!(matches!(
+        PaymentMethodData::<DefaultPCIHolder>::foreign_try_from(payment_method.clone()).unwrap(),
+        PaymentMethodData::Voucher(payment_method_data::VoucherData::Oxxo)
+    ));
+    assert_eq!(
+        Option::<PaymentMethodType>::foreign_try_from(payment_method.clone()).unwrap(),
+        Some(PaymentMethodType::Oxxo)
+    );
+    assert_eq!(
+        common_enums::PaymentMethod::foreign_try_from(payment_method).unwrap(),
+        common_enums::PaymentMethod::Voucher
+    );
+}
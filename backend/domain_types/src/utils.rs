@@ -1,5 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
+    str::FromStr,
     sync::LazyLock,
 };
 
@@ -13,11 +14,12 @@ use serde_json::Value;
 use time::PrimitiveDateTime;
 
 use crate::{
+    connector_types::ConnectorEnum,
     errors::{self, ApiError, ApplicationErrorResponse, ParsingError},
     payment_method_data::{Card, PaymentMethodData, PaymentMethodDataTypes},
     router_data::ErrorResponse,
     router_response_types::Response,
-    types::PaymentMethodDataType,
+    types::{Connectors, PaymentMethodDataType},
 };
 
 pub type Error = error_stack::Report<errors::ConnectorError>;
@@ -394,3 +396,76 @@ pub fn extract_merchant_id_from_metadata(
             })
         })?)
 }
+
+fn invalid_base_url_override(error_message: String) -> ApplicationErrorResponse {
+    ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: "INVALID_CONNECTOR_BASE_URL".to_owned(),
+        error_identifier: 400,
+        error_message,
+        error_object: None,
+    })
+}
+
+/// Replaces the base url of the request's connector, named in the `x-connector` header or else
+/// the server's default connector, with the url in the `x-connector-base-url` header. The url
+/// must be one of the connector's configured `allowed_base_url_overrides`, so a request can
+/// only select a base url the server trusts, and must use https unless the request is in
+/// `test_mode`.
+pub fn apply_base_url_override(
+    connectors: &mut Connectors,
+    metadata: &tonic::metadata::MetadataMap,
+    test_mode: Option<bool>,
+) -> Result<(), ApplicationErrorResponse> {
+    let Some(base_url) = metadata.get(consts::X_CONNECTOR_BASE_URL) else {
+        return Ok(());
+    };
+    let base_url = base_url.to_str().map_err(|e| {
+        invalid_base_url_override(format!(
+            "Invalid {} in request metadata: {e}",
+            consts::X_CONNECTOR_BASE_URL
+        ))
+    })?;
+    let connector = match metadata.get(consts::X_CONNECTOR) {
+        Some(connector) => connector
+            .to_str()
+            .ok()
+            .and_then(|connector| ConnectorEnum::from_str(connector).ok()),
+        None => connectors.default_connector,
+    }
+    .ok_or_else(|| {
+        invalid_base_url_override(format!(
+            "{} requires a valid connector in {} or a configured default connector",
+            consts::X_CONNECTOR_BASE_URL,
+            consts::X_CONNECTOR
+        ))
+    })?;
+    let url = url::Url::parse(base_url).map_err(|e| {
+        invalid_base_url_override(format!("Invalid connector base url {base_url:?}: {e}"))
+    })?;
+
+    let connector_params = connectors.get_connector_params_mut(&connector);
+    let allowed = connector_params
+        .allowed_base_url_overrides
+        .iter()
+        .filter_map(|allowed| url::Url::parse(allowed).ok())
+        .any(|allowed| allowed == url);
+    if !allowed {
+        return Err(report!(ApplicationErrorResponse::ForbiddenCommonResource(
+            ApiError {
+                sub_code: "BASE_URL_OVERRIDE_NOT_ALLOWED".to_owned(),
+                error_identifier: 403,
+                error_message: format!(
+                    "{base_url} is not an allowed base url override for {connector}"
+                ),
+                error_object: None,
+            }
+        )));
+    }
+    if url.scheme() != "https" && test_mode != Some(true) {
+        return Err(report!(invalid_base_url_override(format!(
+            "{base_url} must use https outside test mode"
+        ))));
+    }
+    connector_params.base_url = url.to_string();
+    Ok(())
+}
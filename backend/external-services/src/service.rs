@@ -1,18 +1,30 @@
-use std::{str::FromStr, time::Duration};
+use std::{
+    collections::HashMap,
+    path::Path,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
 
 use common_utils::ext_traits::AsyncExt;
 // use base64::engine::Engine;
 use common_utils::{
+    custom_serde::minor_unit_as_string,
     lineage,
     // consts::BASE64_ENGINE,
-    request::{Method, Request, RequestContent},
+    request::{ContentType, Method, Request, RequestContent},
 };
 use domain_types::{
-    connector_types::{ConnectorResponseHeaders, RawConnectorResponse},
+    connector_types::{
+        ConnectorEnum, ConnectorHttpStatusCode, ConnectorResponseHeaders, RawConnectorResponse,
+    },
     errors::{ApiClientError, ApiErrorResponse, ConnectorError},
     router_data_v2::RouterDataV2,
     router_response_types::Response,
-    types::Proxy,
+    types::{
+        ConnectorTlsConfig, ConnectorTrafficLogConfig, Connectors, Proxy,
+        UnparsableResponseFallbackConfig,
+    },
 };
 
 pub trait ConnectorRequestReference {
@@ -36,6 +48,68 @@ impl ConnectorRequestReference for domain_types::connector_types::DisputeFlowDat
         &self.connector_request_reference_id
     }
 }
+
+impl ConnectorRequestReference for domain_types::connector_types::WebhookFlowData {
+    fn get_connector_request_reference_id(&self) -> &str {
+        &self.connector_request_reference_id
+    }
+}
+
+pub trait ConnectorsConfig {
+    fn get_connectors(&self) -> &Connectors;
+}
+
+impl ConnectorsConfig for domain_types::connector_types::PaymentFlowData {
+    fn get_connectors(&self) -> &Connectors {
+        &self.connectors
+    }
+}
+
+impl ConnectorsConfig for domain_types::connector_types::RefundFlowData {
+    fn get_connectors(&self) -> &Connectors {
+        &self.connectors
+    }
+}
+
+impl ConnectorsConfig for domain_types::connector_types::DisputeFlowData {
+    fn get_connectors(&self) -> &Connectors {
+        &self.connectors
+    }
+}
+
+impl ConnectorsConfig for domain_types::connector_types::WebhookFlowData {
+    fn get_connectors(&self) -> &Connectors {
+        &self.connectors
+    }
+}
+
+pub trait TestModeFlag {
+    fn is_test_mode(&self) -> bool;
+}
+
+impl TestModeFlag for domain_types::connector_types::PaymentFlowData {
+    fn is_test_mode(&self) -> bool {
+        self.test_mode.unwrap_or(false)
+    }
+}
+
+impl TestModeFlag for domain_types::connector_types::RefundFlowData {
+    fn is_test_mode(&self) -> bool {
+        false
+    }
+}
+
+impl TestModeFlag for domain_types::connector_types::DisputeFlowData {
+    fn is_test_mode(&self) -> bool {
+        false
+    }
+}
+
+impl TestModeFlag for domain_types::connector_types::WebhookFlowData {
+    fn is_test_mode(&self) -> bool {
+        false
+    }
+}
 // use base64::engine::Engine;
 use common_utils::{
     emit_event_with_config,
@@ -44,11 +118,12 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use interfaces::{
+    api::ConnectorCommon,
     connector_integration_v2::BoxedConnectorIntegrationV2,
     integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject},
 };
-use masking::{ErasedMaskSerialize, Maskable, Secret};
-use once_cell::sync::OnceCell;
+use masking::{ErasedMaskSerialize, Mask, Maskable, Secret};
+use once_cell::sync::{Lazy, OnceCell};
 use reqwest::Client;
 use serde_json::json;
 use tracing::field::Empty;
@@ -56,16 +131,71 @@ use tracing::field::Empty;
 use crate::shared_metrics as metrics;
 pub type Headers = std::collections::HashSet<(String, Maskable<String>)>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EventProcessingParams<'a> {
     pub connector_name: &'a str,
     pub service_name: &'a str,
     pub flow_name: FlowName,
     pub event_config: &'a EventConfig,
+    pub traffic_log_config: &'a ConnectorTrafficLogConfig,
     pub raw_request_data: Option<SecretSerdeValue>,
     pub request_id: &'a str,
+    pub merchant_id: &'a str,
     pub lineage_ids: &'a lineage::LineageIds<'a>,
     pub reference_id: &'a Option<String>,
+    pub forwarded_headers: &'a [(String, String)],
+    /// Timeout of the connector call, applied only when the flow is in test mode
+    pub connector_timeout: Option<Duration>,
+}
+
+/// Request and response bodies of a connector call, logged once the call's outcome is known:
+/// always when it failed, at the configured rate when it succeeded. A call that returns early
+/// with an error is logged as failed when this is dropped.
+struct TrafficLog<'a> {
+    config: &'a ConnectorTrafficLogConfig,
+    sample: f64,
+    request: Option<serde_json::Value>,
+    response_body: Option<serde_json::Value>,
+    logged: bool,
+}
+
+impl<'a> TrafficLog<'a> {
+    fn new(config: &'a ConnectorTrafficLogConfig) -> Self {
+        Self {
+            config,
+            sample: rand::random::<f64>(),
+            request: None,
+            response_body: None,
+            logged: false,
+        }
+    }
+
+    /// Whether the raw response should be logged, given whether it already shows a failure
+    fn should_log_response(&self, is_failure: bool) -> bool {
+        self.config.should_log(is_failure, self.sample)
+    }
+
+    fn finish(&mut self, is_failure: bool) {
+        if std::mem::replace(&mut self.logged, true)
+            || !self.config.should_log(is_failure, self.sample)
+        {
+            return;
+        }
+        if let Some(request) = self.request.take() {
+            tracing::info!(?request, "request of connector");
+            tracing::Span::current().record("request.body", tracing::field::display(request));
+        }
+        if let Some(response_body) = self.response_body.take() {
+            tracing::Span::current()
+                .record("response.body", tracing::field::display(response_body));
+        }
+    }
+}
+
+impl Drop for TrafficLog<'_> {
+    fn drop(&mut self) {
+        self.finish(true);
+    }
 }
 
 #[tracing::instrument(
@@ -100,11 +230,44 @@ where
         + 'static
         + RawConnectorResponse
         + ConnectorResponseHeaders
-        + ConnectorRequestReference,
+        + ConnectorHttpStatusCode
+        + ConnectorRequestReference
+        + ConnectorsConfig
+        + TestModeFlag,
 {
     let start = tokio::time::Instant::now();
+    let mut traffic_log = TrafficLog::new(event_params.traffic_log_config);
     let connector_request = connector.build_request_v2(&router_data)?;
 
+    let connector_params = ConnectorEnum::from_str(event_params.connector_name)
+        .ok()
+        .map(|connector_enum| {
+            router_data
+                .resource_common_data
+                .get_connectors()
+                .get_connector_params(&connector_enum)
+                .clone()
+        });
+    let content_type_override = connector_params
+        .as_ref()
+        .and_then(|params| params.request_content_type);
+    let connector_tls = connector_params
+        .as_ref()
+        .and_then(|params| params.tls.clone());
+    let unparsable_response_fallback = connector_params
+        .map(|params| params.unparsable_response_fallback)
+        .unwrap_or_default();
+    let connector_request = match (connector_request, content_type_override) {
+        (Some(request), Some(content_type)) => Some(override_request_content_type(
+            request,
+            content_type,
+            connector.supported_request_content_types(),
+        )?),
+        (connector_request, _) => connector_request,
+    };
+    let connector_request = connector_request
+        .map(|request| add_forwarded_headers(request, event_params.forwarded_headers));
+
     let headers = connector_request
         .as_ref()
         .map(|connector_request| connector_request.headers.clone())
@@ -126,25 +289,28 @@ where
     let headers = serde_json::Value::Object(masked_headers);
     tracing::Span::current().record("request.headers", tracing::field::display(&headers));
     let router_data = router_data.clone();
+    let connector_timeout = event_params
+        .connector_timeout
+        .filter(|_| router_data.resource_common_data.is_test_mode());
 
     let req = connector_request.as_ref().map(|connector_request| {
         let masked_request = match connector_request.body.as_ref() {
             Some(request) => match request {
                 RequestContent::Json(i)
                 | RequestContent::FormUrlEncoded(i)
-                | RequestContent::Xml(i) => (**i)
-                    .masked_serialize()
-                    .unwrap_or(json!({ "error": "failed to mask serialize connector request"})),
+                | RequestContent::Xml(i) => minor_unit_as_string::scope(
+                    event_params.event_config.minor_unit_as_string,
+                    || (**i).masked_serialize(),
+                )
+                .unwrap_or(json!({ "error": "failed to mask serialize connector request"})),
                 RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
                 RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
             },
             None => serde_json::Value::Null,
         };
-        tracing::info!(request=?masked_request, "request of connector");
-        tracing::Span::current().record("request.body", tracing::field::display(&masked_request));
-
         masked_request
     });
+    traffic_log.request = req.clone();
 
     let result = match connector_request {
         Some(request) => {
@@ -161,18 +327,25 @@ where
             tracing::Span::current().record("request.url", tracing::field::display(&url));
             tracing::Span::current().record("request.method", tracing::field::display(method));
             let request_id = event_params.request_id.to_string();
-            let response = call_connector_api(proxy, request, "execute_connector_processing_step")
-                .await
-                .change_context(ConnectorError::RequestEncodingFailed)
-                .inspect_err(|err| {
-                    info_log(
-                        "NETWORK_ERROR",
-                        &json!(format!(
-                            "Failed getting response from connector. Error: {:?}",
-                            err
-                        )),
-                    );
-                });
+            let merchant_id = event_params.merchant_id.to_string();
+            let response = call_connector_api(
+                proxy,
+                request,
+                "execute_connector_processing_step",
+                connector_timeout,
+                connector_tls.as_ref(),
+            )
+            .await
+            .change_context(ConnectorError::RequestEncodingFailed)
+            .inspect_err(|err| {
+                info_log(
+                    "NETWORK_ERROR",
+                    &json!(format!(
+                        "Failed getting response from connector. Error: {:?}",
+                        err
+                    )),
+                );
+            });
             let external_service_elapsed = external_service_start_latency.elapsed();
             metrics::EXTERNAL_SERVICE_API_CALLS_LATENCY
                 .with_label_values(&[
@@ -181,7 +354,12 @@ where
                     event_params.connector_name,
                 ])
                 .observe(external_service_elapsed.as_secs_f64());
-            tracing::info!(?response, "response from connector");
+            metrics::CONNECTOR_CALL_LATENCY
+                .with_label_values(&[event_params.connector_name, event_params.flow_name.as_str()])
+                .observe(external_service_elapsed.as_secs_f64());
+            if traffic_log.should_log_response(!matches!(response, Ok(Ok(_)))) {
+                tracing::info!(?response, "response from connector");
+            }
 
             match &response {
                 Ok(Ok(body)) => {
@@ -214,6 +392,7 @@ where
 
                             let event = Event {
                                 request_id: request_id.to_string(),
+                                merchant_id: merchant_id.clone(),
                                 timestamp: chrono::Utc::now().timestamp().into(),
                                 flow_type: flow_name,
                                 connector: connector_name.clone(),
@@ -275,6 +454,7 @@ where
 
                             let event = Event {
                                 request_id: request_id.to_string(),
+                                merchant_id: merchant_id.clone(),
                                 timestamp: chrono::Utc::now().timestamp().into(),
                                 flow_type: flow_name,
                                 connector: connector_name.clone(),
@@ -335,6 +515,7 @@ where
 
                             let event = Event {
                                 request_id: request_id.to_string(),
+                                merchant_id: merchant_id.clone(),
                                 timestamp: chrono::Utc::now().timestamp().into(),
                                 flow_type: flow_name,
                                 connector: connector_name.clone(),
@@ -398,7 +579,13 @@ where
                                     "response.headers",
                                     tracing::field::display(header_map),
                                 );
-                                tracing::Span::current().record("response.body", tracing::field::display(response.masked_serialize().unwrap_or(json!({ "error": "failed to mask serialize connector response"}))));
+                                traffic_log.response_body = Some(
+                                    minor_unit_as_string::scope(
+                                        event_params.event_config.minor_unit_as_string_in_responses,
+                                        || response.masked_serialize(),
+                                    )
+                                    .unwrap_or(json!({ "error": "failed to mask serialize connector response"})),
+                                );
                             }
 
                             let is_source_verified = connector.verify(&router_data, interfaces::verification::ConnectorSourceVerificationSecrets::AuthHeaders(router_data.connector_auth_type.clone()), &body.response)?;
@@ -411,6 +598,9 @@ where
 
                             // Set raw_connector_response BEFORE calling the transformer
                             let mut updated_router_data = router_data.clone();
+                            updated_router_data
+                                .resource_common_data
+                                .set_connector_http_status_code(status_code);
                             if all_keys_required.unwrap_or(true) {
                                 let raw_response_string =
                                     strip_bom_and_convert_to_string(&body.response);
@@ -435,9 +625,42 @@ where
                                     tracing::info!("Transformer completed successfully");
                                     Ok(data)
                                 }
+                                Err(err)
+                                    if unparsable_response_fallback.enabled
+                                        && err.current_context()
+                                            == &ConnectorError::ResponseDeserializationFailed =>
+                                {
+                                    tracing::warn!(
+                                        "Connector response could not be parsed, returning its text as the error"
+                                    );
+                                    let test_mode =
+                                        updated_router_data.resource_common_data.is_test_mode();
+                                    updated_router_data.response = Err(unparsable_response_error(
+                                        &body,
+                                        test_mode,
+                                        &unparsable_response_fallback,
+                                    ));
+                                    Ok(updated_router_data)
+                                }
                                 Err(err) => Err(err),
                             }?
                         }
+                        Err(body) if is_connector_auth_failure(body.status_code) => {
+                            metrics::EXTERNAL_SERVICE_API_CALLS_ERRORS
+                                .with_label_values(&[
+                                    &method.to_string(),
+                                    event_params.service_name,
+                                    event_params.connector_name,
+                                    body.status_code.to_string().as_str(),
+                                ])
+                                .inc();
+                            // Credential problems are reported as such rather than as a decline
+                            return Err(error_stack::report!(
+                                ConnectorError::ConnectorAuthenticationFailed {
+                                    status_code: body.status_code,
+                                }
+                            ));
+                        }
                         Err(body) => {
                             metrics::EXTERNAL_SERVICE_API_CALLS_ERRORS
                                 .with_label_values(&[
@@ -450,6 +673,9 @@ where
 
                             // Set raw connector response for error cases BEFORE processing error
                             let mut updated_router_data = router_data.clone();
+                            updated_router_data
+                                .resource_common_data
+                                .set_connector_http_status_code(body.status_code);
                             if all_keys_required.unwrap_or(true) {
                                 let raw_response_string =
                                     strip_bom_and_convert_to_string(&body.response);
@@ -504,14 +730,126 @@ where
     };
 
     let elapsed = start.elapsed().as_millis();
-    if let Some(req) = req {
-        tracing::Span::current().record("request.body", tracing::field::display(req));
-    }
+    traffic_log.finish(
+        result_with_integrity_check
+            .as_ref()
+            .map_or(true, |data| data.response.is_err()),
+    );
     tracing::Span::current().record("latency", elapsed);
     tracing::info!(tag = ?Tag::OutgoingApi, log_type = "api", "Outgoing Request completed");
     result_with_integrity_check
 }
 
+/// Error returned in place of a connector response that could not be parsed, carrying the
+/// start of the response text so integrators can see what the connector sent.
+///
+/// Outside test mode the text may hold card or customer data, so its letters and digits are
+/// masked, leaving only the shape of the response (an HTML page, truncated JSON). The outcome
+/// at the connector is unknown, so the attempt is left pending.
+pub fn unparsable_response_error(
+    response: &Response,
+    test_mode: bool,
+    config: &UnparsableResponseFallbackConfig,
+) -> domain_types::router_data::ErrorResponse {
+    let text = strip_bom_and_convert_to_string(&response.response)
+        .unwrap_or_else(|| String::from_utf8_lossy(&response.response).into_owned());
+    let description: String = text
+        .chars()
+        .take(config.max_chars)
+        .map(|character| {
+            if test_mode || !character.is_alphanumeric() {
+                character
+            } else {
+                '*'
+            }
+        })
+        .collect();
+    domain_types::router_data::ErrorResponse {
+        code: UNPARSABLE_RESPONSE_ERROR_CODE.to_string(),
+        message: format!("Connector response could not be parsed: {description}"),
+        reason: Some(description),
+        status_code: response.status_code,
+        attempt_status: Some(common_enums::AttemptStatus::Pending),
+        connector_transaction_id: None,
+        network_decline_code: None,
+        network_advice_code: None,
+        network_error_message: None,
+    }
+}
+
+pub const UNPARSABLE_RESPONSE_ERROR_CODE: &str = "UNPARSABLE_CONNECTOR_RESPONSE";
+
+/// Returns true when the connector rejected the request because of the configured credentials.
+pub fn is_connector_auth_failure(status_code: u16) -> bool {
+    matches!(status_code, 401 | 403)
+}
+
+/// Adds the headers forwarded from the inbound request metadata.
+///
+/// Headers built by the connector take precedence over forwarded ones with the same name.
+pub fn add_forwarded_headers(
+    mut request: Request,
+    forwarded_headers: &[(String, String)],
+) -> Request {
+    for (name, value) in forwarded_headers {
+        let already_set = request
+            .headers
+            .iter()
+            .any(|(existing, _)| existing.eq_ignore_ascii_case(name));
+        if already_set {
+            tracing::warn!(header = %name, "forwarded header is already set by the connector");
+            continue;
+        }
+        request.add_header(name, value.clone().into_masked());
+    }
+    request
+}
+
+/// Re-encodes the request body in the configured format and rewrites the `Content-Type` header.
+///
+/// The format a flow builds is always accepted; any other format must be listed in the
+/// connector's supported request content types.
+pub fn override_request_content_type(
+    mut request: Request,
+    content_type: ContentType,
+    supported_content_types: &[ContentType],
+) -> CustomResult<Request, ConnectorError> {
+    let Some(body) = request.body.take() else {
+        return Ok(request);
+    };
+
+    if body.content_type() == Some(content_type) {
+        request.body = Some(body);
+        return Ok(request);
+    }
+
+    if !supported_content_types.contains(&content_type) {
+        tracing::error!(
+            %content_type,
+            "configured request content type is not supported by the connector"
+        );
+        return Err(report!(ConnectorError::InvalidConnectorConfig {
+            config: "request_content_type",
+        }));
+    }
+
+    let body = body.into_content_type(content_type).map_err(|_| {
+        report!(ConnectorError::InvalidConnectorConfig {
+            config: "request_content_type",
+        })
+    })?;
+
+    request
+        .headers
+        .retain(|(name, _)| !name.eq_ignore_ascii_case(http::header::CONTENT_TYPE.as_str()));
+    request.headers.insert((
+        http::header::CONTENT_TYPE.to_string(),
+        content_type.header_value().to_string().into(),
+    ));
+    request.body = Some(body);
+    Ok(request)
+}
+
 pub enum ApplicationResponse<R> {
     Json(R),
 }
@@ -524,6 +862,8 @@ pub async fn call_connector_api(
     proxy: &Proxy,
     request: Request,
     _flow_name: &str,
+    timeout: Option<Duration>,
+    tls: Option<&ConnectorTlsConfig>,
 ) -> CustomResult<Result<Response, Response>, ApiClientError> {
     let url =
         reqwest::Url::parse(&request.url).change_context(ApiClientError::UrlEncodingFailed)?;
@@ -535,6 +875,7 @@ pub async fn call_connector_api(
         should_bypass_proxy,
         request.certificate,
         request.certificate_key,
+        tls,
     )?;
 
     let headers = request.headers.construct_header_map()?;
@@ -562,6 +903,10 @@ pub async fn call_connector_api(
         }
         .add_headers(headers)
     };
+    let request = match timeout {
+        Some(timeout) => request.timeout(timeout),
+        None => request,
+    };
     let send_request = async {
         request.send().await.map_err(|error| {
             let api_error = match error {
@@ -586,8 +931,12 @@ pub fn create_client(
     should_bypass_proxy: bool,
     _client_certificate: Option<Secret<String>>,
     _client_certificate_key: Option<Secret<String>>,
+    tls: Option<&ConnectorTlsConfig>,
 ) -> CustomResult<Client, ApiClientError> {
-    get_base_client(proxy_config, should_bypass_proxy)
+    match tls {
+        Some(tls) => get_tls_client(proxy_config, should_bypass_proxy, tls),
+        None => get_base_client(proxy_config, should_bypass_proxy),
+    }
     // match (client_certificate, client_certificate_key) {
     //     (Some(encoded_certificate), Some(encoded_certificate_key)) => {
     //         let client_builder = get_client_builder(proxy_config, should_bypass_proxy)?;
@@ -649,6 +998,87 @@ fn get_base_client(
     .clone())
 }
 
+/// Clients of connectors with TLS settings, keyed by whether they bypass the proxy and the settings
+static TLS_CLIENTS: Lazy<Mutex<HashMap<(bool, ConnectorTlsConfig), Client>>> =
+    Lazy::new(Mutex::default);
+
+fn get_tls_client(
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    tls: &ConnectorTlsConfig,
+) -> CustomResult<Client, ApiClientError> {
+    let mut clients = TLS_CLIENTS.lock().unwrap_or_else(PoisonError::into_inner);
+    let key = (should_bypass_proxy, tls.clone());
+    if let Some(client) = clients.get(&key) {
+        return Ok(client.clone());
+    }
+    let client = build_tls_client(proxy_config, should_bypass_proxy, tls)?;
+    clients.insert(key, client.clone());
+    Ok(client)
+}
+
+/// Builds a client that presents the configured client certificate and trusts the configured
+/// CA bundle, failing when a certificate or key cannot be loaded
+pub fn build_tls_client(
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    tls: &ConnectorTlsConfig,
+) -> CustomResult<Client, ApiClientError> {
+    let mut client_builder =
+        get_client_builder(proxy_config, should_bypass_proxy)?.use_rustls_tls();
+
+    match (&tls.client_certificate_path, &tls.client_key_path) {
+        (Some(certificate_path), Some(key_path)) => {
+            let mut identity_pem = read_pem_file(key_path)?;
+            identity_pem.extend(read_pem_file(certificate_path)?);
+            let identity = reqwest::Identity::from_pem(&identity_pem)
+                .change_context(ApiClientError::CertificateDecodeFailed)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "invalid client certificate {} or key {}",
+                        certificate_path.display(),
+                        key_path.display()
+                    )
+                })?;
+            client_builder = client_builder.identity(identity);
+        }
+        (None, None) => {}
+        _ => {
+            return Err(report!(ApiClientError::CertificateDecodeFailed)).attach_printable(
+                "client_certificate_path and client_key_path must be configured together",
+            )
+        }
+    }
+
+    if let Some(root_ca_path) = &tls.root_ca_path {
+        let certificates = reqwest::Certificate::from_pem_bundle(&read_pem_file(root_ca_path)?)
+            .change_context(ApiClientError::CertificateDecodeFailed)
+            .attach_printable_lazy(|| format!("invalid CA bundle {}", root_ca_path.display()))?;
+        client_builder = certificates
+            .into_iter()
+            .fold(client_builder, |client_builder, certificate| {
+                client_builder.add_root_certificate(certificate)
+            });
+    }
+
+    client_builder
+        .danger_accept_invalid_certs(tls.danger_accept_invalid_certs)
+        .build()
+        .change_context(ApiClientError::ClientConstructionFailed)
+        .inspect_err(|err| {
+            info_log(
+                "ERROR",
+                &json!(format!("Failed to construct TLS client. Error: {:?}", err)),
+            );
+        })
+}
+
+fn read_pem_file(path: &Path) -> CustomResult<Vec<u8>, ApiClientError> {
+    std::fs::read(path)
+        .change_context(ApiClientError::CertificateDecodeFailed)
+        .attach_printable_lazy(|| format!("failed to read {}", path.display()))
+}
+
 fn get_client_builder(
     proxy_config: &Proxy,
     should_bypass_proxy: bool,
@@ -897,3 +1327,255 @@ pub fn error_log(action: &str, message: &serde_json::Value) {
 pub fn warn_log(action: &str, message: &serde_json::Value) {
     tracing::warn!(tags = %action, json_value= %message);
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::request::RequestBuilder;
+
+    use super::*;
+
+    fn json_request() -> Request {
+        RequestBuilder::new()
+            .method(Method::Post)
+            .url("https://connector.test/payments")
+            .header("Content-Type", "application/json")
+            .set_body(RequestContent::Json(Box::new(json!({ "amount": 1000 }))))
+            .build()
+    }
+
+    fn content_type_headers(request: &Request) -> Vec<String> {
+        request
+            .headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone().into_inner())
+            .collect()
+    }
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(name)
+    }
+
+    fn no_proxy() -> Proxy {
+        Proxy {
+            http_url: None,
+            https_url: None,
+            idle_pool_connection_timeout: None,
+            bypass_proxy_urls: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_tls_client_is_built_with_client_certificate() {
+        let tls = ConnectorTlsConfig {
+            client_certificate_path: Some(fixture("client_cert.pem")),
+            client_key_path: Some(fixture("client_key.pem")),
+            root_ca_path: Some(fixture("client_cert.pem")),
+            danger_accept_invalid_certs: false,
+        };
+
+        assert!(build_tls_client(&no_proxy(), false, &tls).is_ok());
+    }
+
+    #[test]
+    fn test_tls_client_requires_loadable_certificate_and_key() {
+        let missing_key = ConnectorTlsConfig {
+            client_certificate_path: Some(fixture("client_cert.pem")),
+            client_key_path: Some(fixture("missing_key.pem")),
+            root_ca_path: None,
+            danger_accept_invalid_certs: false,
+        };
+        let certificate_without_key = ConnectorTlsConfig {
+            client_key_path: None,
+            ..missing_key.clone()
+        };
+
+        for tls in [missing_key, certificate_without_key] {
+            assert_eq!(
+                build_tls_client(&no_proxy(), false, &tls)
+                    .unwrap_err()
+                    .current_context(),
+                &ApiClientError::CertificateDecodeFailed
+            );
+        }
+    }
+
+    #[test]
+    fn test_supported_override_rewrites_content_type_header() {
+        let request = override_request_content_type(
+            json_request(),
+            ContentType::FormUrlEncoded,
+            &[ContentType::Json, ContentType::FormUrlEncoded],
+        )
+        .unwrap();
+
+        assert_eq!(
+            content_type_headers(&request),
+            vec!["application/x-www-form-urlencoded".to_string()]
+        );
+        assert_eq!(
+            request.body.as_ref().and_then(RequestContent::content_type),
+            Some(ContentType::FormUrlEncoded)
+        );
+    }
+
+    #[test]
+    fn test_canonical_content_type_is_left_untouched() {
+        let request =
+            override_request_content_type(json_request(), ContentType::Json, &[]).unwrap();
+
+        assert_eq!(
+            content_type_headers(&request),
+            vec!["application/json".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_override_is_rejected() {
+        let result = override_request_content_type(json_request(), ContentType::Xml, &[]);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            ConnectorError::InvalidConnectorConfig { .. }
+        ));
+    }
+
+    #[test]
+    fn test_forwarded_metadata_becomes_connector_header() {
+        let request = add_forwarded_headers(
+            json_request(),
+            &[("x-partner-id".to_string(), "partner_123".to_string())],
+        );
+
+        let forwarded: Vec<String> = request
+            .headers
+            .iter()
+            .filter(|(name, _)| name == "x-partner-id")
+            .map(|(_, value)| value.clone().into_inner())
+            .collect();
+        assert_eq!(forwarded, vec!["partner_123".to_string()]);
+    }
+
+    #[test]
+    fn test_forwarded_header_does_not_override_connector_header() {
+        let request = add_forwarded_headers(
+            json_request(),
+            &[("content-type".to_string(), "text/plain".to_string())],
+        );
+
+        assert_eq!(
+            content_type_headers(&request),
+            vec!["application/json".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_failed_calls_are_always_logged() {
+        let config = ConnectorTrafficLogConfig {
+            success_sample_rate: 0.0,
+        };
+
+        assert!(config.should_log(true, 0.0));
+        assert!(config.should_log(true, 0.99));
+    }
+
+    #[test]
+    fn test_successful_calls_are_sampled() {
+        let config = ConnectorTrafficLogConfig {
+            success_sample_rate: 0.25,
+        };
+
+        assert!(config.should_log(false, 0.1));
+        assert!(!config.should_log(false, 0.25));
+        assert!(!config.should_log(false, 0.9));
+        assert!(ConnectorTrafficLogConfig::default().should_log(false, 0.99));
+    }
+
+    #[test]
+    fn test_traffic_of_failed_calls_is_logged_once() {
+        let config = ConnectorTrafficLogConfig {
+            success_sample_rate: 0.0,
+        };
+
+        let mut succeeded = TrafficLog::new(&config);
+        succeeded.request = Some(json!({ "amount": 1000 }));
+        succeeded.finish(false);
+        assert!(succeeded.request.is_some());
+
+        let mut failed = TrafficLog::new(&config);
+        failed.request = Some(json!({ "amount": 1000 }));
+        failed.finish(true);
+        assert!(failed.request.is_none());
+        assert!(failed.logged);
+    }
+
+    fn malformed_response() -> Response {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+        Response {
+            headers: Some(headers),
+            response: r#"{"pspReference":"8815123456789012","resultCode":"Authorised","amount":"#
+                .as_bytes()
+                .to_vec()
+                .into(),
+            status_code: 200,
+        }
+    }
+
+    #[test]
+    fn test_unparsable_response_text_is_masked_outside_test_mode() {
+        let config = UnparsableResponseFallbackConfig {
+            max_chars: 40,
+            ..Default::default()
+        };
+
+        let error = unparsable_response_error(&malformed_response(), false, &config);
+
+        assert_eq!(error.code, UNPARSABLE_RESPONSE_ERROR_CODE);
+        assert_eq!(
+            error.reason.as_deref(),
+            Some(r#"{"************":"****************","****"#)
+        );
+        assert!(!error.message.contains("8815"));
+        assert!(!error.message.contains("Authorised"));
+        assert_eq!(
+            error.attempt_status,
+            Some(common_enums::AttemptStatus::Pending)
+        );
+    }
+
+    #[test]
+    fn test_unparsable_response_text_is_kept_in_test_mode() {
+        let config = UnparsableResponseFallbackConfig::default();
+
+        let error = unparsable_response_error(&malformed_response(), true, &config);
+
+        assert_eq!(
+            error.message,
+            r#"Connector response could not be parsed: {"pspReference":"8815123456789012","resultCode":"Authorised","amount":"#
+        );
+    }
+
+    #[test]
+    fn test_unparsable_html_response_keeps_its_markup() {
+        let response = Response {
+            headers: None,
+            response: b"<html><body>Bad Gateway</body></html>".to_vec().into(),
+            status_code: 200,
+        };
+        let config = UnparsableResponseFallbackConfig::default();
+
+        let error = unparsable_response_error(&response, false, &config);
+
+        assert_eq!(
+            error.reason.as_deref(),
+            Some("<****><****>*** *******</****></****>")
+        );
+    }
+}
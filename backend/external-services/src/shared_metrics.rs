@@ -1,23 +1,31 @@
 #![allow(clippy::unwrap_used)]
 use std::{
+    collections::{HashMap, VecDeque},
     future::Future,
     pin::Pin,
+    sync::Mutex,
     task::{Context, Poll},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use error_stack::ResultExt;
 use http_body::Body as HttpBody;
 use lazy_static::lazy_static;
 use prometheus::{
-    self, register_histogram_vec, register_int_counter_vec, Encoder, HistogramVec, IntCounterVec,
-    TextEncoder,
+    self, core::Collector, register_gauge_vec, register_histogram_vec, register_int_counter_vec,
+    register_int_gauge, Encoder, GaugeVec, HistogramVec, IntCounterVec, IntGauge, TextEncoder,
 };
 use tower::{Layer, Service};
 // Define latency buckets for histograms
 const LATENCY_BUCKETS: &[f64] = &[
     0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
 ];
+// Connector calls take from tens of milliseconds to tens of seconds (3DS, slow acquirers)
+const CONNECTOR_LATENCY_BUCKETS: &[f64] = &[
+    0.05, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 7.5, 10.0, 15.0, 30.0, 60.0,
+];
+/// Quantiles exposed for connector call latency, with their `quantile` label
+const CONNECTOR_LATENCY_QUANTILES: &[(f64, &str)] = &[(0.5, "0.5"), (0.9, "0.9"), (0.99, "0.99")];
 
 lazy_static! {
     pub static ref GRPC_SERVER_REQUESTS_TOTAL: IntCounterVec = register_int_counter_vec!(
@@ -32,6 +40,24 @@ lazy_static! {
         &["method", "service", "connector"]
     )
     .unwrap();
+    pub static ref GRPC_SERVER_AUDIT_EVENTS_TOTAL: IntCounterVec = register_int_counter_vec!(
+        "GRPC_SERVER_AUDIT_EVENTS_TOTAL",
+        "Total number of audit events emitted for gRPC requests",
+        &["method", "connector"]
+    )
+    .unwrap();
+    pub static ref DECLINE_EVENTS_TOTAL: IntCounterVec = register_int_counter_vec!(
+        "DECLINE_EVENTS_TOTAL",
+        "Total number of decline events emitted for failed authorizations",
+        &["connector", "category"]
+    )
+    .unwrap();
+    pub static ref PAYMENT_OUTCOMES_TOTAL: IntCounterVec = register_int_counter_vec!(
+        "PAYMENT_OUTCOMES_TOTAL",
+        "Total number of connector outcomes by connector, flow and normalized status",
+        &["connector", "flow", "status"]
+    )
+    .unwrap();
     pub static ref GRPC_SERVER_REQUEST_LATENCY: HistogramVec = register_histogram_vec!(
         "GRPC_SERVER_REQUEST_LATENCY",
         "Request latency in seconds",
@@ -46,6 +72,20 @@ lazy_static! {
         LATENCY_BUCKETS.to_vec()
     )
     .unwrap();
+    /// Connector call latency per flow, with buckets sized for payment latencies
+    pub static ref CONNECTOR_CALL_LATENCY: HistogramVec = register_histogram_vec!(
+        "CONNECTOR_CALL_LATENCY_SECONDS",
+        "Latency of connector calls by connector and flow",
+        &["connector", "flow"],
+        CONNECTOR_LATENCY_BUCKETS.to_vec()
+    )
+    .unwrap();
+    pub static ref CONNECTOR_CALL_LATENCY_QUANTILES: GaugeVec = register_gauge_vec!(
+        "CONNECTOR_CALL_LATENCY_QUANTILE_SECONDS",
+        "p50, p90 and p99 of recent connector call latency, estimated from CONNECTOR_CALL_LATENCY_SECONDS",
+        &["connector", "flow", "quantile"]
+    )
+    .unwrap();
     pub static ref EXTERNAL_SERVICE_TOTAL_API_CALLS: IntCounterVec = register_int_counter_vec!(
         "EXTERNAL_SERVICE_TOTAL_API_CALLS",
         "Total number of external service API calls",
@@ -58,6 +98,11 @@ lazy_static! {
         &["method", "service", "connector", "error"]
     )
     .unwrap();
+    pub static ref CONNECTOR_CALL_QUEUE_DEPTH: IntGauge = register_int_gauge!(
+        "CONNECTOR_CALL_QUEUE_DEPTH",
+        "Number of requests waiting for a free slot under the global connector concurrency limit"
+    )
+    .unwrap();
 }
 
 // Middleware Layer that automatically handles all gRPC methods
@@ -211,6 +256,126 @@ fn is_grpc_success<B>(response: &hyper::Response<B>) -> bool {
     true
 }
 
+/// Cumulative bucket counts and sample count of one connector latency series
+type SeriesCounts = (Vec<(f64, u64)>, u64);
+
+/// Counts of every connector latency series, keyed by (connector, flow), at one refresh
+struct LatencySnapshot {
+    taken_at: Instant,
+    series: HashMap<(String, String), SeriesCounts>,
+}
+
+static LATENCY_SNAPSHOTS: Mutex<VecDeque<LatencySnapshot>> = Mutex::new(VecDeque::new());
+
+/// Refreshes the connector latency quantile gauges, so they can be read without aggregating
+/// the buckets. The quantiles cover the calls of the last `window`, measured against the
+/// counts of an earlier refresh; a series without calls in the window has its gauges removed.
+pub fn update_connector_latency_quantiles(window: Duration) {
+    let now = Instant::now();
+    let mut current = HashMap::new();
+    for family in CONNECTOR_CALL_LATENCY.collect() {
+        for metric in family.get_metric() {
+            let label = |name: &str| {
+                metric
+                    .get_label()
+                    .iter()
+                    .find(|label| label.get_name() == name)
+                    .map(|label| label.get_value().to_string())
+                    .unwrap_or_default()
+            };
+            let histogram = metric.get_histogram();
+            let buckets: Vec<(f64, u64)> = histogram
+                .get_bucket()
+                .iter()
+                .map(|bucket| (bucket.get_upper_bound(), bucket.get_cumulative_count()))
+                .collect();
+            current.insert(
+                (label("connector"), label("flow")),
+                (buckets, histogram.get_sample_count()),
+            );
+        }
+    }
+
+    let mut snapshots = LATENCY_SNAPSHOTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    // Keep the newest snapshot taken before the window started as the baseline
+    while snapshots
+        .get(1)
+        .is_some_and(|snapshot| now.duration_since(snapshot.taken_at) >= window)
+    {
+        snapshots.pop_front();
+    }
+    let baseline = snapshots
+        .front()
+        .filter(|snapshot| now.duration_since(snapshot.taken_at) >= window);
+
+    for ((connector, flow), (buckets, sample_count)) in &current {
+        let earlier =
+            baseline.and_then(|snapshot| snapshot.series.get(&(connector.clone(), flow.clone())));
+        let (window_buckets, window_count): (Vec<(f64, u64)>, u64) = match earlier {
+            Some((earlier_buckets, earlier_count)) => (
+                buckets
+                    .iter()
+                    .zip(earlier_buckets)
+                    .map(|((upper_bound, count), (_, earlier))| {
+                        (*upper_bound, count.saturating_sub(*earlier))
+                    })
+                    .collect(),
+                sample_count.saturating_sub(*earlier_count),
+            ),
+            None => (buckets.clone(), *sample_count),
+        };
+        for (quantile, quantile_label) in CONNECTOR_LATENCY_QUANTILES {
+            let labels = [connector.as_str(), flow.as_str(), *quantile_label];
+            match histogram_quantile(*quantile, &window_buckets, window_count) {
+                Some(value) => CONNECTOR_CALL_LATENCY_QUANTILES
+                    .with_label_values(&labels)
+                    .set(value),
+                None => {
+                    let _ = CONNECTOR_CALL_LATENCY_QUANTILES.remove_label_values(&labels);
+                }
+            }
+        }
+    }
+
+    snapshots.push_back(LatencySnapshot {
+        taken_at: now,
+        series: current,
+    });
+}
+
+/// Estimates a quantile from cumulative histogram buckets, interpolating linearly inside the
+/// bucket it falls in like Prometheus' `histogram_quantile`. Observations above the last bucket
+/// are reported as its upper bound.
+fn histogram_quantile(quantile: f64, buckets: &[(f64, u64)], sample_count: u64) -> Option<f64> {
+    if sample_count == 0 {
+        return None;
+    }
+    let rank = quantile * count_as_f64(sample_count);
+    let mut lower_bound = 0.0;
+    let mut lower_count = 0;
+    for (upper_bound, cumulative_count) in buckets {
+        if count_as_f64(*cumulative_count) >= rank {
+            let bucket_count = cumulative_count.saturating_sub(lower_count);
+            if bucket_count == 0 {
+                return Some(*upper_bound);
+            }
+            let position = (rank - count_as_f64(lower_count)) / count_as_f64(bucket_count);
+            return Some(lower_bound + (upper_bound - lower_bound) * position);
+        }
+        lower_bound = *upper_bound;
+        lower_count = *cumulative_count;
+    }
+    buckets.last().map(|(upper_bound, _)| *upper_bound)
+}
+
+// Bucket counts stay far below 2^53, so the conversion is exact
+#[allow(clippy::as_conversions)]
+fn count_as_f64(count: u64) -> f64 {
+    count as f64
+}
+
 // Metrics handler
 pub async fn metrics_handler() -> error_stack::Result<String, MetricsError> {
     let mut buffer = Vec::new();
@@ -229,3 +394,26 @@ pub enum MetricsError {
     #[error("Error converting metrics to utf8")]
     Utf8Error,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_quantile_interpolates_within_bucket() {
+        let buckets = [(0.1, 50), (0.5, 90), (1.0, 100)];
+        let estimate = |quantile| histogram_quantile(quantile, &buckets, 100).unwrap_or(f64::NAN);
+
+        assert!((estimate(0.5) - 0.1).abs() < 1e-9);
+        assert!((estimate(0.7) - 0.3).abs() < 1e-9);
+        assert!((estimate(0.99) - 0.95).abs() < 1e-9);
+        assert_eq!(histogram_quantile(0.5, &buckets, 0), None);
+    }
+
+    #[test]
+    fn histogram_quantile_caps_at_last_bucket() {
+        let buckets = [(0.1, 1), (0.5, 2)];
+
+        assert_eq!(histogram_quantile(0.99, &buckets, 10), Some(0.5));
+    }
+}
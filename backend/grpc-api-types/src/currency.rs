@@ -0,0 +1,81 @@
+//! ISO 4217 alphabetic codes for [`Currency`], e.g. to read currencies from JSON config.
+
+use std::{fmt, str::FromStr};
+
+use crate::payments::Currency;
+
+/// Error returned when a string is not the alphabetic code of a supported currency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCurrencyError {
+    code: String,
+}
+
+impl UnknownCurrencyError {
+    /// The code that could not be parsed
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl fmt::Display for UnknownCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown ISO 4217 currency code: {:?}", self.code)
+    }
+}
+
+impl std::error::Error for UnknownCurrencyError {}
+
+impl FromStr for Currency {
+    type Err = UnknownCurrencyError;
+
+    /// Parses an ISO 4217 alphabetic code such as `USD`, ignoring case
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::from_str_name(&code.to_ascii_uppercase())
+            .filter(|currency| *currency != Self::Unspecified)
+            .ok_or_else(|| UnknownCurrencyError {
+                code: code.to_string(),
+            })
+    }
+}
+
+impl fmt::Display for Currency {
+    /// Writes the ISO 4217 alphabetic code, e.g. `USD`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn round_trips_every_currency() {
+        let currencies: Vec<Currency> = (1..)
+            .map_while(|value| Currency::try_from(value).ok())
+            .collect();
+
+        assert_eq!(currencies.len(), 160);
+        for currency in currencies {
+            assert_eq!(Currency::from_str(&currency.to_string()), Ok(currency));
+        }
+    }
+
+    #[test]
+    fn parses_lowercase_codes() {
+        assert_eq!(Currency::from_str("usd"), Ok(Currency::Usd));
+    }
+
+    #[test]
+    fn rejects_unknown_codes() {
+        for code in ["XYZ", "", "CURRENCY_UNSPECIFIED"] {
+            let error = Currency::from_str(code).unwrap_err();
+            assert_eq!(error.code(), code);
+        }
+        assert_eq!(
+            Currency::from_str("XYZ").unwrap_err().to_string(),
+            "unknown ISO 4217 currency code: \"XYZ\""
+        );
+    }
+}
@@ -8,6 +8,8 @@ pub mod payments {
     tonic::include_proto!("ucs.v2");
 }
 
+pub mod currency;
+
 pub mod health_check {
     tonic::include_proto!("grpc.health.v1");
 }
@@ -1,4 +1,4 @@
-use std::{future::Future, net, sync::Arc};
+use std::{future::Future, net, sync::Arc, time::Duration};
 
 use axum::{extract::Request, http};
 use common_utils::consts;
@@ -17,12 +17,23 @@ use tokio::{
 use tonic::transport::Server;
 use tower_http::{request_id::MakeRequestUuid, trace as tower_trace};
 
-use crate::{configs, error::ConfigurationError, logger, utils};
+use crate::{
+    configs,
+    connector_limiter::ConnectorLimiterLayer,
+    error::ConfigurationError,
+    logger,
+    request_timeout::RequestTimeoutLayer,
+    server::health_check::{MetricsServerBound, READINESS_POLL_INTERVAL},
+    utils,
+};
 
 /// # Panics
 ///
 /// Will panic if redis connection establishment fails or signal handling fails
-pub async fn server_builder(config: configs::Config) -> Result<(), ConfigurationError> {
+pub async fn server_builder(
+    config: configs::Config,
+    metrics_server_bound: MetricsServerBound,
+) -> Result<(), ConfigurationError> {
     let server_config = config.server.clone();
     let socket_addr = net::SocketAddr::new(server_config.host.parse()?, server_config.port);
 
@@ -66,7 +77,7 @@ pub async fn server_builder(config: configs::Config) -> Result<(), Configuration
         logger::info!("Shutdown signal received");
     };
 
-    let service = Service::new(Arc::new(config));
+    let service = Service::new(Arc::new(config), metrics_server_bound);
 
     logger::info!(host = %server_config.host, port = %server_config.port, r#type = ?server_config.type_, "starting connector service");
 
@@ -101,7 +112,10 @@ impl Service {
     /// Will panic if EventPublisher initialization fails, database password, hash key isn't present in configs or unable to
     /// deserialize any of the above keys
     #[allow(clippy::expect_used)]
-    pub async fn new(config: Arc<configs::Config>) -> Self {
+    pub async fn new(
+        config: Arc<configs::Config>,
+        metrics_server_bound: MetricsServerBound,
+    ) -> Self {
         // Initialize the global EventPublisher - fail fast on startup
         if config.events.enabled {
             common_utils::init_event_publisher(&config.events)
@@ -112,14 +126,25 @@ impl Service {
         }
 
         Self {
-            health_check_service: crate::server::health_check::HealthCheck,
+            health_check_service: crate::server::health_check::HealthCheck {
+                config: Arc::clone(&config),
+                metrics_server_bound,
+                readiness_poll_interval: READINESS_POLL_INTERVAL,
+                kafka_logging_probe: logger::setup::kafka_logging_connectivity,
+            },
             payments_service: crate::server::payments::Payments {
                 config: Arc::clone(&config),
+                capture_responses: Arc::new(config.state_store.build()),
+                auto_captured_payments: Arc::new(config.state_store.build()),
+                payment_correlations: Arc::new(config.state_store.build()),
             },
             refunds_service: crate::server::refunds::Refunds {
                 config: Arc::clone(&config),
             },
-            disputes_service: crate::server::disputes::Disputes { config },
+            disputes_service: crate::server::disputes::Disputes {
+                evidence_store: Arc::new(config.evidence_store.build()),
+                config,
+            },
         }
     }
 
@@ -151,6 +176,9 @@ impl Service {
             http::HeaderName::from_static(consts::X_REQUEST_ID),
         );
 
+        let service_version_layer =
+            utils::service_version_layer(&self.payments_service.config.server);
+
         let router = axum::Router::new()
             .route("/health", axum::routing::get(|| async { "health is good" }))
             .merge(payment_service_handler(self.payments_service))
@@ -158,13 +186,24 @@ impl Service {
             .merge(dispute_service_handler(self.disputes_service))
             .layer(logging_layer)
             .layer(request_id_layer)
-            .layer(propagate_request_id_layer);
+            .layer(propagate_request_id_layer)
+            .layer(service_version_layer);
+
+        let grace_period = self.payments_service.config.server.shutdown_grace_period();
+        let (draining_tx, draining_rx) = oneshot::channel();
+        let shutdown_signal = async move {
+            shutdown_signal.await;
+            let _ = draining_tx.send(());
+        };
 
         let listener = tokio::net::TcpListener::bind(socket).await?;
 
-        axum::serve(listener, router.into_make_service())
-            .with_graceful_shutdown(shutdown_signal)
-            .await?;
+        let serve = async move {
+            axum::serve(listener, router.into_make_service())
+                .with_graceful_shutdown(shutdown_signal)
+                .await
+        };
+        drain_within(serve, draining_rx, grace_period).await?;
 
         Ok(())
     }
@@ -174,6 +213,13 @@ impl Service {
         socket: net::SocketAddr,
         shutdown_signal: impl Future<Output = ()>,
     ) -> Result<(), ConfigurationError> {
+        let grace_period = self.payments_service.config.server.shutdown_grace_period();
+        let (draining_tx, draining_rx) = oneshot::channel();
+        let shutdown_signal = async move {
+            shutdown_signal.await;
+            let _ = draining_tx.send(());
+        };
+
         let reflection_service = tonic_reflection::server::Builder::configure()
             .register_encoded_file_descriptor_set(grpc_api_types::FILE_DESCRIPTOR_SET)
             .build_v1()?;
@@ -195,6 +241,9 @@ impl Service {
             );
 
         let metrics_layer = metrics::GrpcMetricsLayer::new();
+        let request_timeout_layer = RequestTimeoutLayer::new(&self.payments_service.config.timeout);
+        let connector_limiter_layer =
+            ConnectorLimiterLayer::new(&self.payments_service.config.connector_concurrency);
 
         let request_id_layer = tower_http::request_id::SetRequestIdLayer::new(
             http::HeaderName::from_static(consts::X_REQUEST_ID),
@@ -203,12 +252,17 @@ impl Service {
         let propagate_request_id_layer = tower_http::request_id::PropagateRequestIdLayer::new(
             http::HeaderName::from_static(consts::X_REQUEST_ID),
         );
+        let service_version_layer =
+            utils::service_version_layer(&self.payments_service.config.server);
 
-        Server::builder()
+        let serve = Server::builder()
             .layer(logging_layer)
             .layer(request_id_layer)
             .layer(propagate_request_id_layer)
+            .layer(service_version_layer)
             .layer(metrics_layer)
+            .layer(request_timeout_layer)
+            .layer(connector_limiter_layer)
             .add_service(reflection_service)
             .add_service(health_server::HealthServer::new(self.health_check_service))
             .add_service(payment_service_server::PaymentServiceServer::new(
@@ -220,19 +274,56 @@ impl Service {
             .add_service(dispute_service_server::DisputeServiceServer::new(
                 self.disputes_service,
             ))
-            .serve_with_shutdown(socket, shutdown_signal)
-            .await?;
+            .serve_with_shutdown(socket, shutdown_signal);
+        drain_within(serve, draining_rx, grace_period).await?;
 
         Ok(())
     }
 }
 
-pub async fn metrics_server_builder(config: configs::Config) -> Result<(), ConfigurationError> {
+/// Runs the server until it stops. Once `draining` fires the server no longer accepts
+/// connections, and the requests still in flight get `grace_period` to complete before they
+/// are dropped.
+async fn drain_within<E>(
+    serve: impl Future<Output = Result<(), E>>,
+    draining: oneshot::Receiver<()>,
+    grace_period: Duration,
+) -> Result<(), E> {
+    tokio::pin!(serve);
+
+    tokio::select! {
+        result = &mut serve => return result,
+        Ok(()) = draining => {}
+    }
+
+    logger::info!(?grace_period, "draining in-flight requests");
+    match tokio::time::timeout(grace_period, serve).await {
+        Ok(result) => result,
+        Err(_) => {
+            logger::warn!(
+                ?grace_period,
+                "in-flight requests did not complete within the shutdown grace period"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub async fn metrics_server_builder(
+    config: configs::Config,
+    metrics_server_bound: MetricsServerBound,
+) -> Result<(), ConfigurationError> {
     let listener = config.metrics.tcp_listener().await?;
+    metrics_server_bound.mark_bound();
+    let latency_quantiles = config.metrics.latency_quantiles;
+    let latency_quantile_window = Duration::from_secs(config.metrics.latency_quantile_window_secs);
 
     let router = axum::Router::new().route(
         "/metrics",
-        axum::routing::get(|| async {
+        axum::routing::get(move || async move {
+            if latency_quantiles {
+                metrics::update_connector_latency_quantiles(latency_quantile_window);
+            }
             let output = metrics::metrics_handler().await;
             match output {
                 Ok(metrics) => Ok(metrics),
@@ -248,12 +339,8 @@ pub async fn metrics_server_builder(config: configs::Config) -> Result<(), Confi
         }),
     );
 
-    axum::serve(listener, router.into_make_service())
-        .with_graceful_shutdown(async {
-            let output = tokio::signal::ctrl_c().await;
-            tracing::error!(?output, "shutting down");
-        })
-        .await?;
+    // Runs until the process exits, which waits on the API server to drain its requests
+    axum::serve(listener, router.into_make_service()).await?;
 
     Ok(())
 }
@@ -1,9 +1,16 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf, str::FromStr, sync::Arc};
 
 use common_utils::{consts, events::EventConfig};
-use domain_types::types::{Connectors, Proxy};
+use domain_types::{
+    connector_types::ConnectorEnum,
+    types::{ConnectorTrafficLogConfig, Connectors, IpNetwork, Proxy},
+};
 
-use crate::{error::ConfigurationError, logger::config::Log};
+use crate::{
+    error::ConfigurationError,
+    event_sink::{EventSink, KafkaEventSink},
+    logger::config::Log,
+};
 
 #[derive(Clone, serde::Deserialize, Debug)]
 pub struct Config {
@@ -12,11 +19,189 @@ pub struct Config {
     pub metrics: MetricsServer,
     pub log: Log,
     pub proxy: Proxy,
+    /// Missing connectors are left without a base url, which startup validation reports
+    #[serde(default)]
     pub connectors: Connectors,
     #[serde(default)]
     pub events: EventConfig,
     #[serde(default)]
     pub lineage: LineageConfig,
+    #[serde(default)]
+    pub connector_traffic_log: ConnectorTrafficLogConfig,
+    #[serde(default)]
+    pub connector_retry: ConnectorRetryConfig,
+    #[serde(default)]
+    pub connector_concurrency: ConnectorConcurrencyConfig,
+    #[serde(default)]
+    pub error_localization: ErrorLocalizationConfig,
+    #[serde(default)]
+    pub payment_correlation: PaymentCorrelationConfig,
+    #[serde(default)]
+    pub timeout: RequestTimeoutConfig,
+    #[serde(default)]
+    pub state_store: StateStoreConfig,
+    #[serde(default)]
+    pub evidence_store: EvidenceStoreConfig,
+    #[serde(default)]
+    pub webhook_source: WebhookSourceConfig,
+    /// Destination of the audit and decline events, Kafka unless replaced
+    #[serde(skip, default = "default_event_sink")]
+    pub event_sink: Arc<dyn EventSink>,
+}
+
+fn default_event_sink() -> Arc<dyn EventSink> {
+    Arc::new(KafkaEventSink)
+}
+
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct WebhookSourceConfig {
+    /// Proxy networks whose x-forwarded-for entries are trusted when the reported webhook
+    /// source belongs to one of them
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpNetwork>,
+}
+
+#[derive(Clone, serde::Deserialize, Debug)]
+pub struct StateStoreConfig {
+    /// Time (in seconds) the in-memory stores keep an entry, e.g. a capture response replayed
+    /// to repeated captures
+    #[serde(default = "default_state_store_ttl_secs")]
+    pub ttl_secs: u64,
+    /// Entries each in-memory store keeps; the oldest are evicted first
+    #[serde(default = "default_state_store_max_entries")]
+    pub max_entries: usize,
+}
+
+impl Default for StateStoreConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: default_state_store_ttl_secs(),
+            max_entries: default_state_store_max_entries(),
+        }
+    }
+}
+
+impl StateStoreConfig {
+    pub fn build<V>(&self) -> crate::state_store::StateStore<V> {
+        crate::state_store::StateStore::new(
+            std::time::Duration::from_secs(self.ttl_secs),
+            self.max_entries,
+        )
+    }
+}
+
+fn default_state_store_ttl_secs() -> u64 {
+    crate::state_store::DEFAULT_TTL.as_secs()
+}
+
+fn default_state_store_max_entries() -> usize {
+    crate::state_store::DEFAULT_MAX_ENTRIES
+}
+
+#[derive(Clone, serde::Deserialize, Debug)]
+pub struct EvidenceStoreConfig {
+    /// Time (in seconds) an evidence file uploaded through `UploadDisputeEvidence` is kept
+    /// while no submission uses it
+    #[serde(default = "default_evidence_store_ttl_secs")]
+    pub ttl_secs: u64,
+    /// Bytes of uploaded evidence files kept in memory; uploads beyond it are rejected
+    #[serde(default = "default_evidence_store_max_total_bytes")]
+    pub max_total_bytes: usize,
+}
+
+impl Default for EvidenceStoreConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: default_evidence_store_ttl_secs(),
+            max_total_bytes: default_evidence_store_max_total_bytes(),
+        }
+    }
+}
+
+impl EvidenceStoreConfig {
+    pub fn build(&self) -> crate::evidence_store::EvidenceFileStore {
+        crate::evidence_store::EvidenceFileStore::new(
+            std::time::Duration::from_secs(self.ttl_secs),
+            self.max_total_bytes,
+        )
+    }
+}
+
+fn default_evidence_store_ttl_secs() -> u64 {
+    crate::evidence_store::DEFAULT_EVIDENCE_FILE_TTL.as_secs()
+}
+
+fn default_evidence_store_max_total_bytes() -> usize {
+    crate::evidence_store::DEFAULT_MAX_TOTAL_BYTES
+}
+
+#[derive(Clone, serde::Deserialize, Debug)]
+pub struct RequestTimeoutConfig {
+    /// Time (in milliseconds) a request may take when its flow has no entry in `flows`; no
+    /// timeout is applied when unset
+    #[serde(default)]
+    pub default_ms: Option<u64>,
+    /// Time (in milliseconds) a request may take, keyed by flow, the snake_case gRPC method
+    /// name (e.g. `authorize`, `refund`)
+    #[serde(default)]
+    pub flows: HashMap<String, u64>,
+    /// Largest timeout a caller can request through `x-timeout-ms`
+    #[serde(default = "default_max_request_timeout_ms")]
+    pub max_ms: u64,
+}
+
+impl Default for RequestTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            default_ms: None,
+            flows: HashMap::new(),
+            max_ms: default_max_request_timeout_ms(),
+        }
+    }
+}
+
+fn default_max_request_timeout_ms() -> u64 {
+    consts::MAX_REQUEST_TIMEOUT_MS
+}
+
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct PaymentCorrelationConfig {
+    /// Stamp the request reference and transaction id of a payment's authorization on the
+    /// spans of its later capture, void, refund and sync calls
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct ErrorLocalizationConfig {
+    /// Translate connector error messages of every flow into the language requested in the
+    /// x-locale header, for the connector error codes in the bundled catalog
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct ConnectorConcurrencyConfig {
+    /// Maximum number of requests calling connectors at once, across all merchants and
+    /// connectors; unlimited when unset
+    #[serde(default)]
+    pub max_concurrent_calls: Option<usize>,
+    /// Number of requests that may wait for a free slot before further requests are
+    /// rejected with `ResourceExhausted`
+    #[serde(default)]
+    pub max_queued_calls: usize,
+}
+
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct ConnectorRetryConfig {
+    /// Time (in milliseconds) that must remain before the client's deadline, on top of the
+    /// duration of the previous attempt, for a connector request to be retried
+    #[serde(default)]
+    pub min_remaining_time_ms: u64,
+    /// Connector attempts a request may make, the first one included, across all retry
+    /// mechanisms combined; unlimited when unset
+    #[serde(default)]
+    pub max_attempts: Option<u32>,
 }
 
 #[derive(Clone, serde::Deserialize, Debug, Default)]
@@ -42,6 +227,9 @@ fn default_lineage_prefix() -> String {
 #[derive(Clone, serde::Deserialize, Debug)]
 pub struct Common {
     pub environment: String,
+    /// Connector used when a request does not carry the x-connector header
+    #[serde(default)]
+    pub default_connector: Option<String>,
 }
 
 impl Common {
@@ -52,6 +240,13 @@ impl Common {
                 "Invalid environment '{}'. Must be 'development' or 'production'",
                 self.environment
             ))),
+        }?;
+
+        match self.default_connector.as_deref() {
+            Some(connector) if ConnectorEnum::from_str(connector).is_err() => Err(
+                config::ConfigError::Message(format!("Invalid default_connector '{connector}'")),
+            ),
+            _ => Ok(()),
         }
     }
 }
@@ -62,12 +257,32 @@ pub struct Server {
     pub port: u16,
     #[serde(rename = "type", default)]
     pub type_: ServiceType,
+    /// Return the build version in the x-service-version response header
+    #[serde(default)]
+    pub expose_service_version: bool,
+    /// Time (in seconds) in-flight requests get to complete once a shutdown signal is received
+    #[serde(default = "default_shutdown_grace_period_secs")]
+    pub shutdown_grace_period_secs: u64,
+}
+
+fn default_shutdown_grace_period_secs() -> u64 {
+    consts::DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS
 }
 
 #[derive(Clone, serde::Deserialize, Debug)]
 pub struct MetricsServer {
     pub host: String,
     pub port: u16,
+    /// Expose p50/p90/p99 gauges of connector call latency, refreshed on every scrape
+    #[serde(default)]
+    pub latency_quantiles: bool,
+    /// Time (in seconds) of connector calls the latency quantiles cover
+    #[serde(default = "default_latency_quantile_window_secs")]
+    pub latency_quantile_window_secs: u64,
+}
+
+fn default_latency_quantile_window_secs() -> u64 {
+    consts::DEFAULT_LATENCY_QUANTILE_WINDOW_SECS
 }
 
 #[derive(Clone, serde::Deserialize, Debug, Default)]
@@ -78,6 +293,17 @@ pub enum ServiceType {
     Http,
 }
 
+/// File holding the values shared by every environment, read from the directory of the
+/// environment-specific config file which overrides it
+const DEFAULT_CONFIG_FILE: &str = "default.toml";
+
+/// Source of secret config values, e.g. connector credentials kept in a secrets manager,
+/// fetched once at startup
+pub trait SecretsProvider {
+    /// Secret values keyed by their dotted config path, e.g. `connectors.adyen.tls.client_key_path`
+    fn fetch_secrets(&self) -> Result<HashMap<String, String>, config::ConfigError>;
+}
+
 impl Config {
     /// Function to build the configuration by picking it from default locations
     pub fn new() -> Result<Self, config::ConfigError> {
@@ -87,23 +313,46 @@ impl Config {
     /// Function to build the configuration by picking it from default locations
     pub fn new_with_config_path(
         explicit_config_path: Option<PathBuf>,
+    ) -> Result<Self, config::ConfigError> {
+        Self::load(explicit_config_path, None)
+    }
+
+    /// Builds the configuration like [`Config::new_with_config_path`], with the values fetched
+    /// from `secrets_provider` layered over the config files
+    pub fn new_with_secrets_provider(
+        explicit_config_path: Option<PathBuf>,
+        secrets_provider: &dyn SecretsProvider,
+    ) -> Result<Self, config::ConfigError> {
+        Self::load(explicit_config_path, Some(secrets_provider))
+    }
+
+    /// Layers the config sources, each taking precedence over the previous ones: the default
+    /// file, the environment-specific file, the secrets provider, then `CS__` and
+    /// `CONNECTOR_SERVICE__` environment variables
+    fn load(
+        explicit_config_path: Option<PathBuf>,
+        secrets_provider: Option<&dyn SecretsProvider>,
     ) -> Result<Self, config::ConfigError> {
         let env = consts::Env::current_env();
         let config_path = Self::config_path(&env, explicit_config_path);
+        let default_config_path = config_path.with_file_name(DEFAULT_CONFIG_FILE);
 
-        let config = Self::builder(&env)?
-            .add_source(config::File::from(config_path).required(false))
-            .add_source(
-                config::Environment::with_prefix("CS")
-                    .try_parsing(true)
-                    .separator("__")
-                    .list_separator(",")
-                    .with_list_parse_key("proxy.bypass_proxy_urls")
-                    .with_list_parse_key("redis.cluster_urls")
-                    .with_list_parse_key("database.tenants")
-                    .with_list_parse_key("log.kafka.brokers")
-                    .with_list_parse_key("events.brokers"),
-            )
+        let mut builder = Self::builder(&env)?
+            .add_source(config::File::from(default_config_path).required(false))
+            .add_source(config::File::from(config_path).required(false));
+        if let Some(secrets_provider) = secrets_provider {
+            let secrets = secrets_provider
+                .fetch_secrets()?
+                .into_iter()
+                .try_fold(config::Config::builder(), |secrets, (key, value)| {
+                    secrets.set_override(key, value)
+                })?
+                .build()?;
+            builder = builder.add_source(secrets);
+        }
+        let config = builder
+            .add_source(environment_source("CS"))
+            .add_source(environment_source("CONNECTOR_SERVICE"))
             .build()?;
 
         #[allow(clippy::print_stderr)]
@@ -114,10 +363,59 @@ impl Config {
 
         // Validate the environment field
         config.common.validate()?;
+        config.validate_connector_base_urls()?;
+        config.validate_connector_tls()?;
 
         Ok(config)
     }
 
+    /// Connector configs for a single request, carrying the default connector so the request
+    /// conversions resolve the connector like [`crate::utils::connector_from_metadata`]
+    pub fn request_connectors(&self) -> Connectors {
+        Connectors {
+            default_connector: self
+                .common
+                .default_connector
+                .as_deref()
+                .and_then(|connector| ConnectorEnum::from_str(connector).ok()),
+            ..self.connectors.clone()
+        }
+    }
+
+    /// Fails startup when connectors have no base url, naming every missing key at once
+    fn validate_connector_base_urls(&self) -> Result<(), config::ConfigError> {
+        let missing_keys: Vec<_> = self
+            .connectors
+            .iter()
+            .filter(|(_, params)| params.base_url.trim().is_empty())
+            .map(|(connector, _)| format!("connectors.{}.base_url", config_section(connector)))
+            .collect();
+        if missing_keys.is_empty() {
+            return Ok(());
+        }
+        Err(config::ConfigError::Message(format!(
+            "Missing connector base URLs, set them in the config files or environment: {}",
+            missing_keys.join(", ")
+        )))
+    }
+
+    /// Loads the certificates of every connector with TLS settings, so that a missing or
+    /// malformed file fails startup instead of the first payment
+    fn validate_connector_tls(&self) -> Result<(), config::ConfigError> {
+        self.connectors
+            .iter()
+            .filter_map(|(connector, params)| Some((connector, params.tls.as_ref()?)))
+            .try_for_each(|(connector, tls)| {
+                external_services::service::build_tls_client(&self.proxy, false, tls)
+                    .map(|_| ())
+                    .map_err(|error| {
+                        config::ConfigError::Message(format!(
+                            "Invalid TLS settings of connector {connector}: {error:?}"
+                        ))
+                    })
+            })
+    }
+
     pub fn builder(
         environment: &consts::Env,
     ) -> Result<config::ConfigBuilder<config::builder::DefaultState>, config::ConfigError> {
@@ -156,6 +454,10 @@ impl Server {
 
         Ok(tokio::net::TcpListener::bind(loc).await?)
     }
+
+    pub fn shutdown_grace_period(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.shutdown_grace_period_secs)
+    }
 }
 
 impl MetricsServer {
@@ -168,6 +470,58 @@ impl MetricsServer {
     }
 }
 
+/// Config section of a connector, named without separators, e.g. `razorpayv2`
+fn config_section(connector: ConnectorEnum) -> String {
+    connector.to_string().replace('_', "")
+}
+
+/// Environment variables named `<prefix>__<section>__<key>`, e.g.
+/// `CONNECTOR_SERVICE__CONNECTORS__ADYEN__BASE_URL`.
+///
+/// Connector settings may leave out the `CONNECTORS` section, e.g.
+/// `CONNECTOR_SERVICE__ADYEN__BASE_URL`; the full name wins when both are set.
+fn environment_source(prefix: &str) -> config::Environment {
+    let connector_sections: Vec<_> = Connectors::default()
+        .iter()
+        .map(|(connector, _)| config_section(connector).to_uppercase())
+        .collect();
+    let mut variables = config::Map::new();
+    let mut connector_variables = Vec::new();
+    for (key, value) in std::env::vars() {
+        let connector_key = key
+            .to_uppercase()
+            .strip_prefix(&format!("{}__", prefix.to_uppercase()))
+            .filter(|rest| {
+                rest.split_once("__").is_some_and(|(section, _)| {
+                    connector_sections
+                        .iter()
+                        .any(|connector| connector == section)
+                })
+            })
+            .map(|rest| format!("{prefix}__CONNECTORS__{rest}"));
+        match connector_key {
+            Some(connector_key) => connector_variables.push((connector_key, value)),
+            None => {
+                variables.insert(key, value);
+            }
+        }
+    }
+    for (key, value) in connector_variables {
+        variables.entry(key).or_insert(value);
+    }
+
+    config::Environment::with_prefix(prefix)
+        .source(Some(variables))
+        .try_parsing(true)
+        .separator("__")
+        .list_separator(",")
+        .with_list_parse_key("proxy.bypass_proxy_urls")
+        .with_list_parse_key("redis.cluster_urls")
+        .with_list_parse_key("database.tenants")
+        .with_list_parse_key("log.kafka.brokers")
+        .with_list_parse_key("events.brokers")
+}
+
 pub fn workspace_path() -> PathBuf {
     if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
         let mut path = PathBuf::from(manifest_dir);
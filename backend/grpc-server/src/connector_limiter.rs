@@ -0,0 +1,142 @@
+//! Global cap on the number of requests calling connectors at once.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use external_services::shared_metrics::CONNECTOR_CALL_QUEUE_DEPTH;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tower::{Layer, Service};
+
+use crate::configs::ConnectorConcurrencyConfig;
+
+/// Path prefix of the gRPC health and reflection services, which never call connectors
+pub(crate) const GRPC_INFRASTRUCTURE_PATH_PREFIX: &str = "/grpc.";
+
+#[derive(Debug)]
+struct ConnectorLimiter {
+    permits: Arc<Semaphore>,
+    queued: AtomicUsize,
+    max_queued: usize,
+}
+
+/// Place in the wait queue, given up when the request gets a permit or is cancelled
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+        CONNECTOR_CALL_QUEUE_DEPTH.dec();
+    }
+}
+
+impl ConnectorLimiter {
+    async fn acquire(&self) -> Result<OwnedSemaphorePermit, tonic::Status> {
+        if let Ok(permit) = Arc::clone(&self.permits).try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        self.queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < self.max_queued).then_some(queued + 1)
+            })
+            .map_err(|_| {
+                tonic::Status::resource_exhausted(
+                    "Too many concurrent connector calls, retry later",
+                )
+            })?;
+        CONNECTOR_CALL_QUEUE_DEPTH.inc();
+        let _slot = QueueSlot(&self.queued);
+
+        Arc::clone(&self.permits)
+            .acquire_owned()
+            .await
+            .map_err(|_| tonic::Status::unavailable("Connector concurrency limiter is closed"))
+    }
+}
+
+/// Layer that holds requests beyond `max_concurrent_calls` in a bounded queue and rejects
+/// requests beyond the queue with `ResourceExhausted`
+#[derive(Clone, Debug)]
+pub struct ConnectorLimiterLayer {
+    limiter: Option<Arc<ConnectorLimiter>>,
+}
+
+impl ConnectorLimiterLayer {
+    /// No limit is applied when `max_concurrent_calls` is unset.
+    pub fn new(config: &ConnectorConcurrencyConfig) -> Self {
+        Self {
+            limiter: config.max_concurrent_calls.map(|max_concurrent_calls| {
+                Arc::new(ConnectorLimiter {
+                    permits: Arc::new(Semaphore::new(max_concurrent_calls)),
+                    queued: AtomicUsize::new(0),
+                    max_queued: config.max_queued_calls,
+                })
+            }),
+        }
+    }
+}
+
+impl<S> Layer<S> for ConnectorLimiterLayer {
+    type Service = ConnectorLimiterService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConnectorLimiterService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ConnectorLimiterService<S> {
+    inner: S,
+    limiter: Option<Arc<ConnectorLimiter>>,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for ConnectorLimiterService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Default,
+{
+    type Response = http::Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let limiter = match &self.limiter {
+            Some(limiter)
+                if !req
+                    .uri()
+                    .path()
+                    .starts_with(GRPC_INFRASTRUCTURE_PATH_PREFIX) =>
+            {
+                Arc::clone(limiter)
+            }
+            _ => return Box::pin(inner.call(req)),
+        };
+
+        Box::pin(async move {
+            let _permit = match limiter.acquire().await {
+                Ok(permit) => permit,
+                Err(status) => return Ok(status.into_http()),
+            };
+            inner.call(req).await
+        })
+    }
+}
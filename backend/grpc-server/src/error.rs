@@ -182,6 +182,14 @@ impl ErrorSwitch<ApplicationErrorResponse> for ConnectorError {
                     error_object: None,
                 })
             }
+            Self::ConnectorAuthenticationFailed { .. } => {
+                ApplicationErrorResponse::Unauthorized(ApiError {
+                    sub_code: "CONNECTOR_AUTH_FAILED".to_string(),
+                    error_identifier: 401,
+                    error_message: self.to_string(),
+                    error_object: None,
+                })
+            }
             Self::WebhookEventTypeNotFound
             | Self::WebhookSignatureNotFound
             | Self::WebhookReferenceIdNotFound
@@ -270,6 +278,9 @@ impl IntoGrpcStatus for error_stack::Report<ApplicationErrorResponse> {
             ApplicationErrorResponse::BadRequest(api_error) => {
                 Status::invalid_argument(&api_error.error_message)
             }
+            ApplicationErrorResponse::PreconditionFailed(api_error) => {
+                Status::failed_precondition(&api_error.error_message)
+            }
         }
     }
 }
@@ -310,9 +321,28 @@ impl From<PaymentAuthorizationError> for PaymentServiceAuthorizeResponse {
             error_message: error.error_message,
             error_code: error.error_code,
             status_code: error.status_code.unwrap_or(500),
+            connector_http_status_code: None,
             response_headers: std::collections::HashMap::new(),
             connector_metadata: std::collections::HashMap::new(),
             raw_connector_response: None,
+            next_action: None,
+            three_ds_version: None,
+            card_issuer: None,
+            card_issuing_country: None,
+            bin: None,
+            fraud_score: None,
+            fraud_decision: None,
+            exemption_decision: None,
+            payment_method_details: None,
+            warnings: Vec::new(),
+            mandate_reference: None,
+            raw_error_message: None,
+            installment_plan: None,
+            retryable: None,
+            three_ds_fallback_allowed: None,
+            connector: None,
+            environment: None,
+            payment_id: None,
         }
     }
 }
@@ -0,0 +1,209 @@
+use common_utils::consts;
+use domain_types::connector_types::ConnectorEnum;
+use grpc_api_types::payments::{
+    AcceptDisputeResponse, DisputeDefendResponse, DisputeResponse,
+    DisputeServiceSubmitEvidenceResponse, DisputeServiceTransformResponse,
+    PaymentServiceAuthorizeResponse, PaymentServiceCaptureResponse, PaymentServiceGetResponse,
+    PaymentServiceRegisterResponse, PaymentServiceRegisterWebhookResponse,
+    PaymentServiceRepeatEverythingResponse, PaymentServiceReverseCaptureResponse,
+    PaymentServiceTransformResponse, PaymentServiceVoidResponse, RefundResponse,
+    RefundServiceTransformResponse, UploadDisputeEvidenceResponse,
+};
+use tonic::metadata::MetadataMap;
+
+use crate::configs::ErrorLocalizationConfig;
+
+/// Connector errors with a bundled translation
+#[derive(Debug, Clone, Copy)]
+enum ErrorMessage {
+    CardDeclined,
+    DoNotHonor,
+    BlockedCard,
+    ExpiredCard,
+    InvalidCardNumber,
+    IncorrectCvc,
+    InsufficientFunds,
+}
+
+impl ErrorMessage {
+    /// Looks up an error code as the connector returns it
+    fn for_connector_code(connector: ConnectorEnum, error_code: &str) -> Option<Self> {
+        match connector {
+            // Refusal reason codes
+            ConnectorEnum::Adyen => match error_code {
+                "2" => Some(Self::CardDeclined),
+                "5" => Some(Self::BlockedCard),
+                "6" => Some(Self::ExpiredCard),
+                "8" => Some(Self::InvalidCardNumber),
+                "12" => Some(Self::InsufficientFunds),
+                "24" => Some(Self::IncorrectCvc),
+                _ => None,
+            },
+            // Response codes
+            ConnectorEnum::Checkout => match error_code {
+                "20005" => Some(Self::DoNotHonor),
+                "20014" => Some(Self::InvalidCardNumber),
+                "20051" => Some(Self::InsufficientFunds),
+                "20054" => Some(Self::ExpiredCard),
+                "20082" => Some(Self::IncorrectCvc),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn translation(self, language: &str) -> Option<&'static str> {
+        let message = match (self, language.to_ascii_lowercase().as_str()) {
+            (Self::CardDeclined, "es") => "La tarjeta fue rechazada",
+            (Self::CardDeclined, "fr") => "La carte a été refusée",
+            (Self::DoNotHonor, "es") => "El banco emisor rechazó el pago",
+            (Self::DoNotHonor, "fr") => "La banque émettrice a refusé le paiement",
+            (Self::BlockedCard, "es") => "La tarjeta está bloqueada",
+            (Self::BlockedCard, "fr") => "La carte est bloquée",
+            (Self::ExpiredCard, "es") => "La tarjeta ha caducado",
+            (Self::ExpiredCard, "fr") => "La carte a expiré",
+            (Self::InvalidCardNumber, "es") => "El número de tarjeta no es válido",
+            (Self::InvalidCardNumber, "fr") => "Le numéro de carte n'est pas valide",
+            (Self::IncorrectCvc, "es") => "El código de seguridad de la tarjeta es incorrecto",
+            (Self::IncorrectCvc, "fr") => "Le code de sécurité de la carte est incorrect",
+            (Self::InsufficientFunds, "es") => "La tarjeta no tiene fondos suficientes",
+            (Self::InsufficientFunds, "fr") => "La carte ne dispose pas de fonds suffisants",
+            _ => return None,
+        };
+        Some(message)
+    }
+}
+
+/// Lowercases an error code and joins its words with underscores, so `Insufficient Funds`
+/// and `INSUFFICIENT-FUNDS` both become `insufficient_funds`
+pub fn normalize_error_code(code: &str) -> String {
+    code.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Returns the bundled message for an error code of `connector` in `language`, if there is one
+pub fn localized_message(
+    connector: ConnectorEnum,
+    error_code: &str,
+    language: &str,
+) -> Option<&'static str> {
+    ErrorMessage::for_connector_code(connector, error_code.trim())
+        .and_then(|message| message.translation(language))
+}
+
+/// Primary language subtag of the `x-locale` header, e.g. `es` for `es-MX`; `None` when
+/// localization is disabled
+pub fn requested_language(
+    config: &ErrorLocalizationConfig,
+    metadata: &MetadataMap,
+) -> Option<String> {
+    if !config.enabled {
+        return None;
+    }
+    let locale = metadata.get(consts::X_LOCALE)?.to_str().ok()?;
+    locale
+        .split(['-', '_'])
+        .next()
+        .map(str::trim)
+        .filter(|language| !language.is_empty())
+        .map(ToString::to_string)
+}
+
+/// Response that can carry a connector error
+pub trait LocalizableErrorResponse {
+    /// The error code, error message and raw error message of the response, `None` for
+    /// responses without an error
+    fn error_fields(&mut self) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)>;
+}
+
+macro_rules! impl_localizable_error_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl LocalizableErrorResponse for $response {
+                fn error_fields(
+                    &mut self,
+                ) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)> {
+                    Some((
+                        self.error_code.as_deref(),
+                        &mut self.error_message,
+                        &mut self.raw_error_message,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_localizable_error_response!(
+    PaymentServiceAuthorizeResponse,
+    PaymentServiceGetResponse,
+    PaymentServiceVoidResponse,
+    PaymentServiceCaptureResponse,
+    PaymentServiceReverseCaptureResponse,
+    PaymentServiceRegisterResponse,
+    PaymentServiceRepeatEverythingResponse,
+    PaymentServiceRegisterWebhookResponse,
+    RefundResponse,
+    DisputeResponse,
+    DisputeServiceSubmitEvidenceResponse,
+    DisputeDefendResponse,
+    AcceptDisputeResponse,
+);
+
+// Webhook transformations report no connector error of their own
+impl LocalizableErrorResponse for PaymentServiceTransformResponse {
+    fn error_fields(&mut self) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)> {
+        None
+    }
+}
+
+impl LocalizableErrorResponse for RefundServiceTransformResponse {
+    fn error_fields(&mut self) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)> {
+        None
+    }
+}
+
+impl LocalizableErrorResponse for DisputeServiceTransformResponse {
+    fn error_fields(&mut self) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)> {
+        None
+    }
+}
+
+// Evidence uploads never reach the connector
+impl LocalizableErrorResponse for UploadDisputeEvidenceResponse {
+    fn error_fields(&mut self) -> Option<(Option<&str>, &mut Option<String>, &mut Option<String>)> {
+        None
+    }
+}
+
+/// Replaces the error message with its translation into `language`, keeping the connector's
+/// message in `raw_error_message`. Responses without a translation are left unchanged.
+pub fn localize_error_message<R: LocalizableErrorResponse>(
+    response: &mut R,
+    connector: ConnectorEnum,
+    language: &str,
+) {
+    let Some((error_code, error_message, raw_error_message)) = response.error_fields() else {
+        return;
+    };
+    let Some(message) =
+        error_code.and_then(|error_code| localized_message(connector, error_code, language))
+    else {
+        return;
+    };
+    *raw_error_message = error_message.replace(message.to_string());
+}
+
+/// Localizes the error message of a handler's response, when a language was requested
+pub fn localize_result<R: LocalizableErrorResponse>(
+    result: &mut Result<tonic::Response<R>, tonic::Status>,
+    connector: ConnectorEnum,
+    language: Option<&str>,
+) {
+    if let (Some(language), Ok(response)) = (language, result.as_mut()) {
+        localize_error_message(response.get_mut(), connector, language);
+    }
+}
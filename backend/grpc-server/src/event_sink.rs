@@ -0,0 +1,40 @@
+//! Destination of the structured events the service publishes besides its logs
+
+use common_utils::events::{AuditEvent, DeclineEvent, EventConfig};
+
+/// Publishes the structured events of the service, without blocking the calling request
+pub trait EventSink: std::fmt::Debug + Send + Sync {
+    /// Publishes the audit event of a finished gRPC call
+    fn publish_audit_event(&self, event: AuditEvent, config: &EventConfig);
+
+    /// Publishes the decline event of a failed authorization
+    fn publish_decline_event(&self, event: DeclineEvent, config: &EventConfig);
+}
+
+/// Publishes events to Kafka with the global event publisher
+#[derive(Debug, Default)]
+pub struct KafkaEventSink;
+
+impl EventSink for KafkaEventSink {
+    fn publish_audit_event(&self, event: AuditEvent, config: &EventConfig) {
+        tokio::spawn({
+            let config = config.clone();
+            async move {
+                if let Err(e) = common_utils::emit_audit_event(event, &config).await {
+                    tracing::error!("Failed to publish audit event: {:?}", e)
+                }
+            }
+        });
+    }
+
+    fn publish_decline_event(&self, event: DeclineEvent, config: &EventConfig) {
+        tokio::spawn({
+            let config = config.clone();
+            async move {
+                if let Err(e) = common_utils::emit_decline_event(event, &config).await {
+                    tracing::error!("Failed to publish decline event: {:?}", e)
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use common_utils::errors::CustomResult;
+use domain_types::errors::{ApiError, ApplicationErrorResponse};
+use error_stack::Report;
+use grpc_api_types::payments::DisputeServiceSubmitEvidenceRequest;
+
+/// Maximum size of a single evidence file accepted through `UploadDisputeEvidence`
+pub const MAX_EVIDENCE_FILE_SIZE_BYTES: usize = 50 * 1024 * 1024;
+
+/// Time an uploaded file is kept when the store is built with [`Default`]
+pub const DEFAULT_EVIDENCE_FILE_TTL: Duration = Duration::from_secs(60 * 60);
+/// Bytes of uploaded files kept when the store is built with [`Default`]
+pub const DEFAULT_MAX_TOTAL_BYTES: usize = 10 * MAX_EVIDENCE_FILE_SIZE_BYTES;
+
+const EVIDENCE_FILE_ID_PREFIX: &str = "evidence_file";
+
+/// Dispute evidence file assembled from an upload stream
+#[derive(Debug, Clone)]
+pub struct EvidenceFile {
+    pub dispute_id: String,
+    pub file_mime_type: Option<String>,
+    pub content: Vec<u8>,
+}
+
+/// In-memory store for evidence files uploaded ahead of a `SubmitEvidence` call.
+///
+/// Files are kept per merchant until a submission referencing them succeeds or they expire,
+/// and only the merchant that uploaded a file can submit it. Uploads reserve their declared
+/// size before any content is buffered and are refused once the stored and reserved bytes
+/// reach the byte cap, rather than evicting files another submission may still reference.
+#[derive(Debug)]
+pub struct EvidenceFileStore {
+    inner: Mutex<Inner>,
+    ttl: Duration,
+    max_total_bytes: usize,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Stored files keyed by (merchant_id, file_id)
+    files: HashMap<(String, String), (Instant, EvidenceFile)>,
+    /// Bytes of the stored files and of the uploads still in progress
+    total_bytes: usize,
+}
+
+/// Room set aside in the store for an upload in progress, released when dropped unless the
+/// file is inserted
+#[derive(Debug)]
+pub struct EvidenceFileReservation<'a> {
+    store: &'a EvidenceFileStore,
+    bytes: usize,
+}
+
+impl Default for EvidenceFileStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVIDENCE_FILE_TTL, DEFAULT_MAX_TOTAL_BYTES)
+    }
+}
+
+impl EvidenceFileStore {
+    pub fn new(ttl: Duration, max_total_bytes: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner::default()),
+            ttl,
+            max_total_bytes,
+        }
+    }
+
+    /// Sets aside room for a file of `bytes` bytes, `None` when the store has no room left
+    /// for it
+    pub fn reserve(&self, bytes: usize) -> Option<EvidenceFileReservation<'_>> {
+        let mut inner = self.lock();
+        inner.evict_expired(Instant::now());
+        inner.total_bytes = inner
+            .total_bytes
+            .checked_add(bytes)
+            .filter(|total_bytes| *total_bytes <= self.max_total_bytes)?;
+        Some(EvidenceFileReservation { store: self, bytes })
+    }
+
+    pub fn remove(&self, merchant_id: &str, file_ids: &[String]) {
+        let mut inner = self.lock();
+        for file_id in file_ids {
+            inner.remove(&(merchant_id.to_string(), file_id.clone()));
+        }
+    }
+
+    /// Inlines the content of files the merchant stored that are referenced by the evidence
+    /// documents of the request.
+    ///
+    /// Returns the ids of the stored files that were used; ids that are not known to the store
+    /// for the merchant are left untouched as they refer to files held by the connector.
+    pub fn resolve_documents(
+        &self,
+        merchant_id: &str,
+        request: &mut DisputeServiceSubmitEvidenceRequest,
+    ) -> CustomResult<Vec<String>, ApplicationErrorResponse> {
+        let mut inner = self.lock();
+        inner.evict_expired(Instant::now());
+        let mut resolved_file_ids = Vec::new();
+
+        for document in request.evidence_documents.iter_mut() {
+            let Some(file_id) = document.provider_file_id.as_ref() else {
+                continue;
+            };
+            let Some((_, file)) = inner.files.get(&(merchant_id.to_string(), file_id.clone()))
+            else {
+                continue;
+            };
+            if file.dispute_id != request.dispute_id {
+                return Err(Report::new(ApplicationErrorResponse::BadRequest(
+                    ApiError {
+                        sub_code: "EVIDENCE_FILE_DISPUTE_MISMATCH".to_string(),
+                        error_identifier: 400,
+                        error_message: format!(
+                            "Evidence file {file_id} was uploaded for a different dispute"
+                        ),
+                        error_object: None,
+                    },
+                )));
+            }
+
+            resolved_file_ids.push(file_id.clone());
+            document.file_content = Some(file.content.clone());
+            document.file_mime_type = document
+                .file_mime_type
+                .take()
+                .or_else(|| file.file_mime_type.clone());
+            document.provider_file_id = None;
+        }
+
+        Ok(resolved_file_ids)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        // A panic while holding the lock cannot leave the map in an inconsistent state
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl EvidenceFileReservation<'_> {
+    /// Stores the file under the merchant and returns the `provider_file_id` that references
+    /// it. The file takes the place of the reserved room.
+    pub fn insert(mut self, merchant_id: &str, file: EvidenceFile) -> String {
+        let file_id = common_utils::generate_time_ordered_id(EVIDENCE_FILE_ID_PREFIX);
+        let now = Instant::now();
+        let mut inner = self.store.lock();
+        inner.total_bytes = inner
+            .total_bytes
+            .saturating_sub(self.bytes)
+            .saturating_add(file.content.len());
+        inner.files.insert(
+            (merchant_id.to_string(), file_id.clone()),
+            (now + self.store.ttl, file),
+        );
+        self.bytes = 0;
+        file_id
+    }
+}
+
+impl Drop for EvidenceFileReservation<'_> {
+    fn drop(&mut self) {
+        if self.bytes > 0 {
+            let mut inner = self.store.lock();
+            inner.total_bytes = inner.total_bytes.saturating_sub(self.bytes);
+        }
+    }
+}
+
+impl Inner {
+    fn remove(&mut self, key: &(String, String)) {
+        if let Some((_, file)) = self.files.remove(key) {
+            self.total_bytes = self.total_bytes.saturating_sub(file.content.len());
+        }
+    }
+
+    fn evict_expired(&mut self, now: Instant) {
+        let expired: Vec<(String, String)> = self
+            .files
+            .iter()
+            .filter(|(_, (expires_at, _))| *expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            self.remove(&key);
+        }
+    }
+}
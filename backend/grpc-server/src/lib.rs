@@ -1,7 +1,13 @@
 pub mod app;
 pub mod configs;
+pub mod connector_limiter;
 pub mod error;
+pub mod error_localization;
+pub mod event_sink;
+pub mod evidence_store;
 pub mod logger;
 pub mod metrics;
+pub mod request_timeout;
 pub mod server;
+pub mod state_store;
 pub mod utils;
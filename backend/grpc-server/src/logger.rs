@@ -1,5 +1,6 @@
 pub mod config;
 
+pub mod sampling;
 pub mod setup;
 pub use setup::setup;
 
@@ -2,6 +2,8 @@
 //! Logger-specific config.
 //!
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Log config settings.
@@ -12,6 +14,40 @@ pub struct Log {
     /// Logging to Kafka (optional).
     #[serde(default)]
     pub kafka: Option<LogKafka>,
+    /// Whether the build version is attached to every log line (defaults to true).
+    #[serde(default = "default_include_build_version")]
+    pub include_build_version: bool,
+    /// Sampling of the traces logged to the console and Kafka.
+    #[serde(default)]
+    pub sampling: LogSampling,
+}
+
+fn default_include_build_version() -> bool {
+    true
+}
+
+/// Sampling of logged traces, to cut the volume of high-traffic spans.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LogSampling {
+    /// Fraction of traces logged, between 0.0 and 1.0 (defaults to 1.0, every trace).
+    #[serde(default = "default_sampling_ratio")]
+    pub ratio: f64,
+    /// Ratios overriding `ratio` for traces whose root target starts with the key.
+    #[serde(default)]
+    pub targets: HashMap<String, f64>,
+}
+
+impl Default for LogSampling {
+    fn default() -> Self {
+        Self {
+            ratio: default_sampling_ratio(),
+            targets: HashMap::new(),
+        }
+    }
+}
+
+fn default_sampling_ratio() -> f64 {
+    1.0
 }
 
 /// Logging to a console.
@@ -85,4 +121,7 @@ pub struct LogKafka {
     /// Buffer limit for Kafka messages (optional, defaults to Kafka default).
     #[serde(default)]
     pub buffer_limit: Option<usize>,
+    /// Whether the service should report NOT_SERVING when the Kafka producer is disconnected.
+    #[serde(default)]
+    pub mandatory: bool,
 }
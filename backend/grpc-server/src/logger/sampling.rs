@@ -0,0 +1,73 @@
+//! Sampling of logged traces.
+//!
+//! The decision is taken once per trace, at its root span (or a root event): spans and events
+//! inside a kept trace are kept, those inside a dropped trace are dropped. Dropped records never
+//! reach the layer the filter is attached to, so they are not formatted.
+
+use std::sync::Arc;
+
+use tracing::{subscriber::Interest, Metadata, Subscriber};
+use tracing_subscriber::{
+    layer::{Context, Filter},
+    registry::LookupSpan,
+};
+
+use super::config;
+
+#[derive(Debug)]
+struct SamplingRatios {
+    ratio: f64,
+    /// Overrides sorted by descending target prefix length, so the most specific one wins
+    targets: Vec<(String, f64)>,
+}
+
+/// Per-layer filter keeping the configured fraction of traces
+#[derive(Clone, Debug)]
+pub struct SamplingFilter {
+    ratios: Arc<SamplingRatios>,
+}
+
+impl SamplingFilter {
+    pub fn new(config: &config::LogSampling) -> Self {
+        let mut targets: Vec<_> = config
+            .targets
+            .iter()
+            .map(|(target, ratio)| (target.clone(), ratio.clamp(0.0, 1.0)))
+            .collect();
+        targets.sort_by(|(left, _), (right, _)| right.len().cmp(&left.len()));
+        Self {
+            ratios: Arc::new(SamplingRatios {
+                ratio: config.ratio.clamp(0.0, 1.0),
+                targets,
+            }),
+        }
+    }
+
+    fn ratio_for(&self, target: &str) -> f64 {
+        self.ratios
+            .targets
+            .iter()
+            .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .map_or(self.ratios.ratio, |(_, ratio)| *ratio)
+    }
+}
+
+impl<S> Filter<S> for SamplingFilter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        // Inside a trace, follow the decision taken for its root: the parent is only visible
+        // through this filter when it was kept
+        if let Some(parent) = cx.current_span().id() {
+            return cx.span(parent).is_some();
+        }
+        let ratio = self.ratio_for(metadata.target());
+        ratio >= 1.0 || rand::random::<f64>() < ratio
+    }
+
+    fn callsite_enabled(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        // The decision differs between traces, so it cannot be cached per callsite
+        Interest::sometimes()
+    }
+}
@@ -1,12 +1,24 @@
 //! Setup logging subsystem.
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use tracing_appender::non_blocking::WorkerGuard;
 #[cfg(feature = "kafka")]
 use tracing_kafka::KafkaLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
-use super::config;
+use super::{config, sampling::SamplingFilter};
+
+/// Writer of the Kafka logging layer, kept to probe broker connectivity and flush on shutdown
+#[cfg(feature = "kafka")]
+static KAFKA_LOG_WRITER: std::sync::OnceLock<tracing_kafka::KafkaWriter> =
+    std::sync::OnceLock::new();
+
+/// Longest the shutdown waits for buffered Kafka log records to be delivered
+#[cfg(feature = "kafka")]
+const KAFKA_SHUTDOWN_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Contains guards necessary for logging
 #[derive(Debug)]
@@ -14,6 +26,21 @@ pub struct TelemetryGuard {
     _log_guards: Vec<WorkerGuard>,
 }
 
+/// Flushes the Kafka log producer, which the global subscriber would never drop
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "kafka")]
+        if let Some(writer) = KAFKA_LOG_WRITER.get() {
+            if let Err(error) = writer.flush(KAFKA_SHUTDOWN_FLUSH_TIMEOUT) {
+                tracing::warn!(
+                    ?error,
+                    "Kafka log records were not delivered before shutdown"
+                );
+            }
+        }
+    }
+}
+
 /// Setup logging sub-system specifying the logging configuration, service (binary) name, and a
 /// list of external crates for which a more verbose logging must be enabled. All crates within the
 /// current cargo workspace are automatically considered for verbose logging.
@@ -22,13 +49,7 @@ pub fn setup(
     service_name: &str,
     crates_to_filter: impl AsRef<[&'static str]>,
 ) -> Result<TelemetryGuard, log_utils::LoggerError> {
-    let static_top_level_fields = HashMap::from_iter([
-        ("service".to_string(), serde_json::json!(service_name)),
-        (
-            "build_version".to_string(),
-            serde_json::json!(crate::version!()),
-        ),
-    ]);
+    let static_top_level_fields = static_log_fields(config, service_name);
 
     let console_config = if config.console.enabled {
         let console_filter_directive =
@@ -75,12 +96,14 @@ pub fn setup(
     };
 
     let logging_components = log_utils::build_logging_components(logger_config)?;
+    // Sampling only applies to the output layers, the storage layer has to see every span
+    let sampling_filter = SamplingFilter::new(&config.sampling);
 
     let mut subscriber_layers = Vec::new();
 
     subscriber_layers.push(logging_components.storage_layer.boxed());
     if let Some(console_layer) = logging_components.console_log_layer {
-        subscriber_layers.push(console_layer);
+        subscriber_layers.push(console_layer.with_filter(sampling_filter.clone()).boxed());
     }
 
     #[allow(unused_mut)]
@@ -133,7 +156,8 @@ pub fn setup(
                         .with_default_directive(kafka_config.level.into_level().into())
                         .parse_lossy(kafka_filter_directive);
 
-                    Some(layer.with_filter(kafka_filter))
+                    let _ = KAFKA_LOG_WRITER.set(layer.writer());
+                    Some(layer.with_filter(kafka_filter).with_filter(sampling_filter))
                 }
                 Err(e) => {
                     tracing::warn!(error = ?e, "Failed to enable Kafka logging");
@@ -168,6 +192,42 @@ pub fn setup(
     })
 }
 
+/// Fields attached to every log line, on the console and in Kafka log payloads
+pub fn static_log_fields(
+    config: &config::Log,
+    service_name: &str,
+) -> HashMap<String, serde_json::Value> {
+    let mut fields = HashMap::from_iter([("service".to_string(), serde_json::json!(service_name))]);
+    if config.include_build_version {
+        fields.insert(
+            "build_version".to_string(),
+            serde_json::json!(crate::version!()),
+        );
+    }
+    fields
+}
+
+/// Probes the connectivity of the Kafka log producer, which is disconnected when it was never
+/// set up.
+///
+/// Returns `None` when the service is built without Kafka logging. The probe blocks for up
+/// to `timeout`.
+pub fn kafka_logging_connectivity(timeout: Duration) -> Option<bool> {
+    #[cfg(feature = "kafka")]
+    {
+        Some(
+            KAFKA_LOG_WRITER
+                .get()
+                .is_some_and(|writer| writer.check_connectivity(timeout).is_ok()),
+        )
+    }
+    #[cfg(not(feature = "kafka"))]
+    {
+        let _ = timeout;
+        None
+    }
+}
+
 fn get_envfilter_directive(
     default_log_level: tracing::Level,
     filter_log_level: tracing::Level,
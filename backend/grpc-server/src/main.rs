@@ -1,21 +1,29 @@
-use grpc_server::{self, app, configs, logger};
+use grpc_server::{self, app, configs, logger, server::health_check::MetricsServerBound};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[allow(clippy::expect_used)]
     let config = configs::Config::new().expect("Failed while parsing config");
 
-    let _guard = logger::setup(
+    let guard = logger::setup(
         &config.log,
         grpc_server::service_name!(),
         [grpc_server::service_name!(), "grpc_server", "tower_http"],
     );
 
-    let metrics_server = app::metrics_server_builder(config.clone());
-    let server = app::server_builder(config);
+    let metrics_server_bound = MetricsServerBound::default();
+    let metrics_server = app::metrics_server_builder(config.clone(), metrics_server_bound.clone());
+    let server = app::server_builder(config, metrics_server_bound);
 
-    #[allow(clippy::expect_used)]
-    tokio::try_join!(metrics_server, server)?;
+    // The API server returns once it has drained its in-flight requests after a shutdown
+    // signal; the metrics server only returns on failure
+    tokio::select! {
+        result = server => result?,
+        result = metrics_server => result?,
+    }
+
+    // Flushes the Kafka log producer before the process exits
+    drop(guard);
 
     Ok(())
 }
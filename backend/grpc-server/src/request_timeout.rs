@@ -0,0 +1,164 @@
+//! Upper bound on the time a request, including its connector calls, may take.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use common_utils::consts;
+use tower::{timeout::Timeout, BoxError, Layer, Service};
+
+use crate::{configs::RequestTimeoutConfig, connector_limiter::GRPC_INFRASTRUCTURE_PATH_PREFIX};
+
+#[derive(Debug)]
+struct RequestTimeouts {
+    default: Option<Duration>,
+    flows: HashMap<String, Duration>,
+    max_ms: u64,
+}
+
+impl RequestTimeouts {
+    /// Timeout of a request to `flow`: the `x-timeout-ms` header, capped at `max_ms`, or else
+    /// the configured timeout of the flow
+    fn resolve<B>(&self, flow: &str, req: &http::Request<B>) -> Result<Option<Duration>, String> {
+        match req.headers().get(consts::X_TIMEOUT_MS) {
+            Some(timeout) => timeout
+                .to_str()
+                .ok()
+                .and_then(|timeout| timeout.parse::<u64>().ok())
+                .filter(|timeout_ms| *timeout_ms > 0)
+                .map(|timeout_ms| Some(Duration::from_millis(timeout_ms.min(self.max_ms))))
+                .ok_or_else(|| {
+                    format!(
+                        "{} must be a whole number of milliseconds greater than 0",
+                        consts::X_TIMEOUT_MS
+                    )
+                }),
+            None => Ok(self.flows.get(flow).copied().or(self.default)),
+        }
+    }
+}
+
+/// Flow of a gRPC request: its method name in snake_case, e.g. `register_webhook` for
+/// `/ucs.v2.PaymentService/RegisterWebhook`
+fn flow_name(path: &str) -> String {
+    let method = path.rsplit('/').next().unwrap_or_default();
+    let mut flow = String::with_capacity(method.len() + 4);
+    for (index, character) in method.chars().enumerate() {
+        if character.is_ascii_uppercase() && index > 0 {
+            flow.push('_');
+        }
+        flow.push(character.to_ascii_lowercase());
+    }
+    flow
+}
+
+/// Status returned when a request runs past its timeout, with a JSON error body in its details
+fn deadline_exceeded(flow: &str, timeout: Duration) -> tonic::Status {
+    let message = format!("Request did not complete within {}ms", timeout.as_millis());
+    let details = serde_json::json!({
+        "sub_code": "REQUEST_TIMEOUT",
+        "error_message": message,
+        "flow": flow,
+        "timeout_ms": timeout.as_millis(),
+    });
+    tonic::Status::with_details(
+        tonic::Code::DeadlineExceeded,
+        message,
+        serde_json::to_vec(&details).unwrap_or_default().into(),
+    )
+}
+
+/// Layer that fails requests running past their timeout with `DeadlineExceeded`
+#[derive(Clone, Debug)]
+pub struct RequestTimeoutLayer {
+    timeouts: Arc<RequestTimeouts>,
+}
+
+impl RequestTimeoutLayer {
+    pub fn new(config: &RequestTimeoutConfig) -> Self {
+        Self {
+            timeouts: Arc::new(RequestTimeouts {
+                default: config.default_ms.map(Duration::from_millis),
+                flows: config
+                    .flows
+                    .iter()
+                    .map(|(flow, timeout_ms)| (flow.clone(), Duration::from_millis(*timeout_ms)))
+                    .collect(),
+                max_ms: config.max_ms,
+            }),
+        }
+    }
+}
+
+impl<S> Layer<S> for RequestTimeoutLayer {
+    type Service = RequestTimeoutService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestTimeoutService {
+            inner,
+            timeouts: Arc::clone(&self.timeouts),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RequestTimeoutService<S> {
+    inner: S,
+    timeouts: Arc<RequestTimeouts>,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for RequestTimeoutService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Default,
+{
+    type Response = http::Response<ResBody>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        if req
+            .uri()
+            .path()
+            .starts_with(GRPC_INFRASTRUCTURE_PATH_PREFIX)
+        {
+            return Box::pin(async move { inner.call(req).await.map_err(Into::into) });
+        }
+
+        let flow = flow_name(req.uri().path());
+        let timeout = match self.timeouts.resolve(&flow, &req) {
+            Ok(Some(timeout)) => timeout,
+            Ok(None) => return Box::pin(async move { inner.call(req).await.map_err(Into::into) }),
+            Err(message) => {
+                return Box::pin(
+                    async move { Ok(tonic::Status::invalid_argument(message).into_http()) },
+                )
+            }
+        };
+
+        let future = Timeout::new(inner, timeout).call(req);
+        Box::pin(async move {
+            match future.await {
+                Err(error) if error.is::<tower::timeout::error::Elapsed>() => {
+                    Ok(deadline_exceeded(&flow, timeout).into_http())
+                }
+                result => result,
+            }
+        })
+    }
+}
@@ -24,8 +24,8 @@ use grpc_api_types::payments::{
     dispute_service_server::DisputeService, AcceptDisputeRequest, AcceptDisputeResponse,
     DisputeDefendRequest, DisputeDefendResponse, DisputeResponse, DisputeServiceGetRequest,
     DisputeServiceSubmitEvidenceRequest, DisputeServiceSubmitEvidenceResponse,
-    DisputeServiceTransformRequest, DisputeServiceTransformResponse, WebhookEventType,
-    WebhookResponseContent,
+    DisputeServiceTransformRequest, DisputeServiceTransformResponse, UploadDisputeEvidenceRequest,
+    UploadDisputeEvidenceResponse, WebhookEventType, WebhookResponseContent,
 };
 use hyperswitch_masking::ErasedMaskSerialize;
 use interfaces::connector_integration_v2::BoxedConnectorIntegrationV2;
@@ -34,6 +34,7 @@ use tracing::info;
 use crate::{
     configs::Config,
     error::{IntoGrpcStatus, ReportSwitchExt, ResultExtGrpc},
+    evidence_store::{EvidenceFile, EvidenceFileStore, MAX_EVIDENCE_FILE_SIZE_BYTES},
     implement_connector_operation,
     utils::{self, grpc_logging_wrapper},
 };
@@ -48,6 +49,7 @@ trait DisputeOperationsInternal {
 
 pub struct Disputes {
     pub config: Arc<Config>,
+    pub evidence_store: Arc<EvidenceFileStore>,
 }
 
 impl DisputeOperationsInternal for Disputes {
@@ -87,6 +89,7 @@ impl DisputeService for Disputes {
             tenant_id = tracing::field::Empty,
             flow = FlowName::SubmitEvidence.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -107,13 +110,22 @@ impl DisputeService for Disputes {
             |request, metadata_payload| {
                 let service_name = service_name.clone();
                 async move {
-                    let payload = request.into_inner();
+                    let metadata = request.metadata().clone();
+                    let mut payload = request.into_inner();
+                    // Inline evidence files previously streamed through UploadDisputeEvidence
+                    let uploaded_file_ids = self
+                        .evidence_store
+                        .resolve_documents(&metadata_payload.merchant_id, &mut payload)
+                        .map_err(|e| e.into_grpc_status())?;
                     let utils::MetadataPayload {
                         connector,
                         request_id,
+                        merchant_id,
                         lineage_ids,
                         connector_auth_type,
                         reference_id,
+                        forwarded_headers,
+                        connector_timeout,
                         ..
                     } = metadata_payload;
                     let connector_data: ConnectorData<DefaultPCIHolder> =
@@ -132,7 +144,8 @@ impl DisputeService for Disputes {
 
                     let dispute_flow_data = DisputeFlowData::foreign_try_from((
                         payload.clone(),
-                        self.config.connectors.clone(),
+                        self.config.request_connectors(),
+                        &metadata,
                     ))
                     .map_err(|e| e.into_grpc_status())?;
 
@@ -155,12 +168,16 @@ impl DisputeService for Disputes {
                         service_name: &service_name,
                         flow_name: common_utils::events::FlowName::SubmitEvidence,
                         event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
                         raw_request_data: Some(common_utils::pii::SecretSerdeValue::new(
                             payload.masked_serialize().unwrap_or_default(),
                         )),
                         request_id: &request_id,
+                        merchant_id: &merchant_id,
                         lineage_ids: &lineage_ids,
                         reference_id: &reference_id,
+                        forwarded_headers: &forwarded_headers,
+                        connector_timeout,
                     };
 
                     let response = external_services::service::execute_connector_processing_step(
@@ -174,8 +191,17 @@ impl DisputeService for Disputes {
                     .switch()
                     .map_err(|e| e.into_grpc_status())?;
 
-                    let dispute_response = generate_submit_evidence_response(response)
-                        .map_err(|e| e.into_grpc_status())?;
+                    let mut dispute_response =
+                        generate_submit_evidence_response(response, connector)
+                            .map_err(|e| e.into_grpc_status())?;
+                    dispute_response.payment_id = Some(utils::payment_id(
+                        &metadata,
+                        payload.request_ref_id.as_ref(),
+                    ));
+
+                    if dispute_response.error_code.is_none() {
+                        self.evidence_store.remove(&merchant_id, &uploaded_file_ids);
+                    }
 
                     Ok(tonic::Response::new(dispute_response))
                 }
@@ -184,6 +210,127 @@ impl DisputeService for Disputes {
         .await
     }
 
+    #[tracing::instrument(
+        name = "dispute_upload_evidence",
+        fields(
+            name = common_utils::consts::NAME,
+            service_name = tracing::field::Empty,
+            service_method = "UploadDisputeEvidence",
+            request_body = tracing::field::Empty,
+            response_body = tracing::field::Empty,
+            error_message = tracing::field::Empty,
+            merchant_id = tracing::field::Empty,
+            gateway = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            status_code = tracing::field::Empty,
+            message_ = "Golden Log Line (incoming)",
+            response_time = tracing::field::Empty,
+            tenant_id = tracing::field::Empty,
+            flow = "UploadDisputeEvidence",
+            file_size = tracing::field::Empty,
+        )
+        skip(self, request)
+    )]
+    async fn upload_dispute_evidence(
+        &self,
+        request: tonic::Request<tonic::Streaming<UploadDisputeEvidenceRequest>>,
+    ) -> Result<tonic::Response<UploadDisputeEvidenceResponse>, tonic::Status> {
+        let service_name = request
+            .extensions()
+            .get::<String>()
+            .cloned()
+            .unwrap_or_else(|| "unknown_service".to_string());
+        let (metadata, extensions, mut stream) = request.into_parts();
+        // The first chunk carries the file details, it stands in for the request body in logs
+        let first_chunk = stream.message().await?;
+        let file_details = UploadDisputeEvidenceRequest {
+            chunk: Vec::new(),
+            ..first_chunk.clone().unwrap_or_default()
+        };
+        grpc_logging_wrapper(
+            tonic::Request::from_parts(metadata, extensions, file_details),
+            &service_name,
+            self.config.clone(),
+            |request, metadata_payload| async move {
+                let file_details = request.into_inner();
+                let Some(first_chunk) = first_chunk else {
+                    return Err(evidence_upload_error(
+                        "EMPTY_EVIDENCE_FILE",
+                        "No evidence file chunks received".to_string(),
+                    ));
+                };
+                if file_details.dispute_id.is_empty() {
+                    return Err(evidence_upload_error(
+                        "MISSING_DISPUTE_ID",
+                        "Missing dispute_id in the first evidence chunk".to_string(),
+                    ));
+                }
+                let file_size = usize::try_from(file_details.file_size)
+                    .ok()
+                    .filter(|file_size| *file_size > 0)
+                    .ok_or_else(|| {
+                        evidence_upload_error(
+                            "MISSING_FILE_SIZE",
+                            "Missing file_size in the first evidence chunk".to_string(),
+                        )
+                    })?;
+                if file_size > MAX_EVIDENCE_FILE_SIZE_BYTES {
+                    return Err(evidence_upload_error(
+                        "EVIDENCE_FILE_TOO_LARGE",
+                        format!(
+                            "Evidence file exceeds the maximum size of {MAX_EVIDENCE_FILE_SIZE_BYTES} bytes"
+                        ),
+                    ));
+                }
+                tracing::Span::current().record("file_size", file_details.file_size);
+                // Room is set aside before any content is buffered
+                let reservation = self.evidence_store.reserve(file_size).ok_or_else(|| {
+                    tonic::Status::resource_exhausted(
+                        "Too many evidence files awaiting submission, retry later",
+                    )
+                })?;
+
+                let mut content = Vec::with_capacity(file_size);
+                let mut next_chunk = Some(first_chunk);
+                while let Some(chunk) = next_chunk {
+                    if content.len() + chunk.chunk.len() > file_size {
+                        return Err(evidence_upload_error(
+                            "EVIDENCE_FILE_SIZE_MISMATCH",
+                            format!("Evidence file exceeds its declared size of {file_size} bytes"),
+                        ));
+                    }
+                    content.extend_from_slice(&chunk.chunk);
+                    next_chunk = stream.message().await?;
+                }
+                if content.len() != file_size {
+                    return Err(evidence_upload_error(
+                        "EVIDENCE_FILE_SIZE_MISMATCH",
+                        format!(
+                            "Evidence file has {} bytes but declared {file_size}",
+                            content.len()
+                        ),
+                    ));
+                }
+
+                let provider_file_id = reservation.insert(
+                    &metadata_payload.merchant_id,
+                    EvidenceFile {
+                        dispute_id: file_details.dispute_id,
+                        file_mime_type: file_details.file_mime_type,
+                        content,
+                    },
+                );
+                info!("DISPUTE_FLOW: evidence file {provider_file_id} uploaded");
+
+                Ok(tonic::Response::new(UploadDisputeEvidenceResponse {
+                    provider_file_id,
+                    file_size: file_details.file_size,
+                }))
+            },
+        )
+        .await
+    }
+
     #[tracing::instrument(
         name = "dispute_sync",
         fields(
@@ -249,6 +396,7 @@ impl DisputeService for Disputes {
             tenant_id = tracing::field::Empty,
             flow = FlowName::DefendDispute.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -256,7 +404,13 @@ impl DisputeService for Disputes {
         &self,
         request: tonic::Request<DisputeDefendRequest>,
     ) -> Result<tonic::Response<DisputeDefendResponse>, tonic::Status> {
-        self.internal_defend(request).await
+        let payment_id = utils::payment_id(
+            request.metadata(),
+            request.get_ref().request_ref_id.as_ref(),
+        );
+        let mut response = self.internal_defend(request).await?;
+        response.get_mut().payment_id = Some(payment_id);
+        Ok(response)
     }
 
     #[tracing::instrument(
@@ -277,6 +431,7 @@ impl DisputeService for Disputes {
             tenant_id = tracing::field::Empty,
             flow = FlowName::AcceptDispute.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -297,13 +452,17 @@ impl DisputeService for Disputes {
             |request, metadata_payload| {
                 let service_name = service_name.clone();
                 async move {
+                    let metadata = request.metadata().clone();
                     let payload = request.into_inner();
                     let utils::MetadataPayload {
                         connector,
                         request_id,
+                        merchant_id,
                         lineage_ids,
                         connector_auth_type,
                         reference_id,
+                        forwarded_headers,
+                        connector_timeout,
                         ..
                     } = metadata_payload;
 
@@ -323,7 +482,8 @@ impl DisputeService for Disputes {
 
                     let dispute_flow_data = DisputeFlowData::foreign_try_from((
                         payload.clone(),
-                        self.config.connectors.clone(),
+                        self.config.request_connectors(),
+                        &metadata,
                     ))
                     .map_err(|e| e.into_grpc_status())?;
 
@@ -347,12 +507,16 @@ impl DisputeService for Disputes {
                         service_name: &service_name,
                         flow_name: common_utils::events::FlowName::AcceptDispute,
                         event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
                         raw_request_data: Some(common_utils::pii::SecretSerdeValue::new(
                             payload.masked_serialize().unwrap_or_default(),
                         )),
                         request_id: &request_id,
+                        merchant_id: &merchant_id,
                         lineage_ids: &lineage_ids,
                         reference_id: &reference_id,
+                        forwarded_headers: &forwarded_headers,
+                        connector_timeout,
                     };
 
                     let response = external_services::service::execute_connector_processing_step(
@@ -366,8 +530,13 @@ impl DisputeService for Disputes {
                     .switch()
                     .map_err(|e| e.into_grpc_status())?;
 
-                    let dispute_response = generate_accept_dispute_response(response)
-                        .map_err(|e| e.into_grpc_status())?;
+                    let mut dispute_response =
+                        generate_accept_dispute_response(response, connector)
+                            .map_err(|e| e.into_grpc_status())?;
+                    dispute_response.payment_id = Some(utils::payment_id(
+                        &metadata,
+                        payload.request_ref_id.as_ref(),
+                    ));
 
                     Ok(tonic::Response::new(dispute_response))
                 }
@@ -422,6 +591,16 @@ impl DisputeService for Disputes {
                             tonic::Status::invalid_argument("missing request_details in the payload")
                         })?
                         .map_err(|e| e.into_grpc_status())?;
+                    utils::verify_webhook_source_ip(
+                        &connector,
+                        &self
+                            .config
+                            .connectors
+                            .get_connector_params(&connector)
+                            .webhook_allowed_source_ips,
+                        &request_details,
+                        &self.config.webhook_source.trusted_proxies,
+                    )?;
                     let webhook_secrets = payload
                         .webhook_secrets
                         .map(|details| {
@@ -492,3 +671,14 @@ async fn get_disputes_webhook_content(
         ),
     })
 }
+
+fn evidence_upload_error(sub_code: &str, error_message: String) -> tonic::Status {
+    tracing::Span::current().record("error_message", &error_message);
+    error_stack::Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: sub_code.to_string(),
+        error_identifier: 400,
+        error_message,
+        error_object: None,
+    }))
+    .into_grpc_status()
+}
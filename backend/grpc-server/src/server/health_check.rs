@@ -1,21 +1,237 @@
-use grpc_api_types::health_check::{self, health_server};
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use grpc_api_types::health_check::{
+    self, health_check_response::ServingStatus, health_server, DependencyStatus,
+};
+use tokio::{sync::mpsc, time::MissedTickBehavior};
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
 use tonic::{Request, Response, Status};
 
-pub struct HealthCheck;
+use crate::configs::Config;
+
+const KAFKA_LOGGING_DEPENDENCY: &str = "kafka_logging";
+const METRICS_SERVER_DEPENDENCY: &str = "metrics_server";
+const KAFKA_PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Service name of the liveness probe, serving as long as the process is up
+pub const LIVENESS_SERVICE: &str = "liveness";
+/// Service name of the readiness probe, serving once the config is loaded and the metrics
+/// server is bound
+pub const READINESS_SERVICE: &str = "readiness";
+/// How often a readiness watch re-evaluates the readiness of the service
+pub const READINESS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Whether the metrics server has bound its listener, shared between the metrics server and
+/// the readiness probe
+#[derive(Clone, Debug, Default)]
+pub struct MetricsServerBound(Arc<AtomicBool>);
+
+impl MetricsServerBound {
+    /// Marks the metrics server as bound, which the readiness probe waits for
+    pub fn mark_bound(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_bound(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Probe of the Kafka log producer's connectivity, blocking for up to the given timeout;
+/// `None` when the service is built without Kafka logging
+pub type KafkaLoggingProbe = fn(Duration) -> Option<bool>;
+
+type HealthCheckStream =
+    Pin<Box<dyn Stream<Item = Result<health_check::HealthCheckResponse, Status>> + Send>>;
+
+#[derive(Clone)]
+pub struct HealthCheck {
+    pub config: Arc<Config>,
+    pub metrics_server_bound: MetricsServerBound,
+    pub readiness_poll_interval: Duration,
+    pub kafka_logging_probe: KafkaLoggingProbe,
+}
+
+impl HealthCheck {
+    /// Overall status of the service, degraded by its dependencies
+    async fn service_status(&self) -> (ServingStatus, Vec<DependencyStatus>) {
+        let Some(kafka) = self.config.log.kafka.as_ref().filter(|kafka| kafka.enabled) else {
+            return (ServingStatus::Serving, Vec::new());
+        };
+
+        // The metadata fetch blocks, keep it off the async runtime
+        let probe = self.kafka_logging_probe;
+        let connected = tokio::task::spawn_blocking(move || probe(KAFKA_PROBE_TIMEOUT))
+            .await
+            .unwrap_or(Some(false));
+        match connected {
+            Some(connected) => {
+                let (status, dependency) = kafka_logging_status(connected, kafka.mandatory);
+                (status, vec![dependency])
+            }
+            None => (ServingStatus::Serving, Vec::new()),
+        }
+    }
+
+    /// The config is loaded by the time the service exists, so readiness waits on the metrics
+    /// server on top of the overall status
+    async fn readiness_status(&self) -> (ServingStatus, Vec<DependencyStatus>) {
+        let (status, mut dependencies) = self.service_status().await;
+        let (metrics_status, metrics_dependency) =
+            metrics_server_status(self.metrics_server_bound.is_bound());
+        dependencies.push(metrics_dependency);
+        let status = if metrics_status == ServingStatus::Serving {
+            status
+        } else {
+            metrics_status
+        };
+        (status, dependencies)
+    }
+}
 
 #[tonic::async_trait]
 impl health_server::Health for HealthCheck {
+    type WatchStream = HealthCheckStream;
+
     async fn check(
         &self,
         request: Request<health_check::HealthCheckRequest>,
     ) -> Result<Response<health_check::HealthCheckResponse>, Status> {
         tracing::debug!(?request, "health_check request");
 
+        let (status, dependencies) = match request.get_ref().service.as_str() {
+            LIVENESS_SERVICE => (ServingStatus::Serving, Vec::new()),
+            READINESS_SERVICE => self.readiness_status().await,
+            _ => self.service_status().await,
+        };
+
         let response = health_check::HealthCheckResponse {
-            status: health_check::health_check_response::ServingStatus::Serving.into(),
+            status: status.into(),
+            dependencies,
         };
         tracing::info!(?response, "health_check response");
 
         Ok(Response::new(response))
     }
+
+    async fn watch(
+        &self,
+        request: Request<health_check::HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        tracing::debug!(?request, "health_check watch request");
+
+        let stream: Self::WatchStream = match request.get_ref().service.as_str() {
+            // Liveness and the overall status are only probed once, the stream stays open
+            // without further updates
+            LIVENESS_SERVICE => Box::pin(
+                tokio_stream::once(Ok(health_check::HealthCheckResponse {
+                    status: ServingStatus::Serving.into(),
+                    dependencies: Vec::new(),
+                }))
+                .chain(tokio_stream::pending()),
+            ),
+            // Readiness is re-evaluated, with the same checks as `check`, every poll interval
+            // and streamed whenever it changes
+            READINESS_SERVICE => {
+                let health_check = self.clone();
+                let (sender, receiver) = mpsc::channel(1);
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(health_check.readiness_poll_interval);
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                    let mut last_response = None;
+                    loop {
+                        tokio::select! {
+                            _ = sender.closed() => break,
+                            _ = interval.tick() => {}
+                        }
+                        let (status, dependencies) = health_check.readiness_status().await;
+                        let response = health_check::HealthCheckResponse {
+                            status: status.into(),
+                            dependencies,
+                        };
+                        if last_response.as_ref() == Some(&response) {
+                            continue;
+                        }
+                        last_response = Some(response.clone());
+                        if sender.send(Ok(response)).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+                Box::pin(ReceiverStream::new(receiver))
+            }
+            _ => {
+                let (status, dependencies) = self.service_status().await;
+                Box::pin(
+                    tokio_stream::once(Ok(health_check::HealthCheckResponse {
+                        status: status.into(),
+                        dependencies,
+                    }))
+                    .chain(tokio_stream::pending()),
+                )
+            }
+        };
+
+        Ok(Response::new(stream))
+    }
+}
+
+/// Maps whether the metrics server is bound to the readiness of the service.
+pub fn metrics_server_status(bound: bool) -> (ServingStatus, DependencyStatus) {
+    if bound {
+        return (
+            ServingStatus::Serving,
+            DependencyStatus {
+                name: METRICS_SERVER_DEPENDENCY.to_string(),
+                healthy: true,
+                note: None,
+            },
+        );
+    }
+
+    (
+        ServingStatus::NotServing,
+        DependencyStatus {
+            name: METRICS_SERVER_DEPENDENCY.to_string(),
+            healthy: false,
+            note: Some("Metrics server has not bound its listener yet".to_string()),
+        },
+    )
+}
+
+/// Maps the connectivity of the Kafka log producer to the serving status of the service.
+///
+/// A disconnected producer only degrades logging unless Kafka logging is mandatory.
+pub fn kafka_logging_status(connected: bool, mandatory: bool) -> (ServingStatus, DependencyStatus) {
+    if connected {
+        return (
+            ServingStatus::Serving,
+            DependencyStatus {
+                name: KAFKA_LOGGING_DEPENDENCY.to_string(),
+                healthy: true,
+                note: None,
+            },
+        );
+    }
+
+    let status = if mandatory {
+        ServingStatus::NotServing
+    } else {
+        ServingStatus::Serving
+    };
+    (
+        status,
+        DependencyStatus {
+            name: KAFKA_LOGGING_DEPENDENCY.to_string(),
+            healthy: false,
+            note: Some("Kafka log producer is disconnected, logging is degraded".to_string()),
+        },
+    )
 }
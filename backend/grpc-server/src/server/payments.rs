@@ -1,39 +1,44 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use common_enums;
 use common_utils::{consts, errors::CustomResult, events, lineage, pii};
 use connector_integration::types::ConnectorData;
 use domain_types::{
     connector_flow::{
-        self, Authorize, Capture, CreateOrder, CreateSessionToken, PSync, Refund, RepeatPayment,
-        SetupMandate, Void,
+        self, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken, PSync, Refund,
+        RegisterWebhook, RepeatPayment, SetupMandate, Void,
     },
     connector_types::{
-        PaymentCreateOrderData, PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData,
-        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
-        RefundFlowData, RefundsData, RefundsResponseData, RepeatPaymentData,
-        SessionTokenRequestData, SessionTokenResponseData, SetupMandateRequestData,
+        PaymentCaptureReversalData, PaymentCreateOrderData, PaymentCreateOrderResponse,
+        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundsData, RefundsResponseData,
+        RegisterWebhookData, RegisterWebhookResponseData, RepeatPaymentData,
+        SessionTokenRequestData, SessionTokenResponseData, SetupMandateRequestData, ThreeDsVersion,
+        WebhookFlowData,
     },
     errors::{ApiError, ApplicationErrorResponse},
     payment_method_data::{DefaultPCIHolder, PaymentMethodDataTypes, VaultTokenHolder},
     router_data::{ConnectorAuthType, ErrorResponse},
     router_data_v2::RouterDataV2,
     types::{
-        generate_payment_capture_response, generate_payment_sync_response,
-        generate_payment_void_response, generate_refund_response, generate_repeat_payment_response,
-        generate_setup_mandate_response,
+        generate_payment_capture_response, generate_payment_reverse_capture_response,
+        generate_payment_sync_response, generate_payment_void_response, generate_refund_response,
+        generate_register_webhook_response, generate_repeat_payment_response,
+        generate_setup_mandate_response, CvcRequirement, IsRetryable,
     },
     utils::ForeignTryFrom,
 };
 use error_stack::ResultExt;
 use external_services::service::{execute_connector_processing_step, EventProcessingParams};
 use grpc_api_types::payments::{
-    payment_method, payment_service_server::PaymentService, DisputeResponse,
+    identifier::IdType, payment_method, payment_service_server::PaymentService, DisputeResponse,
     PaymentServiceAuthorizeRequest, PaymentServiceAuthorizeResponse, PaymentServiceCaptureRequest,
     PaymentServiceCaptureResponse, PaymentServiceDisputeRequest, PaymentServiceGetRequest,
     PaymentServiceGetResponse, PaymentServiceRefundRequest, PaymentServiceRegisterRequest,
-    PaymentServiceRegisterResponse, PaymentServiceRepeatEverythingRequest,
-    PaymentServiceRepeatEverythingResponse, PaymentServiceTransformRequest,
+    PaymentServiceRegisterResponse, PaymentServiceRegisterWebhookRequest,
+    PaymentServiceRegisterWebhookResponse, PaymentServiceRepeatEverythingRequest,
+    PaymentServiceRepeatEverythingResponse, PaymentServiceReverseCaptureRequest,
+    PaymentServiceReverseCaptureResponse, PaymentServiceTransformRequest,
     PaymentServiceTransformResponse, PaymentServiceVoidRequest, PaymentServiceVoidResponse,
     RefundResponse,
 };
@@ -45,6 +50,7 @@ use crate::{
     configs::Config,
     error::{IntoGrpcStatus, PaymentAuthorizationError, ReportSwitchExt, ResultExtGrpc},
     implement_connector_operation,
+    state_store::StateStore,
     utils::{self, grpc_logging_wrapper},
 };
 
@@ -53,8 +59,11 @@ struct EventParams<'a> {
     connector_name: &'a str,
     service_name: &'a str,
     request_id: &'a str,
+    merchant_id: &'a str,
     lineage_ids: &'a lineage::LineageIds<'a>,
     reference_id: &'a Option<String>,
+    forwarded_headers: &'a [(String, String)],
+    connector_timeout: Option<Duration>,
 }
 
 // Error handling utilities for webhook processing
@@ -89,18 +98,132 @@ trait PaymentOperationsInternal {
         request: tonic::Request<PaymentServiceRefundRequest>,
     ) -> Result<tonic::Response<RefundResponse>, tonic::Status>;
 
-    async fn internal_payment_capture(
+    async fn internal_reverse_capture(
         &self,
-        request: tonic::Request<PaymentServiceCaptureRequest>,
-    ) -> Result<tonic::Response<PaymentServiceCaptureResponse>, tonic::Status>;
+        request: tonic::Request<PaymentServiceReverseCaptureRequest>,
+    ) -> Result<tonic::Response<PaymentServiceReverseCaptureResponse>, tonic::Status>;
 }
 
 #[derive(Clone)]
 pub struct Payments {
     pub config: Arc<Config>,
+    /// successful capture responses, replayed to repeated captures with the same capture_reference
+    pub capture_responses: Arc<StateStore<PaymentServiceCaptureResponse>>,
+    /// payments the connector captured during an automatic-capture authorize, keyed by
+    /// (merchant_id, connector, transaction_id); a later capture of these is refused
+    pub auto_captured_payments: Arc<StateStore<()>>,
+    /// references of authorizations keyed by `correlation_key`, stamped on the spans of later
+    /// flows for the same payment when payment correlation is enabled
+    pub payment_correlations: Arc<StateStore<PaymentCorrelation>>,
+}
+
+/// References of the authorization that created a payment
+#[derive(Clone, Debug)]
+pub struct PaymentCorrelation {
+    pub connector_request_reference_id: String,
+    pub transaction_id: String,
+}
+
+/// Identifies a capture by (merchant_id, connector, transaction_id, capture_reference);
+/// captures without a capture_reference are not deduplicated
+fn capture_dedup_key(
+    connector: domain_types::connector_types::ConnectorEnum,
+    metadata: &tonic::metadata::MetadataMap,
+    request: &PaymentServiceCaptureRequest,
+) -> Option<String> {
+    let capture_reference = &request.multiple_capture_data.as_ref()?.capture_reference;
+    let transaction_id = match request.transaction_id.as_ref()?.id_type.as_ref()? {
+        IdType::Id(id) => id,
+        _ => return None,
+    };
+    let merchant_id = merchant_id_from_metadata(metadata);
+    (!capture_reference.is_empty())
+        .then(|| format!("{merchant_id}:{connector}:{transaction_id}:{capture_reference}"))
+}
+
+fn merchant_id_from_metadata(metadata: &tonic::metadata::MetadataMap) -> &str {
+    metadata
+        .get(consts::X_MERCHANT_ID)
+        .and_then(|merchant_id| merchant_id.to_str().ok())
+        .unwrap_or_default()
+}
+
+fn capture_in_progress_error() -> error_stack::Report<ApplicationErrorResponse> {
+    ApplicationErrorResponse::Conflict(ApiError {
+        sub_code: "CAPTURE_IN_PROGRESS".to_owned(),
+        error_identifier: 409,
+        error_message: "A capture with the same capture_reference is still in progress".to_owned(),
+        error_object: None,
+    })
+    .into()
+}
+
+fn identifier_id(identifier: Option<&grpc_api_types::payments::Identifier>) -> Option<&str> {
+    match identifier?.id_type.as_ref()? {
+        IdType::Id(id) => Some(id),
+        _ => None,
+    }
+}
+
+/// Identifies a payment by (merchant_id, connector, transaction_id)
+fn transaction_key(
+    connector: domain_types::connector_types::ConnectorEnum,
+    metadata: &tonic::metadata::MetadataMap,
+    transaction_id: Option<&grpc_api_types::payments::Identifier>,
+) -> Option<String> {
+    let merchant_id = merchant_id_from_metadata(metadata);
+    identifier_id(transaction_id).map(|id| format!("{merchant_id}:{connector}:{id}"))
+}
+
+/// Identifies the authorization later flows are correlated with by
+/// (merchant_id, connector, transaction_id)
+fn correlation_key(
+    connector: domain_types::connector_types::ConnectorEnum,
+    metadata: &tonic::metadata::MetadataMap,
+    transaction_id: &str,
+) -> String {
+    let merchant_id = merchant_id_from_metadata(metadata);
+    format!("{merchant_id}:{connector}:{transaction_id}")
+}
+
+fn capture_not_required_error() -> error_stack::Report<ApplicationErrorResponse> {
+    ApplicationErrorResponse::PreconditionFailed(ApiError {
+        sub_code: "CAPTURE_NOT_REQUIRED".to_owned(),
+        error_identifier: 412,
+        error_message: "The payment was captured automatically during authorization".to_owned(),
+        error_object: None,
+    })
+    .into()
 }
 
 impl Payments {
+    /// Records the references of the authorization of this payment on the current span
+    fn record_payment_correlation(
+        &self,
+        connector: domain_types::connector_types::ConnectorEnum,
+        metadata: &tonic::metadata::MetadataMap,
+        transaction_id: Option<&grpc_api_types::payments::Identifier>,
+    ) {
+        if !self.config.payment_correlation.enabled {
+            return;
+        }
+        let Some(correlation) = identifier_id(transaction_id).and_then(|id| {
+            self.payment_correlations
+                .get(&correlation_key(connector, metadata, id))
+        }) else {
+            return;
+        };
+        let span = tracing::Span::current();
+        span.record(
+            "original_reference_id",
+            correlation.connector_request_reference_id.as_str(),
+        );
+        span.record(
+            "original_transaction_id",
+            correlation.transaction_id.as_str(),
+        );
+    }
+
     // connector, connector_auth_details, request_id can be obtained from metadata_payload
     #[allow(clippy::too_many_arguments)]
     async fn process_authorization_internal<
@@ -140,7 +263,7 @@ impl Payments {
         // Create common request data
         let payment_flow_data = PaymentFlowData::foreign_try_from((
             payload.clone(),
-            self.config.connectors.clone(),
+            self.config.request_connectors(),
             metadata,
         ))
         .map_err(|err| {
@@ -154,6 +277,7 @@ impl Payments {
         })?;
         let lineage_ids = &metadata_payload.lineage_ids;
         let reference_id = &metadata_payload.reference_id;
+        let forwarded_headers = &metadata_payload.forwarded_headers;
         let should_do_order_create = connector_data.connector.should_do_order_create();
 
         let payment_flow_data = if should_do_order_create {
@@ -161,8 +285,11 @@ impl Payments {
                 connector_name: &connector.to_string(),
                 service_name,
                 request_id,
+                merchant_id: &metadata_payload.merchant_id,
                 lineage_ids,
                 reference_id,
+                forwarded_headers,
+                connector_timeout: metadata_payload.connector_timeout,
             };
 
             let order_id = self
@@ -188,8 +315,11 @@ impl Payments {
                 connector_name: &connector.to_string(),
                 service_name,
                 request_id,
+                merchant_id: &metadata_payload.merchant_id,
                 lineage_ids,
                 reference_id,
+                forwarded_headers,
+                connector_timeout: metadata_payload.connector_timeout,
             };
 
             let payment_session_data = self
@@ -213,18 +343,35 @@ impl Payments {
         // This duplicate session token check has been removed - the session token handling is already done above
 
         // Create connector request data
-        let payment_authorize_data = PaymentsAuthorizeData::foreign_try_from(payload.clone())
-            .map_err(|err| {
-                tracing::error!("Failed to process payment authorize data: {:?}", err);
-                PaymentAuthorizationError::new(
-                    grpc_api_types::payments::PaymentStatus::Pending,
-                    Some("Failed to process payment authorize data".to_string()),
-                    Some("PAYMENT_AUTHORIZE_DATA_ERROR".to_string()),
-                    None,
-                )
-            })?
-            // Set session token from payment flow data if available
-            .set_session_token(payment_flow_data.session_token.clone());
+        let cvc_requirement = if connector_data.connector.supports_cvc_less_card_payments() {
+            CvcRequirement::Optional
+        } else {
+            CvcRequirement::Required
+        };
+        let payment_authorize_data =
+            PaymentsAuthorizeData::foreign_try_from((payload.clone(), cvc_requirement))
+                .map_err(|err| {
+                    tracing::error!("Failed to process payment authorize data: {:?}", err);
+                    PaymentAuthorizationError::new(
+                        grpc_api_types::payments::PaymentStatus::Pending,
+                        Some("Failed to process payment authorize data".to_string()),
+                        Some("PAYMENT_AUTHORIZE_DATA_ERROR".to_string()),
+                        None,
+                    )
+                })?
+                // Set session token from payment flow data if available
+                .set_session_token(payment_flow_data.session_token.clone());
+
+        // Request 3DS2 where the connector supports it, falling back to 3DS1
+        let three_ds_version = if payment_flow_data.is_three_ds() {
+            ThreeDsVersion::negotiate(
+                payment_authorize_data.preferred_three_ds_version,
+                connector_data.connector.supported_three_ds_versions(),
+            )
+        } else {
+            None
+        };
+        let payment_authorize_data = payment_authorize_data.set_three_ds_version(three_ds_version);
 
         // Construct router data
         let router_data = RouterDataV2::<
@@ -245,27 +392,109 @@ impl Payments {
             service_name,
             flow_name: events::FlowName::Authorize,
             event_config: &self.config.events,
+            traffic_log_config: &self.config.connector_traffic_log,
             raw_request_data: Some(pii::SecretSerdeValue::new(
                 payload.masked_serialize().unwrap_or_default(),
             )),
             request_id,
+            merchant_id: &metadata_payload.merchant_id,
             lineage_ids,
             reference_id,
+            forwarded_headers,
+            connector_timeout: metadata_payload.connector_timeout,
         };
 
+        let retry_router_data = router_data.clone();
+        let attempt_started_at = std::time::Instant::now();
         let response = execute_connector_processing_step(
             &self.config.proxy,
-            connector_integration,
+            connector_integration.clone(),
             router_data,
             None,
-            event_params,
+            event_params.clone(),
         )
         .await;
+        let attempt_duration = attempt_started_at.elapsed();
+
+        // Retry a soft decline once. The declined attempt charged nothing, and the retry carries
+        // an idempotency key so the connector charges at most once should it be sent again
+        let connector_params = self.config.connectors.get_connector_params(&connector);
+        let soft_decline_codes = &connector_params.soft_decline_codes;
+        let response = match response {
+            Ok(declined)
+                if domain_types::types::is_soft_decline(&declined.response, soft_decline_codes) =>
+            {
+                let decline_code = declined.response.as_ref().err().map(|error| &error.code);
+                match &connector_params.retry_idempotency_header {
+                    None => {
+                        tracing::info!(
+                            "Skipping authorize retry after soft decline {:?}: no idempotency header configured",
+                            decline_code
+                        );
+                        Ok(declined)
+                    }
+                    Some(_)
+                        if !utils::has_time_for_retry(
+                            metadata_payload.deadline,
+                            attempt_duration,
+                            &self.config.connector_retry,
+                        ) =>
+                    {
+                        // Returning the decline beats a retry the client would stop waiting for
+                        tracing::info!(
+                            "Skipping authorize retry after soft decline {:?}: deadline too close",
+                            decline_code
+                        );
+                        Ok(declined)
+                    }
+                    Some(_) if !metadata_payload.retry_budget.try_retry() => {
+                        tracing::info!(
+                            "Skipping authorize retry after soft decline {:?}: attempt limit reached",
+                            decline_code
+                        );
+                        Ok(declined)
+                    }
+                    Some(idempotency_header) => {
+                        tracing::info!("Retrying authorize after soft decline: {:?}", decline_code);
+                        let retry_headers: Vec<_> = forwarded_headers
+                            .iter()
+                            .cloned()
+                            .chain(std::iter::once((
+                                idempotency_header.clone(),
+                                format!("{request_id}_soft_decline_retry"),
+                            )))
+                            .collect();
+                        execute_connector_processing_step(
+                            &self.config.proxy,
+                            connector_integration,
+                            retry_router_data,
+                            None,
+                            EventProcessingParams {
+                                forwarded_headers: &retry_headers,
+                                ..event_params
+                            },
+                        )
+                        .await
+                    }
+                }
+            }
+            response => response,
+        };
+
+        let retryable = match &response {
+            Ok(router_data) => router_data
+                .response
+                .as_ref()
+                .err()
+                .map(|error| error.is_retryable(soft_decline_codes)),
+            Err(error_report) => Some(error_report.is_retryable(soft_decline_codes)),
+        };
 
         // Generate response - pass both success and error cases
-        let authorize_response = match response {
+        let mut authorize_response = match response {
             Ok(success_response) => domain_types::types::generate_payment_authorize_response(
                 success_response,
+                connector,
             )
             .map_err(|err| {
                 tracing::error!("Failed to generate authorize response: {:?}", err);
@@ -277,31 +506,41 @@ impl Payments {
                 )
             })?,
             Err(error_report) => {
+                // Credential failures keep their own code so they are not read as declines
+                let (status_code, code) = match error_report.current_context() {
+                    domain_types::errors::ConnectorError::ConnectorAuthenticationFailed {
+                        status_code,
+                    } => (*status_code, "CONNECTOR_AUTH_FAILED"),
+                    _ => (400, "CONNECTOR_ERROR"),
+                };
                 // Convert error to RouterDataV2 with error response
                 let error_router_data = RouterDataV2 {
                     flow: std::marker::PhantomData,
                     resource_common_data: payment_flow_data,
                     connector_auth_type: connector_auth_details,
-                    request: PaymentsAuthorizeData::foreign_try_from(payload.clone()).map_err(
-                        |err| {
-                            tracing::error!(
-                                "Failed to process payment authorize data in error path: {:?}",
-                                err
-                            );
-                            PaymentAuthorizationError::new(
-                                grpc_api_types::payments::PaymentStatus::Pending,
-                                Some(
-                                    "Failed to process payment authorize data in error path"
-                                        .to_string(),
-                                ),
-                                Some("PAYMENT_AUTHORIZE_DATA_ERROR".to_string()),
-                                None,
-                            )
-                        },
-                    )?,
+                    request: PaymentsAuthorizeData::foreign_try_from((
+                        payload.clone(),
+                        cvc_requirement,
+                    ))
+                    .map_err(|err| {
+                        tracing::error!(
+                            "Failed to process payment authorize data in error path: {:?}",
+                            err
+                        );
+                        PaymentAuthorizationError::new(
+                            grpc_api_types::payments::PaymentStatus::Pending,
+                            Some(
+                                "Failed to process payment authorize data in error path"
+                                    .to_string(),
+                            ),
+                            Some("PAYMENT_AUTHORIZE_DATA_ERROR".to_string()),
+                            None,
+                        )
+                    })?
+                    .set_three_ds_version(three_ds_version),
                     response: Err(ErrorResponse {
-                        status_code: 400,
-                        code: "CONNECTOR_ERROR".to_string(),
+                        status_code,
+                        code: code.to_string(),
                         message: format!("{error_report}"),
                         reason: None,
                         attempt_status: Some(common_enums::AttemptStatus::Failure),
@@ -311,22 +550,39 @@ impl Payments {
                         network_error_message: None,
                     }),
                 };
-                domain_types::types::generate_payment_authorize_response::<T>(error_router_data)
-                    .map_err(|err| {
-                        tracing::error!(
-                            "Failed to generate authorize response for connector error: {:?}",
-                            err
-                        );
-                        PaymentAuthorizationError::new(
-                            grpc_api_types::payments::PaymentStatus::Pending,
-                            Some(format!("Connector error: {error_report}")),
-                            Some("CONNECTOR_ERROR".to_string()),
-                            None,
-                        )
-                    })?
+                domain_types::types::generate_payment_authorize_response::<T>(
+                    error_router_data,
+                    connector,
+                )
+                .map_err(|err| {
+                    tracing::error!(
+                        "Failed to generate authorize response for connector error: {:?}",
+                        err
+                    );
+                    PaymentAuthorizationError::new(
+                        grpc_api_types::payments::PaymentStatus::Pending,
+                        Some(format!("Connector error: {error_report}")),
+                        Some("CONNECTOR_ERROR".to_string()),
+                        None,
+                    )
+                })?
             }
         };
 
+        authorize_response.retryable = retryable;
+
+        utils::record_payment_outcome(&connector, events::FlowName::Authorize, &authorize_response);
+
+        domain_types::types::add_poll_hints(
+            &mut authorize_response.connector_metadata,
+            authorize_response.status(),
+            self.config
+                .connectors
+                .get_connector_params(&connector)
+                .polling
+                .as_ref(),
+        );
+
         Ok(authorize_response)
     }
 
@@ -399,12 +655,16 @@ impl Payments {
             service_name: event_params.service_name,
             flow_name: events::FlowName::CreateOrder,
             event_config: &self.config.events,
+            traffic_log_config: &self.config.connector_traffic_log,
             raw_request_data: Some(pii::SecretSerdeValue::new(
                 payload.masked_serialize().unwrap_or_default(),
             )),
             request_id: event_params.request_id,
+            merchant_id: event_params.merchant_id,
             lineage_ids: event_params.lineage_ids,
             reference_id: event_params.reference_id,
+            forwarded_headers: event_params.forwarded_headers,
+            connector_timeout: event_params.connector_timeout,
         };
 
         let response = execute_connector_processing_step(
@@ -497,12 +757,16 @@ impl Payments {
             service_name: event_params.service_name,
             flow_name: events::FlowName::CreateOrder,
             event_config: &self.config.events,
+            traffic_log_config: &self.config.connector_traffic_log,
             raw_request_data: Some(pii::SecretSerdeValue::new(
                 payload.masked_serialize().unwrap_or_default(),
             )),
             request_id: event_params.request_id,
+            merchant_id: event_params.merchant_id,
             lineage_ids: event_params.lineage_ids,
             reference_id: event_params.reference_id,
+            forwarded_headers: event_params.forwarded_headers,
+            connector_timeout: event_params.connector_timeout,
         };
 
         let response = execute_connector_processing_step(
@@ -588,12 +852,16 @@ impl Payments {
             service_name: event_params.service_name,
             flow_name: events::FlowName::CreateSessionToken,
             event_config: &self.config.events,
+            traffic_log_config: &self.config.connector_traffic_log,
             raw_request_data: Some(pii::SecretSerdeValue::new(
                 payload.masked_serialize().unwrap_or_default(),
             )),
             request_id: event_params.request_id,
+            merchant_id: event_params.merchant_id,
             lineage_ids: event_params.lineage_ids,
             reference_id: event_params.reference_id,
+            forwarded_headers: event_params.forwarded_headers,
+            connector_timeout: event_params.connector_timeout,
         };
 
         let response = execute_connector_processing_step(
@@ -683,17 +951,17 @@ impl PaymentOperationsInternal for Payments {
     );
 
     implement_connector_operation!(
-        fn_name: internal_payment_capture,
-        log_prefix: "PAYMENT_CAPTURE",
-        request_type: PaymentServiceCaptureRequest,
-        response_type: PaymentServiceCaptureResponse,
-        flow_marker: Capture,
+        fn_name: internal_reverse_capture,
+        log_prefix: "PAYMENT_CAPTURE_REVERSAL",
+        request_type: PaymentServiceReverseCaptureRequest,
+        response_type: PaymentServiceReverseCaptureResponse,
+        flow_marker: CaptureReversal,
         resource_common_data_type: PaymentFlowData,
-        request_data_type: PaymentsCaptureData,
+        request_data_type: PaymentCaptureReversalData,
         response_data_type: PaymentsResponseData,
-        request_data_constructor: PaymentsCaptureData::foreign_try_from,
+        request_data_constructor: PaymentCaptureReversalData::foreign_try_from,
         common_flow_data_constructor: PaymentFlowData::foreign_try_from,
-        generate_response_fn: generate_payment_capture_response,
+        generate_response_fn: generate_payment_reverse_capture_response,
         all_keys_required: None
     );
 }
@@ -718,6 +986,7 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::Authorize.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -738,9 +1007,28 @@ impl PaymentService for Payments {
                 let utils::MetadataPayload {connector, ref request_id, ref connector_auth_type, ..} = metadata_payload;
                 let connector_auth_details = connector_auth_type.clone();
                 let metadata = request.metadata().clone();
-                let payload = request.into_inner();
+                let mut payload = request.into_inner();
+                let connector_params = self.config.connectors.get_connector_params(&connector);
+                domain_types::types::validate_required_fields(
+                    &payload,
+                    &connector_params.required_fields,
+                )
+                .into_grpc_status()?;
+                domain_types::types::validate_installment_count(
+                    &payload,
+                    connector_params.max_installment_count,
+                )
+                .into_grpc_status()?;
+                domain_types::types::validate_single_payment_method(&payload)
+                    .into_grpc_status()?;
+                domain_types::types::apply_default_capture_method(
+                    &mut payload,
+                    connector_params.default_capture_method,
+                );
+                let capture_method = payload.capture_method();
+                let request_ref_id = payload.request_ref_id.clone();
 
-                let authorize_response = match payload.payment_method.as_ref() {
+                let mut authorize_response = match payload.payment_method.as_ref() {
                     Some(pm) => {
                         match pm.payment_method.as_ref() {
                             Some(payment_method::PaymentMethod::Card(card_details)) => {
@@ -814,6 +1102,44 @@ impl PaymentService for Payments {
                         }
                     }
                 };
+                utils::emit_decline_event(
+                    self.config.event_sink.as_ref(),
+                    &self.config.events,
+                    &metadata_payload,
+                    &authorize_response,
+                );
+                // The capture method is the one resolved with the connector default above
+                if matches!(
+                    capture_method,
+                    grpc_api_types::payments::CaptureMethod::Automatic
+                        | grpc_api_types::payments::CaptureMethod::SequentialAutomatic
+                ) && authorize_response.status() == grpc_api_types::payments::PaymentStatus::Charged
+                {
+                    if let Some(key) =
+                        transaction_key(connector, &metadata, authorize_response.transaction_id.as_ref())
+                    {
+                        self.auto_captured_payments.insert(key, ());
+                    }
+                }
+                if self.config.payment_correlation.enabled {
+                    if let Some(transaction_id) =
+                        identifier_id(authorize_response.transaction_id.as_ref())
+                    {
+                        self.payment_correlations.insert(
+                            correlation_key(connector, &metadata, transaction_id),
+                            PaymentCorrelation {
+                                connector_request_reference_id: identifier_id(
+                                    request_ref_id.as_ref(),
+                                )
+                                .unwrap_or_default()
+                                .to_string(),
+                                transaction_id: transaction_id.to_string(),
+                            },
+                        );
+                    }
+                }
+                authorize_response.payment_id =
+                    Some(utils::payment_id(&metadata, request_ref_id.as_ref()));
 
                 Ok(tonic::Response::new(authorize_response))
             })
@@ -839,6 +1165,9 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::Psync.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            original_reference_id = tracing::field::Empty,
+            original_transaction_id = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -846,7 +1175,43 @@ impl PaymentService for Payments {
         &self,
         request: tonic::Request<PaymentServiceGetRequest>,
     ) -> Result<tonic::Response<PaymentServiceGetResponse>, tonic::Status> {
-        self.internal_payment_sync(request).await
+        let connector = utils::connector_from_metadata(
+            request.metadata(),
+            self.config.common.default_connector.as_deref(),
+        )
+        .into_grpc_status()?;
+        self.record_payment_correlation(
+            connector,
+            request.metadata(),
+            request.get_ref().transaction_id.as_ref(),
+        );
+        let payment_id = utils::payment_id(
+            request.metadata(),
+            request.get_ref().request_ref_id.as_ref(),
+        );
+        let prior_status = request
+            .get_ref()
+            .prior_status
+            .and_then(|status| grpc_api_types::payments::PaymentStatus::try_from(status).ok());
+        let mut response = self.internal_payment_sync(request).await?;
+        let sync_response = response.get_mut();
+        sync_response.payment_id = Some(payment_id);
+        domain_types::types::flag_status_conflict(
+            &mut sync_response.metadata,
+            &connector,
+            prior_status,
+            sync_response.status(),
+        );
+        domain_types::types::add_poll_hints(
+            &mut sync_response.metadata,
+            sync_response.status(),
+            self.config
+                .connectors
+                .get_connector_params(&connector)
+                .polling
+                .as_ref(),
+        );
+        Ok(response)
     }
 
     #[tracing::instrument(
@@ -867,6 +1232,9 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::Void.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            original_reference_id = tracing::field::Empty,
+            original_transaction_id = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -874,7 +1242,23 @@ impl PaymentService for Payments {
         &self,
         request: tonic::Request<PaymentServiceVoidRequest>,
     ) -> Result<tonic::Response<PaymentServiceVoidResponse>, tonic::Status> {
-        self.internal_void_payment(request).await
+        let connector = utils::connector_from_metadata(
+            request.metadata(),
+            self.config.common.default_connector.as_deref(),
+        )
+        .into_grpc_status()?;
+        self.record_payment_correlation(
+            connector,
+            request.metadata(),
+            request.get_ref().transaction_id.as_ref(),
+        );
+        let payment_id = utils::payment_id(
+            request.metadata(),
+            request.get_ref().request_ref_id.as_ref(),
+        );
+        let mut response = self.internal_void_payment(request).await?;
+        response.get_mut().payment_id = Some(payment_id);
+        Ok(response)
     }
 
     #[tracing::instrument(
@@ -923,6 +1307,23 @@ impl PaymentService for Payments {
                             tonic::Status::invalid_argument("missing request_details in the payload")
                         })?
                         .map_err(|e| e.into_grpc_status())?;
+                    let connector_params = self.config.connectors.get_connector_params(&connector);
+                    utils::verify_webhook_source_ip(
+                        &connector,
+                        &connector_params.webhook_allowed_source_ips,
+                        &request_details,
+                        &self.config.webhook_source.trusted_proxies,
+                    )?;
+                    // Signatures are computed over the body as received, so only the transform
+                    // sees the decoded body
+                    let decoded_details = domain_types::connector_types::RequestDetails {
+                        body: utils::parse_webhook_body(
+                            &connector,
+                            connector_params.webhook_body_format,
+                            &request_details,
+                        )?,
+                        ..request_details.clone()
+                    };
                     let webhook_secrets = payload
                         .webhook_secrets
                         .map(|details| {
@@ -948,7 +1349,7 @@ impl PaymentService for Payments {
                     let event_type = connector_data
                         .connector
                         .get_event_type(
-                            request_details.clone(),
+                            decoded_details.clone(),
                             webhook_secrets.clone(),
                             Some(connector_auth_details.clone()),
                         )
@@ -958,7 +1359,7 @@ impl PaymentService for Payments {
                     let content = if event_type.is_payment_event() {
                         get_payments_webhook_content(
                             connector_data,
-                            &request_details,
+                            &decoded_details,
                             webhook_secrets.as_ref(),
                             Some(&connector_auth_details),
                         )
@@ -967,7 +1368,7 @@ impl PaymentService for Payments {
                     } else if event_type.is_refund_event() {
                         get_refunds_webhook_content(
                             connector_data,
-                            &request_details,
+                            &decoded_details,
                             webhook_secrets.as_ref(),
                             Some(&connector_auth_details),
                         )
@@ -976,7 +1377,16 @@ impl PaymentService for Payments {
                     } else if event_type.is_dispute_event() {
                         get_disputes_webhook_content(
                             connector_data,
-                            &request_details,
+                            &decoded_details,
+                            webhook_secrets.as_ref(),
+                            Some(&connector_auth_details),
+                        )
+                        .await
+                        .to_grpc_status()?
+                    } else if event_type.is_settlement_event() {
+                        get_settlements_webhook_content(
+                            connector_data,
+                            &decoded_details,
                             webhook_secrets.as_ref(),
                             Some(&connector_auth_details),
                         )
@@ -987,7 +1397,7 @@ impl PaymentService for Payments {
                         // This includes mandate, payout, recovery, and misc events
                         get_payments_webhook_content(
                             connector_data,
-                            &request_details,
+                            &decoded_details,
                             webhook_secrets.as_ref(),
                             Some(&connector_auth_details),
                         )
@@ -1028,14 +1438,40 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::Refund.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            original_reference_id = tracing::field::Empty,
+            original_transaction_id = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
     async fn refund(
         &self,
-        request: tonic::Request<PaymentServiceRefundRequest>,
+        mut request: tonic::Request<PaymentServiceRefundRequest>,
     ) -> Result<tonic::Response<RefundResponse>, tonic::Status> {
-        self.internal_refund(request).await
+        let connector = utils::connector_from_metadata(
+            request.metadata(),
+            self.config.common.default_connector.as_deref(),
+        )
+        .into_grpc_status()?;
+        domain_types::types::apply_default_capture_method(
+            request.get_mut(),
+            self.config
+                .connectors
+                .get_connector_params(&connector)
+                .default_capture_method,
+        );
+        self.record_payment_correlation(
+            connector,
+            request.metadata(),
+            request.get_ref().transaction_id.as_ref(),
+        );
+        let payment_id = utils::payment_id(
+            request.metadata(),
+            request.get_ref().request_ref_id.as_ref(),
+        );
+        let mut response = self.internal_refund(request).await?;
+        response.get_mut().payment_id = Some(payment_id);
+        Ok(response)
     }
 
     #[tracing::instrument(
@@ -1056,6 +1492,7 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::DefendDispute.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -1072,8 +1509,12 @@ impl PaymentService for Payments {
             request,
             &service_name,
             self.config.clone(),
-            |_request, _metadata_payload| async {
+            |request, _metadata_payload| async move {
                 let response = DisputeResponse {
+                    payment_id: Some(utils::payment_id(
+                        request.metadata(),
+                        request.get_ref().request_ref_id.as_ref(),
+                    )),
                     ..Default::default()
                 };
                 Ok(tonic::Response::new(response))
@@ -1100,6 +1541,9 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::Capture.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            original_reference_id = tracing::field::Empty,
+            original_transaction_id = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -1107,7 +1551,191 @@ impl PaymentService for Payments {
         &self,
         request: tonic::Request<PaymentServiceCaptureRequest>,
     ) -> Result<tonic::Response<PaymentServiceCaptureResponse>, tonic::Status> {
-        self.internal_payment_capture(request).await
+        info!("PAYMENT_CAPTURE_FLOW: initiated");
+        let connector = utils::connector_from_metadata(
+            request.metadata(),
+            self.config.common.default_connector.as_deref(),
+        )
+        .into_grpc_status()?;
+        self.record_payment_correlation(
+            connector,
+            request.metadata(),
+            request.get_ref().transaction_id.as_ref(),
+        );
+        let payment_id = utils::payment_id(
+            request.metadata(),
+            request.get_ref().request_ref_id.as_ref(),
+        );
+        let service_name = request
+            .extensions()
+            .get::<String>()
+            .cloned()
+            .unwrap_or_else(|| "unknown_service".to_string());
+        let mut response = grpc_logging_wrapper(
+            request,
+            &service_name,
+            self.config.clone(),
+            |request, metadata_payload| {
+                let service_name = service_name.clone();
+                Box::pin(async move {
+                    let (connector, request_id) =
+                        (metadata_payload.connector, metadata_payload.request_id);
+                    let connector_auth_details = metadata_payload.connector_auth_type;
+                    let metadata = request.metadata().clone();
+                    let payload = request.into_inner();
+
+                    if transaction_key(connector, &metadata, payload.transaction_id.as_ref())
+                        .is_some_and(|key| self.auto_captured_payments.get(&key).is_some())
+                    {
+                        return Err(capture_not_required_error().into_grpc_status());
+                    }
+
+                    //get connector data
+                    let connector_data =
+                        ConnectorData::<DefaultPCIHolder>::get_connector_by_name(&connector);
+
+                    let capture_data = PaymentsCaptureData::foreign_try_from(payload.clone())
+                        .map_err(|e| e.into_grpc_status())?;
+                    if capture_data.capture_at.is_some()
+                        && !connector_data.connector.supports_scheduled_capture()
+                    {
+                        // The connector cannot delay the capture, the caller executes it at the
+                        // returned capture time
+                        return Ok(tonic::Response::new(
+                            domain_types::types::generate_scheduled_capture_response(
+                                payload.transaction_id,
+                                &capture_data,
+                                connector,
+                            ),
+                        ));
+                    }
+
+                    let capture_key = capture_dedup_key(connector, &metadata, &payload);
+                    let _in_flight = match capture_key.as_deref() {
+                        Some(key) => {
+                            let guard = self
+                                .capture_responses
+                                .begin(key)
+                                .ok_or_else(|| capture_in_progress_error().into_grpc_status())?;
+                            if let Some(response) = self.capture_responses.get(key) {
+                                info!(
+                                    "Returning the response of a prior capture with the same \
+                                     capture_reference"
+                                );
+                                return Ok(tonic::Response::new(response));
+                            }
+                            Some(guard)
+                        }
+                        None => None,
+                    };
+
+                    // Get connector integration
+                    let connector_integration: BoxedConnectorIntegrationV2<
+                        '_,
+                        Capture,
+                        PaymentFlowData,
+                        PaymentsCaptureData,
+                        PaymentsResponseData,
+                    > = connector_data.connector.get_connector_integration_v2();
+
+                    // Create common request data
+                    let payment_flow_data = PaymentFlowData::foreign_try_from((
+                        payload.clone(),
+                        self.config.request_connectors(),
+                        &metadata,
+                    ))
+                    .map_err(|e| e.into_grpc_status())?;
+
+                    // Create router data
+                    let router_data: RouterDataV2<
+                        Capture,
+                        PaymentFlowData,
+                        PaymentsCaptureData,
+                        PaymentsResponseData,
+                    > = RouterDataV2 {
+                        flow: std::marker::PhantomData,
+                        resource_common_data: payment_flow_data,
+                        connector_auth_type: connector_auth_details,
+                        request: capture_data,
+                        response: Err(ErrorResponse::default()),
+                    };
+                    let event_params = EventProcessingParams {
+                        connector_name: &connector.to_string(),
+                        service_name: &service_name,
+                        flow_name: events::FlowName::Capture,
+                        event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
+                        raw_request_data: Some(pii::SecretSerdeValue::new(
+                            payload.masked_serialize().unwrap_or_default(),
+                        )),
+                        request_id: &request_id,
+                        merchant_id: &metadata_payload.merchant_id,
+                        lineage_ids: &metadata_payload.lineage_ids,
+                        reference_id: &metadata_payload.reference_id,
+                        forwarded_headers: &metadata_payload.forwarded_headers,
+                        connector_timeout: metadata_payload.connector_timeout,
+                    };
+
+                    let response = execute_connector_processing_step(
+                        &self.config.proxy,
+                        connector_integration,
+                        router_data,
+                        None,
+                        event_params,
+                    )
+                    .await
+                    .switch()
+                    .map_err(|e| e.into_grpc_status())?;
+
+                    // Generate response
+                    let capture_response = generate_payment_capture_response(response, connector)
+                        .map_err(|e| e.into_grpc_status())?;
+                    utils::record_payment_outcome(
+                        &connector,
+                        events::FlowName::Capture,
+                        &capture_response,
+                    );
+                    if let Some(capture_key) = capture_key {
+                        if capture_response.error_code.is_none() {
+                            self.capture_responses
+                                .insert(capture_key, capture_response.clone());
+                        }
+                    }
+                    Ok(tonic::Response::new(capture_response))
+                })
+            },
+        )
+        .await?;
+        response.get_mut().payment_id = Some(payment_id);
+        Ok(response)
+    }
+
+    #[tracing::instrument(
+        name = "payment_capture_reversal",
+        fields(
+            name = consts::NAME,
+            service_name = consts::PAYMENT_SERVICE_NAME,
+            service_method = connector_flow::FlowName::CaptureReversal.to_string(),
+            request_body = tracing::field::Empty,
+            response_body = tracing::field::Empty,
+            error_message = tracing::field::Empty,
+            merchant_id = tracing::field::Empty,
+            gateway = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            status_code = tracing::field::Empty,
+            message_ = "Golden Log Line (incoming)",
+            response_time = tracing::field::Empty,
+            tenant_id = tracing::field::Empty,
+            flow = connector_flow::FlowName::CaptureReversal.to_string(),
+            flow_specific_fields.status = tracing::field::Empty,
+        )
+        skip(self, request)
+    )]
+    async fn reverse_capture(
+        &self,
+        request: tonic::Request<PaymentServiceReverseCaptureRequest>,
+    ) -> Result<tonic::Response<PaymentServiceReverseCaptureResponse>, tonic::Status> {
+        self.internal_reverse_capture(request).await
     }
 
     #[tracing::instrument(
@@ -1128,6 +1756,7 @@ impl PaymentService for Payments {
             tenant_id = tracing::field::Empty,
             flow = connector_flow::FlowName::SetupMandate.to_string(),
             flow_specific_fields.status = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         )
         skip(self, request)
     )]
@@ -1169,7 +1798,7 @@ impl PaymentService for Payments {
                     // Create common request data
                     let payment_flow_data = PaymentFlowData::foreign_try_from((
                         payload.clone(),
-                        self.config.connectors.clone(),
+                        self.config.request_connectors(),
                         self.config.common.environment.clone(),
                         &metadata,
                     ))
@@ -1182,8 +1811,11 @@ impl PaymentService for Payments {
                             connector_name: &connector.to_string(),
                             service_name: &service_name,
                             request_id: &request_id,
+                            merchant_id: &metadata_payload.merchant_id,
                             lineage_ids: &metadata_payload.lineage_ids,
                             reference_id: &metadata_payload.reference_id,
+                            forwarded_headers: &metadata_payload.forwarded_headers,
+                            connector_timeout: metadata_payload.connector_timeout,
                         };
 
                         Some(
@@ -1223,12 +1855,16 @@ impl PaymentService for Payments {
                         service_name: &service_name,
                         flow_name: events::FlowName::SetupMandate,
                         event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
                         raw_request_data: Some(pii::SecretSerdeValue::new(
                             payload.masked_serialize().unwrap_or_default(),
                         )),
                         request_id: &request_id,
+                        merchant_id: &metadata_payload.merchant_id,
                         lineage_ids: &metadata_payload.lineage_ids,
                         reference_id: &metadata_payload.reference_id,
+                        forwarded_headers: &metadata_payload.forwarded_headers,
+                        connector_timeout: metadata_payload.connector_timeout,
                     };
 
                     let response = execute_connector_processing_step(
@@ -1243,8 +1879,18 @@ impl PaymentService for Payments {
                     .map_err(|e| e.into_grpc_status())?;
 
                     // Generate response
-                    let setup_mandate_response = generate_setup_mandate_response(response)
-                        .map_err(|e| e.into_grpc_status())?;
+                    let mut setup_mandate_response =
+                        generate_setup_mandate_response(response, connector)
+                            .map_err(|e| e.into_grpc_status())?;
+                    setup_mandate_response.payment_id = Some(utils::payment_id(
+                        &metadata,
+                        payload.request_ref_id.as_ref(),
+                    ));
+                    utils::record_payment_outcome(
+                        &connector,
+                        events::FlowName::SetupMandate,
+                        &setup_mandate_response,
+                    );
 
                     Ok(tonic::Response::new(setup_mandate_response))
                 })
@@ -1269,6 +1915,7 @@ impl PaymentService for Payments {
             message_ = "Golden Log Line (incoming)",
             response_time = tracing::field::Empty,
             tenant_id = tracing::field::Empty,
+            payment_id = tracing::field::Empty,
         ),
         skip(self, request)
     )]
@@ -1293,7 +1940,14 @@ impl PaymentService for Payments {
                         (metadata_payload.connector, metadata_payload.request_id);
                     let connector_auth_details = metadata_payload.connector_auth_type;
                     let metadata = request.metadata().clone();
-                    let payload = request.into_inner();
+                    let mut payload = request.into_inner();
+                    domain_types::types::apply_default_capture_method(
+                        &mut payload,
+                        self.config
+                            .connectors
+                            .get_connector_params(&connector)
+                            .default_capture_method,
+                    );
 
                     //get connector data
                     let connector_data: ConnectorData<DefaultPCIHolder> =
@@ -1311,7 +1965,7 @@ impl PaymentService for Payments {
                     // Create payment flow data
                     let payment_flow_data = PaymentFlowData::foreign_try_from((
                         payload.clone(),
-                        self.config.connectors.clone(),
+                        self.config.request_connectors(),
                         &metadata,
                     ))
                     .map_err(|e| e.into_grpc_status())?;
@@ -1338,12 +1992,16 @@ impl PaymentService for Payments {
                         service_name: &service_name,
                         flow_name: events::FlowName::RepeatPayment,
                         event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
                         raw_request_data: Some(pii::SecretSerdeValue::new(
                             payload.masked_serialize().unwrap_or_default(),
                         )),
                         request_id: &request_id,
+                        merchant_id: &metadata_payload.merchant_id,
                         lineage_ids: &metadata_payload.lineage_ids,
                         reference_id: &metadata_payload.reference_id,
+                        forwarded_headers: &metadata_payload.forwarded_headers,
+                        connector_timeout: metadata_payload.connector_timeout,
                     };
 
                     let response = execute_connector_processing_step(
@@ -1358,8 +2016,18 @@ impl PaymentService for Payments {
                     .map_err(|e| e.into_grpc_status())?;
 
                     // Generate response
-                    let repeat_payment_response = generate_repeat_payment_response(response)
-                        .map_err(|e| e.into_grpc_status())?;
+                    let mut repeat_payment_response =
+                        generate_repeat_payment_response(response, connector)
+                            .map_err(|e| e.into_grpc_status())?;
+                    repeat_payment_response.payment_id = Some(utils::payment_id(
+                        &metadata,
+                        payload.request_ref_id.as_ref(),
+                    ));
+                    utils::record_payment_outcome(
+                        &connector,
+                        events::FlowName::RepeatPayment,
+                        &repeat_payment_response,
+                    );
 
                     Ok(tonic::Response::new(repeat_payment_response))
                 })
@@ -1367,6 +2035,123 @@ impl PaymentService for Payments {
         )
         .await
     }
+
+    #[tracing::instrument(
+        name = "register_webhook",
+        fields(
+            name = consts::NAME,
+            service_name = consts::PAYMENT_SERVICE_NAME,
+            service_method = connector_flow::FlowName::RegisterWebhook.to_string(),
+            request_body = tracing::field::Empty,
+            response_body = tracing::field::Empty,
+            error_message = tracing::field::Empty,
+            merchant_id = tracing::field::Empty,
+            gateway = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            status_code = tracing::field::Empty,
+            message_ = "Golden Log Line (incoming)",
+            response_time = tracing::field::Empty,
+            tenant_id = tracing::field::Empty,
+            flow = connector_flow::FlowName::RegisterWebhook.to_string(),
+        ),
+        skip(self, request)
+    )]
+    async fn register_webhook(
+        &self,
+        request: tonic::Request<PaymentServiceRegisterWebhookRequest>,
+    ) -> Result<tonic::Response<PaymentServiceRegisterWebhookResponse>, tonic::Status> {
+        info!("REGISTER_WEBHOOK_FLOW: initiated");
+        let service_name = request
+            .extensions()
+            .get::<String>()
+            .cloned()
+            .unwrap_or_else(|| "unknown_service".to_string());
+        grpc_logging_wrapper(
+            request,
+            &service_name,
+            self.config.clone(),
+            |request, metadata_payload| {
+                let service_name = service_name.clone();
+                Box::pin(async move {
+                    let (connector, request_id) =
+                        (metadata_payload.connector, metadata_payload.request_id);
+                    let connector_auth_details = metadata_payload.connector_auth_type;
+                    let payload = request.into_inner();
+
+                    //get connector data
+                    let connector_data: ConnectorData<DefaultPCIHolder> =
+                        ConnectorData::get_connector_by_name(&connector);
+
+                    // Get connector integration
+                    let connector_integration: BoxedConnectorIntegrationV2<
+                        '_,
+                        RegisterWebhook,
+                        WebhookFlowData,
+                        RegisterWebhookData,
+                        RegisterWebhookResponseData,
+                    > = connector_data.connector.get_connector_integration_v2();
+
+                    // Create webhook flow data
+                    let webhook_flow_data = WebhookFlowData::foreign_try_from((
+                        payload.clone(),
+                        self.config.request_connectors(),
+                    ))
+                    .map_err(|e| e.into_grpc_status())?;
+
+                    // Create register webhook data
+                    let register_webhook_data =
+                        RegisterWebhookData::foreign_try_from(payload.clone())
+                            .map_err(|e| e.into_grpc_status())?;
+
+                    // Create router data
+                    let router_data: RouterDataV2<
+                        RegisterWebhook,
+                        WebhookFlowData,
+                        RegisterWebhookData,
+                        RegisterWebhookResponseData,
+                    > = RouterDataV2 {
+                        flow: std::marker::PhantomData,
+                        resource_common_data: webhook_flow_data,
+                        connector_auth_type: connector_auth_details,
+                        request: register_webhook_data,
+                        response: Err(ErrorResponse::default()),
+                    };
+                    let event_params = EventProcessingParams {
+                        connector_name: &connector.to_string(),
+                        service_name: &service_name,
+                        flow_name: events::FlowName::RegisterWebhook,
+                        event_config: &self.config.events,
+                        traffic_log_config: &self.config.connector_traffic_log,
+                        raw_request_data: Some(pii::SecretSerdeValue::new(
+                            payload.masked_serialize().unwrap_or_default(),
+                        )),
+                        request_id: &request_id,
+                        merchant_id: &metadata_payload.merchant_id,
+                        lineage_ids: &metadata_payload.lineage_ids,
+                        reference_id: &metadata_payload.reference_id,
+                        forwarded_headers: &metadata_payload.forwarded_headers,
+                        connector_timeout: metadata_payload.connector_timeout,
+                    };
+
+                    let response = execute_connector_processing_step(
+                        &self.config.proxy,
+                        connector_integration,
+                        router_data,
+                        None,
+                        event_params,
+                    )
+                    .await
+                    .switch()
+                    .map_err(|e| e.into_grpc_status())?;
+
+                    Ok(tonic::Response::new(generate_register_webhook_response(
+                        response,
+                    )))
+                })
+            },
+        )
+        .await
+    }
 }
 
 async fn get_payments_webhook_content(
@@ -1488,3 +2273,41 @@ async fn get_disputes_webhook_content<
         ),
     })
 }
+
+async fn get_settlements_webhook_content<
+    T: PaymentMethodDataTypes
+        + Default
+        + Eq
+        + Debug
+        + Send
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + Clone
+        + Sync
+        + domain_types::types::CardConversionHelper<T>
+        + 'static,
+>(
+    connector_data: ConnectorData<T>,
+    request_details: &domain_types::connector_types::RequestDetails,
+    webhook_secrets: Option<&domain_types::connector_types::ConnectorWebhookSecrets>,
+    connector_auth_details: Option<&ConnectorAuthType>,
+) -> CustomResult<grpc_api_types::payments::WebhookResponseContent, ApplicationErrorResponse> {
+    let webhook_details = connector_data
+        .connector
+        .process_settlement_webhook(
+            request_details.clone(),
+            webhook_secrets.cloned(),
+            connector_auth_details.cloned(),
+        )
+        .switch()?;
+
+    let response = grpc_api_types::payments::SettlementResponse::foreign_try_from(webhook_details)?;
+
+    Ok(grpc_api_types::payments::WebhookResponseContent {
+        content: Some(
+            grpc_api_types::payments::webhook_response_content::Content::SettlementsResponse(
+                response,
+            ),
+        ),
+    })
+}
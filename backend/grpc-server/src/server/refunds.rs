@@ -129,6 +129,16 @@ impl RefundService for Refunds {
                         tonic::Status::invalid_argument("missing request_details in the payload")
                     })?
                     .map_err(|e| e.into_grpc_status())?;
+                utils::verify_webhook_source_ip(
+                    &connector,
+                    &self
+                        .config
+                        .connectors
+                        .get_connector_params(&connector)
+                        .webhook_allowed_source_ips,
+                    &request_details,
+                    &self.config.webhook_source.trusted_proxies,
+                )?;
 
                 let webhook_secrets = payload
                     .webhook_secrets
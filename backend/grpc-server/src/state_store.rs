@@ -0,0 +1,116 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Time an entry is kept when the store is built with [`Default`]
+pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Number of entries kept when the store is built with [`Default`]
+pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+/// In-memory store for state that must outlive a single request, e.g. responses replayed
+/// to repeated requests. Entries expire after a fixed time, and the oldest entries are evicted
+/// once the store is full. The store is local to this instance.
+#[derive(Debug)]
+pub struct StateStore<V> {
+    inner: Mutex<Inner<V>>,
+    in_flight: Arc<Mutex<HashSet<String>>>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+#[derive(Debug)]
+struct Inner<V> {
+    entries: HashMap<String, (Instant, V)>,
+    /// keys in insertion order, with the expiry they were inserted with; a key inserted again
+    /// leaves a stale element behind, skipped when it reaches the front
+    order: VecDeque<(String, Instant)>,
+}
+
+/// Marks a key as in flight until dropped, see [`StateStore::begin`]
+#[derive(Debug)]
+pub struct InFlightGuard {
+    key: String,
+    in_flight: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        lock(&self.in_flight).remove(&self.key);
+    }
+}
+
+impl<V> Default for StateStore<V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_MAX_ENTRIES)
+    }
+}
+
+impl<V> StateStore<V> {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+            in_flight: Arc::default(),
+            ttl,
+            max_entries,
+        }
+    }
+
+    pub fn insert(&self, key: String, value: V) {
+        let now = Instant::now();
+        let expires_at = now + self.ttl;
+        let mut inner = lock(&self.inner);
+        inner.entries.insert(key.clone(), (expires_at, value));
+        inner.order.push_back((key, expires_at));
+        inner.evict(now, self.max_entries);
+    }
+
+    /// Marks `key` as in flight, `None` when a request holding it has not finished yet. Lets
+    /// concurrent requests for the same key be refused instead of all reaching the connector.
+    pub fn begin(&self, key: &str) -> Option<InFlightGuard> {
+        lock(&self.in_flight)
+            .insert(key.to_string())
+            .then(|| InFlightGuard {
+                key: key.to_string(),
+                in_flight: Arc::clone(&self.in_flight),
+            })
+    }
+}
+
+impl<V: Clone> StateStore<V> {
+    pub fn get(&self, key: &str) -> Option<V> {
+        let mut inner = lock(&self.inner);
+        inner.evict(Instant::now(), self.max_entries);
+        inner.entries.get(key).map(|(_, value)| value.clone())
+    }
+}
+
+impl<V> Inner<V> {
+    /// Drops expired entries, then the oldest ones while more than `max_entries` are kept
+    fn evict(&mut self, now: Instant, max_entries: usize) {
+        while let Some((key, expires_at)) = self.order.front() {
+            let current = self
+                .entries
+                .get(key)
+                .is_some_and(|(entry_expires_at, _)| entry_expires_at == expires_at);
+            if current && *expires_at > now && self.entries.len() <= max_entries {
+                break;
+            }
+            if current {
+                self.entries.remove(key);
+            }
+            self.order.pop_front();
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    // A panic while holding the lock cannot leave the map in an inconsistent state
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -1,26 +1,41 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    net::IpAddr,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use common_utils::{
     consts::{self, X_API_KEY, X_API_SECRET, X_AUTH, X_AUTH_KEY_MAP, X_KEY1, X_KEY2},
+    crypto::GenerateDigest,
     errors::CustomResult,
-    events::FlowName,
+    events::{AuditEvent, DeclineEvent, EventConfig, FlowName},
     lineage::LineageIds,
+    new_types::MaskedCardBin,
 };
 use domain_types::{
     connector_flow::{
-        Accept, Authorize, Capture, CreateOrder, CreateSessionToken, DefendDispute, PSync, RSync,
-        Refund, RepeatPayment, SetupMandate, SubmitEvidence, Void,
+        Accept, Authorize, Capture, CaptureReversal, CreateOrder, CreateSessionToken,
+        DefendDispute, PSync, RSync, Refund, RegisterWebhook, RepeatPayment, SetupMandate,
+        SubmitEvidence, Void,
     },
     connector_types,
     errors::{ApiError, ApplicationErrorResponse},
     router_data::ConnectorAuthType,
+    types::{IpNetwork, WebhookBodyFormat},
 };
 use error_stack::{Report, ResultExt};
+use grpc_api_types::payments;
 use http::request::Request;
 use hyperswitch_masking;
 use tonic::metadata;
 
-use crate::{configs, error::ResultExtGrpc};
+use crate::{configs, error::ResultExtGrpc, error_localization, event_sink::EventSink};
 
 // Helper function to map flow markers to flow names
 pub fn flow_marker_to_flow_name<F>() -> FlowName
@@ -41,6 +56,8 @@ where
         FlowName::Refund
     } else if type_id == std::any::TypeId::of::<Capture>() {
         FlowName::Capture
+    } else if type_id == std::any::TypeId::of::<CaptureReversal>() {
+        FlowName::CaptureReversal
     } else if type_id == std::any::TypeId::of::<SetupMandate>() {
         FlowName::SetupMandate
     } else if type_id == std::any::TypeId::of::<RepeatPayment>() {
@@ -55,6 +72,8 @@ where
         FlowName::DefendDispute
     } else if type_id == std::any::TypeId::of::<SubmitEvidence>() {
         FlowName::SubmitEvidence
+    } else if type_id == std::any::TypeId::of::<RegisterWebhook>() {
+        FlowName::RegisterWebhook
     } else {
         tracing::warn!("Unknown flow marker type: {}", std::any::type_name::<F>());
         FlowName::Unknown
@@ -119,6 +138,7 @@ pub fn record_fields_from_header<B: hyper::body::Body>(request: &Request<B>) ->
 }
 
 /// Struct to hold extracted metadata payload
+#[derive(Clone)]
 pub struct MetadataPayload {
     pub tenant_id: String,
     pub request_id: String,
@@ -127,19 +147,81 @@ pub struct MetadataPayload {
     pub lineage_ids: LineageIds<'static>,
     pub connector_auth_type: ConnectorAuthType,
     pub reference_id: Option<String>,
+    pub forwarded_headers: Vec<(String, String)>,
+    /// Point in time by which the client expects a response, when it set a gRPC deadline
+    pub deadline: Option<Instant>,
+    /// Connector call timeout requested through `x-connector-timeout-ms`, applied to test
+    /// mode payments only
+    pub connector_timeout: Option<Duration>,
+    /// Retries left for the request, shared by every retry mechanism
+    pub retry_budget: Arc<RetryBudget>,
+}
+
+/// Number of connector retries a request may still make, across all retry mechanisms.
+///
+/// Each mechanism takes a retry from the budget before retrying, so retries triggered for
+/// different reasons cannot multiply beyond `connector_retry.max_attempts`.
+#[derive(Debug)]
+pub struct RetryBudget {
+    /// `None` when the number of attempts is not capped
+    remaining: Option<AtomicU32>,
+}
+
+impl RetryBudget {
+    /// `max_attempts` counts the first attempt, so a request gets `max_attempts - 1` retries
+    pub fn new(max_attempts: Option<u32>) -> Self {
+        Self {
+            remaining: max_attempts
+                .map(|max_attempts| AtomicU32::new(max_attempts.saturating_sub(1))),
+        }
+    }
+
+    /// Takes a retry from the budget; false once the request has used up its attempts
+    pub fn try_retry(&self) -> bool {
+        self.remaining.as_ref().is_none_or(|remaining| {
+            remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
+                    remaining.checked_sub(1)
+                })
+                .is_ok()
+        })
+    }
+}
+
+/// Sets the x-service-version response header when `expose_service_version` is enabled
+pub fn service_version_layer(
+    config: &configs::Server,
+) -> tower_http::set_header::SetResponseHeaderLayer<Option<http::HeaderValue>> {
+    tower_http::set_header::SetResponseHeaderLayer::if_not_present(
+        http::HeaderName::from_static(consts::X_SERVICE_VERSION),
+        config
+            .expose_service_version
+            .then(|| http::HeaderValue::from_static(crate::version!())),
+    )
 }
 
 pub fn get_metadata_payload(
     metadata: &metadata::MetadataMap,
     server_config: Arc<configs::Config>,
 ) -> CustomResult<MetadataPayload, ApplicationErrorResponse> {
-    let connector = connector_from_metadata(metadata)?;
+    let connector =
+        connector_from_metadata(metadata, server_config.common.default_connector.as_deref())?;
     let merchant_id = merchant_id_from_metadata(metadata)?;
     let tenant_id = tenant_id_from_metadata(metadata)?;
     let request_id = request_id_from_metadata(metadata)?;
     let lineage_ids = extract_lineage_fields_from_metadata(metadata, &server_config.lineage);
     let connector_auth_type = auth_from_metadata(metadata)?;
     let reference_id = reference_id_from_metadata(metadata)?;
+    let forwarded_headers = forwarded_headers_from_metadata(
+        metadata,
+        &server_config
+            .connectors
+            .get_connector_params(&connector)
+            .forwarded_headers,
+    );
+    let deadline = deadline_from_metadata(metadata);
+    let connector_timeout = connector_timeout_from_metadata(metadata)?;
+    let retry_budget = Arc::new(RetryBudget::new(server_config.connector_retry.max_attempts));
     Ok(MetadataPayload {
         tenant_id,
         request_id,
@@ -148,21 +230,277 @@ pub fn get_metadata_payload(
         lineage_ids,
         connector_auth_type,
         reference_id,
+        forwarded_headers,
+        deadline,
+        connector_timeout,
+        retry_budget,
     })
 }
 
-pub fn connector_from_metadata(
+/// Reads the connector call timeout override from `x-connector-timeout-ms`.
+///
+/// The header holds a whole number of milliseconds between 1 and `MAX_CONNECTOR_TIMEOUT_MS`.
+pub fn connector_timeout_from_metadata(
     metadata: &metadata::MetadataMap,
-) -> CustomResult<connector_types::ConnectorEnum, ApplicationErrorResponse> {
-    parse_metadata(metadata, consts::X_CONNECTOR).and_then(|inner| {
-        connector_types::ConnectorEnum::from_str(inner).map_err(|e| {
+) -> CustomResult<Option<Duration>, ApplicationErrorResponse> {
+    let Some(timeout) = parse_optional_metadata(metadata, consts::X_CONNECTOR_TIMEOUT_MS)? else {
+        return Ok(None);
+    };
+    timeout
+        .parse::<u64>()
+        .ok()
+        .filter(|timeout_ms| (1..=consts::MAX_CONNECTOR_TIMEOUT_MS).contains(timeout_ms))
+        .map(|timeout_ms| Some(Duration::from_millis(timeout_ms)))
+        .ok_or_else(|| {
             Report::new(ApplicationErrorResponse::BadRequest(ApiError {
-                sub_code: "INVALID_CONNECTOR".to_string(),
+                sub_code: "INVALID_CONNECTOR_TIMEOUT".to_string(),
                 error_identifier: 400,
-                error_message: format!("Invalid connector: {e}"),
+                error_message: format!(
+                    "{} must be a number of milliseconds between 1 and {}",
+                    consts::X_CONNECTOR_TIMEOUT_MS,
+                    consts::MAX_CONNECTOR_TIMEOUT_MS
+                ),
                 error_object: None,
             }))
         })
+}
+
+/// Resolves the client's deadline from the grpc-timeout header, measured from now.
+///
+/// The header holds an integer followed by a unit (`H`, `M`, `S`, `m`, `u` or `n`); a missing
+/// or malformed header means the client set no deadline.
+pub fn deadline_from_metadata(metadata: &metadata::MetadataMap) -> Option<Instant> {
+    let timeout = metadata.get(consts::GRPC_TIMEOUT)?.to_str().ok()?;
+    let (value, unit) = timeout.split_at(timeout.len().checked_sub(1)?);
+    let value = value.parse::<u64>().ok()?;
+    let timeout = match unit {
+        "H" => Duration::from_secs(value.saturating_mul(3600)),
+        "M" => Duration::from_secs(value.saturating_mul(60)),
+        "S" => Duration::from_secs(value),
+        "m" => Duration::from_millis(value),
+        "u" => Duration::from_micros(value),
+        "n" => Duration::from_nanos(value),
+        _ => return None,
+    };
+    Instant::now().checked_add(timeout)
+}
+
+/// Returns true when enough of the client's deadline remains to retry a connector request.
+///
+/// A retry is assumed to take as long as the attempt before it, plus the configured margin;
+/// without a deadline there is no limit.
+pub fn has_time_for_retry(
+    deadline: Option<Instant>,
+    last_attempt: Duration,
+    retry_config: &configs::ConnectorRetryConfig,
+) -> bool {
+    deadline.is_none_or(|deadline| {
+        let required = last_attempt + Duration::from_millis(retry_config.min_remaining_time_ms);
+        deadline.saturating_duration_since(Instant::now()) >= required
+    })
+}
+
+/// Collects the allow-listed metadata entries to be sent to the connector as request headers.
+///
+/// `allowed_keys` maps an inbound metadata key to the outbound header name; keys that are
+/// absent or not valid ASCII are skipped.
+pub fn forwarded_headers_from_metadata(
+    metadata: &metadata::MetadataMap,
+    allowed_keys: &HashMap<String, String>,
+) -> Vec<(String, String)> {
+    allowed_keys
+        .iter()
+        .filter_map(|(metadata_key, header_name)| {
+            metadata
+                .get(metadata_key.as_str())
+                .and_then(|value| value.to_str().ok())
+                .map(|value| (header_name.clone(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Rejects a webhook whose source address is not in the connector's allow-list.
+///
+/// The source is the address the caller reports in the request details. While that address
+/// belongs to one of the `trusted_proxies`, the x-forwarded-for entry to its left is taken
+/// instead, walking from the right; entries added by untrusted hops are never consulted. An
+/// empty allow-list accepts every source.
+pub fn verify_webhook_source_ip(
+    connector: &connector_types::ConnectorEnum,
+    allowed_sources: &[IpNetwork],
+    request_details: &connector_types::RequestDetails,
+    trusted_proxies: &[IpNetwork],
+) -> Result<(), tonic::Status> {
+    if allowed_sources.is_empty() {
+        tracing::warn!(
+            %connector,
+            "no webhook source IP allow-list configured, accepting webhook from any source"
+        );
+        return Ok(());
+    }
+    let mut forwarded_for = request_details
+        .headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(consts::X_FORWARDED_FOR))
+        .flat_map(|(_, value)| value.rsplit(','));
+    let mut source_ip = request_details.source_ip;
+    while let Some(ip) =
+        source_ip.filter(|ip| trusted_proxies.iter().any(|network| network.contains(*ip)))
+    {
+        source_ip = forwarded_for
+            .next()
+            .and_then(|value| IpAddr::from_str(value.trim()).ok());
+        tracing::debug!(%connector, proxy = %ip, ?source_ip, "webhook forwarded by trusted proxy");
+    }
+    match source_ip {
+        Some(source_ip)
+            if allowed_sources
+                .iter()
+                .any(|network| network.contains(source_ip)) =>
+        {
+            Ok(())
+        }
+        source_ip => {
+            tracing::warn!(%connector, ?source_ip, "webhook rejected from disallowed source");
+            Err(tonic::Status::permission_denied(
+                "webhook source address is not allowed",
+            ))
+        }
+    }
+}
+
+/// Parses a webhook body as the format its connector declares, returning the body encoded as
+/// UTF-8 for the connector to transform. Source verification must use the body as received.
+///
+/// A content-type naming another format is rejected; content-types naming no structured
+/// format, or a missing one, are tolerated and the body alone decides. The charset parameter
+/// may be UTF-8 or ISO-8859-1. Bodies are returned as received when no format is declared.
+pub fn parse_webhook_body(
+    connector: &connector_types::ConnectorEnum,
+    body_format: Option<WebhookBodyFormat>,
+    request_details: &connector_types::RequestDetails,
+) -> Result<Vec<u8>, tonic::Status> {
+    let Some(body_format) = body_format else {
+        return Ok(request_details.body.clone());
+    };
+    let mut content_type = request_details
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(http::header::CONTENT_TYPE.as_str()))
+        .map(|(_, value)| value.split(';'))
+        .into_iter()
+        .flatten();
+    let media_type = content_type.next();
+    let charset = content_type.find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_ascii_lowercase())
+    });
+
+    if let Some(declared_format) = media_type.and_then(WebhookBodyFormat::from_media_type) {
+        if declared_format != body_format {
+            tracing::warn!(%connector, ?media_type, %body_format, "webhook content-type mismatch");
+            return Err(tonic::Status::invalid_argument(format!(
+                "webhook content-type is {declared_format}, but {connector} sends {body_format} webhooks"
+            )));
+        }
+    }
+
+    let body = match charset.as_deref() {
+        None | Some("utf-8") | Some("utf8") => Cow::Borrowed(
+            std::str::from_utf8(&request_details.body)
+                .map_err(|_| tonic::Status::invalid_argument("webhook body is not valid UTF-8"))?,
+        ),
+        Some("iso-8859-1") | Some("latin1") => Cow::Owned(
+            request_details
+                .body
+                .iter()
+                .map(|&byte| char::from(byte))
+                .collect(),
+        ),
+        Some(charset) => {
+            return Err(tonic::Status::invalid_argument(format!(
+                "webhook body charset {charset} is not supported"
+            )))
+        }
+    };
+
+    let parsed = match body_format {
+        WebhookBodyFormat::Json => serde_json::from_str::<serde::de::IgnoredAny>(&body)
+            .map(|_| ())
+            .map_err(|error| error.to_string()),
+        // Any text decodes as form pairs, so a body opening like a JSON or XML document is what
+        // tells a mismatched body apart
+        WebhookBodyFormat::FormUrlEncoded => match body.trim_start().chars().next() {
+            Some('{' | '[' | '<') => Err("body is a JSON or XML document".to_string()),
+            _ => serde_urlencoded::from_str::<Vec<(String, String)>>(&body)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+        },
+        WebhookBodyFormat::Xml => check_xml_document(&body),
+    };
+    parsed.map_err(|error| {
+        tracing::warn!(%connector, %body_format, %error, "webhook body could not be parsed");
+        tonic::Status::invalid_argument(format!(
+            "webhook body is not valid {body_format} as {connector} sends: {error}"
+        ))
+    })?;
+
+    Ok(body.into_owned().into_bytes())
+}
+
+/// Reads an XML document through, failing on malformed markup or a body without elements
+fn check_xml_document(body: &str) -> Result<(), String> {
+    let mut reader = quick_xml::Reader::from_str(body);
+    let mut has_element = false;
+    loop {
+        match reader.read_event().map_err(|error| error.to_string())? {
+            quick_xml::events::Event::Start(_) | quick_xml::events::Event::Empty(_) => {
+                has_element = true;
+            }
+            quick_xml::events::Event::Eof if has_element => return Ok(()),
+            quick_xml::events::Event::Eof => return Err("body has no XML element".to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Resolves the connector from the x-connector header, falling back to the configured
+/// default connector when the header is absent.
+pub fn connector_from_metadata(
+    metadata: &metadata::MetadataMap,
+    default_connector: Option<&str>,
+) -> CustomResult<connector_types::ConnectorEnum, ApplicationErrorResponse> {
+    let connector = match parse_optional_metadata(metadata, consts::X_CONNECTOR)? {
+        Some(connector) => connector,
+        None => {
+            let connector = default_connector.ok_or_else(|| {
+                Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+                    sub_code: "MISSING_CONNECTOR".to_string(),
+                    error_identifier: 400,
+                    error_message: format!(
+                        "Missing {} in request metadata and no default connector is configured",
+                        consts::X_CONNECTOR
+                    ),
+                    error_object: None,
+                }))
+            })?;
+            tracing::debug!(
+                connector,
+                "x-connector header absent, using default connector"
+            );
+            connector
+        }
+    };
+
+    connector_types::ConnectorEnum::from_str(connector).map_err(|e| {
+        Report::new(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "INVALID_CONNECTOR".to_string(),
+            error_identifier: 400,
+            error_message: format!("Invalid connector: {e}"),
+            error_object: None,
+        }))
     })
 }
 
@@ -383,6 +721,171 @@ where
     tracing::info!("Golden Log Line (incoming)");
 }
 
+/// Builds the audit summary of a finished gRPC call from its metadata and outcome
+pub fn build_audit_event<R>(
+    service_name: &str,
+    metadata_payload: &MetadataPayload,
+    result: &Result<tonic::Response<R>, tonic::Status>,
+    latency_ms: u64,
+) -> AuditEvent {
+    let status = match result {
+        Ok(_) => tonic::Code::Ok,
+        Err(status) => status.code(),
+    };
+    AuditEvent {
+        request_id: metadata_payload.request_id.clone(),
+        timestamp: common_utils::date_time::now_unix_timestamp().into(),
+        method: service_name.to_string(),
+        merchant_id: metadata_payload.merchant_id.clone(),
+        connector: metadata_payload.connector.to_string(),
+        status: format!("{status:?}"),
+        latency_ms,
+    }
+}
+
+/// Emits one audit event for the call to `event_sink` when audit events are enabled
+pub fn emit_audit_event<R>(
+    event_sink: &dyn EventSink,
+    event_config: &EventConfig,
+    service_name: &str,
+    metadata_payload: &MetadataPayload,
+    result: &Result<tonic::Response<R>, tonic::Status>,
+    latency_ms: u64,
+) {
+    if !event_config.audit.enabled {
+        return;
+    }
+    let event = build_audit_event(service_name, metadata_payload, result, latency_ms);
+    external_services::shared_metrics::GRPC_SERVER_AUDIT_EVENTS_TOTAL
+        .with_label_values(&[&event.method, &event.connector])
+        .inc();
+    event_sink.publish_audit_event(event, event_config);
+}
+
+/// Server-side id of a payment, returned alongside the connector's transaction id. It is
+/// derived from the merchant and the request reference id, so every flow sent with the
+/// payment's reference reports the same id on any instance, and random when there is no
+/// reference. The id is recorded on the current span to correlate the logs of those flows.
+pub fn payment_id(
+    metadata: &metadata::MetadataMap,
+    request_ref_id: Option<&payments::Identifier>,
+) -> String {
+    let merchant_id = metadata
+        .get(consts::X_MERCHANT_ID)
+        .and_then(|merchant_id| merchant_id.to_str().ok())
+        .unwrap_or_default();
+    let payment_id = match request_ref_id.and_then(|id| id.id_type.as_ref()) {
+        Some(payments::identifier::IdType::Id(reference_id)) if !reference_id.is_empty() => {
+            common_utils::crypto::Sha256
+                .generate_digest(format!("{merchant_id}:{reference_id}").as_bytes())
+                .ok()
+                .and_then(|digest| uuid::Uuid::from_slice(digest.get(..16)?).ok())
+        }
+        _ => None,
+    }
+    .unwrap_or_else(uuid::Uuid::new_v4)
+    .to_string();
+    tracing::Span::current().record("payment_id", payment_id.as_str());
+    payment_id
+}
+
+/// Decline category of a failed authorization: declines the client may retry are soft,
+/// other declines hard, and failures without a decline status technical
+pub fn decline_category(response: &payments::PaymentServiceAuthorizeResponse) -> &'static str {
+    match (response.status(), response.retryable) {
+        (
+            payments::PaymentStatus::Failure | payments::PaymentStatus::AuthorizationFailed,
+            Some(true),
+        ) => "soft_decline",
+        (payments::PaymentStatus::Failure | payments::PaymentStatus::AuthorizationFailed, _) => {
+            "hard_decline"
+        }
+        _ => "technical_error",
+    }
+}
+
+/// Builds the decline event of an authorization, `None` when it did not fail
+pub fn build_decline_event(
+    metadata_payload: &MetadataPayload,
+    response: &payments::PaymentServiceAuthorizeResponse,
+) -> Option<DeclineEvent> {
+    let error_code = response.error_code.as_deref()?;
+    Some(DeclineEvent {
+        request_id: metadata_payload.request_id.clone(),
+        timestamp: common_utils::date_time::now_unix_timestamp().into(),
+        merchant_id: metadata_payload.merchant_id.clone(),
+        connector: metadata_payload.connector.to_string(),
+        error_code: crate::error_localization::normalize_error_code(error_code),
+        category: decline_category(response).to_string(),
+        bin: response.bin.clone().map(MaskedCardBin::from),
+        card_issuer: response.card_issuer.clone(),
+    })
+}
+
+/// Emits a decline event for a failed authorization to `event_sink` when decline events are
+/// enabled
+pub fn emit_decline_event(
+    event_sink: &dyn EventSink,
+    event_config: &EventConfig,
+    metadata_payload: &MetadataPayload,
+    response: &payments::PaymentServiceAuthorizeResponse,
+) {
+    if !event_config.decline.enabled {
+        return;
+    }
+    let Some(event) = build_decline_event(metadata_payload, response) else {
+        return;
+    };
+    external_services::shared_metrics::DECLINE_EVENTS_TOTAL
+        .with_label_values(&[&event.connector, &event.category])
+        .inc();
+    event_sink.publish_decline_event(event, event_config);
+}
+
+/// Normalized status of a flow response, used as a bounded metric label
+pub trait OutcomeStatus {
+    fn outcome_status(&self) -> &'static str;
+}
+
+macro_rules! impl_outcome_status {
+    ($status_getter:ident => $($response:ty),+ $(,)?) => {
+        $(
+            impl OutcomeStatus for $response {
+                fn outcome_status(&self) -> &'static str {
+                    self.$status_getter().as_str_name()
+                }
+            }
+        )+
+    };
+}
+
+impl_outcome_status!(status =>
+    payments::PaymentServiceAuthorizeResponse,
+    payments::PaymentServiceGetResponse,
+    payments::PaymentServiceVoidResponse,
+    payments::PaymentServiceCaptureResponse,
+    payments::PaymentServiceReverseCaptureResponse,
+    payments::PaymentServiceRegisterResponse,
+    payments::PaymentServiceRepeatEverythingResponse,
+    payments::RefundResponse,
+);
+impl_outcome_status!(dispute_status => payments::DisputeDefendResponse);
+
+/// Counts one connector outcome for the approval-rate metrics
+pub fn record_payment_outcome<R: OutcomeStatus>(
+    connector: &connector_types::ConnectorEnum,
+    flow: FlowName,
+    response: &R,
+) {
+    external_services::shared_metrics::PAYMENT_OUTCOMES_TOTAL
+        .with_label_values(&[
+            &connector.to_string(),
+            flow.as_str(),
+            response.outcome_status(),
+        ])
+        .inc();
+}
+
 pub async fn grpc_logging_wrapper<T, F, Fut, R>(
     request: tonic::Request<T>,
     service_name: &str,
@@ -393,17 +896,29 @@ where
     T: serde::Serialize + std::fmt::Debug + Send + 'static,
     F: FnOnce(tonic::Request<T>, MetadataPayload) -> Fut + Send,
     Fut: std::future::Future<Output = Result<tonic::Response<R>, tonic::Status>> + Send,
-    R: serde::Serialize + std::fmt::Debug,
+    R: serde::Serialize + std::fmt::Debug + error_localization::LocalizableErrorResponse,
 {
     let current_span = tracing::Span::current();
     let header_payload =
         get_metadata_payload(request.metadata(), config.clone()).into_grpc_status()?;
     log_before_initialization(&request, service_name, &header_payload).into_grpc_status()?;
+    let language =
+        error_localization::requested_language(&config.error_localization, request.metadata());
     let start_time = tokio::time::Instant::now();
-    let result = handler(request, header_payload).await;
+    let audit_payload = header_payload.clone();
+    let mut result = handler(request, header_payload).await;
+    error_localization::localize_result(&mut result, audit_payload.connector, language.as_deref());
     let duration = start_time.elapsed().as_millis();
     current_span.record("response_time", duration);
     log_after_initialization(&result);
+    emit_audit_event(
+        config.event_sink.as_ref(),
+        &config.events,
+        service_name,
+        &audit_payload,
+        &result,
+        u64::try_from(duration).unwrap_or(u64::MAX),
+    );
     result
 }
 
@@ -436,9 +951,10 @@ macro_rules! implement_connector_operation {
             let current_span = tracing::Span::current();
             let metadata_payload = $crate::utils::get_metadata_payload(request.metadata(), self.config.clone()).into_grpc_status()?;
             $crate::utils::log_before_initialization(&request, service_name.as_str(), &metadata_payload).into_grpc_status()?;
+            let language = $crate::error_localization::requested_language(&self.config.error_localization, request.metadata());
             let start_time = tokio::time::Instant::now();
-            let result = Box::pin(async{
-            let (connector, request_id, connector_auth_details) = (metadata_payload.connector, metadata_payload.request_id, metadata_payload.connector_auth_type);
+            let mut result = Box::pin(async{
+            let (connector, request_id, connector_auth_details) = (metadata_payload.connector.clone(), metadata_payload.request_id.clone(), metadata_payload.connector_auth_type.clone());
             let metadata = request.metadata().clone();
             let payload = request.into_inner();
 
@@ -459,7 +975,7 @@ macro_rules! implement_connector_operation {
                 .into_grpc_status()?;
 
             // Create common request data
-            let common_flow_data = $common_flow_data_constructor((payload.clone(), self.config.connectors.clone(), &metadata))
+            let common_flow_data = $common_flow_data_constructor((payload.clone(), self.config.request_connectors(), &metadata))
                 .into_grpc_status()?;
 
             // Create router data
@@ -483,10 +999,14 @@ macro_rules! implement_connector_operation {
                 service_name: &service_name,
                 flow_name,
                 event_config: &self.config.events,
+                traffic_log_config: &self.config.connector_traffic_log,
                 raw_request_data: Some(common_utils::pii::SecretSerdeValue::new(payload.masked_serialize().unwrap_or_default())),
                 request_id: &request_id,
+                merchant_id: &metadata_payload.merchant_id,
                 lineage_ids: &metadata_payload.lineage_ids,
                 reference_id: &metadata_payload.reference_id,
+                forwarded_headers: &metadata_payload.forwarded_headers,
+                connector_timeout: metadata_payload.connector_timeout,
             };
             let response_result = external_services::service::execute_connector_processing_step(
                 &self.config.proxy,
@@ -500,13 +1020,23 @@ macro_rules! implement_connector_operation {
             .into_grpc_status()?;
 
             // Generate response
-            let final_response = $generate_response_fn(response_result)
+            let final_response = $generate_response_fn(response_result, connector)
                 .into_grpc_status()?;
+            $crate::utils::record_payment_outcome(&connector, flow_name, &final_response);
             Ok(tonic::Response::new(final_response))
         }).await;
+        $crate::error_localization::localize_result(&mut result, metadata_payload.connector, language.as_deref());
         let duration = start_time.elapsed().as_millis();
         current_span.record("response_time", duration);
         $crate::utils::log_after_initialization(&result);
+        $crate::utils::emit_audit_event(
+            self.config.event_sink.as_ref(),
+            &self.config.events,
+            &service_name,
+            &metadata_payload,
+            &result,
+            u64::try_from(duration).unwrap_or(u64::MAX),
+        );
         result
     }
 }
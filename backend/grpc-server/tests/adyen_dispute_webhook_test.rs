@@ -76,6 +76,7 @@ async fn process_webhook_and_get_response(
             uri: Some("/webhooks/adyen".to_string()),
             query_params: None,
             body: request_body_bytes,
+            source_ip: None,
         }),
         webhook_secrets: None,
     });
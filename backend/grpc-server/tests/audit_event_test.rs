@@ -0,0 +1,113 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::Arc;
+
+use external_services::shared_metrics::GRPC_SERVER_AUDIT_EVENTS_TOTAL;
+use grpc_api_types::payments::{PaymentServiceVoidRequest, PaymentServiceVoidResponse};
+use grpc_server::{configs, utils::grpc_logging_wrapper};
+use tonic::Request;
+
+mod common;
+
+fn audited_request(connector: &str) -> Request<PaymentServiceVoidRequest> {
+    let mut request = Request::new(PaymentServiceVoidRequest::default());
+    let metadata = request.metadata_mut();
+    metadata.insert("x-connector", connector.parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "header-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    request
+}
+
+/// Config with audit events enabled, handing them to the returned sink
+fn audit_config() -> (Arc<configs::Config>, Arc<common::CapturingEventSink>) {
+    let event_sink = Arc::new(common::CapturingEventSink::default());
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.events.audit.enabled = true;
+    config.event_sink = event_sink.clone();
+    (Arc::new(config), event_sink)
+}
+
+fn audit_event_count(method: &str, connector: &str) -> u64 {
+    GRPC_SERVER_AUDIT_EVENTS_TOTAL
+        .with_label_values(&[method, connector])
+        .get()
+}
+
+#[tokio::test]
+async fn test_one_audit_event_published_per_successful_rpc() {
+    let before = audit_event_count("PaymentService", "adyen");
+    let (config, event_sink) = audit_config();
+
+    let result = grpc_logging_wrapper(
+        audited_request("adyen"),
+        "PaymentService",
+        config,
+        |_request, _metadata_payload| async {
+            Ok(tonic::Response::new(PaymentServiceVoidResponse::default()))
+        },
+    )
+    .await;
+
+    assert!(result.is_ok());
+    assert_eq!(audit_event_count("PaymentService", "adyen"), before + 1);
+    let audit_events = event_sink.audit_events.lock().unwrap();
+    assert_eq!(audit_events.len(), 1);
+    let event = audit_events.first().unwrap();
+    assert_eq!(event.request_id, "request_123");
+    assert_eq!(event.method, "PaymentService");
+    assert_eq!(event.merchant_id, "merchant_123");
+    assert_eq!(event.connector, "adyen");
+    assert_eq!(event.status, "Ok");
+}
+
+#[tokio::test]
+async fn test_one_audit_event_published_per_failed_rpc() {
+    let before = audit_event_count("PaymentService", "checkout");
+    let (config, event_sink) = audit_config();
+
+    let result: Result<tonic::Response<PaymentServiceVoidResponse>, tonic::Status> =
+        grpc_logging_wrapper(
+            audited_request("checkout"),
+            "PaymentService",
+            config,
+            |_request, _metadata_payload| async {
+                Err(tonic::Status::invalid_argument("invalid request"))
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(audit_event_count("PaymentService", "checkout"), before + 1);
+    let audit_events = event_sink.audit_events.lock().unwrap();
+    assert_eq!(audit_events.len(), 1);
+    let event = audit_events.first().unwrap();
+    assert_eq!(event.connector, "checkout");
+    assert_eq!(event.status, "InvalidArgument");
+}
+
+#[tokio::test]
+async fn test_no_audit_event_published_when_disabled() {
+    let event_sink = Arc::new(common::CapturingEventSink::default());
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.events.audit.enabled = false;
+    config.event_sink = event_sink.clone();
+
+    let result = grpc_logging_wrapper(
+        audited_request("adyen"),
+        "PaymentService",
+        Arc::new(config),
+        |_request, _metadata_payload| async {
+            Ok(tonic::Response::new(PaymentServiceVoidResponse::default()))
+        },
+    )
+    .await;
+
+    assert!(result.is_ok());
+    assert!(event_sink.audit_events.lock().unwrap().is_empty());
+}
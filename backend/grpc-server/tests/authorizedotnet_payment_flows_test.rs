@@ -29,6 +29,7 @@ use grpc_api_types::{
         PaymentServiceRefundRequest, PaymentServiceRegisterRequest,
         PaymentServiceRepeatEverythingRequest, PaymentServiceRepeatEverythingResponse,
         PaymentServiceVoidRequest, PaymentStatus, RefundServiceGetRequest, RefundStatus,
+        StoredCredentialInitiator, StoredCredentialSequence, StoredCredentialUsage,
     },
 };
 use rand::{distributions::Alphanumeric, Rng};
@@ -201,11 +202,15 @@ fn create_repeat_payment_request(mandate_id: &str) -> PaymentServiceRepeatEveryt
         merchant_order_reference_id: Some(format!("repeat_order_{}", get_timestamp())),
         metadata,
         webhook_url: Some("https://your-webhook-url.com/payments/webhook".to_string()),
-        capture_method: None,
+        capture_method: Some(i32::from(CaptureMethod::Automatic)),
         email: None,
         browser_info: None,
         test_mode: None,
         payment_method_type: None,
+        stored_credential_usage: Some(StoredCredentialUsage {
+            sequence: i32::from(StoredCredentialSequence::Subsequent),
+            initiator: i32::from(StoredCredentialInitiator::Merchant),
+        }),
     }
 }
 
@@ -298,6 +303,7 @@ fn create_payment_authorize_request(
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
 
     request.payment_method = Some(PaymentMethod {
@@ -390,6 +396,7 @@ fn create_payment_get_request(transaction_id: &str) -> PaymentServiceGetRequest
     PaymentServiceGetRequest {
         transaction_id: Some(transaction_id_obj),
         request_ref_id: Some(request_ref_id),
+        prior_status: None,
     }
 }
 
@@ -411,6 +418,7 @@ fn create_payment_capture_request(transaction_id: &str) -> PaymentServiceCapture
         multiple_capture_data: None,
         metadata: HashMap::new(),
         browser_info: None,
+        capture_at: None,
     }
 }
 
@@ -473,6 +481,7 @@ fn create_refund_request(transaction_id: &str) -> PaymentServiceRefundRequest {
         metadata: HashMap::new(),
         refund_metadata,
         browser_info: None,
+        minor_captured_amount: None,
     }
 }
 
@@ -517,6 +526,7 @@ fn create_register_request() -> PaymentServiceRegisterRequest {
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
 
     request.payment_method = Some(PaymentMethod {
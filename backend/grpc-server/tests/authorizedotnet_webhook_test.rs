@@ -175,6 +175,7 @@ async fn process_webhook_request(
             uri: Some("/webhooks/authorizedotnet".to_string()),
             query_params: None,
             body: request_body_bytes,
+            source_ip: None,
         }),
         webhook_secrets,
     });
@@ -727,6 +728,7 @@ async fn test_webhook_malformed_body() {
                 uri: Some("/webhooks/authorizedotnet".to_string()),
                 query_params: None,
                 body: request_body_bytes,
+                source_ip: None,
             }),
             webhook_secrets: None,
         });
@@ -981,6 +983,7 @@ async fn test_webhook_source_verification_invalid_signature() {
                 uri: Some("/webhooks/authorizedotnet".to_string()),
                 query_params: None,
                 body: request_body_bytes,
+                source_ip: None,
             }),
             webhook_secrets,
         });
@@ -1077,6 +1080,7 @@ async fn test_webhook_source_verification_missing_signature() {
                 uri: Some("/webhooks/authorizedotnet".to_string()),
                 query_params: None,
                 body: request_body_bytes,
+                source_ip: None,
             }),
             webhook_secrets,
         });
@@ -1171,6 +1175,7 @@ async fn test_webhook_source_verification_no_secret_provided() {
                 uri: Some("/webhooks/authorizedotnet".to_string()),
                 query_params: None,
                 body: request_body_bytes,
+                source_ip: None,
             }),
             webhook_secrets,
         });
@@ -0,0 +1,101 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, MultipleCaptureRequestData,
+    PaymentServiceCaptureRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that accepts every capture and counts the requests it gets
+fn start_capturing_connector() -> (String, Arc<AtomicUsize>) {
+    let request_count = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&request_count);
+    let base_url = common::start_connector(move |_, _| {
+        let capture = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        common::json_response(&format!(
+            r#"{{"merchantAccount":"test_merchant_account","paymentPspReference":"psp_123","pspReference":"capture_psp_{capture}","reference":"capture_ref_123","status":"received","amount":{{"currency":"USD","value":1000}}}}"#
+        ))
+    });
+    (base_url, request_count)
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn capture_request(capture_reference: &str) -> Request<PaymentServiceCaptureRequest> {
+    merchant_capture_request("merchant_123", capture_reference)
+}
+
+fn merchant_capture_request(
+    merchant_id: &str,
+    capture_reference: &str,
+) -> Request<PaymentServiceCaptureRequest> {
+    let mut request = Request::new(PaymentServiceCaptureRequest {
+        multiple_capture_data: Some(MultipleCaptureRequestData {
+            capture_sequence: 1,
+            capture_reference: capture_reference.to_string(),
+        }),
+        ..common::capture_request()
+    });
+    let metadata = request.metadata_mut();
+    common::insert_adyen_metadata(metadata);
+    metadata.insert("x-merchant-id", merchant_id.parse().expect("valid header"));
+    request
+}
+
+#[tokio::test]
+async fn test_duplicate_capture_returns_cached_response() {
+    let (base_url, request_count) = start_capturing_connector();
+    let payments = payments_service(base_url);
+
+    let first = payments
+        .capture(capture_request("capture_1"))
+        .await
+        .unwrap()
+        .into_inner();
+    let duplicate = payments
+        .capture(capture_request("capture_1"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(first.error_code, None);
+    assert_eq!(duplicate, first);
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+    // A different capture_reference is a new capture
+    payments
+        .capture(capture_request("capture_2"))
+        .await
+        .unwrap();
+    assert_eq!(request_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_captures_of_different_merchants_are_not_deduplicated() {
+    let (base_url, request_count) = start_capturing_connector();
+    let payments = payments_service(base_url);
+
+    payments
+        .capture(merchant_capture_request("merchant_123", "capture_1"))
+        .await
+        .unwrap();
+    payments
+        .capture(merchant_capture_request("merchant_456", "capture_1"))
+        .await
+        .unwrap();
+
+    assert_eq!(request_count.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+    PaymentServiceCaptureRequest, PaymentStatus,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that authorises every payment and counts the requests it gets
+fn start_connector() -> (String, Arc<AtomicUsize>) {
+    let request_count = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&request_count);
+    let base_url = common::start_connector(move |_, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        common::json_response(
+            r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"auto_capture_ref_123"}"#,
+        )
+    });
+    (base_url, request_count)
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(common::card_authorize_request("auto_capture_ref_123"));
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+fn capture_request() -> Request<PaymentServiceCaptureRequest> {
+    let mut request = Request::new(common::capture_request());
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_capture_after_auto_capture_is_refused() {
+    let (base_url, request_count) = start_connector();
+    let payments = payments_service(base_url);
+
+    let authorized = payments
+        .authorize(authorize_request())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(authorized.status(), PaymentStatus::Charged);
+
+    let status = payments.capture(capture_request()).await.unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    // The capture never reaches the connector
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_connector_default_automatic_capture_is_recorded() {
+    let (base_url, request_count) = start_connector();
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    config.connectors.adyen.default_capture_method = Some(common_enums::CaptureMethod::Automatic);
+    let payments = common::payments_service(config);
+
+    let mut request = authorize_request();
+    request.get_mut().capture_method = None;
+    let authorized = payments.authorize(request).await.unwrap().into_inner();
+    assert_eq!(authorized.status(), PaymentStatus::Charged);
+
+    let status = payments.capture(capture_request()).await.unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_auto_capture_of_another_merchant_does_not_refuse_capture() {
+    let (base_url, request_count) = start_connector();
+    let payments = payments_service(base_url);
+
+    payments.authorize(authorize_request()).await.unwrap();
+
+    let mut request = capture_request();
+    request.metadata_mut().insert(
+        "x-merchant-id",
+        "merchant_456".parse().expect("valid header"),
+    );
+    let _ = payments.capture(request).await;
+
+    // The capture of the other merchant's payment reaches the connector
+    assert_eq!(request_count.load(Ordering::SeqCst), 2);
+}
@@ -117,6 +117,7 @@ fn create_payment_authorize_request(
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
 
     // Initialize with all required fields
@@ -152,6 +153,7 @@ fn create_payment_sync_request(transaction_id: &str) -> PaymentServiceGetRequest
         request_ref_id: Some(Identifier {
             id_type: Some(IdType::Id(format!("checkout_sync_{}", get_timestamp()))),
         }),
+        prior_status: None,
     }
 }
 
@@ -167,6 +169,7 @@ fn create_payment_capture_request(transaction_id: &str) -> PaymentServiceCapture
         metadata: std::collections::HashMap::new(),
         request_ref_id: None,
         browser_info: None,
+        capture_at: None,
     }
 }
 
@@ -187,6 +190,7 @@ fn create_refund_request(transaction_id: &str) -> PaymentServiceRefundRequest {
         metadata: std::collections::HashMap::new(),
         refund_metadata: std::collections::HashMap::new(),
         browser_info: None,
+        minor_captured_amount: None,
         merchant_account_id: None,
         capture_method: None,
         request_ref_id: None,
@@ -1,17 +1,38 @@
-use std::{future::Future, sync::Arc};
+// Each test crate uses its own subset of the helpers
+#![allow(dead_code)]
+#![allow(clippy::expect_used)]
 
+use std::{
+    future::Future,
+    io::{Read, Write},
+    net::TcpListener,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use cards::CardNumber;
+use common_utils::events::{AuditEvent, DeclineEvent, EventConfig};
 use grpc_api_types::{
     health_check::health_client::HealthClient,
     payments::{
-        payment_service_client::PaymentServiceClient, refund_service_client::RefundServiceClient,
+        card_payment_method_type, dispute_service_client::DisputeServiceClient, identifier::IdType,
+        payment_method, payment_service_client::PaymentServiceClient,
+        refund_service_client::RefundServiceClient, Address, AuthenticationType, CaptureMethod,
+        CardDetails, CardPaymentMethodType, CountryAlpha2, Currency, Identifier, PaymentAddress,
+        PaymentMethod, PaymentServiceAuthorizeRequest, PaymentServiceCaptureRequest,
     },
 };
+use grpc_server::{configs, event_sink::EventSink, server::payments::Payments};
 use http::Uri;
 use hyper_util::rt::TokioIo; // Add this import
+use hyperswitch_masking::Secret;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio_stream::wrappers::UnixListenerStream;
-use tonic::transport::{Channel, Endpoint, Server};
+use tonic::{
+    metadata::MetadataMap,
+    transport::{Channel, Endpoint, Server},
+};
 use tower::service_fn;
 
 pub trait AutoClient {
@@ -34,6 +55,12 @@ impl AutoClient for RefundServiceClient<Channel> {
     }
 }
 
+impl AutoClient for DisputeServiceClient<Channel> {
+    fn new(channel: Channel) -> Self {
+        Self::new(channel)
+    }
+}
+
 /// # Panics
 ///
 /// Will panic if the socket file cannot be created or removed
@@ -67,6 +94,11 @@ where
                     service.refunds_service,
                 ),
             )
+            .add_service(
+                grpc_api_types::payments::dispute_service_server::DisputeServiceServer::new(
+                    service.disputes_service,
+                ),
+            )
             .serve_with_incoming(stream)
             .await;
         // Server must be running fine...
@@ -96,7 +128,7 @@ where
 macro_rules! grpc_test {
     ($client:ident, $c_type:ty, $body:block) => {
         let config = configs::Config::new().expect("Failed while parsing config");
-        let server = app::Service::new(std::sync::Arc::new(config)).await;
+        let server = app::Service::new(std::sync::Arc::new(config), Default::default()).await;
         let (server_fut, mut $client) = common::server_and_client_stub::<$c_type>(server)
             .await
             .expect("Failed to create the server client pair");
@@ -108,3 +140,207 @@ macro_rules! grpc_test {
         }
     };
 }
+
+/// Reads one HTTP request off the stream, returning its head and body
+pub fn read_http_request(stream: &mut impl Read) -> (String, String) {
+    let mut received = Vec::new();
+    let mut buffer = [0; 4096];
+    loop {
+        let read = stream.read(&mut buffer).unwrap_or(0);
+        if read == 0 {
+            break;
+        }
+        received.extend(buffer.iter().take(read));
+        let text = String::from_utf8_lossy(&received).to_string();
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let content_length = head
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then_some(value)?
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                })
+                .unwrap_or(0);
+            if body.len() >= content_length {
+                return (head.to_string(), body.to_string());
+            }
+        }
+    }
+    let text = String::from_utf8_lossy(&received).to_string();
+    let (head, body) = text.split_once("\r\n\r\n").unwrap_or((&text, ""));
+    (head.to_string(), body.to_string())
+}
+
+/// Raw HTTP response with the status line `status`, e.g. `200 OK`
+pub fn http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Raw `200 OK` HTTP response with a JSON body
+pub fn json_response(body: &str) -> String {
+    http_response("200 OK", "application/json", body)
+}
+
+/// Starts a connector stand-in on a local port and returns its base url.
+///
+/// Requests are answered one at a time with the raw HTTP response `respond` builds from the
+/// head and body of the request.
+pub fn start_connector(respond: impl Fn(&str, &str) -> String + Send + 'static) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind the connector stand-in");
+    let address = listener
+        .local_addr()
+        .expect("Failed to read the connector stand-in address");
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let (head, body) = read_http_request(&mut stream);
+            let _ = stream.write_all(respond(&head, &body).as_bytes());
+        }
+    });
+    format!("http://{address}/")
+}
+
+/// Event sink keeping the events it is handed, in place of Kafka
+#[derive(Debug, Default)]
+pub struct CapturingEventSink {
+    pub audit_events: Mutex<Vec<AuditEvent>>,
+    pub decline_events: Mutex<Vec<DeclineEvent>>,
+}
+
+impl EventSink for CapturingEventSink {
+    fn publish_audit_event(&self, event: AuditEvent, _config: &EventConfig) {
+        self.audit_events
+            .lock()
+            .expect("audit events lock")
+            .push(event);
+    }
+
+    fn publish_decline_event(&self, event: DeclineEvent, _config: &EventConfig) {
+        self.decline_events
+            .lock()
+            .expect("decline events lock")
+            .push(event);
+    }
+}
+
+/// Payment service over `config`, with empty state stores
+pub fn payments_service(config: configs::Config) -> Payments {
+    Payments {
+        config: Arc::new(config),
+        capture_responses: Arc::default(),
+        auto_captured_payments: Arc::default(),
+        payment_correlations: Arc::default(),
+    }
+}
+
+/// Adds the headers of an Adyen request of `merchant_123`
+pub fn insert_adyen_metadata(metadata: &mut MetadataMap) {
+    metadata.insert("x-connector", "adyen".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "body-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    metadata.insert(
+        "x-key1",
+        "test_merchant_account".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+}
+
+/// Adds the headers of an Adyen webhook request of `merchant_123`, with the HMAC key
+pub fn insert_adyen_webhook_metadata(metadata: &mut MetadataMap) {
+    metadata.insert("x-connector", "adyen".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "signature-key".parse().expect("valid header"));
+    metadata.insert(
+        "x-api-key",
+        "test_adyen_api_key".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+    metadata.insert("x-key1", "test_merchant_acc".parse().expect("valid header")); // Hardcoded dummy value
+    metadata.insert(
+        "x-api-secret",
+        "test_hmac_key".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+}
+
+/// Adds the headers of a Checkout request of `merchant_123`
+pub fn insert_checkout_metadata(metadata: &mut MetadataMap) {
+    metadata.insert("x-connector", "checkout".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "signature-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    metadata.insert(
+        "x-api-secret",
+        "test_api_secret".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+    metadata.insert(
+        "x-key1",
+        "test_processing_channel".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+}
+
+/// Automatically captured card payment of 10.00 USD with the reference id `reference_id`
+pub fn card_authorize_request(reference_id: &str) -> PaymentServiceAuthorizeRequest {
+    PaymentServiceAuthorizeRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id(reference_id.to_string())),
+        }),
+        amount: 1000,
+        minor_amount: 1000,
+        currency: Currency::Usd as i32,
+        payment_method: Some(PaymentMethod {
+            payment_method: Some(payment_method::PaymentMethod::Card(CardPaymentMethodType {
+                card_type: Some(card_payment_method_type::CardType::Credit(CardDetails {
+                    card_number: Some(
+                        CardNumber::from_str("4111111111111111").expect("valid card number"),
+                    ),
+                    card_exp_month: Some(Secret::new("03".to_string())),
+                    card_exp_year: Some(Secret::new("2030".to_string())),
+                    card_cvc: Some(Secret::new("737".to_string())),
+                    ..Default::default()
+                })),
+            })),
+        }),
+        address: Some(PaymentAddress {
+            shipping_address: None,
+            billing_address: Some(Address {
+                country_alpha2_code: Some(CountryAlpha2::Us as i32),
+                ..Default::default()
+            }),
+        }),
+        auth_type: AuthenticationType::NoThreeDs as i32,
+        capture_method: Some(CaptureMethod::Automatic as i32),
+        return_url: Some("https://example.com/return".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Capture of 10.00 USD of the connector transaction `psp_123`
+pub fn capture_request() -> PaymentServiceCaptureRequest {
+    PaymentServiceCaptureRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("capture_ref_123".to_string())),
+        }),
+        transaction_id: Some(Identifier {
+            id_type: Some(IdType::Id("psp_123".to_string())),
+        }),
+        amount_to_capture: 1000,
+        currency: Currency::Usd as i32,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,48 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use grpc_api_types::payments::{payment_service_server::PaymentService, PaymentStatus};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Payment service whose Checkout stand-in answers every request with 401 Unauthorized
+fn payments_service() -> Payments {
+    let base_url = common::start_connector(|_, _| {
+        common::http_response(
+            "401 Unauthorized",
+            "application/json",
+            r#"{"request_id":"req_123","error_type":"unauthorized"}"#,
+        )
+    });
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.checkout.base_url = base_url;
+    common::payments_service(config)
+}
+
+#[tokio::test]
+async fn test_connector_401_on_authorize_reports_connector_auth_failed() {
+    let payments = payments_service();
+    let mut request = Request::new(common::card_authorize_request("auth_failure_ref_123"));
+    common::insert_checkout_metadata(request.metadata_mut());
+
+    let response = payments.authorize(request).await.unwrap().into_inner();
+
+    assert_eq!(response.status(), PaymentStatus::Failure);
+    assert_eq!(
+        response.error_code.as_deref(),
+        Some("CONNECTOR_AUTH_FAILED")
+    );
+}
+
+#[tokio::test]
+async fn test_connector_401_on_capture_surfaces_as_unauthenticated() {
+    let payments = payments_service();
+    let mut request = Request::new(common::capture_request());
+    common::insert_checkout_metadata(request.metadata_mut());
+
+    let status = payments.capture(request).await.unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+}
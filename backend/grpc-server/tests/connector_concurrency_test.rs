@@ -0,0 +1,99 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{convert::Infallible, sync::Arc, time::Duration};
+
+use external_services::shared_metrics::CONNECTOR_CALL_QUEUE_DEPTH;
+use grpc_server::{configs::ConnectorConcurrencyConfig, connector_limiter::ConnectorLimiterLayer};
+use tokio::sync::{mpsc, Semaphore};
+use tower::{Layer, ServiceExt};
+
+const AUTHORIZE_PATH: &str = "/ucs.v2.PaymentService/Authorize";
+const HEALTH_PATH: &str = "/grpc.health.v1.Health/Check";
+
+type LimitedService =
+    tower::util::BoxCloneService<http::Request<()>, http::Response<String>, Infallible>;
+
+/// Wraps a connector stand-in that reports each call it starts and holds it until `release`
+/// has a permit for it
+fn limited_service(
+    config: &ConnectorConcurrencyConfig,
+    release: Arc<Semaphore>,
+) -> (LimitedService, mpsc::UnboundedReceiver<()>) {
+    let (started_tx, started_rx) = mpsc::unbounded_channel();
+    let connector = tower::service_fn(move |request: http::Request<()>| {
+        let release = Arc::clone(&release);
+        let started_tx = started_tx.clone();
+        async move {
+            if request.uri().path() != HEALTH_PATH {
+                let _ = started_tx.send(());
+                release.acquire().await.unwrap().forget();
+            }
+            Ok::<_, Infallible>(http::Response::new(String::new()))
+        }
+    });
+    let service = ConnectorLimiterLayer::new(config).layer(connector);
+    (tower::util::BoxCloneService::new(service), started_rx)
+}
+
+fn request(path: &str) -> http::Request<()> {
+    http::Request::builder().uri(path).body(()).unwrap()
+}
+
+fn grpc_status(response: &http::Response<String>) -> Option<&str> {
+    response
+        .headers()
+        .get("grpc-status")
+        .map(|status| status.to_str().unwrap())
+}
+
+async fn wait_for_queue_depth(depth: i64) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while CONNECTOR_CALL_QUEUE_DEPTH.get() != depth {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("queue depth not reached");
+}
+
+#[tokio::test]
+async fn test_requests_beyond_global_limit_and_queue_are_rejected() {
+    let config = ConnectorConcurrencyConfig {
+        max_concurrent_calls: Some(1),
+        max_queued_calls: 1,
+    };
+    let release = Arc::new(Semaphore::new(0));
+    let (service, mut started) = limited_service(&config, Arc::clone(&release));
+
+    // Takes the only slot
+    let in_flight = tokio::spawn(service.clone().oneshot(request(AUTHORIZE_PATH)));
+    started.recv().await.unwrap();
+
+    // Waits in the queue
+    let queued = tokio::spawn(service.clone().oneshot(request(AUTHORIZE_PATH)));
+    wait_for_queue_depth(1).await;
+
+    // Neither a slot nor a queue place is left
+    let rejected = service
+        .clone()
+        .oneshot(request(AUTHORIZE_PATH))
+        .await
+        .unwrap();
+    assert_eq!(grpc_status(&rejected), Some("8"));
+
+    // Health checks never call connectors and are not limited
+    let health = service.clone().oneshot(request(HEALTH_PATH)).await.unwrap();
+    assert_eq!(grpc_status(&health), None);
+
+    // Once the connector answers, the queued request gets the slot
+    release.add_permits(1);
+    let in_flight = in_flight.await.unwrap().unwrap();
+    started.recv().await.unwrap();
+    wait_for_queue_depth(0).await;
+    release.add_permits(1);
+    let queued = queued.await.unwrap().unwrap();
+
+    assert_eq!(grpc_status(&in_flight), None);
+    assert_eq!(grpc_status(&queued), None);
+}
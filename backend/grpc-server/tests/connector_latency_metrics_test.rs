@@ -0,0 +1,60 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::time::Duration;
+
+use external_services::shared_metrics::{
+    update_connector_latency_quantiles, CONNECTOR_CALL_LATENCY, CONNECTOR_CALL_LATENCY_QUANTILES,
+};
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that authorises every payment
+fn start_connector() -> String {
+    common::start_connector(|_, _| {
+        common::json_response(
+            r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"latency_ref_123"}"#,
+        )
+    })
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(common::card_authorize_request("latency_ref_123"));
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_connector_latency_recorded_after_connector_call() {
+    let latency = CONNECTOR_CALL_LATENCY.with_label_values(&["adyen", "Authorize"]);
+    let before = latency.get_sample_count();
+
+    let response = payments_service(start_connector())
+        .authorize(authorize_request())
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code, None);
+    assert_eq!(latency.get_sample_count(), before + 1);
+    assert!(latency.get_sample_sum() > 0.0);
+
+    update_connector_latency_quantiles(Duration::from_secs(300));
+    for quantile in ["0.5", "0.9", "0.99"] {
+        let value = CONNECTOR_CALL_LATENCY_QUANTILES
+            .with_label_values(&["adyen", "Authorize", quantile])
+            .get();
+        assert!(value > 0.0, "p{quantile} not recorded");
+    }
+}
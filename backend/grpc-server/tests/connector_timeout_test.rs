@@ -0,0 +1,96 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::time::Duration;
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{configs, server::payments::Payments, utils::connector_timeout_from_metadata};
+use tonic::{metadata::MetadataMap, Request};
+
+mod common;
+
+/// How long the connector stand-in takes to answer each request
+const CONNECTOR_LATENCY: Duration = Duration::from_millis(300);
+
+/// Starts an Adyen stand-in that authorises every payment after `CONNECTOR_LATENCY`
+fn start_slow_connector() -> String {
+    common::start_connector(|_, _| {
+        std::thread::sleep(CONNECTOR_LATENCY);
+        common::json_response(
+            r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"timeout_ref_123"}"#,
+        )
+    })
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn authorize_request(test_mode: bool, timeout_ms: &str) -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(PaymentServiceAuthorizeRequest {
+        test_mode: Some(test_mode),
+        ..common::card_authorize_request("timeout_ref_123")
+    });
+    let metadata = request.metadata_mut();
+    common::insert_adyen_metadata(metadata);
+    metadata.insert(
+        "x-connector-timeout-ms",
+        timeout_ms.parse().expect("valid header"),
+    );
+    request
+}
+
+#[tokio::test]
+async fn test_timeout_override_cuts_connector_call_short_in_test_mode() {
+    let base_url = start_slow_connector();
+
+    let response = payments_service(base_url)
+        .authorize(authorize_request(true, "50"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code.as_deref(), Some("CONNECTOR_ERROR"));
+    assert_eq!(response.retryable, Some(true));
+}
+
+#[tokio::test]
+async fn test_timeout_override_ignored_outside_test_mode() {
+    let base_url = start_slow_connector();
+
+    let response = payments_service(base_url)
+        .authorize(authorize_request(false, "50"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code, None);
+}
+
+#[test]
+fn test_timeout_override_must_be_within_bounds() {
+    let metadata_with = |timeout_ms: &str| {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(
+            "x-connector-timeout-ms",
+            timeout_ms.parse().expect("valid header"),
+        );
+        metadata
+    };
+
+    assert_eq!(
+        connector_timeout_from_metadata(&metadata_with("2500")).unwrap(),
+        Some(Duration::from_millis(2500))
+    );
+    assert_eq!(
+        connector_timeout_from_metadata(&MetadataMap::new()).unwrap(),
+        None
+    );
+    for invalid in ["0", "120001", "-5", "fast"] {
+        assert!(connector_timeout_from_metadata(&metadata_with(invalid)).is_err());
+    }
+}
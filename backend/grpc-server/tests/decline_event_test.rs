@@ -0,0 +1,94 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::Arc;
+
+use external_services::shared_metrics::DECLINE_EVENTS_TOTAL;
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that answers every payment with `body`
+fn start_connector(body: &'static str) -> String {
+    common::start_connector(move |_, _| common::json_response(body))
+}
+
+/// Payment service with decline events enabled, handing them to `event_sink`
+fn payments_service(base_url: String, event_sink: Arc<common::CapturingEventSink>) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    config.events.decline.enabled = true;
+    config.event_sink = event_sink;
+    common::payments_service(config)
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(common::card_authorize_request("decline_ref_123"));
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+fn decline_event_count() -> u64 {
+    ["soft_decline", "hard_decline", "technical_error"]
+        .into_iter()
+        .map(|category| {
+            DECLINE_EVENTS_TOTAL
+                .with_label_values(&["adyen", category])
+                .get()
+        })
+        .sum()
+}
+
+// Approvals and declines share the `adyen` counters, so both run in one test
+#[tokio::test]
+async fn test_decline_event_published_for_failed_authorize_only() {
+    let before = decline_event_count();
+    let event_sink = Arc::new(common::CapturingEventSink::default());
+
+    let approved = payments_service(
+        start_connector(
+            r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"decline_ref_123"}"#,
+        ),
+        event_sink.clone(),
+    )
+    .authorize(authorize_request())
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert_eq!(approved.error_code, None);
+    assert_eq!(decline_event_count(), before);
+    assert!(event_sink.decline_events.lock().unwrap().is_empty());
+
+    let declined = payments_service(
+        start_connector(
+            r#"{"pspReference":"psp_456","resultCode":"Refused","refusalReason":"Refused","refusalReasonCode":"2","merchantReference":"decline_ref_123"}"#,
+        ),
+        event_sink.clone(),
+    )
+    .authorize(authorize_request())
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert_eq!(declined.error_code.as_deref(), Some("2"));
+    assert_eq!(decline_event_count(), before + 1);
+    assert_eq!(
+        DECLINE_EVENTS_TOTAL
+            .with_label_values(&["adyen", "hard_decline"])
+            .get(),
+        1
+    );
+    let decline_events = event_sink.decline_events.lock().unwrap();
+    assert_eq!(decline_events.len(), 1);
+    let event = decline_events.first().unwrap();
+    assert_eq!(event.request_id, "request_123");
+    assert_eq!(event.merchant_id, "merchant_123");
+    assert_eq!(event.connector, "adyen");
+    assert_eq!(event.error_code, "2");
+    assert_eq!(event.category, "hard_decline");
+}
@@ -0,0 +1,40 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use domain_types::{connector_types::ConnectorEnum, errors::ApplicationErrorResponse};
+use grpc_server::utils::connector_from_metadata;
+use tonic::metadata::MetadataMap;
+
+#[test]
+fn test_connector_header_overrides_default_connector() {
+    let mut metadata = MetadataMap::new();
+    metadata.insert("x-connector", "razorpay".parse().expect("valid header"));
+
+    let connector = connector_from_metadata(&metadata, Some("adyen")).unwrap();
+
+    assert!(matches!(connector, ConnectorEnum::Razorpay));
+}
+
+#[test]
+fn test_default_connector_used_when_header_absent() {
+    let metadata = MetadataMap::new();
+
+    let connector = connector_from_metadata(&metadata, Some("adyen")).unwrap();
+
+    assert!(matches!(connector, ConnectorEnum::Adyen));
+}
+
+#[test]
+fn test_missing_connector_without_default_is_rejected() {
+    let metadata = MetadataMap::new();
+
+    let error = connector_from_metadata(&metadata, None).unwrap_err();
+
+    match error.current_context() {
+        ApplicationErrorResponse::BadRequest(api_error) => {
+            assert_eq!(api_error.sub_code, "MISSING_CONNECTOR")
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
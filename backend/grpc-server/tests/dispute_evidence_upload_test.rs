@@ -0,0 +1,113 @@
+#![allow(clippy::expect_used)]
+
+use grpc_server::{app, configs};
+mod common;
+use grpc_api_types::payments::{
+    dispute_service_client::DisputeServiceClient, UploadDisputeEvidenceRequest,
+};
+use tonic::{transport::Channel, Code, Request};
+
+fn evidence_chunk(file_size: usize, chunk: &[u8]) -> UploadDisputeEvidenceRequest {
+    UploadDisputeEvidenceRequest {
+        dispute_id: "dp_test_dispute".to_string(),
+        file_mime_type: Some("application/pdf".to_string()),
+        chunk: chunk.to_vec(),
+        file_size: u64::try_from(file_size).expect("file size fits in u64"),
+    }
+}
+
+fn upload_request(
+    chunks: Vec<UploadDisputeEvidenceRequest>,
+) -> Request<tokio_stream::Iter<std::vec::IntoIter<UploadDisputeEvidenceRequest>>> {
+    let mut request = Request::new(tokio_stream::iter(chunks));
+    let metadata = request.metadata_mut();
+    metadata.insert("x-connector", "adyen".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "header-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    request
+}
+
+#[tokio::test]
+async fn test_upload_multi_chunk_evidence_file() {
+    grpc_test!(client, DisputeServiceClient<Channel>, {
+        let file = vec![7u8; 3 * 64 * 1024 + 17];
+        let chunks: Vec<_> = file
+            .chunks(64 * 1024)
+            .map(|chunk| evidence_chunk(file.len(), chunk))
+            .collect();
+        assert_eq!(chunks.len(), 4);
+
+        let response = client
+            .upload_dispute_evidence(upload_request(chunks))
+            .await
+            .expect("Failed to upload evidence file")
+            .into_inner();
+
+        assert!(response.provider_file_id.starts_with("evidence_file_"));
+        assert_eq!(
+            response.file_size,
+            u64::try_from(file.len()).expect("file size fits in u64")
+        );
+    });
+}
+
+#[tokio::test]
+async fn test_upload_empty_evidence_stream_is_rejected() {
+    grpc_test!(client, DisputeServiceClient<Channel>, {
+        let status = client
+            .upload_dispute_evidence(upload_request(Vec::new()))
+            .await
+            .expect_err("Empty upload should be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    });
+}
+
+#[tokio::test]
+async fn test_upload_declaring_an_oversized_file_is_rejected() {
+    grpc_test!(client, DisputeServiceClient<Channel>, {
+        let status = client
+            .upload_dispute_evidence(upload_request(vec![evidence_chunk(
+                grpc_server::evidence_store::MAX_EVIDENCE_FILE_SIZE_BYTES + 1,
+                &[7u8; 16],
+            )]))
+            .await
+            .expect_err("Oversized upload should be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    });
+}
+
+#[tokio::test]
+async fn test_upload_exceeding_its_declared_size_is_rejected() {
+    grpc_test!(client, DisputeServiceClient<Channel>, {
+        let status = client
+            .upload_dispute_evidence(upload_request(vec![
+                evidence_chunk(16, &[7u8; 16]),
+                evidence_chunk(16, &[7u8; 16]),
+            ]))
+            .await
+            .expect_err("Upload larger than declared should be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    });
+}
+
+#[tokio::test]
+async fn test_upload_without_merchant_is_rejected() {
+    grpc_test!(client, DisputeServiceClient<Channel>, {
+        let status = client
+            .upload_dispute_evidence(Request::new(tokio_stream::iter(vec![evidence_chunk(
+                16, &[7u8; 16],
+            )])))
+            .await
+            .expect_err("Upload without request metadata should be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    });
+}
@@ -112,6 +112,7 @@ fn create_payment_authorize_request(
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
 
     PaymentServiceAuthorizeRequest {
@@ -218,7 +219,8 @@ fn create_payment_sync_request(transaction_id: &str) -> PaymentServiceGetRequest
         request_ref_id: Some(Identifier {
             id_type: Some(IdType::Id(format!("elavon_sync_{}", get_timestamp()))),
         }), // Some(format!("elavon_sync_{}", get_timestamp())),
-            // all_keys_required: Some(false),
+        // all_keys_required: Some(false),
+        prior_status: None,
     }
 }
 
@@ -277,6 +279,7 @@ fn create_payment_capture_request(transaction_id: &str) -> PaymentServiceCapture
         metadata: HashMap::new(),
         request_ref_id: None,
         browser_info: None,
+        capture_at: None,
     }
 }
 
@@ -357,6 +360,7 @@ fn create_refund_request(transaction_id: &str) -> PaymentServiceRefundRequest {
         metadata: HashMap::new(),
         refund_metadata: HashMap::new(),
         browser_info: None,
+        minor_captured_amount: None,
         merchant_account_id: None,
         capture_method: None,
         request_ref_id: None, // all_keys_required: Some(false),
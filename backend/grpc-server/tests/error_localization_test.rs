@@ -0,0 +1,110 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::Arc;
+
+use domain_types::connector_types::ConnectorEnum;
+use grpc_api_types::payments::{
+    PaymentServiceAuthorizeResponse, PaymentServiceCaptureRequest, PaymentServiceCaptureResponse,
+    PaymentStatus,
+};
+use grpc_server::{
+    configs,
+    error_localization::{localize_error_message, normalize_error_code},
+    utils::grpc_logging_wrapper,
+};
+use tonic::Request;
+
+fn declined_response(error_code: &str) -> PaymentServiceAuthorizeResponse {
+    PaymentServiceAuthorizeResponse {
+        status: PaymentStatus::Failure as i32,
+        error_code: Some(error_code.to_string()),
+        error_message: Some("Not enough balance".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_error_message_localized_for_connector_error_code() {
+    let mut response = declined_response("12");
+
+    localize_error_message(&mut response, ConnectorEnum::Adyen, "es");
+
+    assert_eq!(
+        response.error_message.as_deref(),
+        Some("La tarjeta no tiene fondos suficientes")
+    );
+    assert_eq!(
+        response.raw_error_message.as_deref(),
+        Some("Not enough balance")
+    );
+    assert_eq!(response.error_code.as_deref(), Some("12"));
+}
+
+#[test]
+fn test_error_message_unchanged_without_translation() {
+    let mut untranslated_language = declined_response("12");
+    localize_error_message(&mut untranslated_language, ConnectorEnum::Adyen, "ja");
+
+    // Error codes belong to the connector that returned them
+    let mut other_connector_code = declined_response("12");
+    localize_error_message(&mut other_connector_code, ConnectorEnum::Checkout, "fr");
+
+    let mut unknown_code = declined_response("insufficient_funds");
+    localize_error_message(&mut unknown_code, ConnectorEnum::Adyen, "fr");
+
+    for response in [untranslated_language, other_connector_code, unknown_code] {
+        assert_eq!(
+            response.error_message.as_deref(),
+            Some("Not enough balance")
+        );
+        assert_eq!(response.raw_error_message, None);
+    }
+}
+
+#[tokio::test]
+async fn test_error_message_localized_for_every_flow() {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.error_localization.enabled = true;
+
+    let mut request = Request::new(PaymentServiceCaptureRequest::default());
+    let metadata = request.metadata_mut();
+    metadata.insert("x-connector", "checkout".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "header-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    metadata.insert("x-locale", "fr-CA".parse().expect("valid header"));
+
+    let response = grpc_logging_wrapper(
+        request,
+        "PaymentService",
+        Arc::new(config),
+        |_request, _metadata_payload| async {
+            Ok(tonic::Response::new(PaymentServiceCaptureResponse {
+                error_code: Some("20054".to_string()),
+                error_message: Some("Expired Card".to_string()),
+                ..Default::default()
+            }))
+        },
+    )
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert_eq!(response.error_message.as_deref(), Some("La carte a expiré"));
+    assert_eq!(response.raw_error_message.as_deref(), Some("Expired Card"));
+}
+
+#[test]
+fn test_error_codes_normalized_for_decline_events() {
+    assert_eq!(
+        normalize_error_code("Insufficient Funds"),
+        "insufficient_funds"
+    );
+    assert_eq!(normalize_error_code("do-not-honor"), "do_not_honor");
+    assert_eq!(normalize_error_code(" EXPIRED_CARD "), "expired_card");
+}
@@ -0,0 +1,102 @@
+#![allow(clippy::expect_used)]
+
+use std::time::Duration;
+
+use grpc_api_types::payments::{DisputeServiceSubmitEvidenceRequest, EvidenceDocument};
+use grpc_server::evidence_store::{EvidenceFile, EvidenceFileStore};
+
+const MERCHANT_ID: &str = "merchant_123";
+
+fn evidence_file(size: usize) -> EvidenceFile {
+    EvidenceFile {
+        dispute_id: "dp_test_dispute".to_string(),
+        file_mime_type: Some("application/pdf".to_string()),
+        content: vec![7u8; size],
+    }
+}
+
+fn upload(store: &EvidenceFileStore, size: usize) -> Option<String> {
+    store
+        .reserve(size)
+        .map(|reservation| reservation.insert(MERCHANT_ID, evidence_file(size)))
+}
+
+fn submit_evidence_request(file_id: &str) -> DisputeServiceSubmitEvidenceRequest {
+    DisputeServiceSubmitEvidenceRequest {
+        dispute_id: "dp_test_dispute".to_string(),
+        evidence_documents: vec![EvidenceDocument {
+            provider_file_id: Some(file_id.to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn provider_file_id(request: &DisputeServiceSubmitEvidenceRequest) -> Option<&str> {
+    request
+        .evidence_documents
+        .first()
+        .and_then(|document| document.provider_file_id.as_deref())
+}
+
+#[test]
+fn test_files_expire_after_ttl() {
+    let store = EvidenceFileStore::new(Duration::from_millis(10), 1024);
+    let file_id = upload(&store, 16).expect("file fits");
+
+    std::thread::sleep(Duration::from_millis(20));
+    let mut request = submit_evidence_request(&file_id);
+    let resolved = store
+        .resolve_documents(MERCHANT_ID, &mut request)
+        .expect("documents resolve");
+
+    assert!(resolved.is_empty());
+    assert_eq!(provider_file_id(&request), Some(file_id.as_str()));
+    // The expired file no longer counts towards the byte cap
+    assert!(upload(&store, 1024).is_some());
+}
+
+#[test]
+fn test_uploads_beyond_byte_cap_are_refused() {
+    let store = EvidenceFileStore::new(Duration::from_secs(60), 1024);
+    let first = upload(&store, 600).expect("file fits");
+    assert!(store.reserve(600).is_none());
+
+    // Submitted files free their bytes
+    store.remove(MERCHANT_ID, &[first]);
+    assert!(upload(&store, 600).is_some());
+}
+
+#[test]
+fn test_abandoned_upload_releases_its_reservation() {
+    let store = EvidenceFileStore::new(Duration::from_secs(60), 1024);
+    let reservation = store.reserve(1024).expect("room for the upload");
+    assert!(store.reserve(1).is_none());
+
+    drop(reservation);
+
+    assert!(upload(&store, 1024).is_some());
+}
+
+#[test]
+fn test_files_of_another_merchant_are_not_resolved() {
+    let store = EvidenceFileStore::default();
+    let file_id = upload(&store, 16).expect("file fits");
+
+    let mut request = submit_evidence_request(&file_id);
+    let resolved = store
+        .resolve_documents("merchant_456", &mut request)
+        .expect("documents resolve");
+
+    assert!(resolved.is_empty());
+    assert_eq!(provider_file_id(&request), Some(file_id.as_str()));
+    assert!(request
+        .evidence_documents
+        .first()
+        .is_some_and(|document| document.file_content.is_none()));
+
+    let resolved = store
+        .resolve_documents(MERCHANT_ID, &mut request)
+        .expect("documents resolve");
+    assert_eq!(resolved, vec![file_id]);
+}
@@ -155,6 +155,7 @@ fn create_payment_authorize_request(
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
 
     // Initialize with all required fields
@@ -192,6 +193,7 @@ fn create_payment_sync_request(transaction_id: &str) -> PaymentServiceGetRequest
             id_type: Some(IdType::Id(format!("fiserv_sync_{}", get_timestamp()))),
         }),
         // all_keys_required: None,
+        prior_status: None,
     }
 }
 
@@ -219,6 +221,7 @@ fn create_payment_capture_request(transaction_id: &str) -> PaymentServiceCapture
         metadata,
         request_ref_id: None, // all_keys_required: None,
         browser_info: None,
+        capture_at: None,
     }
 }
 
@@ -252,6 +255,7 @@ fn create_refund_request(transaction_id: &str) -> PaymentServiceRefundRequest {
         metadata: metadata.clone(), // Add terminal_id for the main connector_metadata field
         refund_metadata: metadata,  // Add terminal_id for refund
         browser_info: None,
+        minor_captured_amount: None,
         merchant_account_id: None,
         capture_method: None,
         request_ref_id: None, // all_keys_required: None,
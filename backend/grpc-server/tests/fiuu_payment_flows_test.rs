@@ -129,6 +129,7 @@ fn create_authorize_request(capture_method: CaptureMethod) -> PaymentServiceAuth
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
     PaymentServiceAuthorizeRequest {
         amount: TEST_AMOUNT,
@@ -168,6 +169,7 @@ fn create_payment_sync_request(transaction_id: &str) -> PaymentServiceGetRequest
         request_ref_id: Some(Identifier {
             id_type: Some(IdType::Id(generate_unique_id("fiuu_sync"))),
         }),
+        prior_status: None,
     }
 }
 
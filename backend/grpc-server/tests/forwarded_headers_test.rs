@@ -0,0 +1,34 @@
+#![allow(clippy::expect_used)]
+
+use std::collections::HashMap;
+
+use grpc_server::utils::forwarded_headers_from_metadata;
+use tonic::metadata::MetadataMap;
+
+#[test]
+fn test_allowed_metadata_key_is_forwarded_under_connector_header_name() {
+    let mut metadata = MetadataMap::new();
+    metadata.insert("x-partner-id", "partner_123".parse().expect("valid header"));
+    metadata.insert(
+        "x-internal-trace",
+        "trace_456".parse().expect("valid header"),
+    );
+    let allowed_keys = HashMap::from([("x-partner-id".to_string(), "Partner-Id".to_string())]);
+
+    let headers = forwarded_headers_from_metadata(&metadata, &allowed_keys);
+
+    assert_eq!(
+        headers,
+        vec![("Partner-Id".to_string(), "partner_123".to_string())]
+    );
+}
+
+#[test]
+fn test_missing_allowed_metadata_key_is_skipped() {
+    let metadata = MetadataMap::new();
+    let allowed_keys = HashMap::from([("x-partner-id".to_string(), "Partner-Id".to_string())]);
+
+    let headers = forwarded_headers_from_metadata(&metadata, &allowed_keys);
+
+    assert!(headers.is_empty());
+}
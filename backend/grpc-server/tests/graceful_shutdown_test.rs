@@ -0,0 +1,196 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use cards::CardNumber;
+use grpc_api_types::payments::{
+    card_payment_method_type, identifier::IdType, payment_method,
+    payment_service_client::PaymentServiceClient, Address, AuthenticationType, CaptureMethod,
+    CardDetails, CardPaymentMethodType, CountryAlpha2, Currency, Identifier, PaymentAddress,
+    PaymentMethod, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{app, configs};
+use hyperswitch_masking::Secret;
+use tokio::sync::oneshot;
+use tonic::{metadata::MetadataMap, transport::Channel, Request};
+
+/// Starts a Checkout stand-in that reports every payment as authorized after `latency`, and
+/// signals `received` when the first request reaches it
+fn start_slow_connector(latency: Duration, received: oneshot::Sender<()>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        let mut received = Some(received);
+        for mut stream in listener.incoming().flatten() {
+            let mut buffer = [0; 4096];
+            let _ = stream.read(&mut buffer);
+            if let Some(received) = received.take() {
+                let _ = received.send(());
+            }
+            std::thread::sleep(latency);
+            let body = r#"{"id":"pay_123","status":"Authorized","reference":"payment_ref_123","action_id":"act_123","balances":{"available_to_capture":1000}}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    format!("http://{address}/")
+}
+
+fn free_socket() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+}
+
+/// Starts the gRPC server on `socket`, stopping it once `shutdown` fires
+fn start_server(
+    base_url: String,
+    grace_period_secs: u64,
+    socket: SocketAddr,
+    shutdown: oneshot::Receiver<()>,
+) -> tokio::task::JoinHandle<()> {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.checkout.base_url = base_url;
+    config.server.shutdown_grace_period_secs = grace_period_secs;
+
+    tokio::spawn(async move {
+        app::Service::new(Arc::new(config), Default::default())
+            .await
+            .grpc_server(socket, async {
+                let _ = shutdown.await;
+            })
+            .await
+            .expect("Server failed");
+    })
+}
+
+async fn connect(socket: SocketAddr) -> PaymentServiceClient<Channel> {
+    let endpoint = format!("http://{socket}");
+    for _ in 0..50 {
+        if let Ok(client) = PaymentServiceClient::connect(endpoint.clone()).await {
+            return client;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    PaymentServiceClient::connect(endpoint)
+        .await
+        .expect("Server did not start")
+}
+
+fn insert_checkout_metadata(metadata: &mut MetadataMap) {
+    metadata.insert("x-connector", "checkout".parse().expect("valid header"));
+    metadata.insert(
+        "x-merchant-id",
+        "merchant_123".parse().expect("valid header"),
+    );
+    metadata.insert("x-request-id", "request_123".parse().expect("valid header"));
+    metadata.insert("x-auth", "signature-key".parse().expect("valid header"));
+    metadata.insert("x-api-key", "test_api_key".parse().expect("valid header")); // Hardcoded dummy value
+    metadata.insert(
+        "x-api-secret",
+        "test_api_secret".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+    metadata.insert(
+        "x-key1",
+        "test_processing_channel".parse().expect("valid header"),
+    ); // Hardcoded dummy value
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(PaymentServiceAuthorizeRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("payment_ref_123".to_string())),
+        }),
+        amount: 1000,
+        minor_amount: 1000,
+        currency: Currency::Usd as i32,
+        payment_method: Some(PaymentMethod {
+            payment_method: Some(payment_method::PaymentMethod::Card(CardPaymentMethodType {
+                card_type: Some(card_payment_method_type::CardType::Credit(CardDetails {
+                    card_number: Some(CardNumber::from_str("4111111111111111").unwrap()),
+                    card_exp_month: Some(Secret::new("03".to_string())),
+                    card_exp_year: Some(Secret::new("2030".to_string())),
+                    card_cvc: Some(Secret::new("100".to_string())),
+                    ..Default::default()
+                })),
+            })),
+        }),
+        address: Some(PaymentAddress {
+            shipping_address: None,
+            billing_address: Some(Address {
+                country_alpha2_code: Some(CountryAlpha2::Us as i32),
+                ..Default::default()
+            }),
+        }),
+        auth_type: AuthenticationType::NoThreeDs as i32,
+        capture_method: Some(CaptureMethod::Manual as i32),
+        return_url: Some("https://example.com/return".to_string()),
+        ..Default::default()
+    });
+    insert_checkout_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_in_flight_request() {
+    let (received_tx, received_rx) = oneshot::channel();
+    let base_url = start_slow_connector(Duration::from_millis(500), received_tx);
+    let socket = free_socket();
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let server = start_server(base_url, 30, socket, shutdown_rx);
+
+    let mut client = connect(socket).await;
+    let in_flight = tokio::spawn(async move { client.authorize(authorize_request()).await });
+
+    // Shut down while the connector is still answering the authorization
+    received_rx.await.unwrap();
+    shutdown_tx.send(()).unwrap();
+
+    let response = in_flight.await.unwrap().unwrap().into_inner();
+    assert_eq!(
+        response.transaction_id.and_then(|id| id.id_type),
+        Some(IdType::Id("pay_123".to_string()))
+    );
+
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("Server stops once the request has drained")
+        .unwrap();
+
+    // No new connections are accepted after shutdown
+    assert!(PaymentServiceClient::connect(format!("http://{socket}"))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_shutdown_stops_waiting_after_grace_period() {
+    let (received_tx, received_rx) = oneshot::channel();
+    let base_url = start_slow_connector(Duration::from_secs(30), received_tx);
+    let socket = free_socket();
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let server = start_server(base_url, 1, socket, shutdown_rx);
+
+    let mut client = connect(socket).await;
+    let in_flight = tokio::spawn(async move { client.authorize(authorize_request()).await });
+
+    received_rx.await.unwrap();
+    shutdown_tx.send(()).unwrap();
+
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("Server stops once the grace period has elapsed")
+        .unwrap();
+    assert!(!in_flight.is_finished());
+}
@@ -0,0 +1,162 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{collections::HashMap, path::Path};
+
+use grpc_server::configs::{self, Config, SecretsProvider};
+
+/// Development config with `replacements` applied line by line, dropping lines mapped to `None`
+fn development_config(replacements: &[(&str, Option<&str>)]) -> String {
+    let config = std::fs::read_to_string(
+        configs::workspace_path()
+            .join("config")
+            .join("development.toml"),
+    )
+    .unwrap();
+    config
+        .lines()
+        .filter_map(|line| {
+            match replacements
+                .iter()
+                .find(|(prefix, _)| line.starts_with(prefix))
+            {
+                Some((_, replacement)) => replacement.map(str::to_string),
+                None => Some(line.to_string()),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes the default file and an environment-specific file overriding two base urls
+fn write_config_files(directory: &Path) -> std::path::PathBuf {
+    std::fs::write(
+        directory.join("default.toml"),
+        development_config(&[
+            (
+                "xendit.base_url",
+                Some(r#"xendit.base_url = "https://default.example/""#),
+            ),
+            (
+                "noon.base_url",
+                Some(r#"noon.base_url = "https://default.example/""#),
+            ),
+        ]),
+    )
+    .unwrap();
+    let config_path = directory.join("service.toml");
+    std::fs::write(
+        &config_path,
+        r#"[connectors]
+xendit.base_url = "https://file.example/"
+noon.base_url = "https://file.example/"
+"#,
+    )
+    .unwrap();
+    config_path
+}
+
+/// Sets an environment variable until dropped, then restores its previous value
+struct EnvVarGuard {
+    key: &'static str,
+    previous: Option<String>,
+}
+
+impl EnvVarGuard {
+    fn set(key: &'static str, value: &str) -> Self {
+        let previous = std::env::var(key).ok();
+        std::env::set_var(key, value);
+        Self { key, previous }
+    }
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(previous) => std::env::set_var(self.key, previous),
+            None => std::env::remove_var(self.key),
+        }
+    }
+}
+
+struct StaticSecrets(HashMap<String, String>);
+
+impl SecretsProvider for StaticSecrets {
+    fn fetch_secrets(&self) -> Result<HashMap<String, String>, config::ConfigError> {
+        Ok(self.0.clone())
+    }
+}
+
+#[test]
+fn test_layers_override_in_precedence_order() {
+    let directory = tempfile::tempdir().unwrap();
+    let config_path = write_config_files(directory.path());
+    let _noon_base_url =
+        EnvVarGuard::set("CONNECTOR_SERVICE__NOON__BASE_URL", "https://env.example/");
+
+    let config = Config::new_with_config_path(Some(config_path.clone())).unwrap();
+    // Only set in the default file
+    assert_eq!(
+        config.connectors.adyen.base_url,
+        "https://checkout-test.adyen.com/"
+    );
+    // The environment-specific file overrides the default file
+    assert_eq!(config.connectors.xendit.base_url, "https://file.example/");
+    // Environment variables override both files
+    assert_eq!(config.connectors.noon.base_url, "https://env.example/");
+
+    let secrets = StaticSecrets(HashMap::from([
+        (
+            "connectors.xendit.base_url".to_string(),
+            "https://secret.example/".to_string(),
+        ),
+        (
+            "connectors.noon.base_url".to_string(),
+            "https://secret.example/".to_string(),
+        ),
+    ]));
+    let config = Config::new_with_secrets_provider(Some(config_path), &secrets).unwrap();
+    // Secrets override the files, environment variables override secrets
+    assert_eq!(config.connectors.xendit.base_url, "https://secret.example/");
+    assert_eq!(config.connectors.noon.base_url, "https://env.example/");
+}
+
+#[test]
+fn test_missing_base_urls_are_listed() {
+    let directory = tempfile::tempdir().unwrap();
+    std::fs::write(
+        directory.path().join("default.toml"),
+        development_config(&[
+            ("adyen.base_url", None),
+            ("checkout.base_url", None),
+            ("mifinity.base_url", Some(r#"mifinity.base_url = """#)),
+        ]),
+    )
+    .unwrap();
+
+    let error = Config::new_with_config_path(Some(directory.path().join("service.toml")))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains(
+        "connectors.adyen.base_url, connectors.checkout.base_url, connectors.mifinity.base_url"
+    ));
+}
+
+#[test]
+fn test_full_connector_variable_wins_over_shorthand() {
+    let directory = tempfile::tempdir().unwrap();
+    let config_path = write_config_files(directory.path());
+    let _shorthand = EnvVarGuard::set(
+        "CONNECTOR_SERVICE__FISERV__BASE_URL",
+        "https://shorthand.example/",
+    );
+    let _full = EnvVarGuard::set(
+        "CONNECTOR_SERVICE__CONNECTORS__FISERV__BASE_URL",
+        "https://full.example/",
+    );
+
+    let config = Config::new_with_config_path(Some(config_path)).unwrap();
+
+    assert_eq!(config.connectors.fiserv.base_url, "https://full.example/");
+}
@@ -0,0 +1,98 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{
+    collections::HashMap,
+    io::Write,
+    sync::{Arc, Mutex},
+};
+
+use grpc_server::logger::{config::LogSampling, sampling::SamplingFilter};
+use tracing_subscriber::{layer::SubscriberExt, Layer};
+
+/// Writer collecting every formatted log line in memory
+#[derive(Clone, Default)]
+struct CapturingWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for CapturingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl CapturingWriter {
+    fn lines(&self) -> usize {
+        String::from_utf8_lossy(&self.0.lock().unwrap())
+            .lines()
+            .count()
+    }
+}
+
+/// Logs `traces` authorize-like traces of a span with two events through a JSON layer behind
+/// the sampling filter, and returns the number of lines written
+fn logged_lines(sampling: LogSampling, traces: usize) -> usize {
+    let writer = CapturingWriter::default();
+    let make_writer = {
+        let writer = writer.clone();
+        move || writer.clone()
+    };
+    let subscriber = tracing_subscriber::registry().with(
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(make_writer)
+            .with_filter(SamplingFilter::new(&sampling)),
+    );
+
+    tracing::subscriber::with_default(subscriber, || {
+        for _ in 0..traces {
+            let span = tracing::info_span!("payment_authorize");
+            let _entered = span.enter();
+            tracing::info!("calling connector");
+            let child = tracing::info_span!("connector_call");
+            let _child_entered = child.enter();
+            tracing::info!("connector answered");
+        }
+    });
+
+    writer.lines()
+}
+
+fn sampling(ratio: f64) -> LogSampling {
+    LogSampling {
+        ratio,
+        targets: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_zero_ratio_drops_every_record() {
+    assert_eq!(logged_lines(sampling(0.0), 50), 0);
+}
+
+#[test]
+fn test_full_ratio_keeps_every_record() {
+    assert_eq!(logged_lines(sampling(1.0), 50), 100);
+}
+
+#[test]
+fn test_target_override_takes_precedence() {
+    let sampling = LogSampling {
+        ratio: 1.0,
+        targets: HashMap::from([("log_sampling_test".to_string(), 0.0)]),
+    };
+
+    assert_eq!(logged_lines(sampling, 50), 0);
+}
+
+#[test]
+fn test_partial_ratio_keeps_or_drops_whole_traces() {
+    // Every kept trace logs both of its events, so sampling never splits a trace
+    let lines = logged_lines(sampling(0.5), 200);
+
+    assert_eq!(lines % 2, 0);
+    assert!(lines > 0 && lines < 400);
+}
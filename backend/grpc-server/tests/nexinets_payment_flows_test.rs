@@ -133,6 +133,7 @@ fn create_payment_authorize_request(
         card_issuing_country_alpha2: None,
         bank_code: None,
         nick_name: None,
+        co_badged_card_data: None,
     });
     PaymentServiceAuthorizeRequest {
         amount: TEST_AMOUNT,
@@ -171,6 +172,7 @@ fn create_payment_sync_request(
             id_type: Some(IdType::Id(request_ref_id.to_string())),
         }),
         // all_keys_required: None,
+        prior_status: None,
     }
 }
 
@@ -0,0 +1,122 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{Arc, Mutex};
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, CaptureMethod, PaymentServiceAuthorizeRequest,
+    PaymentServiceCaptureRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+use tracing::{
+    field::{Field, Visit},
+    span::{Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer,
+};
+
+mod common;
+
+/// Starts an Adyen stand-in that authorises every payment
+fn start_connector() -> String {
+    common::start_connector(|_, _| {
+        common::json_response(
+            r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"correlation_ref_123"}"#,
+        )
+    })
+}
+
+/// Collects the fields recorded on `payment_capture` spans after they were created
+#[derive(Clone, Default)]
+struct CaptureSpanFields(Arc<Mutex<Vec<(String, String)>>>);
+
+struct FieldCollector<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldCollector<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureSpanFields {
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if ctx
+            .span(id)
+            .is_some_and(|span| span.name() == "payment_capture")
+        {
+            values.record(&mut FieldCollector(&mut self.0.lock().unwrap()));
+        }
+    }
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    config.payment_correlation.enabled = true;
+    common::payments_service(config)
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(PaymentServiceAuthorizeRequest {
+        capture_method: Some(CaptureMethod::Manual as i32),
+        ..common::card_authorize_request("correlation_ref_123")
+    });
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+fn capture_request() -> Request<PaymentServiceCaptureRequest> {
+    let mut request = Request::new(common::capture_request());
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_capture_span_carries_authorize_reference_id() {
+    let fields = CaptureSpanFields::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+    let payments = payments_service(start_connector());
+
+    payments.authorize(authorize_request()).await.unwrap();
+    // Only the span matters here, not how the stand-in answers the capture
+    let _ = payments.capture(capture_request()).await;
+
+    let fields = fields.0.lock().unwrap();
+    assert!(fields.contains(&(
+        "original_reference_id".to_string(),
+        "correlation_ref_123".to_string()
+    )));
+    assert!(fields.contains(&("original_transaction_id".to_string(), "psp_123".to_string())));
+}
+
+#[tokio::test]
+async fn test_capture_span_of_another_merchant_carries_no_reference_id() {
+    let fields = CaptureSpanFields::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+    let payments = payments_service(start_connector());
+
+    payments.authorize(authorize_request()).await.unwrap();
+    let mut request = capture_request();
+    request.metadata_mut().insert(
+        "x-merchant-id",
+        "merchant_456".parse().expect("valid header"),
+    );
+    let _ = payments.capture(request).await;
+
+    let fields = fields.0.lock().unwrap();
+    assert!(!fields
+        .iter()
+        .any(|(name, _)| name == "original_reference_id"));
+}
@@ -0,0 +1,110 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use grpc_api_types::payments::{
+    identifier::IdType, payment_service_server::PaymentService, CaptureMethod, Identifier,
+    PaymentServiceAuthorizeRequest, PaymentServiceGetRequest,
+};
+use grpc_server::{configs, server::payments::Payments, utils};
+use tonic::{metadata::MetadataMap, Request};
+
+mod common;
+
+/// Starts a Checkout stand-in that reports every payment as authorized
+fn start_connector() -> String {
+    common::start_connector(|_, _| {
+        common::json_response(
+            r#"{"id":"pay_123","status":"Authorized","reference":"payment_ref_123","action_id":"act_123","balances":{"available_to_capture":1000}}"#,
+        )
+    })
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.checkout.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn reference_id() -> Option<Identifier> {
+    Some(Identifier {
+        id_type: Some(IdType::Id("payment_ref_123".to_string())),
+    })
+}
+
+fn authorize_request() -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(PaymentServiceAuthorizeRequest {
+        capture_method: Some(CaptureMethod::Manual as i32),
+        ..common::card_authorize_request("payment_ref_123")
+    });
+    common::insert_checkout_metadata(request.metadata_mut());
+    request
+}
+
+fn sync_request() -> Request<PaymentServiceGetRequest> {
+    let mut request = Request::new(PaymentServiceGetRequest {
+        transaction_id: Some(Identifier {
+            id_type: Some(IdType::Id("pay_123".to_string())),
+        }),
+        request_ref_id: reference_id(),
+        ..Default::default()
+    });
+    common::insert_checkout_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_authorize_and_sync_share_payment_id() {
+    let base_url = start_connector();
+    let payments = payments_service(base_url.clone());
+
+    let authorized = payments
+        .authorize(authorize_request())
+        .await
+        .unwrap()
+        .into_inner();
+    let synced = payments.get(sync_request()).await.unwrap().into_inner();
+
+    let payment_id = authorized.payment_id.expect("payment_id is returned");
+    assert_eq!(synced.payment_id.as_deref(), Some(payment_id.as_str()));
+
+    // Another instance, which did not see the authorization, derives the same id
+    let synced_elsewhere = payments_service(base_url)
+        .get(sync_request())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(synced_elsewhere.payment_id, Some(payment_id));
+}
+
+#[test]
+fn test_payment_id_without_reference_is_random() {
+    let mut metadata = MetadataMap::new();
+    common::insert_checkout_metadata(&mut metadata);
+
+    let payment_id = utils::payment_id(&metadata, None);
+
+    assert!(uuid::Uuid::parse_str(&payment_id).is_ok());
+    assert_ne!(payment_id, utils::payment_id(&metadata, None));
+}
+
+#[test]
+fn test_payment_id_differs_per_merchant() {
+    let mut metadata = MetadataMap::new();
+    common::insert_checkout_metadata(&mut metadata);
+    let mut other_metadata = metadata.clone();
+    other_metadata.insert(
+        "x-merchant-id",
+        "merchant_456".parse().expect("valid header"),
+    );
+
+    let payment_id = utils::payment_id(&metadata, reference_id().as_ref());
+
+    assert_eq!(
+        payment_id,
+        utils::payment_id(&metadata, reference_id().as_ref())
+    );
+    assert_ne!(
+        payment_id,
+        utils::payment_id(&other_metadata, reference_id().as_ref())
+    );
+}
@@ -0,0 +1,81 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use common_utils::events::FlowName;
+use domain_types::connector_types::ConnectorEnum;
+use external_services::shared_metrics::PAYMENT_OUTCOMES_TOTAL;
+use grpc_api_types::payments::{
+    DisputeDefendResponse, DisputeStatus, PaymentServiceAuthorizeResponse, PaymentStatus,
+    RefundResponse, RefundStatus,
+};
+use grpc_server::utils::record_payment_outcome;
+
+fn outcome_count(connector: &str, flow: &str, status: &str) -> u64 {
+    PAYMENT_OUTCOMES_TOTAL
+        .with_label_values(&[connector, flow, status])
+        .get()
+}
+
+#[test]
+fn test_authorize_outcome_counted_with_normalized_status() {
+    let before = outcome_count("adyen", "Authorize", "CHARGED");
+    let response = PaymentServiceAuthorizeResponse {
+        status: PaymentStatus::Charged as i32,
+        error_code: Some("00".to_string()),
+        ..Default::default()
+    };
+
+    record_payment_outcome(&ConnectorEnum::Adyen, FlowName::Authorize, &response);
+
+    assert_eq!(outcome_count("adyen", "Authorize", "CHARGED"), before + 1);
+}
+
+#[test]
+fn test_declined_authorize_does_not_use_raw_error_code_as_label() {
+    let before = outcome_count("checkout", "Authorize", "AUTHORIZATION_FAILED");
+    let response = PaymentServiceAuthorizeResponse {
+        status: PaymentStatus::AuthorizationFailed as i32,
+        error_code: Some("20051".to_string()),
+        ..Default::default()
+    };
+
+    record_payment_outcome(&ConnectorEnum::Checkout, FlowName::Authorize, &response);
+
+    assert_eq!(
+        outcome_count("checkout", "Authorize", "AUTHORIZATION_FAILED"),
+        before + 1
+    );
+    assert_eq!(outcome_count("checkout", "Authorize", "20051"), 0);
+}
+
+#[test]
+fn test_refund_and_dispute_outcomes_use_their_own_statuses() {
+    let refund_before = outcome_count("noon", "Refund", "REFUND_SUCCESS");
+    let dispute_before = outcome_count("noon", "DefendDispute", "DISPUTE_CHALLENGED");
+
+    record_payment_outcome(
+        &ConnectorEnum::Noon,
+        FlowName::Refund,
+        &RefundResponse {
+            status: RefundStatus::RefundSuccess as i32,
+            ..Default::default()
+        },
+    );
+    record_payment_outcome(
+        &ConnectorEnum::Noon,
+        FlowName::DefendDispute,
+        &DisputeDefendResponse {
+            dispute_status: DisputeStatus::DisputeChallenged as i32,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        outcome_count("noon", "Refund", "REFUND_SUCCESS"),
+        refund_before + 1
+    );
+    assert_eq!(
+        outcome_count("noon", "DefendDispute", "DISPUTE_CHALLENGED"),
+        dispute_before + 1
+    );
+}
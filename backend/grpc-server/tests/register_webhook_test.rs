@@ -0,0 +1,117 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::mpsc;
+
+use grpc_api_types::payments::{
+    identifier::IdType, payment_service_server::PaymentService, Identifier,
+    PaymentServiceRegisterWebhookRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts a Checkout stand-in that answers every request with `status` and `body`,
+/// forwarding the request it received
+fn start_registration_endpoint(
+    status: &'static str,
+    body: &'static str,
+) -> (String, mpsc::Receiver<(String, String)>) {
+    let (sender, receiver) = mpsc::channel();
+    let base_url = common::start_connector(move |head, request_body| {
+        let _ = sender.send((head.to_string(), request_body.to_string()));
+        common::http_response(status, "application/json", body)
+    });
+    (base_url, receiver)
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.checkout.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn register_webhook_request(webhook_url: &str) -> Request<PaymentServiceRegisterWebhookRequest> {
+    let mut request = Request::new(PaymentServiceRegisterWebhookRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("webhook_ref_123".to_string())),
+        }),
+        webhook_url: webhook_url.to_string(),
+        event_types: vec![
+            "payment_approved".to_string(),
+            "payment_captured".to_string(),
+        ],
+    });
+    common::insert_checkout_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_register_webhook_returns_registration_id() {
+    let (base_url, received) = start_registration_endpoint(
+        "201 Created",
+        r#"{"id":"wh_abc123","url":"https://merchant.example.com/webhooks","active":true}"#,
+    );
+
+    let response = payments_service(base_url)
+        .register_webhook(register_webhook_request(
+            "https://merchant.example.com/webhooks",
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.registration_id.as_deref(), Some("wh_abc123"));
+    assert_eq!(response.error_code, None);
+    assert_eq!(response.status_code, 201);
+
+    let (head, body) = received.recv().unwrap();
+    assert!(head.starts_with("POST /webhooks "));
+    assert!(head
+        .lines()
+        .any(|line| line.eq_ignore_ascii_case("authorization: Bearer test_api_secret")));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "url": "https://merchant.example.com/webhooks",
+            "active": true,
+            "content_type": "json",
+            "event_types": ["payment_approved", "payment_captured"],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_register_webhook_surfaces_connector_errors() {
+    let (base_url, _received) = start_registration_endpoint(
+        "422 Unprocessable Entity",
+        r#"{"request_id":"req_1","error_type":"request_invalid","error_codes":["url_invalid"]}"#,
+    );
+
+    let response = payments_service(base_url)
+        .register_webhook(register_webhook_request(
+            "https://merchant.example.com/webhooks",
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.registration_id, None);
+    assert!(response.error_code.is_some());
+    assert_eq!(response.status_code, 422);
+}
+
+#[tokio::test]
+async fn test_register_webhook_rejects_relative_urls() {
+    let (base_url, received) = start_registration_endpoint("201 Created", r#"{"id":"wh_1"}"#);
+
+    let status = payments_service(base_url)
+        .register_webhook(register_webhook_request("/webhooks"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(received.try_recv().is_err());
+}
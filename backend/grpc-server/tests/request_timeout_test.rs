@@ -0,0 +1,131 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{collections::HashMap, convert::Infallible, time::Duration};
+
+use grpc_server::{configs::RequestTimeoutConfig, request_timeout::RequestTimeoutLayer};
+use tower::{Layer, ServiceExt};
+
+const AUTHORIZE_PATH: &str = "/ucs.v2.PaymentService/Authorize";
+const REFUND_PATH: &str = "/ucs.v2.PaymentService/Refund";
+const HEALTH_PATH: &str = "/grpc.health.v1.Health/Check";
+
+/// How long the connector stand-in takes to answer each request
+const CONNECTOR_LATENCY: Duration = Duration::from_millis(300);
+
+type TimedService =
+    tower::util::BoxCloneService<http::Request<()>, http::Response<String>, tower::BoxError>;
+
+/// Wraps a connector stand-in that answers every request after `CONNECTOR_LATENCY`
+fn timed_service(config: &RequestTimeoutConfig) -> TimedService {
+    let connector = tower::service_fn(|_request: http::Request<()>| async {
+        tokio::time::sleep(CONNECTOR_LATENCY).await;
+        Ok::<_, Infallible>(http::Response::new(String::new()))
+    });
+    tower::util::BoxCloneService::new(RequestTimeoutLayer::new(config).layer(connector))
+}
+
+fn config() -> RequestTimeoutConfig {
+    RequestTimeoutConfig {
+        default_ms: Some(5_000),
+        flows: HashMap::from([("authorize".to_string(), 50)]),
+        max_ms: 1_000,
+    }
+}
+
+fn request(path: &str, timeout_ms: Option<&str>) -> http::Request<()> {
+    let mut builder = http::Request::builder().uri(path);
+    if let Some(timeout_ms) = timeout_ms {
+        builder = builder.header("x-timeout-ms", timeout_ms);
+    }
+    builder.body(()).unwrap()
+}
+
+fn grpc_status(response: &http::Response<String>) -> Option<&str> {
+    response
+        .headers()
+        .get("grpc-status")
+        .map(|status| status.to_str().unwrap())
+}
+
+#[tokio::test]
+async fn test_slow_connector_exceeds_flow_timeout() {
+    let response = timed_service(&config())
+        .oneshot(request(AUTHORIZE_PATH, None))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), Some("4"));
+    let status = tonic::Status::from_header_map(response.headers()).unwrap();
+    let details: serde_json::Value = serde_json::from_slice(status.details()).unwrap();
+    assert_eq!(details["sub_code"], "REQUEST_TIMEOUT");
+    assert_eq!(details["flow"], "authorize");
+    assert_eq!(details["timeout_ms"], 50);
+}
+
+#[tokio::test]
+async fn test_flows_without_own_timeout_use_default() {
+    let response = timed_service(&config())
+        .oneshot(request(REFUND_PATH, None))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), None);
+}
+
+#[tokio::test]
+async fn test_timeout_header_overrides_flow_timeout() {
+    let service = timed_service(&config());
+
+    // Longer than the connector takes, so the request completes
+    let extended = service
+        .clone()
+        .oneshot(request(AUTHORIZE_PATH, Some("800")))
+        .await
+        .unwrap();
+    assert_eq!(grpc_status(&extended), None);
+
+    // Shorter than the default, so the request fails
+    let shortened = service
+        .clone()
+        .oneshot(request(REFUND_PATH, Some("50")))
+        .await
+        .unwrap();
+    assert_eq!(grpc_status(&shortened), Some("4"));
+
+    let invalid = service
+        .oneshot(request(AUTHORIZE_PATH, Some("soon")))
+        .await
+        .unwrap();
+    assert_eq!(grpc_status(&invalid), Some("3"));
+}
+
+#[tokio::test]
+async fn test_timeout_header_is_capped_at_max() {
+    let config = RequestTimeoutConfig {
+        max_ms: 50,
+        ..config()
+    };
+
+    let response = timed_service(&config)
+        .oneshot(request(REFUND_PATH, Some("60000")))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), Some("4"));
+}
+
+#[tokio::test]
+async fn test_health_checks_are_not_timed() {
+    let config = RequestTimeoutConfig {
+        default_ms: Some(50),
+        ..config()
+    };
+
+    let response = timed_service(&config)
+        .oneshot(request(HEALTH_PATH, None))
+        .await
+        .unwrap();
+
+    assert_eq!(grpc_status(&response), None);
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{configs, server::payments::Payments, utils::RetryBudget};
+use tonic::Request;
+
+mod common;
+
+/// How long the connector stand-in takes to answer each request
+const CONNECTOR_LATENCY: Duration = Duration::from_millis(300);
+
+/// Starts an Adyen stand-in that soft-declines every authorize and counts the requests it gets
+fn start_soft_declining_connector() -> (String, Arc<AtomicUsize>) {
+    let request_count = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&request_count);
+    let base_url = common::start_connector(move |_, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(CONNECTOR_LATENCY);
+        common::json_response(
+            r#"{"pspReference":"psp_123","resultCode":"Refused","refusalReason":"Issuer Unavailable","refusalReasonCode":"20","merchantReference":"retry_ref_123"}"#,
+        )
+    });
+    (base_url, request_count)
+}
+
+fn payments_service(base_url: String, max_attempts: Option<u32>) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    config.connector_retry.max_attempts = max_attempts;
+    config.connectors.adyen.soft_decline_codes = vec!["20".to_string()];
+    config.connectors.adyen.retry_idempotency_header = Some("Idempotency-Key".to_string());
+    common::payments_service(config)
+}
+
+fn authorize_request(grpc_timeout: Option<&str>) -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(common::card_authorize_request("retry_ref_123"));
+    let metadata = request.metadata_mut();
+    common::insert_adyen_metadata(metadata);
+    if let Some(grpc_timeout) = grpc_timeout {
+        metadata.insert("grpc-timeout", grpc_timeout.parse().expect("valid header"));
+    }
+    request
+}
+
+#[tokio::test]
+async fn test_soft_decline_retried_within_deadline() {
+    let (base_url, request_count) = start_soft_declining_connector();
+
+    let response = payments_service(base_url, None)
+        .authorize(authorize_request(Some("10S")))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code.as_deref(), Some("20"));
+    assert_eq!(request_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_short_deadline_skips_soft_decline_retry() {
+    let (base_url, request_count) = start_soft_declining_connector();
+
+    // The first attempt uses up more than half of the deadline, so a retry could not finish
+    let response = payments_service(base_url, None)
+        .authorize(authorize_request(Some("500m")))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code.as_deref(), Some("20"));
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_attempt_limit_skips_soft_decline_retry() {
+    let (base_url, request_count) = start_soft_declining_connector();
+
+    let response = payments_service(base_url, Some(1))
+        .authorize(authorize_request(Some("10S")))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_code.as_deref(), Some("20"));
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_retry_budget_is_shared_across_retry_triggers() {
+    let budget = RetryBudget::new(Some(3));
+
+    // A soft decline retry and a retry for another reason use up the two retries left after
+    // the first attempt
+    assert!(budget.try_retry());
+    assert!(budget.try_retry());
+    assert!(!budget.try_retry());
+    assert!(!budget.try_retry());
+}
+
+#[test]
+fn test_retry_budget_without_limit_always_allows_retries() {
+    let budget = RetryBudget::new(None);
+
+    assert!((0..10).all(|_| budget.try_retry()));
+}
+
+#[test]
+fn test_single_attempt_budget_allows_no_retries() {
+    assert!(!RetryBudget::new(Some(1)).try_retry());
+    assert!(!RetryBudget::new(Some(0)).try_retry());
+}
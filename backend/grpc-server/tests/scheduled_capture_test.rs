@@ -0,0 +1,46 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use domain_types::types::SCHEDULED_CAPTURE_AT_KEY;
+use grpc_api_types::payments::{
+    identifier::IdType, payment_service_server::PaymentService, Currency, Identifier,
+    PaymentServiceCaptureRequest, PaymentStatus,
+};
+use grpc_server::configs;
+use tonic::Request;
+
+mod common;
+
+const ONE_DAY_SECS: i64 = 24 * 60 * 60;
+
+fn capture_request(capture_at: i64) -> PaymentServiceCaptureRequest {
+    PaymentServiceCaptureRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("capture_ref_123".to_string())),
+        }),
+        transaction_id: Some(Identifier {
+            id_type: Some(IdType::Id("pay_123".to_string())),
+        }),
+        amount_to_capture: 1000,
+        currency: Currency::Usd as i32,
+        capture_at: Some(capture_at),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_scheduled_capture_returned_in_metadata_for_other_connectors() {
+    let capture_at = common_utils::date_time::now_unix_timestamp() + ONE_DAY_SECS;
+    let mut request = Request::new(capture_request(capture_at));
+    common::insert_adyen_metadata(request.metadata_mut());
+    let payments =
+        common::payments_service(configs::Config::new().expect("Failed while parsing config"));
+
+    let response = payments.capture(request).await.unwrap().into_inner();
+
+    assert_eq!(response.status(), PaymentStatus::Authorized);
+    assert_eq!(
+        response.connector_metadata.get(SCHEDULED_CAPTURE_AT_KEY),
+        Some(&capture_at.to_string())
+    );
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::convert::Infallible;
+
+use grpc_server::{configs, logger::setup::static_log_fields, utils};
+use tower::{Layer, ServiceExt};
+
+fn config() -> configs::Config {
+    configs::Config::new().expect("Failed while parsing config")
+}
+
+async fn service_version_header(server: &configs::Server) -> Option<String> {
+    let service = utils::service_version_layer(server).layer(tower::service_fn(
+        |_: http::Request<()>| async { Ok::<_, Infallible>(http::Response::new(String::new())) },
+    ));
+    let response = service.oneshot(http::Request::new(())).await.unwrap();
+    response
+        .headers()
+        .get("x-service-version")
+        .map(|version| version.to_str().unwrap().to_string())
+}
+
+#[test]
+fn test_build_version_in_log_payload() {
+    let mut config = config();
+
+    let fields = static_log_fields(&config.log, "connector-service");
+    assert_eq!(fields["service"], "connector-service");
+    assert_eq!(fields["build_version"], grpc_server::version!());
+
+    config.log.include_build_version = false;
+    let fields = static_log_fields(&config.log, "connector-service");
+    assert!(!fields.contains_key("build_version"));
+}
+
+#[tokio::test]
+async fn test_service_version_header_is_opt_in() {
+    let mut config = config();
+    assert_eq!(service_version_header(&config.server).await, None);
+
+    config.server.expose_service_version = true;
+    assert_eq!(
+        service_version_header(&config.server).await.as_deref(),
+        Some(grpc_server::version!())
+    );
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use std::sync::{Arc, Mutex};
+
+use grpc_api_types::payments::{payment_service_server::PaymentService, PaymentStatus};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that soft-declines the first authorize and approves the next ones,
+/// keeping the head and body of every request it gets
+fn start_connector() -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = Arc::clone(&requests);
+    let base_url = common::start_connector(move |head, body| {
+        let mut received = received.lock().unwrap();
+        received.push((head.to_lowercase(), body.to_string()));
+        if received.len() == 1 {
+            common::json_response(
+                r#"{"pspReference":"psp_declined_123","resultCode":"Refused","refusalReason":"Issuer Unavailable","refusalReasonCode":"20","merchantReference":"soft_decline_ref_123"}"#,
+            )
+        } else {
+            common::json_response(
+                r#"{"pspReference":"psp_123","resultCode":"Authorised","merchantReference":"soft_decline_ref_123"}"#,
+            )
+        }
+    });
+    (base_url, requests)
+}
+
+fn payments_service(base_url: String, retry_idempotency_header: Option<&str>) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    config.connectors.adyen.soft_decline_codes = vec!["20".to_string()];
+    config.connectors.adyen.retry_idempotency_header = retry_idempotency_header.map(String::from);
+    common::payments_service(config)
+}
+
+fn authorize_request() -> Request<grpc_api_types::payments::PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(common::card_authorize_request("soft_decline_ref_123"));
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_soft_decline_followed_by_success_returns_the_retry_outcome() {
+    let (base_url, requests) = start_connector();
+
+    let response = payments_service(base_url, Some("Idempotency-Key"))
+        .authorize(authorize_request())
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.status(), PaymentStatus::Charged);
+    assert_eq!(response.error_code, None);
+
+    let requests = requests.lock().unwrap();
+    let [(first_head, first_body), (retry_head, retry_body)] = requests.as_slice() else {
+        panic!("expected two connector requests, got {}", requests.len());
+    };
+    // Only the retry carries the idempotency key, so the connector does not replay the decline
+    assert!(!first_head.contains("idempotency-key"));
+    assert!(retry_head.contains("idempotency-key: request_123_soft_decline_retry"));
+    // The retry is the same payment, with the same connector request reference
+    assert_eq!(first_body, retry_body);
+}
+
+#[tokio::test]
+async fn test_soft_decline_is_not_retried_without_idempotency_header() {
+    let (base_url, requests) = start_connector();
+
+    let response = payments_service(base_url, None)
+        .authorize(authorize_request())
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.status(), PaymentStatus::Failure);
+    assert_eq!(response.error_code.as_deref(), Some("20"));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
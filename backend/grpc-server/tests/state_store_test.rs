@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use grpc_server::state_store::StateStore;
+
+#[test]
+fn test_entries_expire_after_ttl() {
+    let store = StateStore::new(Duration::from_millis(10), 10);
+    store.insert("key".to_string(), 1);
+    assert_eq!(store.get("key"), Some(1));
+
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(store.get("key"), None);
+}
+
+#[test]
+fn test_oldest_entries_are_evicted_when_full() {
+    let store = StateStore::new(Duration::from_secs(60), 2);
+    store.insert("first".to_string(), 1);
+    store.insert("second".to_string(), 2);
+    // Inserting a key again refreshes it, so it outlives "second"
+    store.insert("first".to_string(), 3);
+    store.insert("third".to_string(), 4);
+
+    assert_eq!(store.get("second"), None);
+    assert_eq!(store.get("first"), Some(3));
+    assert_eq!(store.get("third"), Some(4));
+}
+
+#[test]
+fn test_key_is_in_flight_until_guard_is_dropped() {
+    let store = StateStore::<()>::default();
+    let guard = store.begin("key");
+    assert!(guard.is_some());
+    assert!(store.begin("key").is_none());
+    assert!(store.begin("other").is_some());
+
+    drop(guard);
+    assert!(store.begin("key").is_some());
+}
@@ -1,8 +1,24 @@
 #![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use grpc_server::{app, configs};
 mod common;
-use grpc_api_types::health_check::{health_client::HealthClient, HealthCheckRequest};
+use grpc_api_types::health_check::{
+    health_check_response::ServingStatus, health_client::HealthClient, health_server::Health,
+    HealthCheckRequest,
+};
+use grpc_server::server::health_check::{
+    kafka_logging_status, HealthCheck, KafkaLoggingProbe, MetricsServerBound,
+};
+use tokio_stream::StreamExt;
 use tonic::{transport::Channel, Request};
 
 #[tokio::test]
@@ -16,9 +32,214 @@ async fn test_health() {
             .expect("Failed to call health check")
             .into_inner();
 
-        assert_eq!(
-            response.status(),
-            grpc_api_types::health_check::health_check_response::ServingStatus::Serving
-        );
+        assert_eq!(response.status(), ServingStatus::Serving);
+    });
+}
+
+fn health_request(service: &str) -> Request<HealthCheckRequest> {
+    Request::new(HealthCheckRequest {
+        service: service.to_string(),
+    })
+}
+
+#[tokio::test]
+async fn test_liveness_is_serving_once_process_is_up() {
+    grpc_test!(client, HealthClient<Channel>, {
+        let response = client
+            .check(health_request("liveness"))
+            .await
+            .expect("Failed to call health check")
+            .into_inner();
+        assert_eq!(response.status(), ServingStatus::Serving);
+
+        let mut updates = client
+            .watch(health_request("liveness"))
+            .await
+            .expect("Failed to watch health")
+            .into_inner();
+        let update = updates.message().await.unwrap().expect("initial status");
+        assert_eq!(update.status(), ServingStatus::Serving);
     });
 }
+
+fn health_check(metrics_server_bound: MetricsServerBound) -> HealthCheck {
+    HealthCheck {
+        config: Arc::new(configs::Config::new().expect("Failed while parsing config")),
+        metrics_server_bound,
+        readiness_poll_interval: Duration::from_millis(10),
+        // Built without Kafka logging
+        kafka_logging_probe: |_| None,
+    }
+}
+
+/// Health check with Kafka logging `enabled` in the config, probing the producer with `probe`
+fn kafka_health_check(enabled: bool, mandatory: bool, probe: KafkaLoggingProbe) -> HealthCheck {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    let kafka = config
+        .log
+        .kafka
+        .as_mut()
+        .expect("development config sets up Kafka logging");
+    kafka.enabled = enabled;
+    kafka.mandatory = mandatory;
+
+    let metrics_server_bound = MetricsServerBound::default();
+    metrics_server_bound.mark_bound();
+    HealthCheck {
+        config: Arc::new(config),
+        metrics_server_bound,
+        readiness_poll_interval: Duration::from_millis(10),
+        kafka_logging_probe: probe,
+    }
+}
+
+#[tokio::test]
+async fn test_readiness_waits_for_metrics_server() {
+    let metrics_server_bound = MetricsServerBound::default();
+    let health_check = health_check(metrics_server_bound.clone());
+
+    let response = health_check
+        .check(health_request("readiness"))
+        .await
+        .expect("Failed to call health check")
+        .into_inner();
+    assert_eq!(response.status(), ServingStatus::NotServing);
+    assert!(response
+        .dependencies
+        .iter()
+        .any(|dependency| dependency.name == "metrics_server" && !dependency.healthy));
+
+    let mut updates = health_check
+        .watch(health_request("readiness"))
+        .await
+        .expect("Failed to watch health")
+        .into_inner();
+    let update = updates.next().await.unwrap().expect("initial status");
+    assert_eq!(update.status(), ServingStatus::NotServing);
+
+    // An unchanged status is not streamed again
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), updates.next())
+            .await
+            .is_err()
+    );
+
+    metrics_server_bound.mark_bound();
+
+    let update = updates.next().await.unwrap().expect("status transition");
+    assert_eq!(update.status(), ServingStatus::Serving);
+
+    let response = health_check
+        .check(health_request("readiness"))
+        .await
+        .expect("Failed to call health check")
+        .into_inner();
+    assert_eq!(response.status(), ServingStatus::Serving);
+    // Watch reports the same dependencies as check
+    assert_eq!(update.dependencies, response.dependencies);
+}
+
+#[test]
+fn test_kafka_logging_connected_is_healthy() {
+    let (status, dependency) = kafka_logging_status(true, true);
+
+    assert_eq!(status, ServingStatus::Serving);
+    assert_eq!(dependency.name, "kafka_logging");
+    assert!(dependency.healthy);
+    assert!(dependency.note.is_none());
+}
+
+#[test]
+fn test_kafka_logging_disconnected_degrades_when_optional() {
+    let (status, dependency) = kafka_logging_status(false, false);
+
+    assert_eq!(status, ServingStatus::Serving);
+    assert!(!dependency.healthy);
+    assert!(dependency.note.is_some());
+}
+
+#[test]
+fn test_kafka_logging_disconnected_fails_when_mandatory() {
+    let (status, dependency) = kafka_logging_status(false, true);
+
+    assert_eq!(status, ServingStatus::NotServing);
+    assert!(!dependency.healthy);
+}
+
+#[tokio::test]
+async fn test_kafka_disabled_is_not_a_dependency() {
+    let health_check = kafka_health_check(false, true, |_| Some(false));
+
+    let response = health_check
+        .check(health_request(""))
+        .await
+        .expect("Failed to call health check")
+        .into_inner();
+
+    assert_eq!(response.status(), ServingStatus::Serving);
+    assert!(response.dependencies.is_empty());
+}
+
+#[tokio::test]
+async fn test_kafka_enabled_and_connected_is_healthy() {
+    let health_check = kafka_health_check(true, true, |_| Some(true));
+
+    let response = health_check
+        .check(health_request(""))
+        .await
+        .expect("Failed to call health check")
+        .into_inner();
+
+    assert_eq!(response.status(), ServingStatus::Serving);
+    assert!(response
+        .dependencies
+        .iter()
+        .any(|dependency| dependency.name == "kafka_logging" && dependency.healthy));
+}
+
+#[tokio::test]
+async fn test_kafka_enabled_and_disconnected_degrades_or_fails() {
+    for (mandatory, expected_status) in [
+        (false, ServingStatus::Serving),
+        (true, ServingStatus::NotServing),
+    ] {
+        let health_check = kafka_health_check(true, mandatory, |_| Some(false));
+
+        let response = health_check
+            .check(health_request(""))
+            .await
+            .expect("Failed to call health check")
+            .into_inner();
+
+        assert_eq!(response.status(), expected_status, "mandatory: {mandatory}");
+        assert!(response
+            .dependencies
+            .iter()
+            .any(|dependency| dependency.name == "kafka_logging" && !dependency.healthy));
+    }
+}
+
+/// Kafka availability seen by the probe of `test_kafka_availability_toggles_readiness`
+static KAFKA_AVAILABLE: AtomicBool = AtomicBool::new(true);
+
+#[tokio::test]
+async fn test_kafka_availability_toggles_readiness() {
+    let health_check =
+        kafka_health_check(true, true, |_| Some(KAFKA_AVAILABLE.load(Ordering::SeqCst)));
+
+    let mut updates = health_check
+        .watch(health_request("readiness"))
+        .await
+        .expect("Failed to watch health")
+        .into_inner();
+    let update = updates.next().await.unwrap().expect("initial status");
+    assert_eq!(update.status(), ServingStatus::Serving);
+
+    KAFKA_AVAILABLE.store(false, Ordering::SeqCst);
+    let update = updates.next().await.unwrap().expect("status transition");
+    assert_eq!(update.status(), ServingStatus::NotServing);
+
+    KAFKA_AVAILABLE.store(true, Ordering::SeqCst);
+    let update = updates.next().await.unwrap().expect("status transition");
+    assert_eq!(update.status(), ServingStatus::Serving);
+}
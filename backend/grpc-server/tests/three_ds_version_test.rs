@@ -0,0 +1,51 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use connector_integration::types::ConnectorData;
+use domain_types::{
+    connector_types::{ConnectorEnum, ThreeDsVersion},
+    payment_method_data::DefaultPCIHolder,
+};
+
+fn negotiate_for(
+    connector: ConnectorEnum,
+    preferred: Option<ThreeDsVersion>,
+) -> Option<ThreeDsVersion> {
+    let connector_data = ConnectorData::<DefaultPCIHolder>::get_connector_by_name(&connector);
+    ThreeDsVersion::negotiate(
+        preferred,
+        connector_data.connector.supported_three_ds_versions(),
+    )
+}
+
+#[test]
+fn test_three_ds2_requested_when_connector_supports_it() {
+    assert_eq!(
+        negotiate_for(ConnectorEnum::Adyen, None),
+        Some(ThreeDsVersion::V2)
+    );
+}
+
+#[test]
+fn test_preferred_three_ds1_honoured_when_supported() {
+    assert_eq!(
+        negotiate_for(ConnectorEnum::Adyen, Some(ThreeDsVersion::V1)),
+        Some(ThreeDsVersion::V1)
+    );
+}
+
+#[test]
+fn test_no_version_for_connector_without_capability() {
+    assert_eq!(
+        negotiate_for(ConnectorEnum::Fiserv, Some(ThreeDsVersion::V2)),
+        None
+    );
+}
+
+#[test]
+fn test_falls_back_to_three_ds1_when_three_ds2_unsupported() {
+    assert_eq!(
+        ThreeDsVersion::negotiate(Some(ThreeDsVersion::V2), &[ThreeDsVersion::V1]),
+        Some(ThreeDsVersion::V1)
+    );
+}
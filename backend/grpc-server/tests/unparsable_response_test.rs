@@ -0,0 +1,78 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use grpc_api_types::payments::{
+    payment_service_server::PaymentService, PaymentServiceAuthorizeRequest,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+/// Starts an Adyen stand-in that answers every payment with a gateway error page
+fn start_html_connector() -> String {
+    common::start_connector(|_, _| {
+        common::http_response(
+            "200 OK",
+            "text/html",
+            "<html><body>Gateway error for card 4111</body></html>",
+        )
+    })
+}
+
+fn payments_service(base_url: String) -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.base_url = base_url;
+    common::payments_service(config)
+}
+
+fn authorize_request(test_mode: bool) -> Request<PaymentServiceAuthorizeRequest> {
+    let mut request = Request::new(PaymentServiceAuthorizeRequest {
+        test_mode: Some(test_mode),
+        ..common::card_authorize_request("unparsable_ref_123")
+    });
+    common::insert_adyen_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_unparsable_response_returned_as_masked_error() {
+    let base_url = start_html_connector();
+
+    let response = payments_service(base_url)
+        .authorize(authorize_request(false))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.error_code.as_deref(),
+        Some("UNPARSABLE_CONNECTOR_RESPONSE")
+    );
+    assert_eq!(
+        response.error_message.as_deref(),
+        Some(
+            "Connector response could not be parsed: \
+             <****><****>******* ***** *** **** ****</****></****>"
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_unparsable_response_text_kept_in_test_mode() {
+    let base_url = start_html_connector();
+
+    let response = payments_service(base_url)
+        .authorize(authorize_request(true))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.error_message.as_deref(),
+        Some(
+            "Connector response could not be parsed: \
+             <html><body>Gateway error for card 4111</body></html>"
+        )
+    );
+}
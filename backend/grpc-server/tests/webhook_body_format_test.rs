@@ -0,0 +1,209 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashMap;
+
+use domain_types::{
+    connector_types::{self, ConnectorEnum},
+    types::WebhookBodyFormat,
+};
+use grpc_api_types::payments::{
+    identifier::IdType, payment_service_server::PaymentService, HttpMethod, Identifier,
+    PaymentServiceTransformRequest, RequestDetails,
+};
+use grpc_server::{configs, server::payments::Payments, utils};
+use tonic::Request;
+
+mod common;
+
+fn request_details(content_type: Option<&str>, body: &[u8]) -> connector_types::RequestDetails {
+    connector_types::RequestDetails {
+        method: connector_types::HttpMethod::Post,
+        uri: Some("/webhooks".to_string()),
+        headers: content_type
+            .map(|content_type| {
+                HashMap::from([("Content-Type".to_string(), content_type.to_string())])
+            })
+            .unwrap_or_default(),
+        body: body.to_vec(),
+        query_params: None,
+        source_ip: None,
+    }
+}
+
+fn adyen_notification() -> Vec<u8> {
+    serde_json::to_vec(&serde_json::json!({
+        "live": "false",
+        "notificationItems": [
+            {
+                "NotificationRequestItem": {
+                    "eventCode": "AUTHORISATION",
+                    "success": "true",
+                    "pspReference": "9915555555555555",
+                    "merchantAccountCode": "YOUR_MERCHANT_ACCOUNT",
+                    "merchantReference": "YOUR_REFERENCE",
+                    "paymentMethod": "mc",
+                    "eventDate": "2023-12-01T12:00:00Z",
+                    "additionalData": {},
+                    "reason": "",
+                    "amount": {
+                        "value": 1000,
+                        "currency": "EUR"
+                    }
+                }
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_json_webhook_is_parsed_for_json_connector() {
+    let body = adyen_notification();
+
+    let parsed = utils::parse_webhook_body(
+        &ConnectorEnum::Adyen,
+        Some(WebhookBodyFormat::Json),
+        &request_details(Some("application/json; charset=utf-8"), &body),
+    )
+    .unwrap();
+
+    assert_eq!(parsed, body);
+}
+
+#[test]
+fn test_form_encoded_webhook_is_parsed_for_form_connector() {
+    // ISO-8859-1 encodes `é` as the single byte 0xE9
+    let body = b"tranID=30810365&orderid=order_123&status=00&channel=cr\xE9dit";
+
+    let parsed = utils::parse_webhook_body(
+        &ConnectorEnum::Fiuu,
+        Some(WebhookBodyFormat::FormUrlEncoded),
+        &request_details(
+            Some("application/x-www-form-urlencoded; charset=ISO-8859-1"),
+            body,
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(
+        String::from_utf8(parsed).unwrap(),
+        "tranID=30810365&orderid=order_123&status=00&channel=crédit"
+    );
+}
+
+#[test]
+fn test_content_type_without_structured_format_is_tolerated() {
+    let body = b"tranID=30810365&status=00";
+
+    for content_type in [None, Some("text/plain"), Some("application/octet-stream")] {
+        let parsed = utils::parse_webhook_body(
+            &ConnectorEnum::Fiuu,
+            Some(WebhookBodyFormat::FormUrlEncoded),
+            &request_details(content_type, body),
+        );
+        assert_eq!(parsed.unwrap(), body);
+    }
+}
+
+#[test]
+fn test_webhook_in_other_format_is_rejected() {
+    let body = adyen_notification();
+
+    // The content-type names another format
+    let status = utils::parse_webhook_body(
+        &ConnectorEnum::Fiuu,
+        Some(WebhookBodyFormat::FormUrlEncoded),
+        &request_details(Some("application/json"), &body),
+    )
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("content-type is json"));
+
+    // Without a content-type, the body does not parse as the declared format
+    let status = utils::parse_webhook_body(
+        &ConnectorEnum::Fiuu,
+        Some(WebhookBodyFormat::FormUrlEncoded),
+        &request_details(None, &body),
+    )
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = utils::parse_webhook_body(
+        &ConnectorEnum::Adyen,
+        Some(WebhookBodyFormat::Json),
+        &request_details(None, b"tranID=30810365&status=00"),
+    )
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[test]
+fn test_unsupported_charset_is_rejected() {
+    let status = utils::parse_webhook_body(
+        &ConnectorEnum::Adyen,
+        Some(WebhookBodyFormat::Json),
+        &request_details(Some("application/json; charset=utf-16"), b"{}"),
+    )
+    .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[test]
+fn test_body_is_unchecked_without_declared_format() {
+    let body = b"not a structured body";
+
+    let parsed = utils::parse_webhook_body(
+        &ConnectorEnum::Adyen,
+        None,
+        &request_details(Some("application/json"), body),
+    )
+    .unwrap();
+
+    assert_eq!(parsed, body);
+}
+
+fn payments_service() -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.webhook_body_format = Some(WebhookBodyFormat::Json);
+    common::payments_service(config)
+}
+
+fn webhook_request(content_type: &str) -> Request<PaymentServiceTransformRequest> {
+    let mut request = Request::new(PaymentServiceTransformRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("webhook_body_format_test".to_string())),
+        }),
+        request_details: Some(RequestDetails {
+            method: HttpMethod::Post.into(),
+            headers: HashMap::from([("Content-Type".to_string(), content_type.to_string())]),
+            uri: Some("/webhooks/adyen".to_string()),
+            query_params: None,
+            body: adyen_notification(),
+            source_ip: None,
+        }),
+        webhook_secrets: None,
+    });
+    common::insert_adyen_webhook_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_transform_processes_webhook_in_declared_format() {
+    let response = payments_service()
+        .transform(webhook_request("application/json"))
+        .await;
+
+    assert!(response.is_ok(), "unexpected error: {response:?}");
+}
+
+#[tokio::test]
+async fn test_transform_rejects_webhook_in_other_format() {
+    let status = payments_service()
+        .transform(webhook_request("application/x-www-form-urlencoded"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
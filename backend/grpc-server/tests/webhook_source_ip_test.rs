@@ -0,0 +1,134 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashMap;
+
+use grpc_api_types::payments::{
+    identifier::IdType, payment_service_server::PaymentService, HttpMethod, Identifier,
+    PaymentServiceTransformRequest, RequestDetails,
+};
+use grpc_server::{configs, server::payments::Payments};
+use tonic::Request;
+
+mod common;
+
+fn payments_service() -> Payments {
+    let mut config = configs::Config::new().expect("Failed while parsing config");
+    config.connectors.adyen.webhook_allowed_source_ips =
+        vec!["203.0.113.0/24".parse().expect("valid network")];
+    config.webhook_source.trusted_proxies = vec!["10.0.0.0/8".parse().expect("valid network")];
+    common::payments_service(config)
+}
+
+fn webhook_request(
+    source_ip: Option<&str>,
+    forwarded_for: Option<&str>,
+) -> Request<PaymentServiceTransformRequest> {
+    let body = serde_json::json!({
+        "live": "false",
+        "notificationItems": [
+            {
+                "NotificationRequestItem": {
+                    "eventCode": "AUTHORISATION",
+                    "success": "true",
+                    "pspReference": "9915555555555555",
+                    "merchantAccountCode": "YOUR_MERCHANT_ACCOUNT",
+                    "merchantReference": "YOUR_REFERENCE",
+                    "paymentMethod": "mc",
+                    "eventDate": "2023-12-01T12:00:00Z",
+                    "additionalData": {},
+                    "reason": "",
+                    "amount": {
+                        "value": 1000,
+                        "currency": "EUR"
+                    }
+                }
+            }
+        ]
+    });
+    let mut request = Request::new(PaymentServiceTransformRequest {
+        request_ref_id: Some(Identifier {
+            id_type: Some(IdType::Id("webhook_source_test".to_string())),
+        }),
+        request_details: Some(RequestDetails {
+            method: HttpMethod::Post.into(),
+            headers: forwarded_for
+                .map(|forwarded_for| {
+                    HashMap::from([("X-Forwarded-For".to_string(), forwarded_for.to_string())])
+                })
+                .unwrap_or_default(),
+            uri: Some("/webhooks/adyen".to_string()),
+            query_params: None,
+            body: serde_json::to_vec(&body).unwrap(),
+            source_ip: source_ip.map(str::to_string),
+        }),
+        webhook_secrets: None,
+    });
+    common::insert_adyen_webhook_metadata(request.metadata_mut());
+    request
+}
+
+#[tokio::test]
+async fn test_webhook_from_allowed_source_is_processed() {
+    let response = payments_service()
+        .transform(webhook_request(Some("203.0.113.7"), None))
+        .await;
+
+    assert!(response.is_ok(), "unexpected error: {response:?}");
+}
+
+#[tokio::test]
+async fn test_webhook_from_disallowed_source_is_rejected() {
+    let status = payments_service()
+        .transform(webhook_request(Some("198.51.100.9"), None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn test_webhook_forwarded_by_trusted_proxies_is_processed() {
+    let response = payments_service()
+        .transform(webhook_request(
+            Some("10.0.0.1"),
+            Some("198.51.100.9, 203.0.113.7, 10.0.0.2"),
+        ))
+        .await;
+
+    assert!(response.is_ok(), "unexpected error: {response:?}");
+}
+
+#[tokio::test]
+async fn test_spoofed_forwarded_for_entry_is_ignored() {
+    // The sender prepends an allowed address; the trusted proxy appends the real one
+    let status = payments_service()
+        .transform(webhook_request(
+            Some("10.0.0.1"),
+            Some("203.0.113.7, 198.51.100.9"),
+        ))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn test_forwarded_for_from_untrusted_source_is_ignored() {
+    let status = payments_service()
+        .transform(webhook_request(Some("198.51.100.9"), Some("203.0.113.7")))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn test_webhook_without_reported_source_is_rejected() {
+    let status = payments_service()
+        .transform(webhook_request(None, Some("203.0.113.7")))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+}
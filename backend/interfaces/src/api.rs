@@ -1,6 +1,7 @@
 use common_enums::CurrencyUnit;
 use common_utils::{
     consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
+    request::ContentType,
     CustomResult,
 };
 use domain_types::{
@@ -39,6 +40,12 @@ pub trait ConnectorCommon {
         "application/json"
     }
 
+    /// Request body formats the connector accepts in addition to the one each flow builds.
+    /// Only these can be selected through the `request_content_type` connector config.
+    fn supported_request_content_types(&self) -> &'static [ContentType] {
+        &[]
+    }
+
     // FIXME write doc - think about this
     // fn headers(&self) -> Vec<(&str, &str)>;
 
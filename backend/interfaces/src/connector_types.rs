@@ -7,12 +7,13 @@ use domain_types::{
     connector_types::{
         AcceptDisputeData, ConnectorSpecifications, ConnectorWebhookSecrets, DisputeDefendData,
         DisputeFlowData, DisputeResponseData, DisputeWebhookDetailsResponse, EventType,
-        PaymentCreateOrderData, PaymentCreateOrderResponse, PaymentFlowData, PaymentVoidData,
-        PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
-        RefundFlowData, RefundSyncData, RefundWebhookDetailsResponse, RefundsData,
-        RefundsResponseData, RepeatPaymentData, RequestDetails, SessionTokenRequestData,
-        SessionTokenResponseData, SetupMandateRequestData, SubmitEvidenceData,
-        WebhookDetailsResponse,
+        PaymentCaptureReversalData, PaymentCreateOrderData, PaymentCreateOrderResponse,
+        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData,
+        RefundWebhookDetailsResponse, RefundsData, RefundsResponseData, RegisterWebhookData,
+        RegisterWebhookResponseData, RepeatPaymentData, RequestDetails, SessionTokenRequestData,
+        SessionTokenResponseData, SettlementWebhookDetailsResponse, SetupMandateRequestData,
+        SubmitEvidenceData, ThreeDsVersion, WebhookDetailsResponse, WebhookFlowData,
     },
     payment_method_data::{PaymentMethodData, PaymentMethodDataTypes},
     router_data::ConnectorAuthType,
@@ -33,12 +34,14 @@ pub trait ConnectorServiceTrait<T: PaymentMethodDataTypes>:
     + IncomingWebhook
     + RefundV2
     + PaymentCapture
+    + PaymentCaptureReversalV2
     + SetupMandateV2<T>
     + RepeatPaymentV2
     + AcceptDispute
     + RefundSyncV2
     + DisputeDefend
     + SubmitEvidenceV2
+    + RegisterWebhookV2
 {
 }
 
@@ -57,6 +60,23 @@ pub trait ValidationTrait {
     fn should_do_session_token(&self) -> bool {
         false
     }
+
+    /// 3DS versions the connector can be asked to use, empty when the connector picks the
+    /// version on its own
+    fn supported_three_ds_versions(&self) -> &'static [ThreeDsVersion] {
+        &[]
+    }
+
+    /// Whether the connector accepts a capture to be executed at a later time
+    fn supports_scheduled_capture(&self) -> bool {
+        false
+    }
+
+    /// Whether the connector accepts card payments without the card's CVC. Such payments carry
+    /// an empty `card_cvc`, which the connector must leave out of its request.
+    fn supports_cvc_less_card_payments(&self) -> bool {
+        false
+    }
 }
 
 pub trait PaymentOrderCreate:
@@ -119,6 +139,26 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentCaptureReversalV2:
+    ConnectorIntegrationV2<
+    connector_flow::CaptureReversal,
+    PaymentFlowData,
+    PaymentCaptureReversalData,
+    PaymentsResponseData,
+>
+{
+}
+
+pub trait RegisterWebhookV2:
+    ConnectorIntegrationV2<
+    connector_flow::RegisterWebhook,
+    WebhookFlowData,
+    RegisterWebhookData,
+    RegisterWebhookResponseData,
+>
+{
+}
+
 pub trait SetupMandateV2<T: PaymentMethodDataTypes>:
     ConnectorIntegrationV2<
     connector_flow::SetupMandate,
@@ -250,6 +290,21 @@ pub trait IncomingWebhook {
         )
         .into())
     }
+
+    fn process_settlement_webhook(
+        &self,
+        _request: RequestDetails,
+        _connector_webhook_secret: Option<ConnectorWebhookSecrets>,
+        _connector_account_details: Option<ConnectorAuthType>,
+    ) -> Result<
+        SettlementWebhookDetailsResponse,
+        error_stack::Report<domain_types::errors::ConnectorError>,
+    > {
+        Err(domain_types::errors::ConnectorError::NotImplemented(
+            "process_settlement_webhook".to_string(),
+        )
+        .into())
+    }
 }
 
 /// trait ConnectorValidation
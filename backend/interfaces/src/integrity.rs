@@ -6,18 +6,19 @@
 use common_utils::errors::IntegrityCheckError;
 // Domain type imports
 use domain_types::connector_types::{
-    AcceptDisputeData, DisputeDefendData, PaymentCreateOrderData, PaymentVoidData,
-    PaymentsAuthorizeData, PaymentsCaptureData, PaymentsSyncData, RefundSyncData, RefundsData,
-    RepeatPaymentData, SessionTokenRequestData, SetupMandateRequestData, SubmitEvidenceData,
+    AcceptDisputeData, DisputeDefendData, PaymentCaptureReversalData, PaymentCreateOrderData,
+    PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData, PaymentsSyncData, RefundSyncData,
+    RefundsData, RegisterWebhookData, RepeatPaymentData, SessionTokenRequestData,
+    SetupMandateRequestData, SubmitEvidenceData,
 };
 use domain_types::{
     payment_method_data::PaymentMethodDataTypes,
     router_request_types::{
         AcceptDisputeIntegrityObject, AuthoriseIntegrityObject, CaptureIntegrityObject,
-        CreateOrderIntegrityObject, DefendDisputeIntegrityObject, PaymentSynIntegrityObject,
-        PaymentVoidIntegrityObject, RefundIntegrityObject, RefundSyncIntegrityObject,
-        RepeatPaymentIntegrityObject, SessionTokenIntegrityObject, SetupMandateIntegrityObject,
-        SubmitEvidenceIntegrityObject,
+        CaptureReversalIntegrityObject, CreateOrderIntegrityObject, DefendDisputeIntegrityObject,
+        PaymentSynIntegrityObject, PaymentVoidIntegrityObject, RefundIntegrityObject,
+        RefundSyncIntegrityObject, RegisterWebhookIntegrityObject, RepeatPaymentIntegrityObject,
+        SessionTokenIntegrityObject, SetupMandateIntegrityObject, SubmitEvidenceIntegrityObject,
     },
 };
 
@@ -143,6 +144,8 @@ impl_check_integrity!(PaymentCreateOrderData);
 impl_check_integrity!(SetupMandateRequestData<S>);
 impl_check_integrity!(PaymentsSyncData);
 impl_check_integrity!(PaymentVoidData);
+impl_check_integrity!(PaymentCaptureReversalData);
+impl_check_integrity!(RegisterWebhookData);
 impl_check_integrity!(RefundsData);
 impl_check_integrity!(PaymentsCaptureData);
 impl_check_integrity!(AcceptDisputeData);
@@ -224,6 +227,30 @@ impl GetIntegrityObject<PaymentVoidIntegrityObject> for PaymentVoidData {
     }
 }
 
+impl GetIntegrityObject<CaptureReversalIntegrityObject> for PaymentCaptureReversalData {
+    fn get_response_integrity_object(&self) -> Option<CaptureReversalIntegrityObject> {
+        self.integrity_object.clone()
+    }
+
+    fn get_request_integrity_object(&self) -> CaptureReversalIntegrityObject {
+        CaptureReversalIntegrityObject {
+            connector_transaction_id: self.connector_transaction_id.clone(),
+        }
+    }
+}
+
+impl GetIntegrityObject<RegisterWebhookIntegrityObject> for RegisterWebhookData {
+    fn get_response_integrity_object(&self) -> Option<RegisterWebhookIntegrityObject> {
+        self.integrity_object.clone()
+    }
+
+    fn get_request_integrity_object(&self) -> RegisterWebhookIntegrityObject {
+        RegisterWebhookIntegrityObject {
+            webhook_url: self.webhook_url.clone(),
+        }
+    }
+}
+
 impl GetIntegrityObject<RefundIntegrityObject> for RefundsData {
     fn get_response_integrity_object(&self) -> Option<RefundIntegrityObject> {
         self.integrity_object.clone()
@@ -495,6 +522,52 @@ impl FlowIntegrity for PaymentVoidIntegrityObject {
     }
 }
 
+impl FlowIntegrity for CaptureReversalIntegrityObject {
+    type IntegrityObject = Self;
+
+    fn compare(
+        req_integrity_object: Self,
+        res_integrity_object: Self,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        let mut mismatched_fields = Vec::new();
+
+        if req_integrity_object.connector_transaction_id
+            != res_integrity_object.connector_transaction_id
+        {
+            mismatched_fields.push(format_mismatch(
+                "connector_transaction_id",
+                &req_integrity_object.connector_transaction_id,
+                &res_integrity_object.connector_transaction_id,
+            ));
+        }
+
+        check_integrity_result(mismatched_fields, connector_transaction_id)
+    }
+}
+
+impl FlowIntegrity for RegisterWebhookIntegrityObject {
+    type IntegrityObject = Self;
+
+    fn compare(
+        req_integrity_object: Self,
+        res_integrity_object: Self,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        let mut mismatched_fields = Vec::new();
+
+        if req_integrity_object.webhook_url != res_integrity_object.webhook_url {
+            mismatched_fields.push(format_mismatch(
+                "webhook_url",
+                &req_integrity_object.webhook_url,
+                &res_integrity_object.webhook_url,
+            ));
+        }
+
+        check_integrity_result(mismatched_fields, connector_transaction_id)
+    }
+}
+
 impl FlowIntegrity for RefundIntegrityObject {
     type IntegrityObject = Self;
 
@@ -1,8 +1,11 @@
 //! Builder pattern implementation for KafkaWriter
 
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
-use super::writer::{KafkaWriter, KafkaWriterError};
+use super::{
+    spill::DEFAULT_SPILL_MAX_BYTES,
+    writer::{BackpressurePolicy, KafkaWriter, KafkaWriterError, SecretString, SecurityConfig},
+};
 
 /// Builder for creating a KafkaWriter with custom configuration
 #[derive(Debug, Clone, Default)]
@@ -15,6 +18,12 @@ pub struct KafkaWriterBuilder {
     queue_buffering_max_kbytes: Option<usize>,
     reconnect_backoff_min_ms: Option<u64>,
     reconnect_backoff_max_ms: Option<u64>,
+    spill_dir: Option<PathBuf>,
+    spill_max_bytes: Option<u64>,
+    backpressure: BackpressurePolicy,
+    block_timeout: Option<Duration>,
+    delivery_timeout_ms: Option<u64>,
+    security: SecurityConfig,
 }
 
 impl KafkaWriterBuilder {
@@ -72,6 +81,68 @@ impl KafkaWriterBuilder {
         self
     }
 
+    /// Spills messages that cannot be produced to a file in `dir` and replays them
+    /// once Kafka is reachable again
+    pub fn spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.spill_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the maximum size of the spill file in bytes (default: 64MiB)
+    pub fn spill_max_bytes(mut self, bytes: u64) -> Self {
+        self.spill_max_bytes = Some(bytes);
+        self
+    }
+
+    /// Sets what happens to a message when the producer queue is full (default: DropNewest)
+    pub fn backpressure(mut self, policy: BackpressurePolicy) -> Self {
+        self.backpressure = policy;
+        self
+    }
+
+    /// Sets how long the `Block` back-pressure policy waits for room in the queue before the
+    /// message is dropped (default: 50ms). The calling thread is blocked meanwhile.
+    pub fn block_timeout(mut self, timeout: Duration) -> Self {
+        self.block_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long a message may take to be delivered before it is reported as failed.
+    pub fn delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.delivery_timeout_ms = timeout.as_millis().try_into().ok();
+        self
+    }
+
+    /// Sets the protocol used to reach the brokers, e.g. `SASL_SSL` (default: PLAINTEXT)
+    pub fn security_protocol(mut self, protocol: impl Into<String>) -> Self {
+        self.security.security_protocol = Some(protocol.into());
+        self
+    }
+
+    /// Sets the SASL mechanism, e.g. `SCRAM-SHA-512`
+    pub fn sasl_mechanism(mut self, mechanism: impl Into<String>) -> Self {
+        self.security.sasl_mechanism = Some(mechanism.into());
+        self
+    }
+
+    /// Sets the SASL username
+    pub fn sasl_username(mut self, username: impl Into<String>) -> Self {
+        self.security.sasl_username = Some(username.into());
+        self
+    }
+
+    /// Sets the SASL password. It is redacted in the builder's Debug output.
+    pub fn sasl_password(mut self, password: impl Into<String>) -> Self {
+        self.security.sasl_password = Some(SecretString(password.into()));
+        self
+    }
+
+    /// Sets the CA certificate file used to verify the brokers' certificates
+    pub fn ssl_ca_location(mut self, path: impl Into<PathBuf>) -> Self {
+        self.security.ssl_ca_location = Some(path.into());
+        self
+    }
+
     /// Builds the KafkaWriter with the configured settings
     pub fn build(self) -> Result<KafkaWriter, KafkaWriterError> {
         let brokers = self.brokers.ok_or_else(|| {
@@ -86,7 +157,11 @@ impl KafkaWriterBuilder {
             ))
         })?;
 
-        KafkaWriter::new(
+        let spill = self
+            .spill_dir
+            .map(|dir| (dir, self.spill_max_bytes.unwrap_or(DEFAULT_SPILL_MAX_BYTES)));
+
+        KafkaWriter::create(
             brokers,
             topic,
             self.batch_size,
@@ -95,6 +170,40 @@ impl KafkaWriterBuilder {
             self.queue_buffering_max_kbytes,
             self.reconnect_backoff_min_ms,
             self.reconnect_backoff_max_ms,
+            spill,
+            self.backpressure,
+            self.block_timeout,
+            self.delivery_timeout_ms,
+            self.security,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rdkafka::config::ClientConfig;
+
+    use super::*;
+
+    #[test]
+    fn security_settings_translated_to_client_config() {
+        let builder = KafkaWriterBuilder::new()
+            .brokers(vec!["kafka-1:9096".to_string()])
+            .topic("application-logs")
+            .security_protocol("SASL_SSL")
+            .sasl_mechanism("SCRAM-SHA-512")
+            .sasl_username("connector-service")
+            .sasl_password("test_kafka_password") // Hardcoded dummy value
+            .ssl_ca_location("/etc/kafka/ca.pem");
+
+        let mut config = ClientConfig::new();
+        builder.security.apply(&mut config);
+
+        assert_eq!(config.get("security.protocol"), Some("SASL_SSL"));
+        assert_eq!(config.get("sasl.mechanism"), Some("SCRAM-SHA-512"));
+        assert_eq!(config.get("sasl.username"), Some("connector-service"));
+        assert_eq!(config.get("sasl.password"), Some("test_kafka_password"));
+        assert_eq!(config.get("ssl.ca.location"), Some("/etc/kafka/ca.pem"));
+        assert!(!format!("{builder:?}").contains("test_kafka_password"));
+    }
+}
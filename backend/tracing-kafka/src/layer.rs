@@ -2,25 +2,101 @@
 
 use std::{
     collections::{HashMap, HashSet},
+    path::PathBuf,
     time::Duration,
 };
 
 use log_utils::{
     AdditionalFieldsPlacement, JsonFormattingLayer, JsonFormattingLayerConfig, LoggerError,
 };
-use tracing::Subscriber;
-use tracing_subscriber::Layer;
+use tracing::{
+    field::{DisplayValue, Field, Value, ValueSet, Visit},
+    Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    layer::Context,
+    registry::{LookupSpan, SpanRef},
+    Layer,
+};
 
 use crate::{
     builder::KafkaWriterBuilder,
-    writer::{KafkaWriter, KafkaWriterError},
+    writer::{
+        with_record_routing, BackpressurePolicy, KafkaWriter, KafkaWriterError, RecordRouting,
+    },
 };
 
+/// What the Kafka partition key of a log record is taken from.
+/// Records with the same key go to the same partition and stay in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PartitionKeyStrategy {
+    /// No key, records are spread across partitions
+    #[default]
+    None,
+    /// Id of the span the event was recorded in, as 16 hex digits
+    SpanId,
+    /// OpenTelemetry trace id of the span the event was recorded in, as 32 hex digits.
+    /// Needs the `opentelemetry` feature and a `tracing_opentelemetry` layer in the subscriber.
+    TraceId,
+    /// Value of the named event field
+    Field(String),
+}
+
+/// Name of the field holding an event's message, kept by `include_fields`
+const MESSAGE_FIELD: &str = "message";
+
+/// Most fields an event or span can carry once some of its fields were filtered out
+const MAX_FILTERED_FIELDS: usize = 32;
+
+/// Which event and span fields are serialized into log records
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FieldFilter {
+    include: Option<HashSet<String>>,
+    exclude: HashSet<String>,
+}
+
+impl FieldFilter {
+    fn is_empty(&self) -> bool {
+        self.include.is_none() && self.exclude.is_empty()
+    }
+
+    fn permits(&self, field: &str) -> bool {
+        !self.exclude.contains(field)
+            && (field == MESSAGE_FIELD
+                || self
+                    .include
+                    .as_ref()
+                    .is_none_or(|include| include.contains(field)))
+    }
+
+    /// Copies the permitted fields of a record, or returns `None` when all of them are
+    /// permitted. Fields that are left out are never formatted.
+    fn permitted_fields(&self, record: impl FnOnce(&mut dyn Visit)) -> Option<PermittedFields> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut visitor = FilteringVisitor {
+            filter: self,
+            fields: Vec::new(),
+            dropped: None,
+        };
+        record(&mut visitor);
+        Some(PermittedFields {
+            fields: visitor.fields,
+            dropped: visitor.dropped?,
+        })
+    }
+}
+
 /// Tracing layer that sends JSON-formatted logs to Kafka
 ///
 /// Wraps log_utils' JsonFormattingLayer
 pub struct KafkaLayer {
     inner: JsonFormattingLayer<KafkaWriter, serde_json::ser::CompactFormatter>,
+    writer: KafkaWriter,
+    topic_field: Option<String>,
+    partition_key: PartitionKeyStrategy,
+    field_filter: FieldFilter,
 }
 
 impl KafkaLayer {
@@ -34,6 +110,9 @@ impl KafkaLayer {
     pub(crate) fn from_writer(
         kafka_writer: KafkaWriter,
         static_fields: HashMap<String, serde_json::Value>,
+        topic_field: Option<String>,
+        partition_key: PartitionKeyStrategy,
+        field_filter: FieldFilter,
     ) -> Result<Self, KafkaLayerError> {
         let config = JsonFormattingLayerConfig {
             static_top_level_fields: static_fields,
@@ -43,18 +122,263 @@ impl KafkaLayer {
         };
 
         let inner: JsonFormattingLayer<KafkaWriter, serde_json::ser::CompactFormatter> =
-            JsonFormattingLayer::new(config, kafka_writer, serde_json::ser::CompactFormatter)?;
+            JsonFormattingLayer::new(
+                config,
+                kafka_writer.clone(),
+                serde_json::ser::CompactFormatter,
+            )?;
+
+        Ok(Self {
+            inner,
+            writer: kafka_writer,
+            topic_field,
+            partition_key,
+            field_filter,
+        })
+    }
+
+    /// Waits up to `timeout` for buffered log records to be delivered.
+    ///
+    /// Call this when shutting down: a layer installed in the global subscriber is never
+    /// dropped, so records still buffered in the producer when the process exits are lost.
+    pub fn flush(&self, timeout: Duration) -> Result<(), KafkaLayerError> {
+        Ok(self.writer.flush(timeout)?)
+    }
+
+    /// Returns a handle to the underlying writer, e.g. to probe broker connectivity.
+    pub fn writer(&self) -> KafkaWriter {
+        self.writer.clone()
+    }
+
+    /// Returns the topic named by the event's topic field, if it carries one.
+    fn event_topic(&self, event: &tracing::Event<'_>) -> Option<String> {
+        field_value(event, self.topic_field.as_deref()?)
+    }
+
+    /// Returns the partition key of the event's record according to the strategy.
+    fn partition_key<S>(&self, event: &tracing::Event<'_>, ctx: &Context<'_, S>) -> Option<String>
+    where
+        S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+    {
+        match &self.partition_key {
+            PartitionKeyStrategy::Field(field) => field_value(event, field),
+            _ => ctx
+                .event_span(event)
+                .and_then(|span| self.span_partition_key(&span)),
+        }
+    }
+
+    /// Returns the partition key of records logged in `span`, for the span based strategies.
+    fn span_partition_key<S>(&self, span: &SpanRef<'_, S>) -> Option<String>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        match &self.partition_key {
+            PartitionKeyStrategy::SpanId => Some(format!("{:016x}", span.id().into_u64())),
+            PartitionKeyStrategy::TraceId => otel_trace_id(span),
+            PartitionKeyStrategy::None | PartitionKeyStrategy::Field(_) => None,
+        }
+    }
+
+    /// Routes the span lifecycle records of span `id` to the span's partition.
+    fn span_routing<S>(&self, id: &tracing::span::Id, ctx: &Context<'_, S>) -> RecordRouting
+    where
+        S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+    {
+        RecordRouting {
+            topic: None,
+            key: ctx.span(id).and_then(|span| self.span_partition_key(&span)),
+        }
+    }
+}
+
+/// Returns the value of the event's field named `field`, if it carries one.
+fn field_value(event: &tracing::Event<'_>, field: &str) -> Option<String> {
+    let mut visitor = FieldValueVisitor { field, value: None };
+    event.record(&mut visitor);
+    visitor.value
+}
+
+/// Picks the value of a single field
+struct FieldValueVisitor<'a> {
+    field: &'a str,
+    value: Option<String>,
+}
+
+impl Visit for FieldValueVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == self.field {
+            self.value = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == self.field {
+            self.value = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// Owned copy of a permitted field value, recorded again into the filtered event or span
+enum FieldValue {
+    F64(f64),
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    Bool(bool),
+    Str(String),
+    Debug(DisplayValue<String>),
+}
+
+impl FieldValue {
+    fn as_value(&self) -> &dyn Value {
+        match self {
+            Self::F64(value) => value,
+            Self::I64(value) => value,
+            Self::U64(value) => value,
+            Self::I128(value) => value,
+            Self::U128(value) => value,
+            Self::Bool(value) => value,
+            Self::Str(value) => value,
+            Self::Debug(value) => value,
+        }
+    }
+}
+
+/// Fields of a record that a `FieldFilter` permits, when it left out at least one
+struct PermittedFields {
+    fields: Vec<(Field, FieldValue)>,
+    /// One of the fields left out
+    dropped: Field,
+}
+
+impl PermittedFields {
+    /// Runs `f` with a value set holding only the permitted fields.
+    /// Fields beyond `MAX_FILTERED_FIELDS` are left out.
+    fn with_value_set<R>(
+        &self,
+        metadata: &'static Metadata<'static>,
+        f: impl FnOnce(&ValueSet<'_>) -> R,
+    ) -> R {
+        // Unused slots name a left out field without a value, which is not recorded
+        let mut values: [(&Field, Option<&dyn Value>); MAX_FILTERED_FIELDS] =
+            [(&self.dropped, None); MAX_FILTERED_FIELDS];
+        for (slot, (field, value)) in values.iter_mut().zip(&self.fields) {
+            *slot = (field, Some(value.as_value()));
+        }
+        f(&metadata.fields().value_set(&values))
+    }
+}
+
+/// Copies the fields permitted by a `FieldFilter`
+struct FilteringVisitor<'a> {
+    filter: &'a FieldFilter,
+    fields: Vec<(Field, FieldValue)>,
+    dropped: Option<Field>,
+}
 
-        Ok(Self { inner })
+impl FilteringVisitor<'_> {
+    fn push(&mut self, field: &Field, value: impl FnOnce() -> FieldValue) {
+        if self.filter.permits(field.name()) {
+            self.fields.push((field.clone(), value()));
+        } else if self.dropped.is_none() {
+            self.dropped = Some(field.clone());
+        }
     }
 }
 
+impl Visit for FilteringVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.push(field, || FieldValue::F64(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.push(field, || FieldValue::I64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.push(field, || FieldValue::U64(value));
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.push(field, || FieldValue::I128(value));
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.push(field, || FieldValue::U128(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.push(field, || FieldValue::Bool(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, || FieldValue::Str(value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        // Displaying the captured text reproduces the original debug output
+        self.push(field, || {
+            FieldValue::Debug(tracing::field::display(format!("{value:?}")))
+        });
+    }
+}
+
+/// Trace id that tracing-opentelemetry recorded for the span or its closest ancestor
+#[cfg(feature = "opentelemetry")]
+fn otel_trace_id<S>(span: &SpanRef<'_, S>) -> Option<String>
+where
+    S: for<'lookup> LookupSpan<'lookup>,
+{
+    use opentelemetry::trace::{TraceContextExt, TraceId};
+
+    span.scope().find_map(|span| {
+        let extensions = span.extensions();
+        let otel_data = extensions.get::<tracing_opentelemetry::OtelData>()?;
+        // Root spans carry their own trace id, child spans inherit the parent context's
+        let trace_id = otel_data
+            .builder
+            .trace_id
+            .filter(|trace_id| *trace_id != TraceId::INVALID)
+            .unwrap_or_else(|| otel_data.parent_cx.span().span_context().trace_id());
+        (trace_id != TraceId::INVALID).then(|| trace_id.to_string())
+    })
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+fn otel_trace_id<S>(_span: &SpanRef<'_, S>) -> Option<String>
+where
+    S: for<'lookup> LookupSpan<'lookup>,
+{
+    None
+}
+
 impl<S> Layer<S> for KafkaLayer
 where
     S: Subscriber + for<'lookup> tracing_subscriber::registry::LookupSpan<'lookup>,
 {
     fn on_event(&self, event: &tracing::Event<'_>, ctx: tracing_subscriber::layer::Context<'_, S>) {
-        self.inner.on_event(event, ctx);
+        let routing = RecordRouting {
+            topic: self.event_topic(event),
+            key: self.partition_key(event, &ctx),
+        };
+        let Some(fields) = self
+            .field_filter
+            .permitted_fields(|visitor| event.record(visitor))
+        else {
+            with_record_routing(routing, || self.inner.on_event(event, ctx));
+            return;
+        };
+        let metadata = event.metadata();
+        fields.with_value_set(metadata, |values| {
+            let filtered = if event.is_contextual() {
+                tracing::Event::new(metadata, values)
+            } else {
+                tracing::Event::new_child_of(event.parent().cloned(), metadata, values)
+            };
+            with_record_routing(routing, || self.inner.on_event(&filtered, ctx));
+        });
     }
 
     fn on_new_span(
@@ -63,7 +387,25 @@ where
         id: &tracing::span::Id,
         ctx: tracing_subscriber::layer::Context<'_, S>,
     ) {
-        self.inner.on_new_span(attrs, id, ctx);
+        let routing = self.span_routing(id, &ctx);
+        let Some(fields) = self
+            .field_filter
+            .permitted_fields(|visitor| attrs.record(visitor))
+        else {
+            with_record_routing(routing, || self.inner.on_new_span(attrs, id, ctx));
+            return;
+        };
+        let metadata = attrs.metadata();
+        fields.with_value_set(metadata, |values| {
+            let filtered = match attrs.parent() {
+                Some(parent) => {
+                    tracing::span::Attributes::child_of(parent.clone(), metadata, values)
+                }
+                None if attrs.is_root() => tracing::span::Attributes::new_root(metadata, values),
+                None => tracing::span::Attributes::new(metadata, values),
+            };
+            with_record_routing(routing, || self.inner.on_new_span(&filtered, id, ctx));
+        });
     }
 
     fn on_enter(&self, id: &tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
@@ -75,7 +417,8 @@ where
     }
 
     fn on_close(&self, id: tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
-        self.inner.on_close(id, ctx);
+        let routing = self.span_routing(&id, &ctx);
+        with_record_routing(routing, || self.inner.on_close(id, ctx));
     }
 }
 
@@ -110,7 +453,11 @@ pub enum KafkaLayerError {
 #[derive(Debug, Clone, Default)]
 pub struct KafkaLayerBuilder {
     writer_builder: KafkaWriterBuilder,
+    shared_writer: Option<KafkaWriter>,
     static_fields: HashMap<String, serde_json::Value>,
+    topic_field: Option<String>,
+    partition_key: PartitionKeyStrategy,
+    field_filter: FieldFilter,
 }
 
 impl KafkaLayerBuilder {
@@ -169,6 +516,75 @@ impl KafkaLayerBuilder {
         self
     }
 
+    /// Spills messages that cannot be produced to a file in `dir` and replays them
+    /// once Kafka is reachable again.
+    pub fn spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.writer_builder = self.writer_builder.spill_dir(dir);
+        self
+    }
+
+    /// Sets the maximum size of the spill file in bytes.
+    pub fn spill_max_bytes(mut self, bytes: u64) -> Self {
+        self.writer_builder = self.writer_builder.spill_max_bytes(bytes);
+        self
+    }
+
+    /// Sets what happens to a log message when the producer queue is full.
+    pub fn backpressure(mut self, policy: BackpressurePolicy) -> Self {
+        self.writer_builder = self.writer_builder.backpressure(policy);
+        self
+    }
+
+    /// Sets how long the `Block` back-pressure policy waits for room in the queue.
+    pub fn block_timeout(mut self, timeout: Duration) -> Self {
+        self.writer_builder = self.writer_builder.block_timeout(timeout);
+        self
+    }
+
+    /// Sets how long a log message may take to be delivered before it is dropped.
+    pub fn delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.writer_builder = self.writer_builder.delivery_timeout(timeout);
+        self
+    }
+
+    /// Sets the protocol used to reach the brokers, e.g. `SASL_SSL`.
+    pub fn security_protocol(mut self, protocol: impl Into<String>) -> Self {
+        self.writer_builder = self.writer_builder.security_protocol(protocol);
+        self
+    }
+
+    /// Sets the SASL mechanism, e.g. `SCRAM-SHA-512`.
+    pub fn sasl_mechanism(mut self, mechanism: impl Into<String>) -> Self {
+        self.writer_builder = self.writer_builder.sasl_mechanism(mechanism);
+        self
+    }
+
+    /// Sets the SASL username.
+    pub fn sasl_username(mut self, username: impl Into<String>) -> Self {
+        self.writer_builder = self.writer_builder.sasl_username(username);
+        self
+    }
+
+    /// Sets the SASL password.
+    pub fn sasl_password(mut self, password: impl Into<String>) -> Self {
+        self.writer_builder = self.writer_builder.sasl_password(password);
+        self
+    }
+
+    /// Sets the CA certificate file used to verify the brokers' certificates.
+    pub fn ssl_ca_location(mut self, path: impl Into<PathBuf>) -> Self {
+        self.writer_builder = self.writer_builder.ssl_ca_location(path);
+        self
+    }
+
+    /// Sends logs through an existing writer instead of creating a new producer.
+    /// Use `KafkaWriter::with_topic` to build per-topic layers that share one producer.
+    /// The broker, topic and producer settings of this builder are ignored.
+    pub fn shared_writer(mut self, writer: KafkaWriter) -> Self {
+        self.shared_writer = Some(writer);
+        self
+    }
+
     /// Adds static fields that will be included in every log entry.
     /// These fields are added at the top level of the JSON output.
     pub fn static_fields(mut self, fields: HashMap<String, serde_json::Value>) -> Self {
@@ -182,9 +598,49 @@ impl KafkaLayerBuilder {
         self
     }
 
+    /// Routes events carrying a field with this name to the topic given by its value,
+    /// e.g. `info!(kafka_topic = "audit-logs", ...)` with `topic_field("kafka_topic")`.
+    /// Events without the field go to the configured topic.
+    pub fn topic_field(mut self, field: impl Into<String>) -> Self {
+        self.topic_field = Some(field.into());
+        self
+    }
+
+    /// Sets what the partition key of each log record is taken from.
+    /// Records are spread across partitions without a key by default.
+    pub fn partition_key(mut self, strategy: PartitionKeyStrategy) -> Self {
+        self.partition_key = strategy;
+        self
+    }
+
+    /// Serializes only the named event and span fields, besides an event's `message`,
+    /// keeping large fields out of the produced records.
+    pub fn include_fields(mut self, fields: &[&str]) -> Self {
+        self.field_filter.include = Some(fields.iter().map(|field| field.to_string()).collect());
+        self
+    }
+
+    /// Leaves the named event and span fields out of the produced records.
+    /// A field both included and excluded is left out.
+    pub fn exclude_fields(mut self, fields: &[&str]) -> Self {
+        self.field_filter
+            .exclude
+            .extend(fields.iter().map(|field| field.to_string()));
+        self
+    }
+
     /// Builds the KafkaLayer with the configured settings.
     pub fn build(self) -> Result<KafkaLayer, KafkaLayerError> {
-        let kafka_writer = self.writer_builder.build()?;
-        KafkaLayer::from_writer(kafka_writer, self.static_fields)
+        let kafka_writer = match self.shared_writer {
+            Some(writer) => writer,
+            None => self.writer_builder.build()?,
+        };
+        KafkaLayer::from_writer(
+            kafka_writer,
+            self.static_fields,
+            self.topic_field,
+            self.partition_key,
+            self.field_filter,
+        )
     }
 }
@@ -47,19 +47,38 @@
 //!     eprintln!("Failed to publish event: {}", e);
 //! }
 //! ```
+//!
+//! High-volume publishers can enqueue a batch at once with `publish_events`, which returns how
+//! many events were enqueued before the producer queue was full:
+//!
+//! ```no_run
+//! # use tracing_kafka::KafkaWriter;
+//! # let writer = KafkaWriter::new(
+//! #     vec!["localhost:9092".to_string()],
+//! #     "default-topic".to_string(),
+//! #     None, None, None, None, None, None
+//! # ).expect("Failed to create KafkaWriter");
+//! let events = [
+//!     (Some("event-key"), b"first-payload".as_slice(), None),
+//!     (Some("event-key"), b"second-payload".as_slice(), None),
+//! ];
+//! let accepted = writer.publish_events("custom-events", &events);
+//! ```
 
 pub mod builder;
 mod layer;
+mod spill;
 mod writer;
 
-pub use layer::{KafkaLayer, KafkaLayerError};
-pub use writer::{KafkaWriter, KafkaWriterError};
+pub use layer::{KafkaLayer, KafkaLayerError, PartitionKeyStrategy};
+pub use writer::{BackpressurePolicy, KafkaWriter, KafkaWriterError};
 
-#[cfg(feature = "kafka-metrics")]
-mod metrics;
+pub mod metrics;
 
 /// Initializes the metrics for the tracing kafka.
 /// This function should be called once at application startup.
+/// Pair it with a call to [`KafkaLayer::flush`] in the shutdown path, or buffered log
+/// records are lost when the process exits.
 #[cfg(feature = "kafka-metrics")]
 pub fn init() {
     metrics::initialize_all_metrics();
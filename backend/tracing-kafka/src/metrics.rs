@@ -1,12 +1,22 @@
 //! Prometheus metrics for Kafka writer
+//!
+//! The metrics are registered with the `kafka-metrics` feature; without it the handles in this
+//! module record nothing.
 
+#[cfg(feature = "kafka-metrics")]
 use std::sync::LazyLock;
+use std::time::Duration;
 
-use prometheus::{register_int_counter, register_int_gauge, IntCounter, IntGauge};
+#[cfg(feature = "kafka-metrics")]
+use prometheus::{
+    exponential_buckets, register_histogram_vec, register_int_counter, register_int_counter_vec,
+    register_int_gauge, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+};
 
 /// Total number of logs successfully sent to Kafka
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_LOGS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_LOGS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_logs_sent_total",
         "Total number of logs successfully sent to Kafka"
@@ -15,8 +25,9 @@ pub static KAFKA_LOGS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Total number of logs dropped due to Kafka queue full or errors
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_LOGS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_LOGS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_logs_dropped_total",
         "Total number of logs dropped due to Kafka queue full or errors"
@@ -25,8 +36,9 @@ pub static KAFKA_LOGS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Current size of Kafka producer queue
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
+pub(crate) static KAFKA_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
     register_int_gauge!(
         "kafka_producer_queue_size",
         "Current size of Kafka producer queue"
@@ -35,8 +47,9 @@ pub static KAFKA_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
 });
 
 /// Logs dropped due to queue full
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_drops_queue_full_total",
         "Total number of logs dropped due to Kafka queue being full"
@@ -45,8 +58,9 @@ pub static KAFKA_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Logs dropped due to message too large
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_drops_msg_too_large_total",
         "Total number of logs dropped due to message size exceeding limit"
@@ -55,8 +69,9 @@ pub static KAFKA_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Logs dropped due to timeout
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_drops_timeout_total",
         "Total number of logs dropped due to timeout"
@@ -65,8 +80,9 @@ pub static KAFKA_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Logs dropped due to other errors
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_drops_other_total",
         "Total number of logs dropped due to other errors"
@@ -75,8 +91,9 @@ pub static KAFKA_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Total number of audit events successfully sent to Kafka
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_EVENTS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_EVENTS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_events_sent_total",
         "Total number of audit events successfully sent to Kafka"
@@ -85,8 +102,9 @@ pub static KAFKA_AUDIT_EVENTS_SENT: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Total number of audit events dropped due to Kafka queue full or errors
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_EVENTS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_EVENTS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_events_dropped_total",
         "Total number of audit events dropped due to Kafka queue full or errors"
@@ -95,8 +113,9 @@ pub static KAFKA_AUDIT_EVENTS_DROPPED: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Current size of Kafka audit event producer queue
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_EVENT_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_EVENT_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
     register_int_gauge!(
         "kafka_audit_event_queue_size",
         "Current size of Kafka audit event producer queue"
@@ -105,8 +124,9 @@ pub static KAFKA_AUDIT_EVENT_QUEUE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
 });
 
 /// Audit events dropped due to queue full
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_drops_queue_full_total",
         "Total number of audit events dropped due to Kafka queue being full"
@@ -115,8 +135,9 @@ pub static KAFKA_AUDIT_DROPS_QUEUE_FULL: LazyLock<IntCounter> = LazyLock::new(||
 });
 
 /// Audit events dropped due to message too large
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_drops_msg_too_large_total",
         "Total number of audit events dropped due to message size exceeding limit"
@@ -125,8 +146,9 @@ pub static KAFKA_AUDIT_DROPS_MSG_TOO_LARGE: LazyLock<IntCounter> = LazyLock::new
 });
 
 /// Audit events dropped due to timeout
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_drops_timeout_total",
         "Total number of audit events dropped due to timeout"
@@ -135,8 +157,9 @@ pub static KAFKA_AUDIT_DROPS_TIMEOUT: LazyLock<IntCounter> = LazyLock::new(|| {
 });
 
 /// Audit events dropped due to other errors
+#[cfg(feature = "kafka-metrics")]
 #[allow(clippy::expect_used)]
-pub static KAFKA_AUDIT_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
+pub(crate) static KAFKA_AUDIT_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
     register_int_counter!(
         "kafka_audit_drops_other_total",
         "Total number of audit events dropped due to other errors"
@@ -144,13 +167,109 @@ pub static KAFKA_AUDIT_DROPS_OTHER: LazyLock<IntCounter> = LazyLock::new(|| {
     .expect("Failed to register kafka_audit_drops_other_total metric")
 });
 
+/// Messages written to the spill file after failing to be produced
+#[cfg(feature = "kafka-metrics")]
+#[allow(clippy::expect_used)]
+pub(crate) static KAFKA_MESSAGES_SPILLED: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "kafka_messages_spilled_total",
+        "Total number of messages written to the spill file after failing to be produced"
+    )
+    .expect("Failed to register kafka_messages_spilled_total metric")
+});
+
+/// Spilled messages produced again after Kafka recovered
+#[cfg(feature = "kafka-metrics")]
+#[allow(clippy::expect_used)]
+pub(crate) static KAFKA_MESSAGES_REPLAYED: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "kafka_messages_replayed_total",
+        "Total number of spilled messages produced again from the spill file"
+    )
+    .expect("Failed to register kafka_messages_replayed_total metric")
+});
+
+/// Time from enqueueing a message to its delivery report, by topic
+#[cfg(feature = "kafka-metrics")]
+#[allow(clippy::expect_used)]
+static KAFKA_PRODUCE_LATENCY: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "kafka_produce_latency_seconds",
+        "Time from enqueueing a message to its delivery report, by topic",
+        &["topic"],
+        exponential_buckets(0.001, 2.0, 15).expect("Failed to build produce latency buckets")
+    )
+    .expect("Failed to register kafka_produce_latency_seconds metric")
+});
+
+/// Messages delivered to Kafka, by topic
+#[cfg(feature = "kafka-metrics")]
+#[allow(clippy::expect_used)]
+pub(crate) static KAFKA_TOPIC_DELIVERIES_SUCCEEDED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "kafka_topic_deliveries_succeeded_total",
+        "Total number of messages delivered to Kafka, by topic",
+        &["topic"]
+    )
+    .expect("Failed to register kafka_topic_deliveries_succeeded_total metric")
+});
+
+/// Messages whose delivery to Kafka failed, by topic
+#[cfg(feature = "kafka-metrics")]
+#[allow(clippy::expect_used)]
+pub(crate) static KAFKA_TOPIC_DELIVERIES_FAILED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "kafka_topic_deliveries_failed_total",
+        "Total number of messages whose delivery to Kafka failed, by topic",
+        &["topic"]
+    )
+    .expect("Failed to register kafka_topic_deliveries_failed_total metric")
+});
+
+/// Handle to the `kafka_produce_latency_seconds` histogram
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProduceLatencyHistogram;
+
+#[cfg(feature = "kafka-metrics")]
+impl ProduceLatencyHistogram {
+    /// Records the time a message to `topic` took from enqueueing to its delivery report.
+    pub fn observe(&self, topic: &str, latency: Duration) {
+        KAFKA_PRODUCE_LATENCY
+            .with_label_values(&[topic])
+            .observe(latency.as_secs_f64());
+    }
+
+    /// Returns how many latencies were recorded for `topic`.
+    pub fn sample_count(&self, topic: &str) -> u64 {
+        KAFKA_PRODUCE_LATENCY
+            .with_label_values(&[topic])
+            .get_sample_count()
+    }
+}
+
+#[cfg(not(feature = "kafka-metrics"))]
+impl ProduceLatencyHistogram {
+    /// Records nothing without the `kafka-metrics` feature.
+    pub fn observe(&self, _topic: &str, _latency: Duration) {}
+
+    /// Always 0 without the `kafka-metrics` feature.
+    pub fn sample_count(&self, _topic: &str) -> u64 {
+        0
+    }
+}
+
+/// Time from enqueueing a message to its delivery report, by topic
+pub fn produce_latency_seconds() -> ProduceLatencyHistogram {
+    ProduceLatencyHistogram
+}
+
 /// Forces the initialization of all metrics in this module.
 ///
 /// This function should be called once at application startup to ensure that all metrics
 /// are registered upfront. If any metric registration fails (e.g., due to a duplicate
 /// metric name), the application will panic immediately.
 #[cfg(feature = "kafka-metrics")]
-pub fn initialize_all_metrics() {
+pub(crate) fn initialize_all_metrics() {
     // Force evaluation of all lazy metrics to fail fast if registration fails.
     let _ = &*KAFKA_LOGS_SENT;
     let _ = &*KAFKA_LOGS_DROPPED;
@@ -166,4 +285,9 @@ pub fn initialize_all_metrics() {
     let _ = &*KAFKA_AUDIT_DROPS_MSG_TOO_LARGE;
     let _ = &*KAFKA_AUDIT_DROPS_TIMEOUT;
     let _ = &*KAFKA_AUDIT_DROPS_OTHER;
+    let _ = &*KAFKA_MESSAGES_SPILLED;
+    let _ = &*KAFKA_MESSAGES_REPLAYED;
+    let _ = &*KAFKA_PRODUCE_LATENCY;
+    let _ = &*KAFKA_TOPIC_DELIVERIES_SUCCEEDED;
+    let _ = &*KAFKA_TOPIC_DELIVERIES_FAILED;
 }
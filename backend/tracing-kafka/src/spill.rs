@@ -0,0 +1,256 @@
+//! Local spill file for Kafka messages that could not be produced.
+//!
+//! Messages are appended as length-prefixed records and produced again, in order, once the
+//! brokers are reachable. The file is bounded; messages that would grow it past the bound are
+//! dropped.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+/// Default upper bound of a spill file in bytes
+pub(crate) const DEFAULT_SPILL_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A message waiting in the spill file to be produced again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SpilledMessage {
+    pub is_event: bool,
+    pub topic: String,
+    pub key: Option<Vec<u8>>,
+    pub payload: Vec<u8>,
+    pub headers: Vec<(String, Vec<u8>)>,
+}
+
+impl SpilledMessage {
+    fn encode(&self, out: &mut Vec<u8>) -> io::Result<()> {
+        out.push(u8::from(self.is_event));
+        write_field(out, self.topic.as_bytes())?;
+        match &self.key {
+            Some(key) => {
+                out.push(1);
+                write_field(out, key)?;
+            }
+            None => out.push(0),
+        }
+        write_field(out, &self.payload)?;
+        write_len(out, self.headers.len())?;
+        for (name, value) in &self.headers {
+            write_field(out, name.as_bytes())?;
+            write_field(out, value)?;
+        }
+        Ok(())
+    }
+
+    /// Decodes the record at the start of `input`, returning it with the rest of the input.
+    /// A truncated record, e.g. from a crash mid-append, decodes to `None`.
+    fn decode(input: &[u8]) -> Option<(Self, &[u8])> {
+        let (&is_event, input) = input.split_first()?;
+        let (topic, input) = read_field(input)?;
+        let (&has_key, input) = input.split_first()?;
+        let (key, input) = match has_key {
+            0 => (None, input),
+            _ => {
+                let (key, input) = read_field(input)?;
+                (Some(key.to_vec()), input)
+            }
+        };
+        let (payload, input) = read_field(input)?;
+        let (header_count, mut input) = read_len(input)?;
+        let mut headers = Vec::new();
+        for _ in 0..header_count {
+            let (name, rest) = read_field(input)?;
+            let (value, rest) = read_field(rest)?;
+            headers.push((String::from_utf8(name.to_vec()).ok()?, value.to_vec()));
+            input = rest;
+        }
+        let message = Self {
+            is_event: is_event != 0,
+            topic: String::from_utf8(topic.to_vec()).ok()?,
+            key,
+            payload: payload.to_vec(),
+            headers,
+        };
+        Some((message, input))
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "spilled field too large"))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+fn write_field(out: &mut Vec<u8>, field: &[u8]) -> io::Result<()> {
+    write_len(out, field.len())?;
+    out.extend_from_slice(field);
+    Ok(())
+}
+
+fn read_len(input: &[u8]) -> Option<(usize, &[u8])> {
+    let (len, rest) = input.split_first_chunk::<4>()?;
+    Some((usize::try_from(u32::from_le_bytes(*len)).ok()?, rest))
+}
+
+fn read_field(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (len, rest) = read_len(input)?;
+    (rest.len() >= len).then(|| rest.split_at(len))
+}
+
+/// Bounded append-only file of messages waiting to be produced
+#[derive(Debug)]
+pub(crate) struct SpillFile {
+    path: PathBuf,
+    max_bytes: u64,
+    lock: Mutex<()>,
+}
+
+impl SpillFile {
+    /// Uses `<dir>/<name>.spill`, creating the directory when needed.
+    pub fn open(dir: &Path, name: &str, max_bytes: u64) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            path: dir.join(format!("{name}.spill")),
+            max_bytes,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Appends a message, returning false when it would grow the file past its bound.
+    pub fn append(&self, message: &SpilledMessage) -> io::Result<bool> {
+        let mut record = Vec::new();
+        message.encode(&mut record)?;
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let record_len = u64::try_from(record.len()).unwrap_or(u64::MAX);
+        if file.metadata()?.len().saturating_add(record_len) > self.max_bytes {
+            return Ok(false);
+        }
+        file.write_all(&record)?;
+        Ok(true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        fs::metadata(&self.path).map_or(true, |metadata| metadata.len() == 0)
+    }
+
+    /// Hands the spilled messages to `produce` in order and truncates the file.
+    ///
+    /// Once `produce` rejects a message, it and every later message are kept for the next
+    /// replay. Returns the number of messages produced.
+    pub fn replay(&self, mut produce: impl FnMut(&SpilledMessage) -> bool) -> io::Result<usize> {
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+
+        let mut input = contents.as_slice();
+        let mut remaining = Vec::new();
+        let mut replayed = 0;
+        while let Some((message, rest)) = SpilledMessage::decode(input) {
+            if remaining.is_empty() && produce(&message) {
+                replayed += 1;
+            } else {
+                message.encode(&mut remaining)?;
+            }
+            input = rest;
+        }
+        fs::write(&self.path, remaining)?;
+        Ok(replayed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn spill_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("tracing-kafka-spill-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn message(payload: &str) -> SpilledMessage {
+        SpilledMessage {
+            is_event: true,
+            topic: "audit-events".to_string(),
+            key: Some(b"request_123".to_vec()),
+            payload: payload.as_bytes().to_vec(),
+            headers: vec![("retention".to_string(), b"long".to_vec())],
+        }
+    }
+
+    #[test]
+    fn spilled_messages_replayed_after_outage() {
+        let spill = SpillFile::open(&spill_dir("replay"), "logs", DEFAULT_SPILL_MAX_BYTES).unwrap();
+        let messages = [message("first"), message("second"), message("third")];
+        for message in &messages {
+            assert!(spill.append(message).unwrap());
+        }
+
+        // Kafka is still down, nothing is produced and nothing is lost
+        assert_eq!(spill.replay(|_| false).unwrap(), 0);
+        assert!(!spill.is_empty());
+
+        // Kafka recovered
+        let mut produced = Vec::new();
+        let replayed = spill
+            .replay(|message| {
+                produced.push(message.clone());
+                true
+            })
+            .unwrap();
+
+        assert_eq!(replayed, 3);
+        assert_eq!(produced, messages);
+        assert!(spill.is_empty());
+    }
+
+    #[test]
+    fn messages_after_a_failed_one_stay_in_order() {
+        let spill =
+            SpillFile::open(&spill_dir("partial"), "logs", DEFAULT_SPILL_MAX_BYTES).unwrap();
+        for payload in ["first", "second", "third"] {
+            spill.append(&message(payload)).unwrap();
+        }
+
+        let mut attempts = 0;
+        let replayed = spill
+            .replay(|_| {
+                attempts += 1;
+                attempts == 1
+            })
+            .unwrap();
+        let mut produced = Vec::new();
+        spill
+            .replay(|message| {
+                produced.push(message.clone());
+                true
+            })
+            .unwrap();
+
+        assert_eq!(replayed, 1);
+        assert_eq!(produced, [message("second"), message("third")]);
+    }
+
+    #[test]
+    fn spill_file_is_bounded() {
+        let mut record = Vec::new();
+        message("first").encode(&mut record).unwrap();
+        let max_bytes = u64::try_from(record.len()).unwrap();
+        let spill = SpillFile::open(&spill_dir("bounded"), "logs", max_bytes).unwrap();
+
+        assert!(spill.append(&message("first")).unwrap());
+        assert!(!spill.append(&message("second")).unwrap());
+    }
+}
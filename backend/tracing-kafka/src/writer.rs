@@ -1,40 +1,263 @@
 //! Kafka writer implementation for sending formatted log messages to Kafka.
 
 use std::{
+    cell::RefCell,
     io::{self, Write},
-    sync::Arc,
-    time::Duration,
+    path::PathBuf,
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
 };
 
 use rdkafka::{
     config::ClientConfig,
     error::{KafkaError, RDKafkaErrorCode},
-    message::OwnedHeaders,
-    producer::{BaseRecord, DeliveryResult, Producer, ProducerContext, ThreadedProducer},
+    message::{Header, Headers, Message, OwnedHeaders, ToBytes},
+    producer::{
+        BaseRecord, DeliveryResult, Producer, ProducerContext, PurgeConfig, ThreadedProducer,
+    },
     ClientContext,
 };
 
+use super::spill::{SpillFile, SpilledMessage};
+
 #[cfg(feature = "kafka-metrics")]
 use super::metrics::{
     KAFKA_AUDIT_DROPS_MSG_TOO_LARGE, KAFKA_AUDIT_DROPS_OTHER, KAFKA_AUDIT_DROPS_QUEUE_FULL,
     KAFKA_AUDIT_DROPS_TIMEOUT, KAFKA_AUDIT_EVENTS_DROPPED, KAFKA_AUDIT_EVENTS_SENT,
     KAFKA_AUDIT_EVENT_QUEUE_SIZE, KAFKA_DROPS_MSG_TOO_LARGE, KAFKA_DROPS_OTHER,
     KAFKA_DROPS_QUEUE_FULL, KAFKA_DROPS_TIMEOUT, KAFKA_LOGS_DROPPED, KAFKA_LOGS_SENT,
-    KAFKA_QUEUE_SIZE,
+    KAFKA_MESSAGES_REPLAYED, KAFKA_MESSAGES_SPILLED, KAFKA_QUEUE_SIZE,
+    KAFKA_TOPIC_DELIVERIES_FAILED, KAFKA_TOPIC_DELIVERIES_SUCCEEDED,
 };
 
-/// A `ProducerContext` that handles delivery callbacks to increment metrics.
+/// How often the spill file is checked for messages to replay
+const SPILL_REPLAY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often a blocked send retries while waiting for room in the producer queue
+const BLOCK_RETRY_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Longest a blocked send waits for room unless configured otherwise. Sends happen on the
+/// thread emitting the log, so the wait is kept to a few retries.
+const DEFAULT_BLOCK_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Topic and partition key of the log messages written on a thread, overriding the
+/// writer's topic and the default of no key
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecordRouting {
+    pub topic: Option<String>,
+    pub key: Option<String>,
+}
+
+thread_local! {
+    static RECORD_ROUTING: RefCell<RecordRouting> = const {
+        RefCell::new(RecordRouting {
+            topic: None,
+            key: None,
+        })
+    };
+}
+
+/// Routes the log messages written on this thread while `f` runs according to `routing`.
+pub(crate) fn with_record_routing<R>(routing: RecordRouting, f: impl FnOnce() -> R) -> R {
+    struct RestoreRouting(RecordRouting);
+
+    impl Drop for RestoreRouting {
+        fn drop(&mut self) {
+            RECORD_ROUTING.set(std::mem::take(&mut self.0));
+        }
+    }
+
+    let _restore = RestoreRouting(RECORD_ROUTING.replace(routing));
+    f()
+}
+
+/// What the writer does with a message when the producer queue is full
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Waits for room in the queue, blocking the calling thread for at most the block timeout
+    /// (50ms unless configured), then discards the new message
+    Block,
+    /// Drops the oldest messages to make room for the new one. librdkafka cannot remove a
+    /// single queued message, so every message waiting in the queue is purged; messages
+    /// already sent to a broker are kept.
+    DropOldest,
+    /// Discards the new message
+    #[default]
+    DropNewest,
+}
+
+/// Security settings of the connection to the brokers, translated into the librdkafka
+/// `security.protocol`, `sasl.*` and `ssl.*` keys
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SecurityConfig {
+    pub security_protocol: Option<String>,
+    pub sasl_mechanism: Option<String>,
+    pub sasl_username: Option<String>,
+    pub sasl_password: Option<SecretString>,
+    pub ssl_ca_location: Option<PathBuf>,
+}
+
+impl SecurityConfig {
+    /// Sets the configured keys on the client config.
+    pub(crate) fn apply(&self, config: &mut ClientConfig) {
+        if let Some(protocol) = &self.security_protocol {
+            config.set("security.protocol", protocol);
+        }
+        if let Some(mechanism) = &self.sasl_mechanism {
+            config.set("sasl.mechanism", mechanism);
+        }
+        if let Some(username) = &self.sasl_username {
+            config.set("sasl.username", username);
+        }
+        if let Some(password) = &self.sasl_password {
+            config.set("sasl.password", &password.0);
+        }
+        if let Some(ca_location) = &self.ssl_ca_location {
+            config.set("ssl.ca.location", ca_location.to_string_lossy());
+        }
+    }
+}
+
+/// A credential that is redacted in Debug output
 #[derive(Clone)]
-struct MetricsProducerContext;
+pub(crate) struct SecretString(pub String);
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+/// The producer operations the back-pressure policy relies on
+trait QueueProducer<R> {
+    fn try_send(&self, record: R) -> Result<(), (KafkaError, R)>;
+
+    /// Discards the messages waiting in the queue, not those already sent to a broker
+    fn drop_queued(&self);
+}
+
+impl<'a, K, P> QueueProducer<BaseRecord<'a, K, P, Box<MessageOpaque>>>
+    for ThreadedProducer<MetricsProducerContext>
+where
+    K: ToBytes + ?Sized,
+    P: ToBytes + ?Sized,
+{
+    fn try_send(
+        &self,
+        record: BaseRecord<'a, K, P, Box<MessageOpaque>>,
+    ) -> Result<(), (KafkaError, BaseRecord<'a, K, P, Box<MessageOpaque>>)> {
+        self.send(record)
+    }
+
+    fn drop_queued(&self) {
+        self.purge(PurgeConfig::default().queue());
+    }
+}
+
+/// Sends a record, handling a full producer queue according to `policy`.
+/// A record that could not be sent is handed back with the error.
+fn send_with_backpressure<R>(
+    producer: &impl QueueProducer<R>,
+    policy: BackpressurePolicy,
+    block_timeout: Duration,
+    record: R,
+) -> Result<(), (KafkaError, R)> {
+    let deadline = Instant::now().checked_add(block_timeout);
+    let mut record = record;
+    loop {
+        let rejected = match producer.try_send(record) {
+            Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), rejected)) => rejected,
+            result => return result,
+        };
+        match policy {
+            BackpressurePolicy::DropNewest => {
+                return Err((
+                    KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull),
+                    rejected,
+                ))
+            }
+            BackpressurePolicy::DropOldest => {
+                producer.drop_queued();
+                return producer.try_send(rejected);
+            }
+            BackpressurePolicy::Block => {
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    return Err((
+                        KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull),
+                        rejected,
+                    ));
+                }
+                std::thread::sleep(BLOCK_RETRY_INTERVAL);
+                record = rejected;
+            }
+        }
+    }
+}
+
+/// A `ProducerContext` that handles delivery callbacks to increment metrics
+/// and spills undelivered messages to disk when a spill file is configured.
+#[derive(Clone, Default)]
+struct MetricsProducerContext {
+    spill: Option<Arc<SpillFile>>,
+}
+
+impl MetricsProducerContext {
+    /// Appends a message that could not be produced to the spill file, if any.
+    fn spill<H: Headers>(
+        &self,
+        message_type: KafkaMessageType,
+        topic: &str,
+        key: Option<&[u8]>,
+        payload: Option<&[u8]>,
+        headers: Option<&H>,
+    ) {
+        let Some(spill) = &self.spill else {
+            return;
+        };
+        let message = SpilledMessage {
+            is_event: message_type == KafkaMessageType::Event,
+            topic: topic.to_string(),
+            key: key.map(<[u8]>::to_vec),
+            payload: payload.unwrap_or_default().to_vec(),
+            headers: headers
+                .map(|headers| {
+                    headers
+                        .iter()
+                        .map(|header| {
+                            (
+                                header.key.to_string(),
+                                header.value.unwrap_or_default().to_vec(),
+                            )
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        // Nothing can be logged from here without feeding the failure back into Kafka
+        let _spilled = spill.append(&message).unwrap_or(false);
+        #[cfg(feature = "kafka-metrics")]
+        if _spilled {
+            KAFKA_MESSAGES_SPILLED.inc();
+        }
+    }
+}
 
 impl ClientContext for MetricsProducerContext {}
 
 impl ProducerContext for MetricsProducerContext {
-    type DeliveryOpaque = Box<KafkaMessageType>;
+    type DeliveryOpaque = Box<MessageOpaque>;
 
     fn delivery(&self, delivery_result: &DeliveryResult<'_>, opaque: Self::DeliveryOpaque) {
-        let message_type = *opaque;
+        let MessageOpaque {
+            message_type,
+            enqueued_at,
+        } = *opaque;
         let is_success = delivery_result.is_ok();
+        let topic = match delivery_result {
+            Ok(message) | Err((_, message)) => message.topic(),
+        };
+        crate::metrics::produce_latency_seconds().observe(topic, enqueued_at.elapsed());
 
         #[cfg(feature = "kafka-metrics")]
         {
@@ -44,9 +267,32 @@ impl ProducerContext for MetricsProducerContext {
                 (KafkaMessageType::Log, true) => KAFKA_LOGS_SENT.inc(),
                 (KafkaMessageType::Log, false) => KAFKA_LOGS_DROPPED.inc(),
             }
+            if is_success {
+                KAFKA_TOPIC_DELIVERIES_SUCCEEDED
+                    .with_label_values(&[topic])
+                    .inc();
+            } else {
+                KAFKA_TOPIC_DELIVERIES_FAILED
+                    .with_label_values(&[topic])
+                    .inc();
+            }
         }
 
-        if let Err((kafka_error, _)) = delivery_result {
+        if let Err((kafka_error, message)) = delivery_result {
+            // Oversized messages would be rejected again on replay
+            if !matches!(
+                kafka_error,
+                KafkaError::MessageProduction(RDKafkaErrorCode::MessageSizeTooLarge)
+            ) {
+                self.spill(
+                    message_type,
+                    message.topic(),
+                    message.key(),
+                    message.payload(),
+                    message.headers(),
+                );
+            }
+
             #[cfg(feature = "kafka-metrics")]
             match (message_type, &kafka_error) {
                 (
@@ -97,23 +343,42 @@ impl ProducerContext for MetricsProducerContext {
 }
 
 /// This enum helps the callback distinguish between logs and events.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum KafkaMessageType {
     Event,
     Log,
 }
 
+/// Per-message state handed to the delivery callback.
+struct MessageOpaque {
+    message_type: KafkaMessageType,
+    /// When the message was first handed to the producer, for the produce latency
+    enqueued_at: Instant,
+}
+
+impl MessageOpaque {
+    fn new(message_type: KafkaMessageType) -> Box<Self> {
+        Box::new(Self {
+            message_type,
+            enqueued_at: Instant::now(),
+        })
+    }
+}
+
 /// Kafka writer that implements std::io::Write for seamless integration with tracing
 #[derive(Clone)]
 pub struct KafkaWriter {
     producer: Arc<ThreadedProducer<MetricsProducerContext>>,
     topic: String,
+    backpressure: BackpressurePolicy,
+    block_timeout: Duration,
 }
 
 impl std::fmt::Debug for KafkaWriter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KafkaWriter")
             .field("topic", &self.topic)
+            .field("backpressure", &self.backpressure)
             .finish()
     }
 }
@@ -130,9 +395,45 @@ impl KafkaWriter {
         queue_buffering_max_kbytes: Option<usize>,
         reconnect_backoff_min_ms: Option<u64>,
         reconnect_backoff_max_ms: Option<u64>,
+    ) -> Result<Self, KafkaWriterError> {
+        Self::create(
+            brokers,
+            topic,
+            batch_size,
+            linger_ms,
+            queue_buffering_max_messages,
+            queue_buffering_max_kbytes,
+            reconnect_backoff_min_ms,
+            reconnect_backoff_max_ms,
+            None,
+            BackpressurePolicy::default(),
+            None,
+            None,
+            SecurityConfig::default(),
+        )
+    }
+
+    /// Creates a new KafkaWriter, spilling messages that cannot be produced to
+    /// `<spill_dir>/<topic>.spill` when a spill directory and size bound are given.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create(
+        brokers: Vec<String>,
+        topic: String,
+        batch_size: Option<usize>,
+        linger_ms: Option<u64>,
+        queue_buffering_max_messages: Option<usize>,
+        queue_buffering_max_kbytes: Option<usize>,
+        reconnect_backoff_min_ms: Option<u64>,
+        reconnect_backoff_max_ms: Option<u64>,
+        spill: Option<(PathBuf, u64)>,
+        backpressure: BackpressurePolicy,
+        block_timeout: Option<Duration>,
+        delivery_timeout_ms: Option<u64>,
+        security: SecurityConfig,
     ) -> Result<Self, KafkaWriterError> {
         let mut config = ClientConfig::new();
         config.set("bootstrap.servers", brokers.join(","));
+        security.apply(&mut config);
 
         if let Some(min_backoff) = reconnect_backoff_min_ms {
             config.set("reconnect.backoff.ms", min_backoff.to_string());
@@ -152,9 +453,19 @@ impl KafkaWriter {
         if let Some(ms) = linger_ms {
             config.set("linger.ms", ms.to_string());
         }
+        if let Some(ms) = delivery_timeout_ms {
+            config.set("delivery.timeout.ms", ms.to_string());
+        }
+
+        let spill = spill
+            .map(|(dir, max_bytes)| SpillFile::open(&dir, &topic, max_bytes).map(Arc::new))
+            .transpose()
+            .map_err(KafkaWriterError::SpillFile)?;
 
         let producer: ThreadedProducer<MetricsProducerContext> = config
-            .create_with_context(MetricsProducerContext)
+            .create_with_context(MetricsProducerContext {
+                spill: spill.clone(),
+            })
             .map_err(KafkaWriterError::ProducerCreation)?;
 
         producer
@@ -162,35 +473,46 @@ impl KafkaWriter {
             .fetch_metadata(Some(&topic), Duration::from_secs(5))
             .map_err(KafkaWriterError::MetadataFetch)?;
 
+        let producer = Arc::new(producer);
+        if let Some(spill) = spill {
+            spawn_spill_replay(Arc::downgrade(&producer), spill, topic.clone())
+                .map_err(KafkaWriterError::SpillFile)?;
+        }
+
         Ok(Self {
-            producer: Arc::new(producer),
+            producer,
             topic,
+            backpressure,
+            block_timeout: block_timeout.unwrap_or(DEFAULT_BLOCK_TIMEOUT),
         })
     }
 
-    /// Publishes a single event to Kafka. This method is non-blocking.
-    /// Returns an error if the message cannot be enqueued to the producer's buffer.
+    /// Publishes a single event to Kafka. This method is non-blocking unless the
+    /// `Block` back-pressure policy is used.
+    /// Returns an error if the message cannot be enqueued to the producer's buffer,
+    /// `KafkaWriterError::QueueFull` when it was dropped because the queue was full.
     pub fn publish_event(
         &self,
         topic: &str,
         key: Option<&str>,
         payload: &[u8],
         headers: Option<OwnedHeaders>,
-    ) -> Result<(), KafkaError> {
+    ) -> Result<(), KafkaWriterError> {
         #[cfg(feature = "kafka-metrics")]
         {
             let queue_size = self.producer.in_flight_count();
             KAFKA_AUDIT_EVENT_QUEUE_SIZE.set(queue_size.into());
         }
 
-        let mut record = BaseRecord::with_opaque_to(topic, Box::new(KafkaMessageType::Event))
-            .payload(payload)
-            .timestamp(
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_millis().try_into().unwrap_or(0))
-                    .unwrap_or(0),
-            );
+        let mut record =
+            BaseRecord::with_opaque_to(topic, MessageOpaque::new(KafkaMessageType::Event))
+                .payload(payload)
+                .timestamp(
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_millis().try_into().unwrap_or(0))
+                        .unwrap_or(0),
+                );
 
         if let Some(k) = key {
             record = record.key(k);
@@ -200,9 +522,22 @@ impl KafkaWriter {
             record = record.headers(h);
         }
 
-        match self.producer.send(record) {
+        match send_with_backpressure(
+            self.producer.as_ref(),
+            self.backpressure,
+            self.block_timeout,
+            record,
+        ) {
             Ok(_) => Ok(()),
-            Err((kafka_error, _)) => {
+            Err((kafka_error, record)) => {
+                self.producer.context().spill(
+                    KafkaMessageType::Event,
+                    record.topic,
+                    record.key.map(str::as_bytes),
+                    record.payload,
+                    record.headers.as_ref(),
+                );
+
                 #[cfg(feature = "kafka-metrics")]
                 {
                     KAFKA_AUDIT_EVENTS_DROPPED.inc();
@@ -217,11 +552,124 @@ impl KafkaWriter {
                         }
                     }
                 }
-                Err(kafka_error)
+                Err(send_error(kafka_error))
             }
         }
     }
 
+    /// Publishes a batch of events to `topic`, each given as its key, payload and headers.
+    ///
+    /// The events are enqueued in order, handling a full queue according to the back-pressure
+    /// policy, and the producer is polled once at the end. Returns how many events were
+    /// enqueued before the queue was full; the rest are spilled to disk when a spill file is
+    /// configured. Other enqueue errors are returned as `KafkaWriterError::Produce`.
+    pub fn publish_events(
+        &self,
+        topic: &str,
+        events: &[(Option<&str>, &[u8], Option<OwnedHeaders>)],
+    ) -> Result<usize, KafkaWriterError> {
+        #[cfg(feature = "kafka-metrics")]
+        {
+            let queue_size = self.producer.in_flight_count();
+            KAFKA_AUDIT_EVENT_QUEUE_SIZE.set(queue_size.into());
+        }
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis().try_into().unwrap_or(0))
+            .unwrap_or(0);
+        let mut accepted = 0;
+        let mut send_result = Ok(());
+        for (key, payload, headers) in events {
+            let mut record =
+                BaseRecord::with_opaque_to(topic, MessageOpaque::new(KafkaMessageType::Event))
+                    .payload(*payload)
+                    .timestamp(timestamp);
+            if let Some(k) = key {
+                record = record.key(*k);
+            }
+            if let Some(h) = headers {
+                record = record.headers(h.clone());
+            }
+
+            if let Err((kafka_error, _)) = send_with_backpressure(
+                self.producer.as_ref(),
+                self.backpressure,
+                self.block_timeout,
+                record,
+            ) {
+                send_result = Err(kafka_error);
+                break;
+            }
+            accepted += 1;
+        }
+        self.producer.poll(Duration::ZERO);
+
+        let Err(kafka_error) = send_result else {
+            return Ok(accepted);
+        };
+        for (key, payload, headers) in events.iter().skip(accepted) {
+            self.producer.context().spill(
+                KafkaMessageType::Event,
+                topic,
+                key.map(str::as_bytes),
+                Some(*payload),
+                headers.as_ref(),
+            );
+        }
+
+        #[cfg(feature = "kafka-metrics")]
+        {
+            let dropped = u64::try_from(events.len() - accepted).unwrap_or(u64::MAX);
+            KAFKA_AUDIT_EVENTS_DROPPED.inc_by(dropped);
+            match &kafka_error {
+                KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull) => {
+                    KAFKA_AUDIT_DROPS_QUEUE_FULL.inc_by(dropped);
+                }
+                _ => {
+                    KAFKA_AUDIT_DROPS_OTHER.inc_by(dropped);
+                }
+            }
+        }
+        match send_error(kafka_error) {
+            KafkaWriterError::QueueFull => Ok(accepted),
+            error => Err(error),
+        }
+    }
+
+    /// Probes broker connectivity by fetching the metadata of the writer's topic.
+    /// This call blocks for up to `timeout`.
+    pub fn check_connectivity(&self, timeout: Duration) -> Result<(), KafkaWriterError> {
+        self.producer
+            .client()
+            .fetch_metadata(Some(&self.topic), timeout)
+            .map(|_| ())
+            .map_err(KafkaWriterError::MetadataFetch)
+    }
+
+    /// Waits up to `timeout` for the queued messages to be delivered.
+    ///
+    /// Returns `KafkaWriterError::Flush` with rdkafka's timeout error when messages are still
+    /// queued after `timeout`.
+    pub fn flush(&self, timeout: Duration) -> Result<(), KafkaWriterError> {
+        self.producer
+            .flush(timeout)
+            .map_err(KafkaWriterError::Flush)
+    }
+
+    /// Creates a writer for `topic` that reuses this writer's producer.
+    ///
+    /// Layers and publishers built from the returned writer share one connection to the
+    /// brokers, and the producer is flushed when the last of them is dropped.
+    pub fn with_topic(&self, topic: impl Into<String>) -> Self {
+        Self {
+            producer: Arc::clone(&self.producer),
+            topic: topic.into(),
+            backpressure: self.backpressure,
+            block_timeout: self.block_timeout,
+        }
+    }
+
     /// Creates a new builder for constructing a KafkaWriter
     pub fn builder() -> crate::builder::KafkaWriterBuilder {
         crate::builder::KafkaWriterBuilder::new()
@@ -236,16 +684,35 @@ impl Write for KafkaWriter {
             KAFKA_QUEUE_SIZE.set(queue_size.into());
         }
 
-        let record = BaseRecord::with_opaque_to(&self.topic, Box::new(KafkaMessageType::Log))
-            .payload(buf)
-            .timestamp(
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_millis().try_into().unwrap_or(0))
-                    .unwrap_or(0),
+        let routing = RECORD_ROUTING.with_borrow(Clone::clone);
+        let topic = routing.topic.as_deref().unwrap_or(&self.topic);
+        let mut record: BaseRecord<'_, [u8], [u8], _> =
+            BaseRecord::with_opaque_to(topic, MessageOpaque::new(KafkaMessageType::Log))
+                .payload(buf)
+                .timestamp(
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_millis().try_into().unwrap_or(0))
+                        .unwrap_or(0),
+                );
+        if let Some(key) = &routing.key {
+            record = record.key(key.as_bytes());
+        }
+
+        if let Err((kafka_error, record)) = send_with_backpressure(
+            self.producer.as_ref(),
+            self.backpressure,
+            self.block_timeout,
+            record,
+        ) {
+            self.producer.context().spill(
+                KafkaMessageType::Log,
+                record.topic,
+                record.key,
+                record.payload,
+                record.headers.as_ref(),
             );
 
-        if let Err((kafka_error, _)) = self.producer.send::<(), [u8]>(record) {
             #[cfg(feature = "kafka-metrics")]
             {
                 KAFKA_LOGS_DROPPED.inc();
@@ -280,6 +747,79 @@ pub enum KafkaWriterError {
     ProducerCreation(KafkaError),
     #[error("Failed to fetch Kafka metadata: {0}")]
     MetadataFetch(KafkaError),
+    #[error("Failed to set up Kafka spill file: {0}")]
+    SpillFile(io::Error),
+    #[error("Kafka producer queue is full, message dropped")]
+    QueueFull,
+    #[error("Failed to enqueue Kafka message: {0}")]
+    Produce(KafkaError),
+    #[error("Failed to flush Kafka producer: {0}")]
+    Flush(KafkaError),
+}
+
+fn send_error(kafka_error: KafkaError) -> KafkaWriterError {
+    match kafka_error {
+        KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull) => KafkaWriterError::QueueFull,
+        kafka_error => KafkaWriterError::Produce(kafka_error),
+    }
+}
+
+/// Starts a background thread that produces spilled messages again once the brokers are
+/// reachable. The thread exits when the last writer sharing the producer is dropped.
+fn spawn_spill_replay(
+    producer: Weak<ThreadedProducer<MetricsProducerContext>>,
+    spill: Arc<SpillFile>,
+    topic: String,
+) -> io::Result<()> {
+    std::thread::Builder::new()
+        .name("kafka-spill-replay".to_string())
+        .spawn(move || loop {
+            std::thread::sleep(SPILL_REPLAY_INTERVAL);
+            let Some(producer) = producer.upgrade() else {
+                break;
+            };
+            if spill.is_empty()
+                || producer
+                    .client()
+                    .fetch_metadata(Some(&topic), SPILL_REPLAY_INTERVAL)
+                    .is_err()
+            {
+                continue;
+            }
+
+            let _replayed = spill.replay(|message| {
+                let message_type = if message.is_event {
+                    KafkaMessageType::Event
+                } else {
+                    KafkaMessageType::Log
+                };
+                let mut record = BaseRecord::<'_, [u8], [u8], _>::with_opaque_to(
+                    &message.topic,
+                    MessageOpaque::new(message_type),
+                )
+                .payload(message.payload.as_slice());
+                if let Some(key) = &message.key {
+                    record = record.key(key.as_slice());
+                }
+                if !message.headers.is_empty() {
+                    record = record.headers(message.headers.iter().fold(
+                        OwnedHeaders::new(),
+                        |headers, (key, value)| {
+                            headers.insert(Header {
+                                key,
+                                value: Some(value),
+                            })
+                        },
+                    ));
+                }
+                producer.send(record).is_ok()
+            });
+            #[cfg(feature = "kafka-metrics")]
+            if let Ok(replayed) = _replayed {
+                KAFKA_MESSAGES_REPLAYED.inc_by(replayed.try_into().unwrap_or(u64::MAX));
+            }
+        })
+        .map(|_| ())
 }
 
 /// Make KafkaWriter compatible with tracing_appender's MakeWriter trait.
@@ -303,3 +843,455 @@ impl Drop for KafkaWriter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::{KafkaLayer, PartitionKeyStrategy};
+
+    /// Creating a producer does not contact the brokers, only the metadata fetch in `new` does
+    fn unconnected_writer(topic: &str) -> KafkaWriter {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", "localhost:9092")
+            .create_with_context(MetricsProducerContext::default())
+            .expect("Failed to create Kafka producer");
+        KafkaWriter {
+            producer: Arc::new(producer),
+            topic: topic.to_string(),
+            backpressure: BackpressurePolicy::default(),
+            block_timeout: DEFAULT_BLOCK_TIMEOUT,
+        }
+    }
+
+    #[test]
+    fn layers_built_from_shared_writer_reuse_one_producer() {
+        let writer = unconnected_writer("application-logs");
+
+        let application_layer = KafkaLayer::builder()
+            .shared_writer(writer.clone())
+            .build()
+            .unwrap();
+        let audit_layer = KafkaLayer::builder()
+            .shared_writer(writer.with_topic("audit-logs"))
+            .build()
+            .unwrap();
+
+        let application_writer = application_layer.writer();
+        let audit_writer = audit_layer.writer();
+        assert!(Arc::ptr_eq(
+            &application_writer.producer,
+            &audit_writer.producer
+        ));
+        assert_eq!(application_writer.topic, "application-logs");
+        assert_eq!(audit_writer.topic, "audit-logs");
+    }
+
+    /// Producer whose queue holds one message. A full queue frees its slot after
+    /// `drain_after` rejected sends, or never when unset.
+    struct MockProducer {
+        queue: std::cell::RefCell<Vec<&'static str>>,
+        rejections: std::cell::Cell<usize>,
+        drain_after: Option<usize>,
+    }
+
+    impl MockProducer {
+        fn full(drain_after: Option<usize>) -> Self {
+            Self {
+                queue: std::cell::RefCell::new(vec!["queued"]),
+                rejections: std::cell::Cell::new(0),
+                drain_after,
+            }
+        }
+    }
+
+    impl QueueProducer<&'static str> for MockProducer {
+        fn try_send(&self, record: &'static str) -> Result<(), (KafkaError, &'static str)> {
+            let mut queue = self.queue.borrow_mut();
+            if !queue.is_empty() {
+                self.rejections.set(self.rejections.get() + 1);
+                if self.drain_after != Some(self.rejections.get()) {
+                    return Err((
+                        KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull),
+                        record,
+                    ));
+                }
+                queue.clear();
+            }
+            queue.push(record);
+            Ok(())
+        }
+
+        fn drop_queued(&self) {
+            self.queue.borrow_mut().clear();
+        }
+    }
+
+    #[test]
+    fn drop_newest_rejects_the_new_message() {
+        let producer = MockProducer::full(None);
+
+        let (error, record) = send_with_backpressure(
+            &producer,
+            BackpressurePolicy::DropNewest,
+            DEFAULT_BLOCK_TIMEOUT,
+            "new",
+        )
+        .unwrap_err();
+
+        assert_eq!(record, "new");
+        assert!(matches!(send_error(error), KafkaWriterError::QueueFull));
+        assert_eq!(*producer.queue.borrow(), ["queued"]);
+    }
+
+    #[test]
+    fn drop_oldest_makes_room_for_the_new_message() {
+        let producer = MockProducer::full(None);
+
+        send_with_backpressure(
+            &producer,
+            BackpressurePolicy::DropOldest,
+            DEFAULT_BLOCK_TIMEOUT,
+            "new",
+        )
+        .unwrap();
+
+        assert_eq!(*producer.queue.borrow(), ["new"]);
+    }
+
+    #[test]
+    fn block_waits_for_room_in_the_queue() {
+        let producer = MockProducer::full(Some(3));
+
+        send_with_backpressure(
+            &producer,
+            BackpressurePolicy::Block,
+            DEFAULT_BLOCK_TIMEOUT,
+            "new",
+        )
+        .unwrap();
+
+        assert_eq!(producer.rejections.get(), 3);
+        assert_eq!(*producer.queue.borrow(), ["new"]);
+    }
+
+    #[test]
+    fn block_gives_up_after_the_timeout() {
+        let producer = MockProducer::full(None);
+
+        let (error, record) = send_with_backpressure(
+            &producer,
+            BackpressurePolicy::Block,
+            Duration::from_millis(50),
+            "new",
+        )
+        .unwrap_err();
+
+        assert_eq!(record, "new");
+        assert!(matches!(send_error(error), KafkaWriterError::QueueFull));
+        assert_eq!(*producer.queue.borrow(), ["queued"]);
+    }
+
+    #[test]
+    fn flush_returns_promptly_when_queue_is_empty() {
+        let layer = KafkaLayer::builder()
+            .shared_writer(unconnected_writer("application-logs"))
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        layer.flush(Duration::from_secs(5)).unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn flush_times_out_while_messages_are_queued() {
+        let mut writer = unconnected_writer("application-logs");
+        let layer = KafkaLayer::builder()
+            .shared_writer(writer.clone())
+            .build()
+            .unwrap();
+        writer.write_all(b"Refund created").unwrap();
+
+        let error = layer.flush(Duration::from_millis(100)).unwrap_err();
+
+        assert!(matches!(
+            error,
+            crate::KafkaLayerError::Writer(KafkaWriterError::Flush(KafkaError::Flush(
+                RDKafkaErrorCode::OperationTimedOut
+            )))
+        ));
+        // Spares the drop of the last writer its own flush
+        writer.producer.purge(PurgeConfig::default().queue());
+    }
+
+    #[test]
+    fn publish_events_stops_when_the_queue_is_full() {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", "localhost:9092")
+            .set("queue.buffering.max.messages", "5000")
+            .create_with_context(MetricsProducerContext::default())
+            .expect("Failed to create Kafka producer");
+        let writer = KafkaWriter {
+            producer: Arc::new(producer),
+            topic: "application-logs".to_string(),
+            backpressure: BackpressurePolicy::DropNewest,
+            block_timeout: DEFAULT_BLOCK_TIMEOUT,
+        };
+        let payloads: Vec<Vec<u8>> = (0..10_000)
+            .map(|i| format!("{{\"event_id\":{i}}}").into_bytes())
+            .collect();
+        let events: Vec<(Option<&str>, &[u8], Option<OwnedHeaders>)> = payloads
+            .iter()
+            .map(|payload| (Some("merchant_123"), payload.as_slice(), None))
+            .collect();
+
+        let started = Instant::now();
+        let accepted = writer.publish_events("custom-events", &events).unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(accepted, 5000);
+        assert_eq!(writer.producer.in_flight_count(), 5000);
+        // Spares the drop of the last writer its own flush
+        writer.producer.purge(PurgeConfig::default().queue());
+    }
+
+    #[cfg(feature = "kafka-metrics")]
+    #[test]
+    fn produce_latency_recorded_on_delivery_report() {
+        let writer = unconnected_writer("application-logs");
+        writer
+            .publish_event("produce-latency-test", None, b"{\"event_id\":1}", None)
+            .unwrap();
+
+        // Purged messages get a failed delivery report, which is still timed
+        writer.producer.purge(PurgeConfig::default().queue());
+        writer.flush(Duration::from_secs(5)).unwrap();
+
+        assert!(
+            crate::metrics::produce_latency_seconds().sample_count("produce-latency-test") >= 1
+        );
+    }
+
+    /// Layer producing to unreachable brokers, whose records can be read back with
+    /// `produced_records`
+    fn spilling_layer(
+        name: &str,
+        builder: crate::layer::KafkaLayerBuilder,
+    ) -> (KafkaLayer, KafkaWriter, Arc<SpillFile>) {
+        let spill_dir =
+            std::env::temp_dir().join(format!("tracing-kafka-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&spill_dir);
+        let spill = Arc::new(SpillFile::open(&spill_dir, "logs", u64::MAX).unwrap());
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", "localhost:9092")
+            .create_with_context(MetricsProducerContext {
+                spill: Some(Arc::clone(&spill)),
+            })
+            .expect("Failed to create Kafka producer");
+        let writer = KafkaWriter {
+            producer: Arc::new(producer),
+            topic: "application-logs".to_string(),
+            backpressure: BackpressurePolicy::default(),
+            block_timeout: DEFAULT_BLOCK_TIMEOUT,
+        };
+        let layer = builder.shared_writer(writer.clone()).build().unwrap();
+        (layer, writer, spill)
+    }
+
+    /// Records queued by the producer, in order
+    fn produced_records(writer: &KafkaWriter, spill: &SpillFile) -> Vec<SpilledMessage> {
+        // The brokers are unreachable, so purging the queued records spills them with
+        // the topic and key they were produced with
+        writer.producer.purge(PurgeConfig::default().queue());
+        writer.flush(Duration::from_secs(5)).unwrap();
+        let mut records = Vec::new();
+        spill
+            .replay(|message| {
+                records.push(message.clone());
+                true
+            })
+            .unwrap();
+        records
+    }
+
+    /// Partition key of the record of the event logged with `message`
+    fn event_key(records: &[SpilledMessage], message: &str) -> Option<Vec<u8>> {
+        records
+            .iter()
+            .find(|record| String::from_utf8_lossy(&record.payload).contains(message))
+            .expect("event record produced")
+            .key
+            .clone()
+    }
+
+    #[test]
+    fn topic_field_routes_event_to_named_topic() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "topic-field",
+            KafkaLayer::builder().topic_field("kafka_topic"),
+        );
+
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(kafka_topic = "audit-logs", "Refund approved");
+            tracing::info!("Refund created");
+        });
+
+        let topics: Vec<String> = produced_records(&writer, &spill)
+            .into_iter()
+            .map(|record| record.topic)
+            .collect();
+        assert_eq!(topics, ["audit-logs", "application-logs"]);
+    }
+
+    #[test]
+    fn excluded_field_left_out_of_produced_json() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "exclude-fields",
+            KafkaLayer::builder()
+                .include_fields(&["merchant_id", "raw_body"])
+                .exclude_fields(&["raw_body"]),
+        );
+
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(
+                merchant_id = "merchant_123",
+                raw_body = "0xdeadbeef",
+                "Refund approved"
+            );
+        });
+
+        let records = produced_records(&writer, &spill);
+        let payload = String::from_utf8_lossy(&records.first().unwrap().payload).into_owned();
+        assert!(payload.contains("Refund approved"));
+        assert!(payload.contains("merchant_123"));
+        assert!(!payload.contains("raw_body"));
+        assert!(!payload.contains("0xdeadbeef"));
+    }
+
+    #[test]
+    fn include_fields_drops_other_fields() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "include-fields",
+            KafkaLayer::builder().include_fields(&["merchant_id"]),
+        );
+
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(
+                merchant_id = "merchant_123",
+                connector = "adyen",
+                amount = 1000,
+                card = ?vec![4, 1, 1, 1],
+                "Refund approved"
+            );
+        });
+
+        let records = produced_records(&writer, &spill);
+        let payload: serde_json::Value =
+            serde_json::from_slice(&records.first().unwrap().payload).unwrap();
+        assert_eq!(payload["merchant_id"], "merchant_123");
+        assert!(payload.to_string().contains("Refund approved"));
+        assert!(payload.get("connector").is_none());
+        assert!(payload.get("amount").is_none());
+        assert!(payload.get("card").is_none());
+    }
+
+    #[test]
+    fn span_id_partition_key_used_inside_a_span() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "span-id",
+            KafkaLayer::builder().partition_key(PartitionKeyStrategy::SpanId),
+        );
+
+        let subscriber = tracing_subscriber::registry().with(layer);
+        let span_id = tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("Refund created");
+            let span = tracing::info_span!("refund");
+            span.in_scope(|| tracing::info!("Refund approved"));
+            span.id().unwrap()
+        });
+
+        let records = produced_records(&writer, &spill);
+        assert_eq!(event_key(&records, "Refund created"), None);
+        assert_eq!(
+            event_key(&records, "Refund approved"),
+            Some(format!("{:016x}", span_id.into_u64()).into_bytes())
+        );
+    }
+
+    #[test]
+    fn field_partition_key_taken_from_the_event() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "field",
+            KafkaLayer::builder()
+                .partition_key(PartitionKeyStrategy::Field("request_id".to_string())),
+        );
+
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(request_id = "req_123", "Refund approved");
+            tracing::info!("Refund created");
+        });
+
+        let records = produced_records(&writer, &spill);
+        assert_eq!(
+            event_key(&records, "Refund approved"),
+            Some(b"req_123".to_vec())
+        );
+        assert_eq!(event_key(&records, "Refund created"), None);
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn trace_id_partition_key_taken_from_the_otel_context() {
+        use opentelemetry::trace::{
+            noop::NoopTracer, SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+        };
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (layer, writer, spill) = spilling_layer(
+            "trace-id",
+            KafkaLayer::builder().partition_key(PartitionKeyStrategy::TraceId),
+        );
+
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(NoopTracer::new()))
+            .with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("Refund created");
+            let span = tracing::info_span!("refund");
+            span.set_parent(opentelemetry::Context::new().with_remote_span_context(
+                SpanContext::new(
+                    TraceId::from(42_u128),
+                    SpanId::from(7_u64),
+                    TraceFlags::SAMPLED,
+                    true,
+                    TraceState::default(),
+                ),
+            ));
+            span.in_scope(|| tracing::info!("Refund approved"));
+        });
+
+        let records = produced_records(&writer, &spill);
+        assert_eq!(event_key(&records, "Refund created"), None);
+        assert_eq!(
+            event_key(&records, "Refund approved"),
+            Some(format!("{:032x}", 42_u128).into_bytes())
+        );
+    }
+}